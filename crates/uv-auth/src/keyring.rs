@@ -1,4 +1,6 @@
-use std::{io::Write, process::Stdio};
+use std::io::Write;
+use std::process::{ExitStatus, Stdio};
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use url::Url;
@@ -15,12 +17,23 @@ pub struct KeyringProvider {
     backend: KeyringProviderBackend,
 }
 
+/// An error encountered while updating credentials in a keyring.
+#[derive(Debug, thiserror::Error)]
+pub enum KeyringError {
+    #[error("Failed to run the `keyring` command; is it installed?")]
+    Spawn(#[source] std::io::Error),
+    #[error("Failed to communicate with the `keyring` command")]
+    Io(#[source] std::io::Error),
+    #[error("`keyring {0}` failed with {1}")]
+    Failed(&'static str, ExitStatus),
+}
+
 #[derive(Debug)]
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
     #[cfg(test)]
-    Dummy(Vec<(String, &'static str, &'static str)>),
+    Dummy(std::sync::Mutex<Vec<(String, String, String)>>),
 }
 
 impl KeyringProvider {
@@ -60,7 +73,7 @@ impl KeyringProvider {
             }
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
-                Self::fetch_dummy(store, url.as_str(), username)
+                Self::fetch_dummy(&store.lock().unwrap(), url.as_str(), username)
             }
         };
         // And fallback to a check for the host
@@ -75,7 +88,7 @@ impl KeyringProvider {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
-                    Self::fetch_dummy(store, &host, username)
+                    Self::fetch_dummy(&store.lock().unwrap(), &host, username)
                 }
             };
         }
@@ -83,6 +96,88 @@ impl KeyringProvider {
         credentials.map(|(username, password)| Credentials::basic(Some(username), Some(password)))
     }
 
+    /// Store the password for the given [`Url`] and username in the keyring.
+    ///
+    /// The credentials are stored under the full URL, such that they're found by [`Self::fetch`].
+    #[instrument(skip(self, password), fields(url = % url.to_string(), username))]
+    pub async fn store(
+        &self,
+        url: &Url,
+        username: &str,
+        password: &str,
+    ) -> Result<(), KeyringError> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                Self::run_subprocess("set", url.as_str(), username, Some(password)).await
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => {
+                let mut store = store.lock().unwrap();
+                store.retain(|(service, user, _)| service != url.as_str() || user != username);
+                store.push((url.to_string(), username.to_string(), password.to_string()));
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove the password for the given [`Url`] and username from the keyring.
+    #[instrument(skip(self), fields(url = % url.to_string(), username))]
+    pub async fn remove(&self, url: &Url, username: &str) -> Result<(), KeyringError> {
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                Self::run_subprocess("del", url.as_str(), username, None).await
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => {
+                store
+                    .lock()
+                    .unwrap()
+                    .retain(|(service, user, _)| service != url.as_str() || user != username);
+                Ok(())
+            }
+        }
+    }
+
+    /// Run a `keyring` subcommand that updates the credentials for a service and username,
+    /// passing the password (if any) on stdin.
+    async fn run_subprocess(
+        subcommand: &'static str,
+        service_name: &str,
+        username: &str,
+        password: Option<&str>,
+    ) -> Result<(), KeyringError> {
+        let mut child = Command::new("keyring")
+            .arg(subcommand)
+            .arg(service_name)
+            .arg(username)
+            .stdin(if password.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::null())
+            // Stream stderr, so the user has visibility into any failures in the keyring backend
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(KeyringError::Spawn)?;
+
+        if let Some(password) = password {
+            // `keyring set` reads the password from stdin, up to the first newline.
+            let mut stdin = child.stdin.take().expect("stdin is piped");
+            stdin
+                .write_all(format!("{password}\n").as_bytes())
+                .await
+                .map_err(KeyringError::Io)?;
+        }
+
+        let status = child.wait().await.map_err(KeyringError::Io)?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(KeyringError::Failed(subcommand, status))
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(
         &self,
@@ -177,13 +272,13 @@ impl KeyringProvider {
 
     #[cfg(test)]
     fn fetch_dummy(
-        store: &Vec<(String, &'static str, &'static str)>,
+        store: &[(String, String, String)],
         service_name: &str,
         username: Option<&str>,
     ) -> Option<(String, String)> {
         store.iter().find_map(|(service, user, password)| {
-            if service == service_name && username.is_none_or(|username| username == *user) {
-                Some(((*user).to_string(), (*password).to_string()))
+            if service == service_name && username.is_none_or(|username| username == user) {
+                Some((user.clone(), password.clone()))
             } else {
                 None
             }
@@ -196,11 +291,13 @@ impl KeyringProvider {
         iter: T,
     ) -> Self {
         Self {
-            backend: KeyringProviderBackend::Dummy(
+            backend: KeyringProviderBackend::Dummy(std::sync::Mutex::new(
                 iter.into_iter()
-                    .map(|(service, username, password)| (service.into(), username, password))
+                    .map(|(service, username, password)| {
+                        (service.into(), username.to_string(), password.to_string())
+                    })
                     .collect(),
-            ),
+            )),
         }
     }
 
@@ -208,7 +305,7 @@ impl KeyringProvider {
     #[cfg(test)]
     pub fn empty() -> Self {
         Self {
-            backend: KeyringProviderBackend::Dummy(Vec::new()),
+            backend: KeyringProviderBackend::Dummy(std::sync::Mutex::default()),
         }
     }
 }
@@ -359,4 +456,32 @@ mod tests {
         let credentials = keyring.fetch(&url, Some("bar")).await;
         assert_eq!(credentials, None);
     }
+
+    #[tokio::test]
+    async fn store_and_remove() {
+        let url = Url::parse("https://example.com/simple").unwrap();
+        let keyring = KeyringProvider::empty();
+
+        keyring.store(&url, "user", "password").await.unwrap();
+        assert_eq!(
+            keyring.fetch(&url, Some("user")).await,
+            Some(Credentials::basic(
+                Some("user".to_string()),
+                Some("password".to_string())
+            ))
+        );
+
+        // Storing again replaces the existing password
+        keyring.store(&url, "user", "other").await.unwrap();
+        assert_eq!(
+            keyring.fetch(&url, Some("user")).await,
+            Some(Credentials::basic(
+                Some("user".to_string()),
+                Some("other".to_string())
+            ))
+        );
+
+        keyring.remove(&url, "user").await.unwrap();
+        assert_eq!(keyring.fetch(&url, Some("user")).await, None);
+    }
 }
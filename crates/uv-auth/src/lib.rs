@@ -6,7 +6,7 @@ use url::Url;
 use cache::CredentialsCache;
 pub use credentials::Credentials;
 pub use index::{AuthPolicy, Index, Indexes};
-pub use keyring::KeyringProvider;
+pub use keyring::{KeyringError, KeyringProvider};
 pub use middleware::AuthMiddleware;
 use realm::Realm;
 
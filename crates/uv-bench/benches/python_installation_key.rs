@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use uv_bench::criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkId, Criterion,
+    Throughput,
+};
+use uv_python::PythonInstallationKey;
+
+/// The number of keys to sort in each benchmark iteration.
+const NUM_KEYS: usize = 10_000;
+
+/// Generate a deterministic set of synthetic installation keys, covering multiple
+/// implementations, platforms, variants, and pre-releases.
+fn synthetic_keys() -> Vec<PythonInstallationKey> {
+    let implementations = ["cpython", "pypy", "graalpy"];
+    let prereleases = ["", "a1", "a7", "b2", "rc1", "rc3"];
+    let variants = ["", "+freethreaded"];
+    let platforms = [
+        "linux-x86_64-gnu",
+        "linux-aarch64-musl",
+        "macos-aarch64-none",
+        "windows-x86_64-none",
+    ];
+
+    let mut keys = Vec::with_capacity(NUM_KEYS);
+    let mut index = 0usize;
+    while keys.len() < NUM_KEYS {
+        let implementation = implementations[index % implementations.len()];
+        let minor = 8 + (index / 3) % 7;
+        let patch = (index / 7) % 20;
+        let prerelease = prereleases[(index / 11) % prereleases.len()];
+        let variant = variants[(index / 13) % variants.len()];
+        let platform = platforms[(index / 17) % platforms.len()];
+        let key = format!("{implementation}-3.{minor}.{patch}{prerelease}{variant}-{platform}");
+        keys.push(PythonInstallationKey::from_str(&key).expect("valid installation key"));
+        index += 1;
+    }
+    keys
+}
+
+/// Benchmarks sorting installation keys, as done when listing or upgrading Python installations.
+///
+/// The keys are constructed outside the timed loop, so this only measures the comparisons.
+fn benchmark_sort_installation_keys(c: &mut Criterion<WallTime>) {
+    let keys = synthetic_keys();
+
+    let mut group = c.benchmark_group("python_installation_key_sort");
+    group.throughput(Throughput::Elements(
+        u64::try_from(keys.len()).expect("length fits in u64"),
+    ));
+    group.bench_function(BenchmarkId::from_parameter(NUM_KEYS), |b| {
+        b.iter_batched(
+            || keys.clone(),
+            |mut keys| {
+                keys.sort();
+                keys
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(uv_python_installation_key, benchmark_sort_installation_keys);
+criterion_main!(uv_python_installation_key);
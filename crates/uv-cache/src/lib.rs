@@ -973,6 +973,17 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// Downloaded Python distribution archives, e.g., from `python-build-standalone`.
+    ///
+    /// Archives are keyed by their download URL and expected SHA256 digest, such that a change to
+    /// the digest in the downloads metadata invalidates the entry.
+    ///
+    /// Remote download metadata, as used by `uv python list --outdated`, is cached alongside the
+    /// archives.
+    ///
+    /// Cache structure: `python-v0/<digest(url, sha256)>/<filename>` and
+    /// `python-v0/downloads-<digest(url)>.json`
+    Python,
 }
 
 impl CacheBucket {
@@ -983,7 +994,7 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v6",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
             Self::Simple => "simple-v15",
@@ -995,6 +1006,7 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
+            Self::Python => "python-v0",
         }
     }
 
@@ -1096,7 +1108,12 @@ impl CacheBucket {
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
-            Self::Git | Self::Interpreter | Self::Archive | Self::Builds | Self::Environments => {
+            Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Python => {
                 // Nothing to do.
             }
         }
@@ -1115,6 +1132,7 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Python,
         ]
         .iter()
         .copied()
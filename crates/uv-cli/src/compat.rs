@@ -329,9 +329,6 @@ impl CompatArgs for VenvCompatArgs {
 pub struct PipInstallCompatArgs {
     #[clap(long, hide = true)]
     disable_pip_version_check: bool,
-
-    #[clap(long, hide = false)]
-    user: bool,
 }
 
 impl CompatArgs for PipInstallCompatArgs {
@@ -345,12 +342,6 @@ impl CompatArgs for PipInstallCompatArgs {
             warn_user!("pip's `--disable-pip-version-check` has no effect");
         }
 
-        if self.user {
-            return Err(anyhow!(
-                "pip's `--user` is unsupported (use a virtual environment instead)"
-            ));
-        }
-
         Ok(())
     }
 }
@@ -12,13 +12,17 @@ use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
     ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ProjectBuildBackend, PythonPin, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
+};
+use uv_distribution_types::{
+    Index, IndexName, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex,
 };
-use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
-use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_pypi_types::{HashAlgorithm, VerbatimParsedUrl};
+use uv_python::platform::{Arch, TargetPlatform};
+use uv_python::{BuildFlavor, MirrorList, PythonDownloads, PythonPreference, PythonVersion};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -45,6 +49,24 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ToolListFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonInstallFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -57,6 +79,27 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipHashAlgorithm {
+    /// Compute a SHA-256 digest.
+    #[default]
+    Sha256,
+    /// Compute a SHA-384 digest.
+    Sha384,
+    /// Compute a SHA-512 digest.
+    Sha512,
+}
+
+impl From<PipHashAlgorithm> for HashAlgorithm {
+    fn from(algorithm: PipHashAlgorithm) -> Self {
+        match algorithm {
+            PipHashAlgorithm::Sha256 => Self::Sha256,
+            PipHashAlgorithm::Sha384 => Self::Sha384,
+            PipHashAlgorithm::Sha512 => Self::Sha512,
+        }
+    }
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -397,6 +440,7 @@ pub enum Commands {
     /// - `<version-specifier>` e.g. `>=3.12,<3.13`
     /// - `<implementation>` e.g. `cpython` or `cp`
     /// - `<implementation>@<version>` e.g. `cpython@3.12`
+    /// - `latest` or `<implementation>@latest` e.g. `pypy@latest`, for the newest stable version
     /// - `<implementation><version>` e.g. `cpython3.12` or `cp312`
     /// - `<implementation><version-specifier>` e.g. `cpython>=3.12,<3.13`
     /// - `<implementation>-<version>-<os>-<arch>-<libc>` e.g. `cpython-3.12.3-macos-aarch64-none`
@@ -487,6 +531,18 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Read or update settings in a `uv.toml` file.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
+    /// Manage the package indexes in a configuration file.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -625,6 +681,165 @@ pub enum CacheCommand {
     Dir,
 }
 
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Show the value of a setting.
+    ///
+    /// Reads the setting from the `uv.toml` file in the project directory, or from the user-level
+    /// `uv.toml` file if `--user` is provided. Strings are printed without quotes; other values are
+    /// printed as TOML.
+    Get(ConfigGetArgs),
+    /// Update the value of a setting.
+    ///
+    /// Writes the setting to the `uv.toml` file in the project directory, or to the user-level
+    /// `uv.toml` file if `--user` is provided, creating the file if it does not exist. Existing
+    /// comments and formatting are preserved.
+    ///
+    /// The value is parsed as TOML (e.g., `true`, `4`, or `["foo", "bar"]`), falling back to a
+    /// string if it is not valid TOML, or if the setting expects a string (e.g., `3.12` for
+    /// `pip.python-version`).
+    Set(ConfigSetArgs),
+    /// List the active settings and where they were set.
+    ///
+    /// Includes settings from environment variables and from the project, user, and system
+    /// configuration files, in order of precedence. Each value is annotated with the environment
+    /// variable or file that set it. Values that are overridden by a source with higher
+    /// precedence are marked as such; arrays are merged across sources instead.
+    List,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigGetArgs {
+    /// The setting to read, as a dotted key (e.g., `pip.index-url`).
+    ///
+    /// A `tool.uv.` prefix, as used in `pyproject.toml` files, is accepted and ignored.
+    pub key: String,
+
+    /// Read from the user-level configuration file, rather than the project's `uv.toml`.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigSetArgs {
+    /// The setting to update, as a dotted key (e.g., `pip.index-url`).
+    ///
+    /// A `tool.uv.` prefix, as used in `pyproject.toml` files, is accepted and ignored.
+    pub key: String,
+
+    /// The value to assign to the setting.
+    #[arg(allow_hyphen_values = true)]
+    pub value: String,
+
+    /// Write to the user-level configuration file, rather than the project's `uv.toml`.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Add a package index.
+    ///
+    /// Writes a `[[index]]` entry to the `uv.toml` file in the project directory or, if there is
+    /// no `uv.toml` file, a `[[tool.uv.index]]` entry to the `pyproject.toml` file in the project
+    /// directory. If neither file exists, a `uv.toml` file is created. With `--user`, the entry
+    /// is written to the user-level `uv.toml` file instead. Existing comments and formatting are
+    /// preserved.
+    ///
+    /// The index is added with the highest priority. An existing index with the same name or URL
+    /// is replaced.
+    Add(IndexAddArgs),
+    /// Remove a package index.
+    ///
+    /// Removes the index with the given name from the same file that `uv index add` writes to.
+    Remove(IndexRemoveArgs),
+    /// List the package indexes.
+    ///
+    /// Lists the indexes in the same file that `uv index add` writes to, in order of priority.
+    List(IndexListArgs),
+    /// Store credentials for a package index in the system keyring.
+    ///
+    /// The credentials are stored with the `keyring` command, under the URL of the index with the
+    /// given name. The index is marked with `authenticate = "always"`, such that uv retrieves the
+    /// credentials from the keyring whenever it accesses the index with the keyring provider
+    /// enabled (e.g., with `--keyring-provider subprocess`).
+    ///
+    /// With `--delete`, the stored credentials are removed instead, and the index's
+    /// `authenticate` setting is reset.
+    Auth(IndexAuthArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexAddArgs {
+    /// The name of the index (e.g., `pytorch`).
+    pub name: IndexName,
+
+    /// The URL of the index (e.g., `https://download.pytorch.org/whl/cpu`).
+    pub url: IndexUrl,
+
+    /// Use the index as the default index, in place of PyPI.
+    ///
+    /// Replaces any existing default index.
+    #[arg(long)]
+    pub default: bool,
+
+    /// Write to the user-level configuration file, rather than the project's configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexRemoveArgs {
+    /// The name of the index to remove.
+    pub name: IndexName,
+
+    /// Write to the user-level configuration file, rather than the project's configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexAuthArgs {
+    /// The name of the index.
+    pub name: IndexName,
+
+    /// The username to store the credentials under.
+    #[arg(long, default_value = "__token__")]
+    pub username: String,
+
+    /// The token (or password) to store.
+    #[arg(long, required_unless_present = "delete", conflicts_with = "delete")]
+    pub token: Option<String>,
+
+    /// Remove the stored credentials, rather than storing new ones.
+    #[arg(long)]
+    pub delete: bool,
+
+    /// Use the index in the user-level configuration file, rather than the project's
+    /// configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexListArgs {
+    /// Read from the user-level configuration file, rather than the project's configuration.
+    #[arg(long)]
+    pub user: bool,
+}
+
 #[derive(Args, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CleanArgs {
@@ -725,6 +940,14 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Compute hashes for local or remote package archives.
+    ///
+    /// The output is suitable for use with `--require-hashes` in a `requirements.txt` file.
+    #[command(
+        after_help = "Use `uv help pip hash` for more details.",
+        after_long_help = ""
+    )]
+    Hash(PipHashArgs),
 }
 
 #[derive(Subcommand)]
@@ -1035,6 +1258,41 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// A value provided to `uv venv --seed`.
+#[derive(Debug, Clone)]
+pub enum SeedPackage {
+    /// A boolean, as provided via `UV_VENV_SEED`, to enable or disable the default seed packages.
+    Flag(bool),
+    /// A package to seed, with an optional version specifier, e.g., `pip==24.0`.
+    Requirement(Requirement<VerbatimParsedUrl>),
+}
+
+/// Parse a seed package for `uv venv --seed`, accepting boolean values for compatibility with
+/// `UV_VENV_SEED`.
+fn parse_seed_package(input: &str) -> Result<SeedPackage, String> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "y" | "yes" | "t" | "true" | "on" | "1" => return Ok(SeedPackage::Flag(true)),
+        "n" | "no" | "f" | "false" | "off" | "0" => return Ok(SeedPackage::Flag(false)),
+        _ => {}
+    }
+
+    let requirement =
+        Requirement::<VerbatimParsedUrl>::from_str(input).map_err(|err| err.to_string())?;
+    if !requirement.extras.is_empty()
+        || !requirement.marker.is_true()
+        || matches!(
+            requirement.version_or_url,
+            Some(uv_pep508::VersionOrUrl::Url(_))
+        )
+    {
+        return Err(
+            "seed packages must be a package name with an optional version specifier, e.g., `pip==24.0`"
+                .to_string(),
+        );
+    }
+    Ok(SeedPackage::Requirement(requirement))
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
@@ -1273,6 +1531,15 @@ pub struct PipCompileArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Options to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    ///
+    /// The options are passed via `config_settings` when extracting metadata from source
+    /// distributions, such that the resolution reflects the configuration used for building.
+    ///
+    /// Equivalent to `--config-setting`; the two may be combined.
+    #[arg(long, help_heading = "Build options")]
+    pub build_option: Vec<ConfigSettingEntry>,
+
     /// The Python version to use for resolution.
     ///
     /// For example, `3.8` or `3.8.17`.
@@ -1840,6 +2107,31 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Install packages into the user `site-packages` directory (e.g.,
+    /// `~/.local/lib/python3.12/site-packages`), rather than into a virtual or system environment.
+    ///
+    /// The user base directory can be changed with the `PYTHONUSERBASE` environment variable.
+    #[arg(long, conflicts_with_all = ["target", "prefix"])]
+    pub user: bool,
+
+    /// Install packages into a temporary virtual environment, which is removed once the
+    /// installation completes.
+    ///
+    /// Useful for verifying that packages install cleanly into a fresh environment. Combine with
+    /// `--test-command` to exercise the installed packages before the environment is removed.
+    #[arg(long, conflicts_with_all = ["target", "prefix", "user", "dry_run"])]
+    pub isolated: bool,
+
+    /// A command to run in the temporary virtual environment once the installation completes.
+    ///
+    /// The command is run with the system shell (i.e., `sh -c` on Unix and `cmd /C` on Windows),
+    /// with the virtual environment's executables directory prepended to the `PATH`. uv exits with
+    /// the exit status of the command.
+    ///
+    /// Requires `--isolated`.
+    #[arg(long, requires = "isolated", value_name = "COMMAND")]
+    pub test_command: Option<String>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1935,6 +2227,22 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the installed packages to the given file.
+    ///
+    /// The report follows the format of pip's `--report`, listing each installed package along with
+    /// its version, where it was obtained from (`download_info`), whether it was installed from a
+    /// direct URL (`is_direct`), and whether it was requested directly (`requested`).
+    #[arg(long, conflicts_with = "dry_run")]
+    pub report: Option<PathBuf>,
+
+    /// Write detailed logs to the given file.
+    ///
+    /// All debug-level log messages (e.g., HTTP requests, the rationale for each selected
+    /// distribution, and the installation of each file) are appended to the file, regardless of
+    /// `--verbose`. The output on stderr is unaffected.
+    #[arg(long, value_name = "FILE")]
+    pub log: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2040,6 +2348,13 @@ pub struct PipUninstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Exit with an error if any of the specified packages are not installed.
+    ///
+    /// By default, packages that are not installed are skipped with a warning. With this option,
+    /// uv exits with a non-zero status instead, without uninstalling any packages.
+    #[arg(long)]
+    pub break_on_not_installed: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipGlobalCompatArgs,
 }
@@ -2118,6 +2433,13 @@ pub struct PipListArgs {
     #[arg(long, value_enum, default_value_t = ListFormat::default())]
     pub format: ListFormat,
 
+    /// Display the list of packages as JSON.
+    ///
+    /// Equivalent to `--format json`. Each package is represented as an object with a `name` and
+    /// `version`; with `--outdated`, the `latest_version` and `latest_filetype` are included too.
+    #[arg(long, conflicts_with = "format")]
+    pub json: bool,
+
     /// List outdated packages.
     ///
     /// The latest version of each package will be shown alongside the installed version. Up-to-date
@@ -2128,6 +2450,12 @@ pub struct PipListArgs {
     #[arg(long, overrides_with("outdated"), hide = true)]
     pub no_outdated: bool,
 
+    /// List packages that are not required by any other installed package.
+    ///
+    /// Useful for finding top-level packages, which may be safe to remove.
+    #[arg(long)]
+    pub not_required: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2211,6 +2539,17 @@ pub struct PipCheckArgs {
     pub no_system: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct PipHashArgs {
+    /// The paths or URLs of the package archives to hash.
+    #[arg(required = true, value_name = "FILE")]
+    pub files: Vec<String>,
+
+    /// The hash algorithm to use.
+    #[arg(long, short, value_enum, default_value_t = PipHashAlgorithm::default())]
+    pub algorithm: PipHashAlgorithm,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipShowArgs {
@@ -2518,8 +2857,19 @@ pub struct VenvArgs {
     /// Install seed packages (one or more of: `pip`, `setuptools`, and `wheel`) into the virtual environment.
     ///
     /// Note that `setuptools` and `wheel` are not included in Python 3.12+ environments.
-    #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_VENV_SEED)]
-    pub seed: bool,
+    ///
+    /// To install a different set of seed packages, provide a comma-separated list of package
+    /// names with optional version specifiers, e.g., `--seed=pip==24.0,setuptools`.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        value_delimiter = ',',
+        value_name = "PACKAGES",
+        value_parser = parse_seed_package,
+        env = EnvVars::UV_VENV_SEED
+    )]
+    pub seed: Option<Vec<SeedPackage>>,
 
     /// Preserve any existing files or directories at the target path.
     ///
@@ -2533,6 +2883,29 @@ pub struct VenvArgs {
     #[clap(long)]
     pub allow_existing: bool,
 
+    /// Re-link an existing virtual environment to the requested Python interpreter.
+    ///
+    /// Useful when the base interpreter of an environment was moved, replaced, or upgraded to a new
+    /// patch version. The environment's executables, activation scripts, and `pyvenv.cfg` are
+    /// regenerated, while any installed packages are left untouched. The Python minor version of an
+    /// existing environment cannot be changed.
+    ///
+    /// If there is no virtual environment at the target path, a new one is created.
+    #[clap(long, conflicts_with = "allow_existing")]
+    pub relink: bool,
+
+    /// Check that an existing virtual environment still matches the Python interpreter it was
+    /// created with.
+    ///
+    /// uv records the full Python installation key, including the patch version, of the base
+    /// interpreter in `pyvenv.cfg`. If the interpreter reachable through the environment differs
+    /// from the recorded one (e.g., because a managed Python installation was upgraded in-place),
+    /// the difference is reported and uv exits with a non-zero status.
+    ///
+    /// Use `--relink` to update the environment to its current base interpreter.
+    #[clap(long, conflicts_with_all = ["allow_existing", "relink", "seed"])]
+    pub check: bool,
+
     /// The path to the virtual environment to create.
     ///
     /// Default to `.venv` in the working directory.
@@ -2551,6 +2924,13 @@ pub struct VenvArgs {
     #[arg(long, verbatim_doc_comment)]
     pub prompt: Option<String>,
 
+    /// The shell to show the activation command for.
+    ///
+    /// By default, uv detects the shell from the parent process (on Unix) or the environment,
+    /// falling back to the POSIX `activate` script if the shell can't be determined.
+    #[arg(long, value_enum, env = EnvVars::UV_VENV_SHELL)]
+    pub shell: Option<uv_shell::Shell>,
+
     /// Give the virtual environment access to the system site packages directory.
     ///
     /// Unlike `pip`, when a virtual environment is created with `--system-site-packages`, uv will
@@ -2794,9 +3174,22 @@ pub struct InitArgs {
     ///
     /// By default, uv will create a `.python-version` file containing the minor version of the
     /// discovered Python interpreter, which will cause subsequent uv commands to use that version.
+    ///
+    /// Equivalent to `--pin none`.
     #[arg(long)]
     pub no_pin_python: bool,
 
+    /// The version to record in the project's `.python-version` file.
+    ///
+    /// By default, uv pins the minor version of the discovered Python interpreter (`minor`). Use
+    /// `--pin patch` to pin the exact patch version of the interpreter, or `--pin none` to avoid
+    /// creating a `.python-version` file, in which case only `requires-python` is recorded.
+    ///
+    /// A request for an exact patch version (e.g., `--python 3.12.4`) is pinned as-is, unless
+    /// `--pin none` is provided.
+    #[arg(long, value_enum, conflicts_with_all = ["no_pin_python", "pin_python"])]
+    pub pin: Option<PythonPin>,
+
     /// Create a `.python-version` file for the project.
     ///
     /// This is the default.
@@ -3014,6 +3407,15 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_sync: bool,
 
+    /// Recreate the project environment if it doesn't provide an exactly requested Python version.
+    ///
+    /// When `--python` requests an exact patch version (e.g., `3.10.8`) that the existing project
+    /// environment doesn't provide (e.g., because its interpreter was upgraded in-place), uv exits
+    /// with an error by default. With this option, the environment is recreated with a matching
+    /// interpreter instead.
+    #[arg(long, conflicts_with = "no_sync")]
+    pub recreate_venv: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -3044,6 +3446,15 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Arguments to pass to the Python interpreter, before the script or module.
+    ///
+    /// The value is split into separate arguments on whitespace, respecting single and double
+    /// quotes, e.g., `--python-args "-X dev -W error"` will run `python -X dev -W error <script>`.
+    ///
+    /// Has no effect when the command is not run with the Python interpreter.
+    #[arg(long, allow_hyphen_values = true, value_name = "ARGS")]
+    pub python_args: Option<String>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3234,6 +3645,15 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Recreate the project environment if it doesn't provide an exactly requested Python version.
+    ///
+    /// When `--python` requests an exact patch version (e.g., `3.10.8`) that the existing project
+    /// environment doesn't provide (e.g., because its interpreter was upgraded in-place), uv exits
+    /// with an error by default. With this option, the environment is recreated with a matching
+    /// interpreter instead.
+    #[arg(long)]
+    pub recreate_venv: bool,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3262,6 +3682,21 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Exclude the given package(s) from the resolution, as they're provided externally.
+    ///
+    /// Any dependency on an excluded package is ignored during resolution, such that the package
+    /// is neither locked nor installed. Excluded packages are recorded as external in the
+    /// lockfile, and remain excluded in subsequent operations unless a different set of packages
+    /// is provided, or `--no-exclude-package` is used. Use this for packages that are available at
+    /// runtime, but not from any index.
+    #[arg(long, conflicts_with = "no_exclude_package")]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Clear the packages excluded from the resolution by a previous `--exclude-package`, such that
+    /// they're locked and installed again.
+    #[arg(long)]
+    pub no_exclude_package: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
@@ -3365,6 +3800,18 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Verify the packages installed in the project environment against the lockfile.
+    ///
+    /// For each package that the lockfile requires in the environment (respecting the requested
+    /// extras and dependency groups), uv checks that the package is installed at the locked
+    /// version, then recomputes the SHA256 hash of every file listed in the package's `RECORD` and
+    /// compares it to the recorded hash.
+    ///
+    /// Any mismatches are reported, and uv will exit with an error. The lockfile and the
+    /// environment are never modified.
+    #[arg(long, conflicts_with_all = ["dry_run", "check"])]
+    pub verify_hashes: bool,
 }
 
 #[derive(Args)]
@@ -3399,6 +3846,41 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Exclude the given package(s) from the resolution, as they're provided externally.
+    ///
+    /// Any dependency on an excluded package is ignored during resolution, such that the package
+    /// is omitted from the lockfile. Excluded packages are recorded as external in the lockfile,
+    /// and remain excluded in subsequent operations unless a different set of packages is
+    /// provided, or `--no-exclude-package` is used. Use this for packages that are available at
+    /// runtime, but not from any index.
+    #[arg(long, conflicts_with = "no_exclude_package")]
+    pub exclude_package: Vec<PackageName>,
+
+    /// Clear the packages excluded from the resolution by a previous `--exclude-package`, such that
+    /// they're locked again.
+    #[arg(long)]
+    pub no_exclude_package: bool,
+
+    /// The platform for which the lockfile should be resolved.
+    ///
+    /// By default, uv locks for all platforms (subject to `tool.uv.environments`). When a target
+    /// platform is provided, the resolution is limited to environments matching that platform's
+    /// `sys_platform` and `platform_machine` markers, regardless of the current host.
+    ///
+    /// The target platform is recorded in the lockfile, and reused by subsequent operations unless
+    /// a different platform is provided, or `--no-python-platform` is used.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    #[arg(long, conflicts_with = "no_python_platform")]
+    pub python_platform: Option<TargetTriple>,
+
+    /// Lock for all platforms, clearing the target platform recorded in the lockfile by a previous
+    /// `--python-platform`.
+    #[arg(long)]
+    pub no_python_platform: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3595,9 +4077,23 @@ pub struct AddArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(required_unless_present = "all")]
     pub packages: Vec<Requirement<VerbatimParsedUrl>>,
 
+    /// Remove all dependencies.
+    ///
+    /// By default, all dependencies are removed from `project.dependencies`. Use with `--dev`,
+    /// `--optional`, or `--group` to remove all dependencies from the given table instead.
+    #[arg(long, conflicts_with = "packages")]
+    pub all: bool,
+
+    /// Remove all dependencies without asking for confirmation.
+    ///
+    /// Without `--yes`, `--all` prompts for confirmation, and fails if the terminal is not
+    /// interactive.
+    #[arg(long, requires = "all")]
+    pub yes: bool,
+
     /// Remove the packages from the development dependency group.
     ///
     /// This option is an alias for `--group dev`.
@@ -3691,6 +4187,32 @@ pub struct TreeArgs {
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
+    /// Show the paths from the project to the given package, rather than the dependency tree.
+    ///
+    /// Each path is displayed on a single line, e.g., `project → a → b → package`, explaining
+    /// why the package is included in the dependency tree. At most 100 paths are shown for each
+    /// package.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE", conflicts_with_all = ["package", "invert"])]
+    pub why: Vec<PackageName>,
+
+    /// Display the dependency tree as JSON.
+    ///
+    /// The output is a JSON object containing the name and version of the project, along with a
+    /// recursive `dependencies` array, in which each package has a `name`, `version`, `extras`,
+    /// and `dependencies`.
+    #[arg(long, conflicts_with = "why")]
+    pub json: bool,
+
+    /// Report any circular dependencies, rather than displaying the dependency tree.
+    ///
+    /// Each cycle is displayed on a single line, e.g., `a → b → c → a`. Every elementary cycle (in
+    /// which no package appears twice) is reported once, up to 100 cycles. If any cycles are found,
+    /// uv will exit with a non-zero status code.
+    #[arg(long, conflicts_with_all = ["why", "json"])]
+    pub cycles: bool,
+
     /// Include the development dependency group.
     ///
     /// Development dependencies are defined via `dependency-groups.dev` or
@@ -4139,6 +4661,15 @@ pub struct ToolRunArgs {
     #[arg(long)]
     pub from: Option<String>,
 
+    /// Run the version of the tool that's pinned in the current project's lockfile.
+    ///
+    /// The package name is assumed to match the command name. The version of that package in the
+    /// `uv.lock` of the enclosing project is used, e.g., `uv tool run --from-lockfile ruff` runs
+    /// the locked version of `ruff`. The project environment and any installed tools are left
+    /// unchanged.
+    #[arg(long, conflicts_with = "from")]
+    pub from_lockfile: bool,
+
     /// Run with the given packages installed.
     #[arg(long)]
     pub with: Vec<comma::CommaSeparatedRequirements>,
@@ -4255,6 +4786,14 @@ pub struct ToolInstallArgs {
     #[arg(long, hide = true)]
     pub from: Option<String>,
 
+    /// Include optional dependencies from the specified extra name of the target package; may be
+    /// provided more than once.
+    ///
+    /// Equivalent to including the extras in the package specifier, e.g., `uv tool install
+    /// --extras zmq ipython` is equivalent to `uv tool install 'ipython[zmq]'`.
+    #[arg(long, alias = "extra", value_parser = extra_name_with_clap_error)]
+    pub extras: Vec<ExtraName>,
+
     /// Include the following additional requirements.
     #[arg(long)]
     pub with: Vec<comma::CommaSeparatedRequirements>,
@@ -4347,6 +4886,19 @@ pub struct ToolListArgs {
     #[arg(long)]
     pub show_with: bool,
 
+    /// Select the output format.
+    ///
+    /// With `json`, the installed tools are printed as an array of objects, each with the tool's
+    /// `name`, `version`, `entrypoints`, and `python` version.
+    #[arg(long, value_enum, default_value_t = ToolListFormat::default(), conflicts_with_all = ["show_paths", "show_version_specifiers", "show_with"])]
+    pub output_format: ToolListFormat,
+
+    /// Display the installed tools as JSON.
+    ///
+    /// Equivalent to `--output-format json`.
+    #[arg(long, conflicts_with_all = ["output_format", "show_paths", "show_version_specifiers", "show_with"])]
+    pub json: bool,
+
     // Hide unused global Python options.
     #[arg(long, hide = true)]
     pub python_preference: Option<PythonPreference>,
@@ -4397,6 +4949,11 @@ pub struct ToolUpgradeArgs {
     #[arg(long, conflicts_with("name"))]
     pub all: bool,
 
+    /// Perform a dry run, i.e., don't actually upgrade anything but resolve the latest versions and
+    /// print the upgrades that would be performed.
+    #[arg(long)]
+    pub dry_run: bool,
+
     /// Upgrade a tool, and specify it to use the given Python interpreter to build its environment.
     /// Use with `--all` to apply to all tools.
     ///
@@ -4692,6 +5249,13 @@ pub enum PythonCommand {
 
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
+
+    /// Generate Python download metadata for a directory of python-build-standalone archives.
+    ///
+    /// Computes the SHA-256 digest of each archive and writes a downloads JSON document that can
+    /// be passed to `--python-downloads-json-url` to install Python from a mirror.
+    #[command(hide = true)]
+    Manifest(PythonManifestArgs),
 }
 
 #[derive(Args)]
@@ -4732,6 +5296,19 @@ pub struct PythonListArgs {
     #[arg(long, conflicts_with("only_installed"))]
     pub only_downloads: bool,
 
+    /// Only show managed Python installations with a newer patch version available.
+    ///
+    /// Each installation is annotated with the version it can be upgraded to, e.g.,
+    /// `3.12.8 -> 3.12.10`. Only the latest installed patch version of each minor version is
+    /// considered. System Python installations are not shown.
+    ///
+    /// If `--python-downloads-json-url` is a remote URL, the download metadata is fetched and
+    /// cached. With `--offline`, the cached metadata is used instead, and its age is shown.
+    ///
+    /// In verbose mode, the annotation is shown for all listed installations.
+    #[arg(long, conflicts_with("only_downloads"))]
+    pub outdated: bool,
+
     /// Show the URLs of available Python downloads.
     ///
     /// By default, these display as `<download available>`.
@@ -4767,6 +5344,30 @@ pub struct PythonDirArgs {
     pub bin: bool,
 }
 
+#[derive(Args)]
+pub struct PythonManifestArgs {
+    /// The directory containing the python-build-standalone archives.
+    pub directory: PathBuf,
+
+    /// The URL from which the archives are served.
+    ///
+    /// Defaults to the `file://` URL of the archive directory.
+    #[arg(long)]
+    pub base_url: Option<Url>,
+
+    /// An existing Python downloads JSON document to merge with, e.g., the upstream
+    /// `download-metadata.json`.
+    ///
+    /// The URLs of its entries are rewritten to the base URL. Entries for the archives in the
+    /// directory take precedence.
+    #[arg(long, value_name = "PATH")]
+    pub upstream: Option<PathBuf>,
+
+    /// Write the metadata to the given path, rather than to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonInstallArgs {
@@ -4797,18 +5398,26 @@ pub struct PythonInstallArgs {
     /// `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g.,
     /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
     ///
+    /// Multiple mirrors can be provided, separated by whitespace, in which case each mirror is
+    /// tried in order until the download succeeds. If every mirror is unavailable, the default
+    /// source is used.
+    ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
-    pub mirror: Option<String>,
+    pub mirror: Option<MirrorList>,
 
     /// Set the URL to use as the source for downloading PyPy installations.
     ///
     /// The provided URL will replace `https://downloads.python.org/pypy` in, e.g.,
     /// `https://downloads.python.org/pypy/pypy3.8-v7.3.7-osx64.tar.bz2`.
     ///
+    /// Multiple mirrors can be provided, separated by whitespace, in which case each mirror is
+    /// tried in order until the download succeeds. If every mirror is unavailable, the default
+    /// source is used.
+    ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
-    pub pypy_mirror: Option<String>,
+    pub pypy_mirror: Option<MirrorList>,
 
     /// URL pointing to JSON of custom Python installations.
     ///
@@ -4844,6 +5453,164 @@ pub struct PythonInstallArgs {
     /// If multiple Python versions are requested, uv will exit with an error.
     #[arg(long)]
     pub default: bool,
+
+    /// Allow installing pre-release Python versions.
+    ///
+    /// By default, pre-release versions are only installed when requested explicitly, e.g.,
+    /// `3.14.0rc2`. With this flag, a request such as `3.14` may be satisfied by a pre-release if
+    /// no stable version is available.
+    #[arg(long)]
+    pub allow_prereleases: bool,
+
+    /// The architecture to install Python for, e.g., `x86_64`.
+    ///
+    /// By default, Python is installed for the architecture of the current machine. A different
+    /// architecture may be requested if the machine can emulate it, e.g., `x86_64` on an Apple
+    /// Silicon Mac with Rosetta 2.
+    #[arg(long)]
+    pub arch: Option<Arch>,
+
+    /// The platform to download Python for, e.g., `linux-aarch64-gnu`.
+    ///
+    /// Accepts `<os>-<arch>[-<libc>]`. On Linux, the libc defaults to `gnu`. Since the
+    /// distribution can't run on the current machine, this requires `--download-only`.
+    #[arg(long, requires = "download_only", conflicts_with = "arch")]
+    pub platform: Option<TargetPlatform>,
+
+    /// The build flavor to install, e.g., `pgo+lto` or `noopt`.
+    ///
+    /// Python distributions may be available in multiple flavors, which differ in the
+    /// optimizations applied when building the interpreter. By default, the fastest available
+    /// flavor is installed. Unoptimized builds (`noopt`) are useful for debugging or profiling C
+    /// extensions.
+    ///
+    /// Different flavors of the same Python version are installed side-by-side.
+    #[arg(long)]
+    pub build_flavor: Option<BuildFlavor>,
+
+    /// Download the Python distribution archives without installing them.
+    ///
+    /// The archives are written to the directory provided with `--out-dir`, alongside a
+    /// `<archive>.json` file recording the installation key, the download URL, and the SHA256
+    /// digest of the archive. The archives can be used to provision other machines, e.g., via
+    /// `UV_PYTHON_INSTALL_MIRROR`.
+    #[arg(
+        long,
+        requires = "out_dir",
+        conflicts_with_all = ["reinstall", "force", "default", "compile_bytecode", "keep"]
+    )]
+    pub download_only: bool,
+
+    /// The directory to write downloaded archives to, with `--download-only`.
+    #[arg(long, requires = "download_only")]
+    pub out_dir: Option<PathBuf>,
+
+    /// Compile the standard library of the installed Python versions to bytecode.
+    ///
+    /// Managed Python distributions are shipped without bytecode, so the first import of each
+    /// standard library module is slower than it would otherwise be. Compiling ahead of time
+    /// trades a longer installation for faster startup of the installed interpreters.
+    ///
+    /// Versions that are already installed are left as-is.
+    #[arg(long)]
+    pub compile_bytecode: bool,
+
+    /// Mark the installed Python versions as kept.
+    ///
+    /// Kept versions are skipped by `uv python uninstall` unless `--force` is provided. The mark
+    /// is preserved across reinstalls. Use `--no-keep` to remove the mark, including from versions
+    /// that are already installed.
+    #[arg(long, overrides_with = "no_keep")]
+    pub keep: bool,
+
+    /// Remove the mark that the installed Python versions are kept.
+    #[arg(long, overrides_with = "keep")]
+    pub no_keep: bool,
+
+    /// Mark the installed Python versions as externally managed (the default).
+    ///
+    /// Writes the standard `EXTERNALLY-MANAGED` file (PEP 668), such that `pip` refuses to install
+    /// packages into the managed interpreter. Use `--externally-managed=false` or
+    /// `--no-externally-managed` to remove the mark, including from versions that are already
+    /// installed.
+    ///
+    /// If not provided, existing installations retain their current state, and new installations
+    /// inherit the state of the installation they upgrade, if any.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        value_name = "BOOL",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with = "no_externally_managed"
+    )]
+    pub externally_managed: Option<bool>,
+
+    /// Do not mark the installed Python versions as externally managed, allowing `pip` to
+    /// install packages into them.
+    #[arg(long, overrides_with = "externally_managed")]
+    pub no_externally_managed: bool,
+
+    /// Link the executables of the installed Python versions into the bin directory.
+    ///
+    /// By default, executables are only linked in preview mode; this flag links them regardless.
+    /// Use `--bin=false` or `--no-bin` to skip linking.
+    ///
+    /// If not provided, existing installations retain the choice made when they were installed,
+    /// and new installations inherit the choice of the installation they upgrade, if any.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        value_name = "BOOL",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        env = EnvVars::UV_PYTHON_INSTALL_BIN,
+        overrides_with = "no_bin"
+    )]
+    pub bin: Option<bool>,
+
+    /// Do not link the executables of the installed Python versions into the bin directory.
+    ///
+    /// The choice is recorded, such that later installations of the same version, and upgrades to
+    /// newer patch versions, do not link executables unless `--bin` is provided.
+    #[arg(long, overrides_with = "bin", conflicts_with = "default")]
+    pub no_bin: bool,
+
+    /// Also link the windowed executable of the installed Python versions into the bin directory,
+    /// e.g., `pythonw3.12.exe`, which runs without opening a console window.
+    ///
+    /// Only supported on Windows.
+    #[arg(long, conflicts_with = "no_bin")]
+    pub windowed: bool,
+
+    /// Add the bin directory to the `PATH`, if it isn't already.
+    ///
+    /// When executables are linked into a bin directory that isn't on the `PATH`, uv prints
+    /// instructions for the current shell by default. With this flag, uv instead appends the
+    /// necessary command to the shell's configuration files (e.g., `.bashrc`, `.zshenv`, or
+    /// `config.fish`), or updates the user `PATH` in the registry on Windows. Repeated installs
+    /// do not duplicate the entry.
+    #[arg(long, overrides_with = "no_modify_path")]
+    pub modify_path: bool,
+
+    #[arg(long, overrides_with = "modify_path", hide = true)]
+    pub no_modify_path: bool,
+
+    /// Fail immediately if another uv process holds the lock on the Python installation
+    /// directory, instead of waiting for it to be released.
+    #[arg(long)]
+    pub no_wait: bool,
+
+    /// Select the output format.
+    ///
+    /// With `json`, a summary of each requested installation is written to stdout, including
+    /// whether it was downloaded, the bytes downloaded, the time spent in each phase, the
+    /// post-install steps that ran, and the shared libraries that were checked and patched, and
+    /// any that failed. Failures are not shown as warnings in this mode.
+    #[arg(long, value_enum, default_value_t = PythonInstallFormat::default())]
+    pub output_format: PythonInstallFormat,
 }
 
 #[derive(Args)]
@@ -4860,8 +5627,26 @@ pub struct PythonUninstallArgs {
     pub targets: Vec<String>,
 
     /// Uninstall all managed Python versions.
+    ///
+    /// Before uninstalling, a report of the affected installations, executables, and virtual
+    /// environments is shown, and confirmation is requested.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Skip the confirmation prompt when uninstalling all managed Python versions.
+    #[arg(long, requires = "all")]
+    pub yes: bool,
+
+    /// Uninstall Python versions even if they are marked as kept.
+    ///
+    /// See `uv python install --keep`.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Fail immediately if another uv process holds the lock on the Python installation
+    /// directory, instead of waiting for it to be released.
+    #[arg(long)]
+    pub no_wait: bool,
 }
 
 #[derive(Args)]
@@ -4911,6 +5696,12 @@ pub struct PythonFindArgs {
     /// Show the Python version that would be used instead of the path to the interpreter.
     #[arg(long)]
     pub show_version: bool,
+
+    /// Show where the Python request came from, e.g., a project or global `.python-version` file.
+    ///
+    /// The source is written to stderr, such that stdout only contains the interpreter.
+    #[arg(long, conflicts_with = "script")]
+    pub show_source: bool,
 }
 
 #[derive(Args)]
@@ -4939,6 +5730,29 @@ pub struct PythonPinArgs {
     #[arg(long, overrides_with("no_resolved"), hide = true)]
     pub no_resolved: bool,
 
+    /// Write the fully-resolved Python version instead of the request, e.g., `3.12.9` for `3.12`.
+    ///
+    /// The request is resolved against the installed Python interpreters. If no request is
+    /// provided, the currently pinned version is resolved instead. Alternative implementations are
+    /// retained in the pin, e.g., `pypy@3.10.16`.
+    ///
+    /// Fails if no matching Python interpreter is installed, unless `--from-downloads` is provided.
+    #[arg(long, conflicts_with = "resolved")]
+    pub exact: bool,
+
+    /// When used with `--exact`, resolve the version against the available Python downloads if no
+    /// matching Python interpreter is installed.
+    #[arg(long, requires = "exact")]
+    pub from_downloads: bool,
+
+    /// Remove the Python version pin.
+    ///
+    /// Removes the `.python-version` file that currently applies, i.e., the nearest file in the
+    /// working directory or any parent directory. When used with `--global`, the global Python
+    /// version pin is removed instead.
+    #[arg(long, conflicts_with_all = ["request", "resolved", "exact"])]
+    pub rm: bool,
+
     /// Avoid validating the Python pin is compatible with the project or workspace.
     ///
     /// By default, a project or workspace is discovered in the current directory or any parent
@@ -5736,6 +6550,9 @@ pub struct DisplayTreeArgs {
     pub prune: Vec<PackageName>,
 
     /// Display only the specified packages.
+    ///
+    /// In `uv tree`, the paths from the project to the specified packages are shown as well,
+    /// unless `--invert` is provided.
     #[arg(long)]
     pub package: Vec<PackageName>,
 
@@ -5856,6 +6673,31 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
+    /// Check that the files can be published, without uploading them.
+    ///
+    /// Reads the metadata of each wheel and source distribution and checks that the package name
+    /// and version match the filename and are accepted by PyPI. For wheels, also checks that the
+    /// `WHEEL` file is valid and that the `RECORD` file matches the contents of the wheel.
+    ///
+    /// No credentials are required and no network requests are made.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Generate a PEP 740 attestation for each file and upload it alongside the file.
+    ///
+    /// The files are signed with Sigstore using the ambient OIDC credentials of the CI provider,
+    /// e.g., in GitHub Actions. Signing requires `pypi-attestations`, which can be installed with
+    /// `uv tool install pypi-attestations`.
+    ///
+    /// By default, attestations are generated when using trusted publishing and
+    /// `pypi-attestations` is available.
+    #[arg(long, overrides_with("no_attestation"))]
+    pub attestation: bool,
+
+    /// Don't generate attestations, even when using trusted publishing.
+    #[arg(long, overrides_with("attestation"))]
+    pub no_attestation: bool,
+
     #[arg(long, hide = true)]
     pub skip_existing: bool,
 }
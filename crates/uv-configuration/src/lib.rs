@@ -15,6 +15,7 @@ pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
 pub use project_build_backend::*;
+pub use python_pin::*;
 pub use required_version::*;
 pub use sources::*;
 pub use target_triple::*;
@@ -40,6 +41,7 @@ mod overrides;
 mod package_options;
 mod preview;
 mod project_build_backend;
+mod python_pin;
 mod required_version;
 mod sources;
 mod target_triple;
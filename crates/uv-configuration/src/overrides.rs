@@ -7,6 +7,11 @@ use uv_distribution_types::Requirement;
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
+/// An error for a package that is both excluded and overridden.
+#[derive(Debug, thiserror::Error)]
+#[error("Package `{0}` is excluded from the resolution, but also has an override; remove one or the other")]
+pub struct ExcludedOverrideError(pub PackageName);
+
 /// A set of overrides for a set of requirements.
 #[derive(Debug, Default, Clone)]
 pub struct Overrides(FxHashMap<PackageName, Vec<Requirement>>);
@@ -25,6 +30,30 @@ impl Overrides {
         Self(overrides)
     }
 
+    /// Exclude the given packages from the resolution entirely, e.g., because they're provided
+    /// externally.
+    ///
+    /// An excluded package is overridden with an empty set of requirements, such that any
+    /// requirement on the package is dropped.
+    ///
+    /// Returns an error if an excluded package also has an override, as the two are contradictory.
+    pub fn with_excluded(
+        mut self,
+        packages: impl IntoIterator<Item = PackageName>,
+    ) -> Result<Self, ExcludedOverrideError> {
+        for package in packages {
+            match self.0.get(&package) {
+                Some(overrides) if !overrides.is_empty() => {
+                    return Err(ExcludedOverrideError(package));
+                }
+                _ => {
+                    self.0.insert(package, Vec::new());
+                }
+            }
+        }
+        Ok(self)
+    }
+
     /// Return an iterator over all [`Requirement`]s in the override set.
     pub fn requirements(&self) -> impl Iterator<Item = &Requirement> {
         self.0.values().flat_map(|requirements| requirements.iter())
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/// The version to record in the `.python-version` file created by `uv init`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonPin {
+    /// Pin the minor version of the Python interpreter, e.g., `3.12`.
+    #[default]
+    Minor,
+    /// Pin the exact patch version of the discovered Python interpreter, e.g., `3.12.4`.
+    Patch,
+    /// Do not create a `.python-version` file.
+    None,
+}
+
+impl PythonPin {
+    /// Returns `true` if a `.python-version` file should be created.
+    pub fn is_enabled(self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
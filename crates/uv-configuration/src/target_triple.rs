@@ -1,6 +1,8 @@
 use tracing::debug;
 
-use uv_pep508::MarkerEnvironment;
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
+};
 use uv_platform_tags::{Arch, Os, Platform};
 use uv_static::EnvVars;
 
@@ -8,7 +10,7 @@ use uv_static::EnvVars;
 /// system.
 ///
 /// See: <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
-#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -747,6 +749,26 @@ impl TargetTriple {
             .with_platform_release(self.platform_release())
             .with_platform_version(self.platform_version())
     }
+
+    /// Return a [`MarkerTree`] that matches the given [`TargetTriple`], based on a base
+    /// [`MarkerEnvironment`].
+    ///
+    /// The returned [`MarkerTree`] matches the `sys_platform` and `platform_machine` of the
+    /// synthetic environment returned by [`TargetTriple::markers`].
+    pub fn marker_tree(self, base: &MarkerEnvironment) -> MarkerTree {
+        let markers = self.markers(base);
+        let mut marker = MarkerTree::expression(MarkerExpression::String {
+            key: MarkerValueString::SysPlatform,
+            operator: MarkerOperator::Equal,
+            value: markers.sys_platform().into(),
+        });
+        marker.and(MarkerTree::expression(MarkerExpression::String {
+            key: MarkerValueString::PlatformMachine,
+            operator: MarkerOperator::Equal,
+            value: markers.platform_machine().into(),
+        }));
+        marker
+    }
 }
 
 /// Return the macOS deployment target as parsed from the environment.
@@ -36,6 +36,7 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &interpreter,
         &Concurrency::default(),
         cache.root(),
+        None,
     )
     .await?;
     info!("Compiled {files} files");
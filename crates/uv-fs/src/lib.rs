@@ -1,11 +1,14 @@
 use std::fmt::Display;
-use std::io;
+use std::io::{self, Seek, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use fs2::FileExt;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, trace, warn};
 
+use uv_warnings::warn_user;
+
 pub use crate::path::*;
 
 pub mod cachedir;
@@ -590,11 +593,153 @@ pub fn is_temporary(path: impl AsRef<Path>) -> bool {
         .is_some_and(|name| name.starts_with(".tmp"))
 }
 
+/// How long to wait for a [`LockedFile`] that is held by another process.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LockWait {
+    /// Wait until the lock is released.
+    #[default]
+    Forever,
+    /// Wait up to the given duration, then fail.
+    Timeout(Duration),
+    /// Fail immediately if the lock is held.
+    NoWait,
+}
+
+impl LockWait {
+    /// The maximum duration to wait for the lock, if any.
+    fn limit(self) -> Option<Duration> {
+        match self {
+            Self::Forever => None,
+            Self::Timeout(duration) => Some(duration),
+            Self::NoWait => Some(Duration::ZERO),
+        }
+    }
+}
+
+/// The process holding a [`LockedFile`], as recorded in the lock file by
+/// [`LockedFile::acquire_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockOwner {
+    pid: u32,
+    acquired: Option<jiff::Timestamp>,
+    command: Option<String>,
+}
+
+impl LockOwner {
+    /// The [`LockOwner`] for the current process.
+    fn current() -> Self {
+        let now = jiff::Timestamp::now();
+        let command = std::env::args_os()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(['\r', '\n'], " ");
+        Self {
+            pid: std::process::id(),
+            acquired: Some(now.round(jiff::Unit::Second).unwrap_or(now)),
+            command: Some(command),
+        }
+    }
+
+    /// Read the [`LockOwner`] recorded in the lock file at the given path, if any.
+    fn read(path: &Path) -> Option<Self> {
+        Self::parse(&fs_err::read_to_string(path).ok()?)
+    }
+
+    /// Parse the contents of a lock file, as written by [`LockOwner::serialize`].
+    fn parse(contents: &str) -> Option<Self> {
+        let mut pid = None;
+        let mut acquired = None;
+        let mut command = None;
+        for line in contents.lines() {
+            match line.split_once('=') {
+                Some(("pid", value)) => pid = value.parse().ok(),
+                Some(("acquired", value)) => acquired = value.parse().ok(),
+                Some(("command", value)) => command = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        Some(Self {
+            pid: pid?,
+            acquired,
+            command,
+        })
+    }
+
+    /// Serialize the [`LockOwner`] for storage in a lock file.
+    fn serialize(&self) -> String {
+        let mut contents = format!("pid={}\n", self.pid);
+        if let Some(acquired) = &self.acquired {
+            contents.push_str(&format!("acquired={acquired}\n"));
+        }
+        if let Some(command) = &self.command {
+            contents.push_str(&format!("command={command}\n"));
+        }
+        contents
+    }
+
+    /// The process ID of the owner.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Returns `Some(false)` if the owning process is known to no longer exist, and `None` if
+    /// liveness can't be determined on this platform.
+    ///
+    /// Note that a lock on a network file system may be held by a process on another host, in
+    /// which case the process ID is meaningless locally.
+    pub fn is_alive(&self) -> Option<bool> {
+        #[cfg(unix)]
+        {
+            let pid = rustix::process::Pid::from_raw(i32::try_from(self.pid).ok()?)?;
+            match rustix::process::test_kill_process(pid) {
+                Ok(()) | Err(rustix::io::Errno::PERM) => Some(true),
+                Err(rustix::io::Errno::SRCH) => Some(false),
+                Err(_) => None,
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            None
+        }
+    }
+}
+
+impl Display for LockOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PID {}", self.pid)?;
+        match (&self.command, &self.acquired) {
+            (Some(command), Some(acquired)) => {
+                write!(f, " (`{command}`, acquired at {acquired})")
+            }
+            (Some(command), None) => write!(f, " (`{command}`)"),
+            (None, Some(acquired)) => write!(f, " (acquired at {acquired})"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
 /// A file lock that is automatically released when dropped.
 #[derive(Debug)]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile {
+    file: fs_err::File,
+    /// Whether the [`LockOwner`] was recorded in the file, and should be cleared on release.
+    owner: bool,
+}
 
 impl LockedFile {
+    /// How often to check whether a contended lock has been released, in
+    /// [`LockedFile::acquire_with`].
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// How long to wait on a contended lock before telling the user, in
+    /// [`LockedFile::acquire_with`].
+    const REPORT_DELAY: Duration = Duration::from_secs(1);
+
+    /// How often to remind the user that we're waiting on a contended lock, in
+    /// [`LockedFile::acquire_with`].
+    const REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
     /// Inner implementation for [`LockedFile::acquire_blocking`] and [`LockedFile::acquire`].
     fn lock_file_blocking(file: fs_err::File, resource: &str) -> Result<Self, std::io::Error> {
         trace!(
@@ -604,7 +749,7 @@ impl LockedFile {
         match file.file().try_lock_exclusive() {
             Ok(()) => {
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self { file, owner: false })
             }
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures.
@@ -628,11 +773,145 @@ impl LockedFile {
                 })?;
 
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self { file, owner: false })
             }
         }
     }
 
+    /// Inner implementation for [`LockedFile::acquire_blocking_with`] and
+    /// [`LockedFile::acquire_with`].
+    ///
+    /// Polls for the lock rather than blocking on it, so that we can report the holder of a
+    /// contended lock to the user and give up once the [`LockWait`] limit is reached.
+    fn lock_file_polling(
+        file: fs_err::File,
+        resource: &str,
+        wait: LockWait,
+    ) -> Result<Self, std::io::Error> {
+        trace!(
+            "Checking lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        let start = Instant::now();
+        let mut contended = false;
+        let mut reported: Option<Instant> = None;
+        loop {
+            match file.file().try_lock_exclusive() {
+                Ok(()) => {
+                    debug!("Acquired lock for `{resource}`");
+                    let mut locked = Self { file, owner: false };
+                    locked.write_owner();
+                    return Ok(locked);
+                }
+                Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                    if !contended {
+                        info!(
+                            "Waiting to acquire lock for `{resource}` at `{}`",
+                            file.path().user_display(),
+                        );
+                        contended = true;
+                    }
+                }
+                Err(err) => {
+                    return Err(std::io::Error::new(
+                        err.kind(),
+                        format!(
+                            "Could not acquire lock for `{resource}` at `{}`: {}",
+                            file.path().user_display(),
+                            err
+                        ),
+                    ));
+                }
+            }
+
+            let owner = LockOwner::read(file.path());
+            let elapsed = start.elapsed();
+            if wait.limit().is_some_and(|limit| elapsed >= limit) {
+                let mut message = if wait == LockWait::NoWait {
+                    format!(
+                        "Lock for `{resource}` at `{}` is held by another process",
+                        file.path().user_display()
+                    )
+                } else {
+                    format!(
+                        "Timed out after {:.1}s waiting for lock for `{resource}` at `{}`",
+                        elapsed.as_secs_f32(),
+                        file.path().user_display()
+                    )
+                };
+                if let Some(owner) = &owner {
+                    message.push_str(&format!(" (held by {owner})"));
+                }
+                if let Some(hint) = owner
+                    .as_ref()
+                    .and_then(|owner| Self::stale_hint(owner, &file))
+                {
+                    message.push_str(&format!(". {hint}"));
+                }
+                return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, message));
+            }
+
+            // Don't bother the user with short waits, but let them know why we're stuck on longer
+            // ones.
+            let report = match reported {
+                None => elapsed >= Self::REPORT_DELAY,
+                Some(reported) => reported.elapsed() >= Self::REPORT_INTERVAL,
+            };
+            if report {
+                if let Some(owner) = &owner {
+                    warn_user!(
+                        "Waiting for lock held by {owner} on `{}`",
+                        file.path().user_display()
+                    );
+                    if let Some(hint) = Self::stale_hint(owner, &file) {
+                        warn_user!("{hint}");
+                    }
+                } else {
+                    warn_user!(
+                        "Waiting for lock on `{}` held by another process",
+                        file.path().user_display()
+                    );
+                }
+                reported = Some(Instant::now());
+            }
+
+            std::thread::sleep(Self::POLL_INTERVAL);
+        }
+    }
+
+    /// If the [`LockOwner`] no longer exists, return a message explaining how to recover from the
+    /// stale lock.
+    ///
+    /// We never remove the lock ourselves, since the owner may be a live process on another host
+    /// sharing the same (network) file system.
+    fn stale_hint(owner: &LockOwner, file: &fs_err::File) -> Option<String> {
+        if owner.is_alive() != Some(false) {
+            return None;
+        }
+        Some(format!(
+            "The process holding the lock (PID {}) no longer exists, so the lock may be stale. If no other uv process is using it, remove `{}` and try again",
+            owner.pid,
+            file.path().user_display()
+        ))
+    }
+
+    /// Record the current process as the [`LockOwner`] in the lock file.
+    fn write_owner(&mut self) {
+        let contents = LockOwner::current().serialize();
+        let result = self.file.file().set_len(0).and_then(|()| {
+            let mut file = self.file.file();
+            file.seek(io::SeekFrom::Start(0))?;
+            file.write_all(contents.as_bytes())
+        });
+        match result {
+            Ok(()) => self.owner = true,
+            Err(err) => debug!(
+                "Failed to record lock owner in `{}`: {err}",
+                self.file.path().user_display()
+            ),
+        }
+    }
+
     /// The same as [`LockedFile::acquire`], but for synchronous contexts. Do not use from an async
     /// context, as this can block the runtime while waiting for another process to release the
     /// lock.
@@ -645,6 +924,19 @@ impl LockedFile {
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// The same as [`LockedFile::acquire_with`], but for synchronous contexts. Do not use from an
+    /// async context, as this can block the runtime while waiting for another process to release
+    /// the lock.
+    pub fn acquire_blocking_with(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        wait: LockWait,
+    ) -> Result<Self, std::io::Error> {
+        let file = Self::create(path)?;
+        let resource = resource.to_string();
+        Self::lock_file_polling(file, &resource, wait)
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire(
@@ -656,6 +948,23 @@ impl LockedFile {
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Acquire a cross-process lock for a resource using a file at the provided path, waiting for
+    /// another process to release it according to the given [`LockWait`].
+    ///
+    /// Unlike [`LockedFile::acquire`], the [`LockOwner`] is recorded in the lock file while it's
+    /// held, so that other processes waiting on the lock can report who holds it. As such, the
+    /// path must be dedicated to the lock.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_with(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        wait: LockWait,
+    ) -> Result<Self, std::io::Error> {
+        let file = Self::create(path)?;
+        let resource = resource.to_string();
+        tokio::task::spawn_blocking(move || Self::lock_file_polling(file, &resource, wait)).await?
+    }
+
     #[cfg(unix)]
     fn create(path: impl AsRef<Path>) -> Result<fs_err::File, std::io::Error> {
         use std::os::unix::fs::PermissionsExt;
@@ -711,14 +1020,22 @@ impl LockedFile {
 
 impl Drop for LockedFile {
     fn drop(&mut self) {
-        if let Err(err) = fs2::FileExt::unlock(self.0.file()) {
+        if self.owner {
+            if let Err(err) = self.file.file().set_len(0) {
+                debug!(
+                    "Failed to clear lock owner in `{}`: {err}",
+                    self.file.path().display()
+                );
+            }
+        }
+        if let Err(err) = fs2::FileExt::unlock(self.file.file()) {
             error!(
                 "Failed to unlock {}; program may be stuck: {}",
-                self.0.path().display(),
+                self.file.path().display(),
                 err
             );
         } else {
-            debug!("Released lock at `{}`", self.0.path().display());
+            debug!("Released lock at `{}`", self.file.path().display());
         }
     }
 }
@@ -771,3 +1088,82 @@ pub fn copy_dir_all(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io::Re
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_owner_round_trip() {
+        let owner = LockOwner::current();
+        assert_eq!(LockOwner::parse(&owner.serialize()), Some(owner));
+        assert_eq!(LockOwner::parse(""), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn lock_timeout_reports_owner() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock");
+
+        let held = LockedFile::acquire_blocking_with(&path, "test", LockWait::Forever).unwrap();
+        let owner = LockOwner::read(&path).unwrap();
+        assert_eq!(owner.pid(), std::process::id());
+        assert_eq!(owner.is_alive(), Some(true));
+
+        let err = LockedFile::acquire_blocking_with(
+            &path,
+            "test",
+            LockWait::Timeout(Duration::from_millis(200)),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        let message = err.to_string();
+        assert!(message.starts_with("Timed out after"), "{message}");
+        assert!(
+            message.contains(&format!("(held by PID {} (`", std::process::id())),
+            "{message}"
+        );
+        assert!(!message.contains("stale"), "{message}");
+
+        let err = LockedFile::acquire_blocking_with(&path, "test", LockWait::NoWait).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(
+            err.to_string().contains("is held by another process"),
+            "{err}"
+        );
+
+        // Once released, the owner is cleared and the lock can be acquired again.
+        drop(held);
+        assert_eq!(LockOwner::read(&path), None);
+        LockedFile::acquire_blocking_with(&path, "test", LockWait::NoWait).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn lock_timeout_reports_stale_owner() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock");
+
+        // Simulate a lock left behind by a process that no longer exists, as can happen on
+        // network file systems.
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        let _held = LockedFile::acquire_blocking(&path, "test").unwrap();
+        fs_err::write(&path, format!("pid={pid}\n")).unwrap();
+
+        let err = LockedFile::acquire_blocking_with(&path, "test", LockWait::NoWait).unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains(&format!("(held by PID {pid})")),
+            "{message}"
+        );
+        assert!(
+            message.contains(&format!(
+                "The process holding the lock (PID {pid}) no longer exists, so the lock may be stale"
+            )),
+            "{message}"
+        );
+    }
+}
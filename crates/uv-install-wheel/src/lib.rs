@@ -13,6 +13,7 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{uninstall_egg, uninstall_legacy_editable, uninstall_wheel, Uninstall};
+pub use verify::{verify_wheel, RecordMismatch};
 pub use wheel::{parse_wheel_file, read_record_file, LibKind};
 
 mod install;
@@ -20,6 +21,7 @@ mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -72,6 +74,8 @@ pub enum Error {
     MissingRecord(PathBuf),
     #[error("Cannot uninstall package; `top_level.txt` file not found at: {}", _0.user_display())]
     MissingTopLevel(PathBuf),
+    #[error("Cannot verify package; `RECORD` file not found at: {}", _0.user_display())]
+    UnverifiableRecord(PathBuf),
     #[error("Invalid package version")]
     InvalidVersion(#[from] uv_pep440::VersionParseError),
     #[error("Wheel package name does not match filename: {0} != {1}")]
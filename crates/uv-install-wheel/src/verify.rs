@@ -0,0 +1,119 @@
+use std::io;
+use std::path::Path;
+
+use fs_err as fs;
+use tracing::trace;
+
+use crate::wheel::{copy_and_hash, read_record_file};
+use crate::Error;
+
+/// A file in an installed wheel that doesn't match its entry in the `RECORD` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordMismatch {
+    /// The file is listed in the `RECORD`, but doesn't exist on disk.
+    Missing { path: String },
+    /// The hash of the file on disk differs from the hash in the `RECORD`.
+    Hash {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Verify the files of the wheel represented by the given `.dist-info` directory against the
+/// hashes in its `RECORD` file.
+///
+/// Entries without a hash (like the `RECORD` itself) and entries hashed with an algorithm other
+/// than SHA256 are skipped. This never modifies the environment.
+pub fn verify_wheel(dist_info: &Path) -> Result<Vec<RecordMismatch>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::UnverifiableRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    let mut mismatches = Vec::new();
+    for entry in record {
+        let Some(expected) = entry.hash else {
+            continue;
+        };
+        if !expected.starts_with("sha256=") {
+            trace!("Skipping unsupported hash for: {}", entry.path);
+            continue;
+        }
+
+        let path = site_packages.join(&entry.path);
+        let mut file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                mismatches.push(RecordMismatch::Missing { path: entry.path });
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual != expected {
+            mismatches.push(RecordMismatch::Hash {
+                path: entry.path,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use super::{verify_wheel, RecordMismatch};
+
+    #[test]
+    fn verify() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("print('hello')\n")?;
+        site_packages.child("foo/bar.py").write_str("tampered\n")?;
+        let dist_info = site_packages.child("foo-1.0.0.dist-info");
+        dist_info.child("RECORD").write_str(
+            "foo/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15\n\
+             foo/bar.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0\n\
+             foo/missing.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0\n\
+             foo-1.0.0.dist-info/RECORD,,\n",
+        )?;
+
+        let mismatches = verify_wheel(dist_info.path())?;
+        assert_eq!(
+            mismatches,
+            vec![
+                RecordMismatch::Hash {
+                    path: "foo/bar.py".to_string(),
+                    expected: "sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU".to_string(),
+                    actual: "sha256=kueNCwMpYvR3kqn6lf2YHvY-Hj7wdNU21jBMde3b4p8".to_string(),
+                },
+                RecordMismatch::Missing {
+                    path: "foo/missing.py".to_string(),
+                },
+            ]
+        );
+        Ok(())
+    }
+}
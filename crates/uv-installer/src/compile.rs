@@ -1,6 +1,7 @@
 use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
 use std::time::Duration;
 use std::{io, panic};
 
@@ -57,6 +58,11 @@ pub enum CompileError {
     StartupTimeout(Duration),
 }
 
+pub trait Reporter: Send + Sync {
+    /// Callback to invoke when a source file is compiled.
+    fn on_compile_progress(&self, source_file: &Path);
+}
+
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
@@ -67,12 +73,15 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+///
+/// If a `reporter` is provided, it is notified as each file is compiled.
+#[instrument(skip(python_executable, reporter))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
+    reporter: Option<Arc<dyn Reporter>>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
@@ -98,6 +107,7 @@ pub async fn compile_tree(
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
             receiver.clone(),
+            reporter.clone(),
         );
 
         // Spawn each worker on a dedicated thread.
@@ -189,6 +199,7 @@ async fn worker(
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
+    reporter: Option<Arc<dyn Reporter>>,
 ) -> Result<(), CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
@@ -223,7 +234,13 @@ async fn worker(
         Ok(child_stderr_collected)
     });
 
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout).await;
+    let result = worker_main_loop(
+        receiver,
+        child_stdin,
+        &mut child_stdout,
+        reporter.as_deref(),
+    )
+    .await;
     // Reap the process to avoid zombies.
     let _ = bytecode_compiler.kill().await;
 
@@ -340,10 +357,11 @@ async fn worker_main_loop(
     receiver: Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
+    reporter: Option<&dyn Reporter>,
 ) -> Result<(), CompileError> {
     let mut out_line = String::new();
-    while let Ok(source_file) = receiver.recv().await {
-        let source_file = source_file.display().to_string();
+    while let Ok(source_path) = receiver.recv().await {
+        let source_file = source_path.display().to_string();
         if source_file.contains(['\r', '\n']) {
             warn_user!("Path contains newline, skipping: {source_file:?}");
             continue;
@@ -385,6 +403,10 @@ async fn worker_main_loop(
         if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
+
+        if let Some(reporter) = reporter {
+            reporter.on_compile_progress(&source_path);
+        }
     }
     Ok(())
 }
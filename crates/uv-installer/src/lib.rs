@@ -1,4 +1,4 @@
-pub use compile::{compile_tree, CompileError};
+pub use compile::{compile_tree, CompileError, Reporter as CompileReporter};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
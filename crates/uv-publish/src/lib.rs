@@ -1,6 +1,8 @@
 mod trusted_publishing;
 
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use std::{env, fmt, io};
@@ -20,6 +22,7 @@ use serde::Deserialize;
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, BufReader};
 use tokio::sync::Semaphore;
+use tokio_util::compat::TokioAsyncReadCompatExt;
 use tokio_util::io::ReaderStream;
 use tracing::{debug, enabled, trace, warn, Level};
 use trusted_publishing::TrustedPublishingToken;
@@ -32,11 +35,16 @@ use uv_client::{
     DEFAULT_RETRIES,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
-use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
+use uv_distribution_filename::{
+    DistFilename, SourceDistExtension, SourceDistFilename, WheelFilename,
+};
 use uv_distribution_types::{IndexCapabilities, IndexUrl};
 use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{ProgressReader, Simplified};
-use uv_metadata::read_metadata_async_seek;
+use uv_install_wheel::{parse_wheel_file, read_record_file};
+use uv_metadata::{find_archive_dist_info, read_metadata_async_seek};
+use uv_normalize::{InvalidNameError, PackageName};
+use uv_pep440::{Version, VersionParseError};
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
@@ -78,6 +86,8 @@ pub enum PublishError {
     },
     #[error("Hash is missing in index for {0}")]
     MissingHash(Box<DistFilename>),
+    #[error("Failed to generate attestation for `{}`", _0.user_display())]
+    Attestation(PathBuf, #[source] AttestationError),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -97,6 +107,50 @@ pub enum PublishPrepareError {
     MultiplePkgInfo(String),
     #[error("Failed to read: `{0}`")]
     Read(String, #[source] io::Error),
+    #[error("Failed to read wheel")]
+    Zip(#[from] async_zip::error::ZipError),
+    #[error("No `{0}` file found")]
+    MissingWheelFile(String),
+    #[error("The `{0}` file is invalid")]
+    InvalidWheelFile(String, #[source] uv_install_wheel::Error),
+    #[error("The `RECORD` file is missing an entry for: `{0}`")]
+    MissingRecordEntry(String),
+    #[error("The `RECORD` file contains an entry for a file that is not in the wheel: `{0}`")]
+    ExtraRecordEntry(String),
+    #[error("Invalid package name in metadata: `{0}`")]
+    InvalidName(String, #[source] InvalidNameError),
+    #[error("Invalid version in metadata: `{0}`")]
+    InvalidVersion(String, #[source] VersionParseError),
+    #[error("Package name in metadata does not match filename: `{metadata}` != `{filename}`")]
+    MismatchedName {
+        metadata: PackageName,
+        filename: PackageName,
+    },
+    #[error("Version in metadata does not match filename: `{metadata}` != `{filename}`")]
+    MismatchedVersion {
+        metadata: Version,
+        filename: Version,
+    },
+    #[error("Local versions can't be uploaded to PyPI: `{0}`")]
+    LocalVersion(Version),
+    #[error("Invalid attestation: `{}`", _0.user_display())]
+    InvalidAttestation(PathBuf, #[source] serde_json::Error),
+}
+
+/// Failure to generate a [PEP 740](https://peps.python.org/pep-0740/) attestation.
+#[derive(Error, Debug)]
+pub enum AttestationError {
+    #[error(
+        "`pypi-attestations` was not found, install it with `uv tool install pypi-attestations` \
+        to generate attestations"
+    )]
+    MissingTool,
+    #[error("Failed to run `pypi-attestations`")]
+    Spawn(#[source] io::Error),
+    #[error("`pypi-attestations sign` failed ({0}):\n{1}")]
+    Sign(ExitStatus, String),
+    #[error("`pypi-attestations sign` did not create an attestation at: `{}`", _0.user_display())]
+    MissingOutput(PathBuf),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -374,12 +428,19 @@ pub async fn upload(
     client: &BaseClient,
     credentials: &Credentials,
     check_url_client: Option<&CheckUrlClient<'_>>,
+    attestations: &[PathBuf],
     download_concurrency: &Semaphore,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
-    let form_metadata = form_metadata(file, filename)
+    let mut form_metadata = form_metadata(file, filename)
         .await
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
+    if !attestations.is_empty() {
+        let attestations = attestations_form_field(attestations)
+            .await
+            .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
+        form_metadata.push(("attestations", attestations));
+    }
 
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
@@ -560,6 +621,60 @@ pub async fn check_url(
     }
 }
 
+/// The path of the attestation that `pypi-attestations sign` creates for a distribution.
+pub fn attestation_path(file: &Path) -> PathBuf {
+    let mut path = file.as_os_str().to_owned();
+    path.push(".publish.attestation");
+    PathBuf::from(path)
+}
+
+/// Generate a [PEP 740](https://peps.python.org/pep-0740/) attestation for a distribution.
+///
+/// The distribution is signed with Sigstore by `pypi-attestations`, which uses the ambient OIDC
+/// credentials of the CI provider, e.g., in GitHub Actions. Returns the path of the attestation,
+/// see [`attestation_path`].
+pub async fn generate_attestation(file: &Path) -> Result<PathBuf, AttestationError> {
+    debug!("Generating attestation for {}", file.user_display());
+    let output = tokio::process::Command::new("pypi-attestations")
+        .arg("sign")
+        .arg(file)
+        .output()
+        .await
+        .map_err(|err| {
+            if err.kind() == io::ErrorKind::NotFound {
+                AttestationError::MissingTool
+            } else {
+                AttestationError::Spawn(err)
+            }
+        })?;
+    if !output.status.success() {
+        return Err(AttestationError::Sign(
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let attestation = attestation_path(file);
+    if !attestation.is_file() {
+        return Err(AttestationError::MissingOutput(attestation));
+    }
+    Ok(attestation)
+}
+
+/// Read attestations into the JSON array the upload API expects in the `attestations` field.
+///
+/// See the upload endpoint changes in [PEP 740](https://peps.python.org/pep-0740/).
+async fn attestations_form_field(attestations: &[PathBuf]) -> Result<String, PublishPrepareError> {
+    let mut values = Vec::with_capacity(attestations.len());
+    for attestation in attestations {
+        let contents = fs_err::tokio::read_to_string(attestation).await?;
+        let value: serde_json::Value = serde_json::from_str(&contents)
+            .map_err(|err| PublishPrepareError::InvalidAttestation(attestation.clone(), err))?;
+        values.push(value);
+    }
+    Ok(serde_json::Value::Array(values).to_string())
+}
+
 /// Calculate the SHA256 of a file.
 async fn hash_file(path: impl AsRef<Path>, hasher: Hasher) -> Result<HashDigest, io::Error> {
     debug!("Hashing {}", path.as_ref().display());
@@ -631,6 +746,124 @@ async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, Pu
     Ok(Metadata23::parse(&contents)?)
 }
 
+/// Check that a file can be published, without uploading it.
+///
+/// Validates the core metadata of wheels and source distributions, including that the name and
+/// version in the metadata match the filename. For wheels, additionally validates the `WHEEL` file
+/// and checks that the `RECORD` file matches the contents of the archive.
+pub async fn check_file(file: &Path, filename: &DistFilename) -> Result<(), PublishPrepareError> {
+    if let DistFilename::WheelFilename(wheel) = filename {
+        check_wheel_files(file, wheel).await?;
+    }
+
+    let metadata = metadata(file, filename).await?;
+
+    let name = PackageName::from_str(&metadata.name)
+        .map_err(|err| PublishPrepareError::InvalidName(metadata.name.clone(), err))?;
+    if &name != filename.name() {
+        return Err(PublishPrepareError::MismatchedName {
+            metadata: name,
+            filename: filename.name().clone(),
+        });
+    }
+
+    let version = Version::from_str(&metadata.version)
+        .map_err(|err| PublishPrepareError::InvalidVersion(metadata.version.clone(), err))?;
+    if &version != filename.version() {
+        return Err(PublishPrepareError::MismatchedVersion {
+            metadata: version,
+            filename: filename.version().clone(),
+        });
+    }
+    // PyPI rejects local versions, see
+    // <https://packaging.python.org/en/latest/specifications/version-specifiers/#local-version-identifiers>
+    if version.is_local() {
+        return Err(PublishPrepareError::LocalVersion(version));
+    }
+
+    Ok(())
+}
+
+/// Check that the `WHEEL` file of a wheel is valid and that the `RECORD` file lists exactly the
+/// files in the archive.
+async fn check_wheel_files(
+    file: &Path,
+    filename: &WheelFilename,
+) -> Result<(), PublishPrepareError> {
+    let reader = futures::io::BufReader::new(File::open(file).await?.compat());
+    let mut zip_reader = async_zip::base::read::seek::ZipFileReader::new(reader).await?;
+
+    let entries: Vec<(usize, String)> = zip_reader
+        .file()
+        .entries()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| Some((index, entry.filename().as_str().ok()?.to_string())))
+        // Skip directory entries, they are not listed in the `RECORD`.
+        .filter(|(_, path)| !path.ends_with('/'))
+        .collect();
+
+    let (_, dist_info_prefix) = find_archive_dist_info(
+        filename,
+        entries.iter().map(|(index, path)| (*index, path.as_str())),
+    )?;
+    let dist_info_dir = format!("{dist_info_prefix}.dist-info");
+
+    let entry_index = |name: &str| {
+        let path = format!("{dist_info_dir}/{name}");
+        entries
+            .iter()
+            .find(|(_, entry)| *entry == path)
+            .map(|(index, _)| *index)
+            .ok_or(PublishPrepareError::MissingWheelFile(path))
+    };
+    let wheel_index = entry_index("WHEEL")?;
+    let record_index = entry_index("RECORD")?;
+
+    let mut wheel = String::new();
+    zip_reader
+        .reader_with_entry(wheel_index)
+        .await?
+        .read_to_string_checked(&mut wheel)
+        .await?;
+    let mut record = String::new();
+    zip_reader
+        .reader_with_entry(record_index)
+        .await?
+        .read_to_string_checked(&mut record)
+        .await?;
+
+    parse_wheel_file(&wheel).map_err(|err| {
+        PublishPrepareError::InvalidWheelFile(format!("{dist_info_dir}/WHEEL"), err)
+    })?;
+    let record = read_record_file(&mut record.as_bytes()).map_err(|err| {
+        PublishPrepareError::InvalidWheelFile(format!("{dist_info_dir}/RECORD"), err)
+    })?;
+
+    // The `RECORD` can't contain a hash of itself, and neither can its signatures.
+    let unrecorded = [
+        format!("{dist_info_dir}/RECORD"),
+        format!("{dist_info_dir}/RECORD.jws"),
+        format!("{dist_info_dir}/RECORD.p7s"),
+    ];
+    let recorded: FxHashSet<&str> = record.iter().map(|entry| entry.path.as_str()).collect();
+    if let Some((_, path)) = entries
+        .iter()
+        .find(|(_, path)| !recorded.contains(path.as_str()) && !unrecorded.contains(path))
+    {
+        return Err(PublishPrepareError::MissingRecordEntry(path.clone()));
+    }
+    let archived: FxHashSet<&str> = entries.iter().map(|(_, path)| path.as_str()).collect();
+    if let Some(entry) = record
+        .iter()
+        .find(|entry| !archived.contains(entry.path.as_str()))
+    {
+        return Err(PublishPrepareError::ExtraRecordEntry(entry.path.clone()));
+    }
+
+    Ok(())
+}
+
 /// Collect the non-file fields for the multipart request from the package METADATA.
 ///
 /// Reference implementation: <https://github.com/pypi/warehouse/blob/d2c36d992cf9168e0518201d998b2707a3ef1e72/warehouse/forklift/legacy.py#L1376-L1430>
@@ -875,7 +1108,10 @@ async fn handle_response(registry: &Url, response: Response) -> Result<(), Publi
 
 #[cfg(test)]
 mod tests {
-    use crate::{build_request, form_metadata, Reporter};
+    use crate::{
+        attestation_path, attestations_form_field, build_request, check_file, form_metadata,
+        PublishPrepareError, Reporter,
+    };
     use insta::{assert_debug_snapshot, assert_snapshot};
     use itertools::Itertools;
     use std::path::PathBuf;
@@ -1156,4 +1392,61 @@ mod tests {
             "#);
         });
     }
+
+    /// Check that valid wheels and source distributions pass `uv publish --check`.
+    #[tokio::test]
+    async fn check_valid_files() {
+        for raw_filename in [
+            "tqdm-999.0.0.tar.gz",
+            "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl",
+        ] {
+            let file = PathBuf::from("../../scripts/links/").join(raw_filename);
+            let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+            check_file(&file, &filename).await.unwrap();
+        }
+    }
+
+    /// Check that a version mismatch between the filename and the metadata is reported.
+    #[tokio::test]
+    async fn check_mismatched_version() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let raw_filename = "tqdm-1.0.0.tar.gz";
+        let file = temp_dir.path().join(raw_filename);
+        fs_err::copy(
+            PathBuf::from("../../scripts/links/").join("tqdm-999.0.0.tar.gz"),
+            &file,
+        )
+        .unwrap();
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+
+        let err = check_file(&file, &filename).await.unwrap_err();
+        assert!(matches!(err, PublishPrepareError::MismatchedVersion { .. }));
+        assert_snapshot!(err, @"Version in metadata does not match filename: `999.0.0` != `1.0.0`");
+    }
+
+    /// Check that attestations are combined into a single JSON array for the upload form.
+    #[tokio::test]
+    async fn attestations_form() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("tqdm-999.0.0.tar.gz");
+        let attestation = attestation_path(&file);
+        assert_eq!(
+            attestation.file_name().unwrap(),
+            "tqdm-999.0.0.tar.gz.publish.attestation"
+        );
+        fs_err::write(
+            &attestation,
+            r#"{"envelope": {}, "verification_material": {}, "version": 1}"#,
+        )
+        .unwrap();
+
+        let field = attestations_form_field(&[attestation.clone()])
+            .await
+            .unwrap();
+        assert_snapshot!(field, @r#"[{"envelope":{},"verification_material":{},"version":1}]"#);
+
+        fs_err::write(&attestation, "not json").unwrap();
+        let err = attestations_form_field(&[attestation]).await.unwrap_err();
+        assert!(matches!(err, PublishPrepareError::InvalidAttestation(..)));
+    }
 }
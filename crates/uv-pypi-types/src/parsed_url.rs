@@ -511,7 +511,8 @@ mod tests {
     use anyhow::Result;
     use url::Url;
 
-    use crate::parsed_url::ParsedUrl;
+    use crate::parsed_url::{ParsedDirectoryUrl, ParsedUrl};
+    use crate::{DirInfo, DirectUrl};
 
     #[test]
     fn direct_url_from_url() -> Result<()> {
@@ -549,4 +550,45 @@ mod tests {
         assert_eq!(expected, actual);
         Ok(())
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn direct_url_from_editable_directory() -> Result<()> {
+        let url = Url::parse("file:///path/to/project")?;
+
+        let parsed = ParsedUrl::Directory(ParsedDirectoryUrl::from_source(
+            std::path::Path::new("/path/to/project").into(),
+            true,
+            false,
+            url.clone(),
+        ));
+        assert_eq!(
+            DirectUrl::from(&parsed),
+            DirectUrl::LocalDirectory {
+                url: "file:///path/to/project".to_string(),
+                dir_info: DirInfo {
+                    editable: Some(true),
+                },
+                subdirectory: None,
+            }
+        );
+
+        // Non-editable installs should omit the `editable` key entirely.
+        let parsed = ParsedUrl::Directory(ParsedDirectoryUrl::from_source(
+            std::path::Path::new("/path/to/project").into(),
+            false,
+            false,
+            url,
+        ));
+        assert_eq!(
+            DirectUrl::from(&parsed),
+            DirectUrl::LocalDirectory {
+                url: "file:///path/to/project".to_string(),
+                dir_info: DirInfo { editable: None },
+                subdirectory: None,
+            }
+        );
+
+        Ok(())
+    }
 }
@@ -50,7 +50,7 @@ import json
 import logging
 import os
 import re
-from dataclasses import asdict, dataclass, field
+from dataclasses import asdict, dataclass, field, replace
 from enum import StrEnum
 from pathlib import Path
 from typing import Generator, Iterable, NamedTuple, Self
@@ -104,7 +104,7 @@ class PlatformTriple(NamedTuple):
     platform: str
     # The architecture, e.g. "x86_64", "aarch64".
     arch: Arch
-    # The libc implementation, e.g. "gnu", "musl", "none".
+    # The libc implementation, e.g. "gnu", "musl", "musl-static", "none".
     libc: str
 
     def key(self) -> PlatformTripleKey:
@@ -157,12 +157,17 @@ class PythonDownload:
     sha256: str | None = None
     build_options: list[str] = field(default_factory=list)
     variant: Variant | None = None
+    # The optimization flavor, e.g., `pgo+lto` or `noopt`, for downloads that are published in
+    # addition to the default build.
+    build: str | None = None
 
     def key(self) -> str:
+        version = str(self.version)
         if self.variant:
-            return f"{self.implementation}-{self.version}+{self.variant}-{self.triple.platform}-{self.triple.arch}-{self.triple.libc}"
-        else:
-            return f"{self.implementation}-{self.version}-{self.triple.platform}-{self.triple.arch}-{self.triple.libc}"
+            version += f"+{self.variant}"
+        if self.build:
+            version += f"+{self.build}"
+        return f"{self.implementation}-{version}-{self.triple.platform}-{self.triple.arch}-{self.triple.libc}"
 
 
 class Finder:
@@ -196,6 +201,8 @@ class CPythonFinder(Finder):
         "windows-x86-shared": "i686-pc-windows",
         "linux64-musl": "x86_64-unknown-linux-musl",
     }
+    # The optimization flavors that are published in addition to the default build
+    BUILD_FLAVORS = ["pgo+lto", "pgo", "lto", "noopt"]
     # Normalized mappings to match the Rust types
     ARCH_MAP = {
         "ppc64": "powerpc64",
@@ -299,6 +306,30 @@ class CPythonFinder(Finder):
             # Drop the priorities
             downloads.extend([download for download, _ in selected.values()])
 
+            # Select a download for each optimization flavor, so that a specific flavor can be
+            # requested
+            flavors: dict[
+                tuple[PlatformTripleKey, Variant | None, str],
+                tuple[PythonDownload, tuple[int, int]],
+            ] = {}
+            for download in version_downloads:
+                build = self._build_flavor(download.build_options)
+                if build is None:
+                    continue
+                priority = self._get_priority(download)
+                key = (download.triple.key(), download.variant, build)
+                existing = flavors.get(key)
+                if existing and priority >= existing[1]:
+                    continue
+                flavors[key] = (download, priority)
+
+            downloads.extend(
+                [
+                    replace(download, build=build)
+                    for (_, _, build), (download, _) in flavors.items()
+                ]
+            )
+
         return downloads
 
     async def _fetch_checksums(self, downloads: list[PythonDownload], n: int) -> None:
@@ -381,6 +412,15 @@ class CPythonFinder(Finder):
             # Skip is logged in `_normalize_triple`
             return None
 
+        # Once the default musl builds became dynamically linked, the statically-linked builds
+        # are tracked separately, as they cannot load compiled extension modules.
+        if (
+            triple.libc == "musl"
+            and "static" in build_options
+            and release >= CPYTHON_MUSL_STATIC_RELEASE_END
+        ):
+            triple = triple._replace(libc="musl-static")
+
         return PythonDownload(
             release=release,
             version=version,
@@ -439,6 +479,18 @@ class CPythonFinder(Finder):
             priority = len(self.FLAVOR_PREFERENCES) + 1
         return priority
 
+    def _build_flavor(self, build_options: list[str]) -> str | None:
+        """Return the optimization flavor of a download, e.g., `pgo+lto`, if any."""
+        options = [
+            option
+            for option in build_options
+            if option not in {"static", *(variant.value for variant in Variant)}
+        ]
+        flavor = "+".join(options)
+        if flavor in self.BUILD_FLAVORS:
+            return flavor
+        return None
+
     def _build_option_priority(self, build_options: list[str]) -> int:
         # Prefer optimized builds
         return -1 * sum(
@@ -678,6 +730,7 @@ def render(downloads: list[PythonDownload]) -> None:
             -prerelease[0],
             -prerelease[1],
             variant_sort_key(download.variant),
+            download.build or "",
             download.triple,
         )
 
@@ -709,6 +762,7 @@ def render(downloads: list[PythonDownload]) -> None:
             "url": download.url,
             "sha256": download.sha256,
             "variant": download.variant if download.variant else None,
+            "build": download.build,
         }
 
     VERSIONS_FILE.parent.mkdir(parents=True, exist_ok=True)
@@ -235,6 +235,66 @@ def get_virtualenv():
         }
 
 
+def get_user_scheme():
+    """Return the user installation scheme (as in `pip install --user`) and the default user base.
+
+    The user base is left as a `{userbase}` placeholder in the scheme paths, such that uv can
+    apply `PYTHONUSERBASE` without re-querying the interpreter.
+    """
+    get_user_base = getattr(sysconfig, "_getuserbase", None)
+    if get_user_base is None:
+        return None, None
+
+    get_preferred_scheme = getattr(sysconfig, "get_preferred_scheme", None)
+    if get_preferred_scheme is not None:
+        scheme_name = get_preferred_scheme("user")
+    elif os.name == "nt":
+        scheme_name = "nt_user"
+    elif sys.platform == "darwin" and getattr(sys, "_framework", None):
+        scheme_name = "osx_framework_user"
+    else:
+        scheme_name = "posix_user"
+
+    if scheme_name not in sysconfig.get_scheme_names():
+        return None, None
+
+    paths = sysconfig.get_paths(scheme=scheme_name, vars={"userbase": "{userbase}"})
+
+    # Determine the default user base, ignoring any `PYTHONUSERBASE` override.
+    user_base = os.environ.pop("PYTHONUSERBASE", None)
+    try:
+        default_user_base = get_user_base()
+    finally:
+        if user_base is not None:
+            os.environ["PYTHONUSERBASE"] = user_base
+
+    scheme = {
+        "purelib": paths["purelib"],
+        "platlib": paths["platlib"],
+        "include": paths.get("include", os.path.join("{userbase}", "include")),
+        "scripts": paths["scripts"],
+        "data": paths["data"],
+    }
+    return scheme, default_user_base
+
+
+def get_sysconfig_vars():
+    """Return a subset of the `sysconfig` paths and configuration variables.
+
+    All values are converted to strings, and unset variables are omitted.
+    """
+    sysconfig_vars = {
+        "platstdlib": sysconfig.get_path("platstdlib"),
+        "include": sysconfig.get_path("include"),
+        "platinclude": sysconfig.get_path("platinclude"),
+    }
+    for name in ("EXT_SUFFIX", "SOABI", "abiflags", "LDVERSION", "MULTIARCH"):
+        sysconfig_vars[name] = sysconfig.get_config_var(name)
+    return {
+        name: str(value) for name, value in sysconfig_vars.items() if value is not None
+    }
+
+
 def get_scheme(use_sysconfig_scheme: bool):
     """Return the Scheme for the current interpreter.
 
@@ -607,6 +667,8 @@ def main() -> None:
             )
             sys.exit(0)
 
+    user_scheme, user_base = get_user_scheme()
+
     interpreter_info = {
         "result": "success",
         "markers": markers,
@@ -626,6 +688,9 @@ def main() -> None:
         "standalone": sysconfig.get_config_var("prefix") == "/install" or bool(sysconfig.get_config_var("PYTHON_BUILD_STANDALONE")),
         "scheme": get_scheme(use_sysconfig_scheme),
         "virtualenv": get_virtualenv(),
+        "user_scheme": user_scheme,
+        "user_base": user_base,
+        "sysconfig_vars": get_sysconfig_vars(),
         "platform": os_and_arch,
         "manylinux_compatible": manylinux_compatible,
         # The `t` abiflag for freethreading Python.
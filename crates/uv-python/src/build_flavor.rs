@@ -0,0 +1,120 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[error("Unknown Python build flavor `{0}`, expected one of: `default`, `pgo+lto`, `pgo`, `lto`, `noopt`")]
+pub struct InvalidBuildFlavor(String);
+
+/// The optimization flavor of a Python build, e.g., `pgo+lto` or `noopt`.
+///
+/// python-build-standalone publishes multiple builds of each Python version, which differ in the
+/// optimizations that were applied when compiling the interpreter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum BuildFlavor {
+    /// The default build of the distribution.
+    #[default]
+    Default,
+    /// A build with profile-guided optimization and link-time optimization.
+    #[cfg_attr(feature = "clap", value(name = "pgo+lto"))]
+    PgoLto,
+    /// A build with profile-guided optimization.
+    Pgo,
+    /// A build with link-time optimization.
+    Lto,
+    /// A build without optimizations, e.g., for debugging or profiling C extensions.
+    Noopt,
+}
+
+impl BuildFlavor {
+    /// Return the suffix for the flavor in an installation key, e.g., `+noopt`.
+    ///
+    /// Returns an empty string for the default flavor.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Default => "",
+            Self::PgoLto => "+pgo+lto",
+            Self::Pgo => "+pgo",
+            Self::Lto => "+lto",
+            Self::Noopt => "+noopt",
+        }
+    }
+
+    /// Split the flavor suffix off a version string, e.g., `3.12.4+pgo+lto` into `3.12.4` and
+    /// [`BuildFlavor::PgoLto`].
+    pub(crate) fn split_suffix(version: &str) -> (&str, Self) {
+        for flavor in [Self::PgoLto, Self::Pgo, Self::Lto, Self::Noopt] {
+            if let Some(version) = version.strip_suffix(flavor.suffix()) {
+                return (version, flavor);
+            }
+        }
+        (version, Self::Default)
+    }
+
+    /// The preference for the flavor when none was requested, where lower values are preferred.
+    ///
+    /// The default builds published by python-build-standalone are fully optimized, so they're
+    /// preferred alongside `pgo+lto`.
+    pub(crate) fn priority(self) -> u8 {
+        match self {
+            Self::Default | Self::PgoLto => 0,
+            Self::Pgo => 1,
+            Self::Lto => 2,
+            Self::Noopt => 3,
+        }
+    }
+}
+
+impl FromStr for BuildFlavor {
+    type Err = InvalidBuildFlavor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "pgo+lto" => Ok(Self::PgoLto),
+            "pgo" => Ok(Self::Pgo),
+            "lto" => Ok(Self::Lto),
+            "noopt" => Ok(Self::Noopt),
+            _ => Err(InvalidBuildFlavor(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for BuildFlavor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::PgoLto => f.write_str("pgo+lto"),
+            Self::Pgo => f.write_str("pgo"),
+            Self::Lto => f.write_str("lto"),
+            Self::Noopt => f.write_str("noopt"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BuildFlavor;
+
+    #[test]
+    fn split_suffix() {
+        assert_eq!(
+            BuildFlavor::split_suffix("3.12.4+pgo+lto"),
+            ("3.12.4", BuildFlavor::PgoLto)
+        );
+        assert_eq!(
+            BuildFlavor::split_suffix("3.13.0+freethreaded+noopt"),
+            ("3.13.0+freethreaded", BuildFlavor::Noopt)
+        );
+        assert_eq!(
+            BuildFlavor::split_suffix("3.12.4+lto"),
+            ("3.12.4", BuildFlavor::Lto)
+        );
+        assert_eq!(
+            BuildFlavor::split_suffix("3.13.0+freethreaded"),
+            ("3.13.0+freethreaded", BuildFlavor::Default)
+        );
+    }
+}
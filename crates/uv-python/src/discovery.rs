@@ -1,13 +1,14 @@
 use itertools::{Either, Itertools};
 use regex::Regex;
-use rustc_hash::{FxBuildHasher, FxHashSet};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use same_file::is_same_file;
+use std::cell::{Cell, RefCell};
 use std::env::consts::EXE_SUFFIX;
 use std::fmt::{self, Debug, Formatter};
 use std::{env, io, iter};
 use std::{path::Path, path::PathBuf, str::FromStr};
 use thiserror::Error;
-use tracing::{debug, instrument, trace};
+use tracing::{debug, instrument, trace, warn};
 use which::{which, which_all};
 
 use uv_cache::Cache;
@@ -20,14 +21,15 @@ use uv_pep440::{
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
+use crate::discovery_trace::{failure_reason, DiscoveryRecorder, DiscoveryTrace, Rejection};
 use crate::downloads::PythonDownloadRequest;
 use crate::implementation::ImplementationName;
 use crate::installation::PythonInstallation;
 use crate::interpreter::Error as InterpreterError;
-use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
 use crate::managed::ManagedPythonInstallations;
 #[cfg(windows)]
 use crate::microsoft_store::find_microsoft_store_pythons;
+use crate::platform::Arch;
 use crate::virtualenv::Error as VirtualEnvError;
 use crate::virtualenv::{
     conda_environment_from_env, virtualenv_from_env, virtualenv_from_working_dir,
@@ -62,6 +64,11 @@ pub enum PythonRequest {
     Implementation(ImplementationName),
     /// A Python implementation name and version e.g. `pypy3.8` or `pypy@3.8` or `pp38`
     ImplementationVersion(ImplementationName, VersionRequest),
+    /// The latest stable version of a Python implementation e.g. `latest` or `pypy@latest`
+    ///
+    /// Unlike other requests, which select the first matching installation, this selects the
+    /// newest matching installation. Pre-release versions are never selected.
+    Latest(ImplementationName),
     /// A request for a specific Python installation key e.g. `cpython-3.12-x86_64-linux-gnu`
     /// Generally these refer to managed Python downloads.
     Key(PythonDownloadRequest),
@@ -180,6 +187,60 @@ pub struct PythonNotFound {
     pub request: PythonRequest,
     pub python_preference: PythonPreference,
     pub environment_preference: EnvironmentPreference,
+    /// A pre-release interpreter that matched, but was skipped because the request did not opt-in
+    /// to pre-releases.
+    pub skipped_prerelease: Option<Version>,
+    /// The locations that were searched, and why each candidate was rejected.
+    pub trace: DiscoveryTrace,
+}
+
+/// The result of failed Python installation discovery for multiple requests.
+///
+/// Contains the reason each request could not be satisfied, in priority order. Returned by
+/// [`find_first_python_installation`].
+#[derive(Clone, Debug, Error)]
+pub struct PythonRequestsNotFound(pub Vec<PythonNotFound>);
+
+/// A memo of interpreter queries, to avoid querying the same executable repeatedly when evaluating
+/// multiple requests in a single discovery call.
+#[derive(Debug, Default)]
+pub(crate) struct QueriedInterpreters {
+    interpreters: RefCell<FxHashMap<PathBuf, Interpreter>>,
+    queries: Cell<usize>,
+}
+
+impl QueriedInterpreters {
+    /// Query the interpreter at the given path, unless it was already queried.
+    fn query(&self, path: &Path, cache: &Cache) -> Result<Interpreter, InterpreterError> {
+        if let Some(interpreter) = self.interpreters.borrow().get(path) {
+            trace!("Using memoized query for {}", path.display());
+            return Ok(interpreter.clone());
+        }
+        self.queries.set(self.queries.get() + 1);
+        let interpreter = Interpreter::query(path, cache)?;
+        self.interpreters
+            .borrow_mut()
+            .insert(path.to_path_buf(), interpreter.clone());
+        Ok(interpreter)
+    }
+
+    /// The number of interpreters that were actually queried.
+    #[cfg(test)]
+    pub(crate) fn queries(&self) -> usize {
+        self.queries.get()
+    }
+}
+
+/// Query the interpreter at the given path, using the memo if provided.
+fn query_interpreter(
+    path: &Path,
+    cache: &Cache,
+    queried: Option<&QueriedInterpreters>,
+) -> Result<Interpreter, InterpreterError> {
+    match queried {
+        Some(queried) => queried.query(path, cache),
+        None => Interpreter::query(path, cache),
+    }
 }
 
 /// A location for discovery of a Python installation or interpreter.
@@ -207,6 +268,8 @@ pub enum PythonSource {
     Managed,
     /// The Python installation was found via the invoking interpreter i.e. via `python -m uv ...`
     ParentInterpreter,
+    /// The path was provided via an environment variable e.g. `UV_PYTHON`
+    EnvironmentVariable(&'static str),
 }
 
 #[derive(Error, Debug)]
@@ -308,6 +371,7 @@ fn python_executables_from_installed<'a>(
     version: &'a VersionRequest,
     implementation: Option<&'a ImplementationName>,
     preference: PythonPreference,
+    recorder: Option<&'a DiscoveryRecorder>,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     let from_managed_installations = iter::once_with(move || {
         ManagedPythonInstallations::from_settings(None)
@@ -317,6 +381,9 @@ fn python_executables_from_installed<'a>(
                     "Searching for managed installations at `{}`",
                     installed_installations.root().user_display()
                 );
+                if let Some(recorder) = recorder {
+                    recorder.searched(PythonSource::Managed, Some(installed_installations.root()));
+                }
                 let installations = installed_installations.find_matching_current_platform()?;
                 // Check that the Python version satisfies the request to avoid unnecessary interpreter queries later
                 Ok(installations
@@ -336,7 +403,7 @@ fn python_executables_from_installed<'a>(
     .flatten_ok();
 
     let from_search_path = iter::once_with(move || {
-        python_executables_from_search_path(version, implementation)
+        python_executables_from_search_path(version, implementation, recorder)
             .enumerate()
             .map(|(i, path)| {
                 if i == 0 {
@@ -368,6 +435,9 @@ fn python_executables_from_installed<'a>(
             env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
                 .is_none()
                 .then(|| {
+                    if let Some(recorder) = recorder {
+                        recorder.searched(PythonSource::Registry, None);
+                    }
                     registry_pythons()
                         .map(|entries| {
                             entries
@@ -421,6 +491,7 @@ fn python_executables<'a>(
     implementation: Option<&'a ImplementationName>,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    recorder: Option<&'a DiscoveryRecorder>,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     // Always read from `UV_INTERNAL__PARENT_INTERPRETER` — it could be a system interpreter
     let from_parent_interpreter = iter::once_with(|| {
@@ -440,7 +511,8 @@ fn python_executables<'a>(
     .flatten();
 
     let from_virtual_environments = python_executables_from_virtual_environments();
-    let from_installed = python_executables_from_installed(version, implementation, preference);
+    let from_installed =
+        python_executables_from_installed(version, implementation, preference, recorder);
 
     // Limit the search to the relevant environment preference; this avoids unnecessary work like
     // traversal of the file system. Subsequent filtering should be done by the caller with
@@ -477,6 +549,7 @@ fn python_executables<'a>(
 fn python_executables_from_search_path<'a>(
     version: &'a VersionRequest,
     implementation: Option<&'a ImplementationName>,
+    recorder: Option<&'a DiscoveryRecorder>,
 ) -> impl Iterator<Item = PathBuf> + 'a {
     // `UV_TEST_PYTHON_PATH` can be used to override `PATH` to limit Python executable availability in the test suite
     let search_path = env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
@@ -520,6 +593,9 @@ fn python_executables_from_search_path<'a>(
                 // If we cannot determine if the directory is unique, we'll assume it is
                 .unwrap_or(true)
                 .then(|| {
+                    if let Some(recorder) = recorder {
+                        recorder.searched(PythonSource::SearchPath, Some(&dir));
+                    }
                     possible_names
                         .clone()
                         .into_iter()
@@ -634,22 +710,42 @@ fn python_interpreters<'a>(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &'a Cache,
+    queried: Option<&'a QueriedInterpreters>,
+    recorder: Option<&'a DiscoveryRecorder>,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     python_interpreters_from_executables(
         // Perform filtering on the discovered executables based on their source. This avoids
         // unnecessary interpreter queries, which are generally expensive. We'll filter again
         // with `interpreter_satisfies_environment_preference` after querying.
-        python_executables(version, implementation, environments, preference).filter_ok(
+        python_executables(version, implementation, environments, preference, recorder).filter_ok(
             move |(source, path)| {
-                source_satisfies_environment_preference(*source, path, environments)
+                let satisfies =
+                    source_satisfies_environment_preference(*source, path, environments);
+                if let (false, Some(recorder)) = (satisfies, recorder) {
+                    recorder.rejected(*source, path, Rejection::Environment(environments));
+                }
+                satisfies
             },
         ),
         cache,
+        queried,
     )
-    .filter_ok(move |(source, interpreter)| {
-        interpreter_satisfies_environment_preference(*source, interpreter, environments)
+    .filter_ok(move |(source, path, interpreter)| {
+        let satisfies =
+            interpreter_satisfies_environment_preference(*source, interpreter, environments);
+        if let (false, Some(recorder)) = (satisfies, recorder) {
+            recorder.rejected(*source, path, Rejection::Environment(environments));
+        }
+        satisfies
+    })
+    .filter_ok(move |(source, path, interpreter)| {
+        let satisfies = interpreter_satisfies_python_preference(*source, interpreter, preference);
+        if let (false, Some(recorder)) = (satisfies, recorder) {
+            recorder.rejected(*source, path, Rejection::Unmanaged);
+        }
+        satisfies
     })
-    .filter_ok(move |(source, interpreter)| {
+    .filter_ok(move |(source, path, interpreter)| {
         let request = version.clone().into_request_for_source(*source);
         if request.matches_interpreter(interpreter) {
             true
@@ -658,28 +754,40 @@ fn python_interpreters<'a>(
                 "Skipping interpreter at `{}` from {source}: does not satisfy request `{request}`",
                 interpreter.sys_executable().user_display()
             );
+            if let Some(recorder) = recorder {
+                recorder.rejected(*source, path, Rejection::request(interpreter));
+            }
             false
         }
     })
+    .map_ok(|(source, _, interpreter)| (source, interpreter))
 }
 
 /// Lazily convert Python executables into interpreters.
+///
+/// The path to each executable is retained alongside the interpreter, since it may differ from
+/// the interpreter's `sys.executable`.
 fn python_interpreters_from_executables<'a>(
     executables: impl Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a,
     cache: &'a Cache,
-) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
-    executables.map(|result| match result {
-        Ok((source, path)) => Interpreter::query(&path, cache)
-            .map(|interpreter| (source, interpreter))
-            .inspect(|(source, interpreter)| {
+    queried: Option<&'a QueriedInterpreters>,
+) -> impl Iterator<Item = Result<(PythonSource, PathBuf, Interpreter), Error>> + 'a {
+    executables.map(move |result| match result {
+        Ok((source, path)) => match query_interpreter(&path, cache, queried) {
+            Ok(interpreter) => {
                 debug!(
                     "Found `{}` at `{}` ({source})",
                     interpreter.key(),
                     path.display()
                 );
-            })
-            .map_err(|err| Error::Query(Box::new(err), path, source))
-            .inspect_err(|err| debug!("{err}")),
+                Ok((source, path, interpreter))
+            }
+            Err(err) => {
+                let err = Error::Query(Box::new(err), path, source);
+                debug!("{err}");
+                Err(err)
+            }
+        },
         Err(err) => Err(err),
     })
 }
@@ -713,7 +821,9 @@ fn interpreter_satisfies_environment_preference(
         (EnvironmentPreference::ExplicitSystem, false) => {
             if matches!(
                 source,
-                PythonSource::ProvidedPath | PythonSource::ParentInterpreter
+                PythonSource::ProvidedPath
+                    | PythonSource::EnvironmentVariable(_)
+                    | PythonSource::ParentInterpreter
             ) {
                 debug!(
                     "Allowing explicitly requested system Python interpreter at `{}`",
@@ -739,6 +849,41 @@ fn interpreter_satisfies_environment_preference(
     }
 }
 
+/// Whether an [`Interpreter`] matches the [`PythonPreference`].
+///
+/// Most sources are filtered by the [`PythonPreference`] before the interpreter is queried, but a
+/// parent interpreter or the base conda environment could be a system interpreter. With
+/// [`PythonPreference::OnlyManaged`], these are only allowed if they are virtual environments or
+/// managed installations.
+fn interpreter_satisfies_python_preference(
+    source: PythonSource,
+    interpreter: &Interpreter,
+    preference: PythonPreference,
+) -> bool {
+    if preference != PythonPreference::OnlyManaged
+        || !matches!(
+            source,
+            PythonSource::ParentInterpreter | PythonSource::BaseCondaPrefix
+        )
+        || interpreter.is_virtualenv()
+    {
+        return true;
+    }
+
+    let managed = ManagedPythonInstallations::from_settings(None).is_ok_and(|installations| {
+        interpreter
+            .sys_base_prefix()
+            .starts_with(installations.root())
+    });
+    if !managed {
+        debug!(
+            "Ignoring Python interpreter at `{}`: only managed interpreters allowed",
+            interpreter.sys_executable().display()
+        );
+    }
+    managed
+}
+
 /// Returns true if a [`PythonSource`] could satisfy the [`EnvironmentPreference`].
 ///
 /// This is useful as a pre-filtering step. Use of [`interpreter_satisfies_environment_preference`]
@@ -794,44 +939,26 @@ fn source_satisfies_environment_preference(
 impl Error {
     pub fn is_critical(&self) -> bool {
         match self {
-            // When querying the Python interpreter fails, we will only raise errors that demonstrate that something is broken
-            // If the Python interpreter returned a bad response, we'll continue searching for one that works
+            // When querying a Python interpreter fails, we'll continue searching for one that
+            // works. Only errors that demonstrate that something systemic is broken, e.g., the
+            // cache is not writable, are raised.
             Error::Query(err, _, source) => match &**err {
-                InterpreterError::Encode(_)
-                | InterpreterError::Io(_)
-                | InterpreterError::SpawnFailed { .. } => true,
-                InterpreterError::UnexpectedResponse(UnexpectedResponseError { path, .. })
-                | InterpreterError::StatusCode(StatusCodeError { path, .. }) => {
-                    debug!(
-                        "Skipping bad interpreter at {} from {source}: {err}",
-                        path.display()
-                    );
-                    false
-                }
-                InterpreterError::QueryScript { path, err } => {
-                    debug!(
-                        "Skipping bad interpreter at {} from {source}: {err}",
-                        path.display()
-                    );
-                    false
-                }
+                InterpreterError::Encode(_) | InterpreterError::Io(_) => true,
+                InterpreterError::UnexpectedResponse(_)
+                | InterpreterError::StatusCode(_)
+                | InterpreterError::QueryTimeout(_)
+                | InterpreterError::QueryScript { .. } => false,
                 InterpreterError::NotFound(path)
-                | InterpreterError::BrokenSymlink(BrokenSymlink { path, .. }) => {
+                | InterpreterError::BrokenSymlink(BrokenSymlink { path, .. })
+                | InterpreterError::SpawnFailed { path, .. }
+                | InterpreterError::Inaccessible { path, .. } => {
                     // If the interpreter is from an active, valid virtual environment, we should
                     // fail because it's broken
-                    if let Some(Ok(true)) = matches!(source, PythonSource::ActiveEnvironment)
-                        .then(|| {
-                            path.parent()
-                                .and_then(Path::parent)
-                                .map(|path| path.join("pyvenv.cfg").try_exists())
-                        })
-                        .flatten()
-                    {
-                        true
-                    } else {
-                        trace!("Skipping missing interpreter at {}", path.display());
-                        false
-                    }
+                    matches!(source, PythonSource::ActiveEnvironment)
+                        && path
+                            .parent()
+                            .and_then(Path::parent)
+                            .is_some_and(|path| path.join("pyvenv.cfg").is_file())
                 }
             },
             Error::VirtualEnv(VirtualEnvError::MissingPyVenvCfg(path)) => {
@@ -846,34 +973,41 @@ impl Error {
 /// Create a [`PythonInstallation`] from a Python interpreter path.
 fn python_installation_from_executable(
     path: &PathBuf,
+    source: PythonSource,
     cache: &Cache,
+    queried: Option<&QueriedInterpreters>,
 ) -> Result<PythonInstallation, crate::interpreter::Error> {
     Ok(PythonInstallation {
-        source: PythonSource::ProvidedPath,
-        interpreter: Interpreter::query(path, cache)?,
+        source,
+        interpreter: query_interpreter(path, cache, queried)?,
     })
 }
 
 /// Create a [`PythonInstallation`] from a Python installation root directory.
 fn python_installation_from_directory(
     path: &PathBuf,
+    source: PythonSource,
     cache: &Cache,
+    queried: Option<&QueriedInterpreters>,
 ) -> Result<PythonInstallation, crate::interpreter::Error> {
     let executable = virtualenv_python_executable(path);
-    python_installation_from_executable(&executable, cache)
+    python_installation_from_executable(&executable, source, cache, queried)
 }
 
 /// Lazily iterate over all Python interpreters on the path with the given executable name.
 fn python_interpreters_with_executable_name<'a>(
     name: &'a str,
     cache: &'a Cache,
+    queried: Option<&'a QueriedInterpreters>,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     python_interpreters_from_executables(
         which_all(name)
             .into_iter()
             .flat_map(|inner| inner.map(|path| Ok((PythonSource::SearchPath, path)))),
         cache,
+        queried,
     )
+    .map_ok(|(source, _, interpreter)| (source, interpreter))
 }
 
 /// Iterate over all Python installations that satisfy the given request.
@@ -882,6 +1016,24 @@ pub fn find_python_installations<'a>(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &'a Cache,
+) -> Box<dyn Iterator<Item = Result<FindPythonResult, Error>> + 'a> {
+    find_python_installations_with(request, environments, preference, None, cache, None, None)
+}
+
+/// Like [`find_python_installations`], but memoizes interpreter queries in `queried`, if provided.
+///
+/// If the request was read from an environment `variable`, e.g., `UV_PYTHON`, an interpreter at
+/// a provided path is attributed to it.
+///
+/// If a `recorder` is provided, the locations searched and the candidates rejected are recorded.
+fn find_python_installations_with<'a>(
+    request: &'a PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    variable: Option<&'static str>,
+    cache: &'a Cache,
+    queried: Option<&'a QueriedInterpreters>,
+    recorder: Option<&'a DiscoveryRecorder>,
 ) -> Box<dyn Iterator<Item = Result<FindPythonResult, Error>> + 'a> {
     let sources = DiscoveryPreferences {
         python_preference: preference,
@@ -892,21 +1044,23 @@ pub fn find_python_installations<'a>(
     match request {
         PythonRequest::File(path) => Box::new(iter::once({
             if preference.allows(PythonSource::ProvidedPath) {
-                debug!("Checking for Python interpreter at {request}");
-                match python_installation_from_executable(path, cache) {
+                let source = variable.map_or(
+                    PythonSource::ProvidedPath,
+                    PythonSource::EnvironmentVariable,
+                );
+                debug!("Checking for Python interpreter at {request} from {source}");
+                match python_installation_from_executable(path, source, cache, queried) {
                     Ok(installation) => Ok(Ok(installation)),
                     Err(InterpreterError::NotFound(_) | InterpreterError::BrokenSymlink(_)) => {
                         Ok(Err(PythonNotFound {
                             request: request.clone(),
                             python_preference: preference,
                             environment_preference: environments,
+                            skipped_prerelease: None,
+                            trace: DiscoveryTrace::default(),
                         }))
                     }
-                    Err(err) => Err(Error::Query(
-                        Box::new(err),
-                        path.clone(),
-                        PythonSource::ProvidedPath,
-                    )),
+                    Err(err) => Err(Error::Query(Box::new(err), path.clone(), source)),
                 }
             } else {
                 Err(Error::SourceNotAllowed(
@@ -918,21 +1072,23 @@ pub fn find_python_installations<'a>(
         })),
         PythonRequest::Directory(path) => Box::new(iter::once({
             if preference.allows(PythonSource::ProvidedPath) {
-                debug!("Checking for Python interpreter in {request}");
-                match python_installation_from_directory(path, cache) {
+                let source = variable.map_or(
+                    PythonSource::ProvidedPath,
+                    PythonSource::EnvironmentVariable,
+                );
+                debug!("Checking for Python interpreter in {request} from {source}");
+                match python_installation_from_directory(path, source, cache, queried) {
                     Ok(installation) => Ok(Ok(installation)),
                     Err(InterpreterError::NotFound(_) | InterpreterError::BrokenSymlink(_)) => {
                         Ok(Err(PythonNotFound {
                             request: request.clone(),
                             python_preference: preference,
                             environment_preference: environments,
+                            skipped_prerelease: None,
+                            trace: DiscoveryTrace::default(),
                         }))
                     }
-                    Err(err) => Err(Error::Query(
-                        Box::new(err),
-                        path.clone(),
-                        PythonSource::ProvidedPath,
-                    )),
+                    Err(err) => Err(Error::Query(Box::new(err), path.clone(), source)),
                 }
             } else {
                 Err(Error::SourceNotAllowed(
@@ -946,13 +1102,21 @@ pub fn find_python_installations<'a>(
             if preference.allows(PythonSource::SearchPath) {
                 debug!("Searching for Python interpreter with {request}");
                 Box::new(
-                    python_interpreters_with_executable_name(name, cache)
+                    python_interpreters_with_executable_name(name, cache, queried)
                         .filter_ok(move |(source, interpreter)| {
-                            interpreter_satisfies_environment_preference(
+                            let satisfies = interpreter_satisfies_environment_preference(
                                 *source,
                                 interpreter,
                                 environments,
-                            )
+                            );
+                            if let (false, Some(recorder)) = (satisfies, recorder) {
+                                recorder.rejected(
+                                    *source,
+                                    interpreter.sys_executable(),
+                                    Rejection::Environment(environments),
+                                );
+                            }
+                            satisfies
                         })
                         .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple))),
                 )
@@ -966,8 +1130,16 @@ pub fn find_python_installations<'a>(
         }
         PythonRequest::Any => Box::new({
             debug!("Searching for any Python interpreter in {sources}");
-            python_interpreters(&VersionRequest::Any, None, environments, preference, cache)
-                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+            python_interpreters(
+                &VersionRequest::Any,
+                None,
+                environments,
+                preference,
+                cache,
+                queried,
+                recorder,
+            )
+            .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
         PythonRequest::Default => Box::new({
             debug!("Searching for default Python interpreter in {sources}");
@@ -977,6 +1149,8 @@ pub fn find_python_installations<'a>(
                 environments,
                 preference,
                 cache,
+                queried,
+                recorder,
             )
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
@@ -986,8 +1160,16 @@ pub fn find_python_installations<'a>(
             }
             Box::new({
                 debug!("Searching for {request} in {sources}");
-                python_interpreters(version, None, environments, preference, cache)
-                    .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+                python_interpreters(
+                    version,
+                    None,
+                    environments,
+                    preference,
+                    cache,
+                    queried,
+                    recorder,
+                )
+                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
         PythonRequest::Implementation(implementation) => Box::new({
@@ -998,11 +1180,18 @@ pub fn find_python_installations<'a>(
                 environments,
                 preference,
                 cache,
+                queried,
+                recorder,
             )
-            .filter_ok(|(_source, interpreter)| {
-                interpreter
-                    .implementation_name()
-                    .eq_ignore_ascii_case(implementation.into())
+            .filter_ok(move |(source, interpreter)| {
+                record_unsatisfied(
+                    recorder,
+                    *source,
+                    interpreter,
+                    interpreter
+                        .implementation_name()
+                        .eq_ignore_ascii_case(implementation.into()),
+                )
             })
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
@@ -1018,15 +1207,45 @@ pub fn find_python_installations<'a>(
                     environments,
                     preference,
                     cache,
+                    queried,
+                    recorder,
                 )
-                .filter_ok(|(_source, interpreter)| {
-                    interpreter
-                        .implementation_name()
-                        .eq_ignore_ascii_case(implementation.into())
+                .filter_ok(move |(source, interpreter)| {
+                    record_unsatisfied(
+                        recorder,
+                        *source,
+                        interpreter,
+                        interpreter
+                            .implementation_name()
+                            .eq_ignore_ascii_case(implementation.into()),
+                    )
                 })
                 .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
+        PythonRequest::Latest(implementation) => Box::new({
+            debug!("Searching for {request} in {sources}");
+            python_interpreters(
+                &VersionRequest::Default,
+                Some(implementation),
+                environments,
+                preference,
+                cache,
+                queried,
+                recorder,
+            )
+            .filter_ok(move |(source, interpreter)| {
+                record_unsatisfied(
+                    recorder,
+                    *source,
+                    interpreter,
+                    interpreter
+                        .implementation_name()
+                        .eq_ignore_ascii_case(implementation.into()),
+                )
+            })
+            .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+        }),
         PythonRequest::Key(request) => {
             if let Some(version) = request.version() {
                 if let Err(err) = version.check_supported() {
@@ -1041,14 +1260,58 @@ pub fn find_python_installations<'a>(
                     environments,
                     preference,
                     cache,
+                    queried,
+                    recorder,
                 )
-                .filter_ok(|(_source, interpreter)| request.satisfied_by_interpreter(interpreter))
+                .filter_ok(move |(source, interpreter)| {
+                    record_unsatisfied(
+                        recorder,
+                        *source,
+                        interpreter,
+                        request.satisfied_by_interpreter(interpreter),
+                    )
+                })
                 .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
     }
 }
 
+/// Record an interpreter that does not satisfy the request, if a recorder is provided.
+///
+/// Returns whether the interpreter satisfies the request, for use in filters.
+fn record_unsatisfied(
+    recorder: Option<&DiscoveryRecorder>,
+    source: PythonSource,
+    interpreter: &Interpreter,
+    satisfied: bool,
+) -> bool {
+    if let (false, Some(recorder)) = (satisfied, recorder) {
+        recorder.rejected(
+            source,
+            interpreter.sys_executable(),
+            Rejection::request(interpreter),
+        );
+    }
+    satisfied
+}
+
+/// Whether the `candidate` installation should be preferred over the `current` installation for a
+/// [`PythonRequest::Latest`] request.
+///
+/// Newer versions are preferred. Between installations of the same version, those that run natively
+/// are preferred over those that require emulation, e.g., x86_64 builds under Rosetta 2 on Apple
+/// Silicon; otherwise, the installation found first is preferred.
+fn is_newer_installation(candidate: &PythonInstallation, current: &PythonInstallation) -> bool {
+    let host = Arch::from_env();
+    let native = |installation: &PythonInstallation| !host.is_emulated(installation.key().arch);
+    match candidate.python_version().cmp(current.python_version()) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => native(candidate) && !native(current),
+    }
+}
+
 /// Find a Python installation that satisfies the given request.
 ///
 /// If an error is encountered while locating or inspecting a candidate installation,
@@ -1059,12 +1322,43 @@ pub(crate) fn find_python_installation(
     preference: PythonPreference,
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
-    let installations = find_python_installations(request, environments, preference, cache);
+    find_python_installation_with(request, environments, preference, None, cache, None)
+}
+
+/// Like [`find_python_installation`], but memoizes interpreter queries in `queried`, if provided.
+///
+/// See [`find_python_installations_with`] for the use of `variable`.
+pub(crate) fn find_python_installation_with(
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    variable: Option<&'static str>,
+    cache: &Cache,
+    queried: Option<&QueriedInterpreters>,
+) -> Result<FindPythonResult, Error> {
+    let recorder = DiscoveryRecorder::default();
+    let installations = find_python_installations_with(
+        request,
+        environments,
+        preference,
+        variable,
+        cache,
+        queried,
+        Some(&recorder),
+    );
     let mut first_prerelease = None;
     let mut first_error = None;
+    let mut latest: Option<PythonInstallation> = None;
     for result in installations {
         // Iterate until the first critical error or happy result
         if !result.as_ref().err().is_none_or(Error::is_critical) {
+            // A broken candidate shouldn't prevent us from finding a working interpreter later
+            // in the search order, but it's surfaced since it's likely a misconfiguration.
+            if let Err(Error::Query(err, path, source)) = &result {
+                let reason = failure_reason(&**err);
+                warn!("Skipping broken interpreter from {source}: {reason}");
+                recorder.rejected(*source, path, Rejection::Failed(reason));
+            }
             // Track the first non-critical error
             if first_error.is_none() {
                 if let Err(err) = result {
@@ -1091,13 +1385,20 @@ pub(crate) fn find_python_installation(
             );
 
         // If it's a pre-release and pre-releases aren't allowed, skip it — but store it for later
-        // since we'll use a pre-release if no other versions are available.
+        // since we'll use a pre-release if no other versions are available. A request for the
+        // latest version never selects a pre-release, regardless of where it was found.
         if installation.python_version().pre().is_some()
-            && !request.allows_prereleases()
-            && !installation.source.allows_prereleases()
-            && !has_default_executable_name
+            && (matches!(request, PythonRequest::Latest(_))
+                || (!request.allows_prereleases()
+                    && !installation.source.allows_prereleases()
+                    && !has_default_executable_name))
         {
             debug!("Skipping pre-release {}", installation.key());
+            recorder.rejected(
+                installation.source,
+                installation.interpreter.sys_executable(),
+                Rejection::Prerelease(installation.python_version().clone()),
+            );
             if first_prerelease.is_none() {
                 first_prerelease = Some(installation.clone());
             }
@@ -1114,6 +1415,22 @@ pub(crate) fn find_python_installation(
             && !has_default_executable_name
         {
             debug!("Skipping alternative implementation {}", installation.key());
+            recorder.rejected(
+                installation.source,
+                installation.interpreter.sys_executable(),
+                Rejection::alternative_implementation(&installation.interpreter),
+            );
+            continue;
+        }
+
+        // If the latest version was requested, keep searching for a newer installation
+        if matches!(request, PythonRequest::Latest(_)) {
+            if latest
+                .as_ref()
+                .is_none_or(|latest| is_newer_installation(installation, latest))
+            {
+                latest = Some(installation.clone());
+            }
             continue;
         }
 
@@ -1121,24 +1438,108 @@ pub(crate) fn find_python_installation(
         return result;
     }
 
-    // If we only found pre-releases, they're implicitly allowed and we should return the first one.
-    if let Some(installation) = first_prerelease {
+    if let Some(installation) = latest {
+        debug!("Selected latest installation {}", installation.key());
         return Ok(Ok(installation));
     }
 
+    // If we only found pre-releases, they're implicitly allowed and we should return the first one,
+    // unless no version was requested at all or the latest version was requested; a pre-release
+    // is never selected by default.
+    if let Some(installation) = first_prerelease.as_ref() {
+        if !matches!(request, PythonRequest::Default | PythonRequest::Latest(_)) {
+            return Ok(Ok(installation.clone()));
+        }
+        debug!(
+            "Only found pre-release {}, which requires an explicit request",
+            installation.key()
+        );
+    }
+
     // If we found a Python, but it was unusable for some reason, report that instead of saying we
-    // couldn't find any Python interpreters.
+    // couldn't find any Python interpreters. If other interpreters were found, but rejected, the
+    // failure is included in the trace instead.
+    let trace = recorder.trace();
     if let Some(err) = first_error {
-        return Err(err);
+        if trace.only_failed() {
+            return Err(err);
+        }
     }
 
     Ok(Err(PythonNotFound {
         request: request.clone(),
         environment_preference: environments,
         python_preference: preference,
+        skipped_prerelease: first_prerelease
+            .map(|installation| installation.python_version().clone()),
+        trace,
     }))
 }
 
+/// Find a Python installation that satisfies one of the given requests, in priority order.
+///
+/// Each request is evaluated as in [`find_python_installation`], but interpreters are queried at
+/// most once across all requests. Returns the installation and the index of the request it
+/// satisfies.
+///
+/// A critical error is raised immediately. If a request is not satisfied, the next request is
+/// tried; if no request is satisfied, the reason for each request is returned.
+pub(crate) fn find_first_python_installation(
+    requests: &[PythonRequest],
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    variable: Option<&'static str>,
+    cache: &Cache,
+    queried: &QueriedInterpreters,
+) -> Result<Result<(usize, PythonInstallation), PythonRequestsNotFound>, Error> {
+    let mut not_found = Vec::with_capacity(requests.len());
+    let mut first_error = None;
+    for (index, request) in requests.iter().enumerate() {
+        match find_python_installation_with(
+            request,
+            environments,
+            preference,
+            variable,
+            cache,
+            Some(queried),
+        ) {
+            Ok(Ok(installation)) => {
+                debug!(
+                    "Found `{}` for request {index} ({request})",
+                    installation.key()
+                );
+                return Ok(Ok((index, installation)));
+            }
+            Ok(Err(err)) => {
+                debug!("{}; trying the next request", err.message());
+                not_found.push(err);
+            }
+            Err(err) if !err.is_critical() => {
+                debug!("{err}; trying the next request");
+                if first_error.is_none() {
+                    first_error = Some(err);
+                }
+                not_found.push(PythonNotFound {
+                    request: request.clone(),
+                    python_preference: preference,
+                    environment_preference: environments,
+                    skipped_prerelease: None,
+                    trace: DiscoveryTrace::default(),
+                });
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    // As in `find_python_installation`, if we found a Python, but it was unusable for some reason,
+    // report that instead of saying we couldn't find any Python interpreters.
+    if let Some(err) = first_error {
+        return Err(err);
+    }
+
+    Ok(Err(PythonRequestsNotFound(not_found)))
+}
+
 /// Find the best-matching Python installation.
 ///
 /// If no Python version is provided, we will use the first available installation.
@@ -1154,20 +1555,32 @@ pub fn find_best_python_installation(
     request: &PythonRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    variable: Option<&'static str>,
     cache: &Cache,
 ) -> Result<FindPythonResult, Error> {
     debug!("Starting Python discovery for {}", request);
 
+    // Each fallback below revisits the same interpreters, so only query them once
+    let queried = QueriedInterpreters::default();
+
     // First, check for an exact match (or the first available version if no Python version was provided)
     debug!("Looking for exact match for request {request}");
-    let result = find_python_installation(request, environments, preference, cache);
+    let mut trace = DiscoveryTrace::default();
+    let result = find_python_installation_with(
+        request,
+        environments,
+        preference,
+        variable,
+        cache,
+        Some(&queried),
+    );
     match result {
         Ok(Ok(installation)) => {
             warn_on_unsupported_python(installation.interpreter());
             return Ok(Ok(installation));
         }
         // Continue if we can't find a matching Python and ignore non-critical discovery errors
-        Ok(Err(_)) => {}
+        Ok(Err(err)) => trace.extend(err.trace),
         Err(ref err) if !err.is_critical() => {}
         _ => return result,
     }
@@ -1188,14 +1601,21 @@ pub fn find_best_python_installation(
         _ => None,
     } {
         debug!("Looking for relaxed patch version {request}");
-        let result = find_python_installation(&request, environments, preference, cache);
+        let result = find_python_installation_with(
+            &request,
+            environments,
+            preference,
+            variable,
+            cache,
+            Some(&queried),
+        );
         match result {
             Ok(Ok(installation)) => {
                 warn_on_unsupported_python(installation.interpreter());
                 return Ok(Ok(installation));
             }
             // Continue if we can't find a matching Python and ignore non-critical discovery errors
-            Ok(Err(_)) => {}
+            Ok(Err(err)) => trace.extend(err.trace),
             Err(ref err) if !err.is_critical() => {}
             _ => return result,
         }
@@ -1204,16 +1624,25 @@ pub fn find_best_python_installation(
     // If a Python version was requested but cannot be fulfilled, just take any version
     debug!("Looking for a default Python installation");
     let request = PythonRequest::Default;
-    Ok(
-        find_python_installation(&request, environments, preference, cache)?.map_err(|err| {
-            // Use a more general error in this case since we looked for multiple versions
-            PythonNotFound {
-                request,
-                python_preference: err.python_preference,
-                environment_preference: err.environment_preference,
-            }
-        }),
-    )
+    Ok(find_python_installation_with(
+        &request,
+        environments,
+        preference,
+        None,
+        cache,
+        Some(&queried),
+    )?
+    .map_err(|err| {
+        // Use a more general error in this case since we looked for multiple versions
+        trace.extend(err.trace);
+        PythonNotFound {
+            request,
+            python_preference: err.python_preference,
+            environment_preference: err.environment_preference,
+            skipped_prerelease: err.skipped_prerelease,
+            trace,
+        }
+    }))
 }
 
 /// Display a warning if the Python version of the [`Interpreter`] is unsupported by uv.
@@ -1397,6 +1826,9 @@ impl PythonRequest {
         if value.eq_ignore_ascii_case("default") {
             return Self::Default;
         }
+        if value.eq_ignore_ascii_case("latest") {
+            return Self::Latest(ImplementationName::CPython);
+        }
 
         // e.g. `3.12.1`, `312`, or `>=3.12`
         if let Ok(version) = VersionRequest::from_str(value) {
@@ -1408,9 +1840,12 @@ impl PythonRequest {
                 return Self::Version(version);
             }
         }
-        // e.g. `pypy@3.12`
+        // e.g. `pypy@3.12` or `pypy@latest`
         if let Some((first, second)) = value.split_once('@') {
             if let Ok(implementation) = ImplementationName::from_str(first) {
+                if second.eq_ignore_ascii_case("latest") {
+                    return Self::Latest(implementation);
+                }
                 if let Ok(version) = VersionRequest::from_str(second) {
                     return Self::ImplementationVersion(implementation, version);
                 }
@@ -1580,6 +2015,12 @@ impl PythonRequest {
                         .implementation_name()
                         .eq_ignore_ascii_case(implementation.into())
             }
+            PythonRequest::Latest(implementation) => {
+                interpreter.python_version().pre().is_none()
+                    && interpreter
+                        .implementation_name()
+                        .eq_ignore_ascii_case(implementation.into())
+            }
             PythonRequest::Key(request) => request.satisfied_by_interpreter(interpreter),
         }
     }
@@ -1593,6 +2034,7 @@ impl PythonRequest {
             Self::Directory(_) | Self::File(_) | Self::ExecutableName(_) => true,
             Self::Implementation(_) => false,
             Self::ImplementationVersion(_, _) => true,
+            Self::Latest(_) => false,
             Self::Key(request) => request.allows_prereleases(),
         }
     }
@@ -1606,6 +2048,7 @@ impl PythonRequest {
             Self::Directory(_) | Self::File(_) | Self::ExecutableName(_) => true,
             Self::Implementation(_) => true,
             Self::ImplementationVersion(_, _) => true,
+            Self::Latest(_) => true,
             Self::Key(request) => request.allows_alternative_implementations(),
         }
     }
@@ -1629,6 +2072,8 @@ impl PythonRequest {
             Self::ImplementationVersion(implementation, version) => {
                 format!("{implementation}@{version}")
             }
+            Self::Latest(ImplementationName::CPython) => "latest".to_string(),
+            Self::Latest(implementation) => format!("{implementation}@latest"),
             Self::Key(request) => request.to_string(),
         }
     }
@@ -1648,6 +2093,7 @@ impl PythonSource {
             | Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
+            | Self::EnvironmentVariable(_)
             | Self::ParentInterpreter
             | Self::ActiveEnvironment
             | Self::DiscoveredEnvironment => true,
@@ -1667,6 +2113,7 @@ impl PythonSource {
             Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
+            | Self::EnvironmentVariable(_)
             | Self::ParentInterpreter
             | Self::ActiveEnvironment
             | Self::DiscoveredEnvironment => true,
@@ -1687,6 +2134,7 @@ impl PythonSource {
     pub(crate) fn is_maybe_virtualenv(self) -> bool {
         match self {
             Self::ProvidedPath
+            | Self::EnvironmentVariable(_)
             | Self::ActiveEnvironment
             | Self::DiscoveredEnvironment
             | Self::CondaPrefix
@@ -1704,6 +2152,7 @@ impl PythonSource {
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
             | Self::ProvidedPath
+            | Self::EnvironmentVariable(_)
             | Self::Managed
             | Self::SearchPath
             | Self::SearchPathFirst
@@ -2115,6 +2564,7 @@ impl VersionRequest {
                 | PythonSource::CondaPrefix
                 | PythonSource::BaseCondaPrefix
                 | PythonSource::ProvidedPath
+                | PythonSource::EnvironmentVariable(_)
                 | PythonSource::DiscoveredEnvironment
                 | PythonSource::ActiveEnvironment => Self::Any,
                 PythonSource::SearchPath
@@ -2533,6 +2983,7 @@ impl fmt::Display for PythonRequest {
             Self::ImplementationVersion(implementation, version) => {
                 write!(f, "{} {version}", implementation.pretty())
             }
+            Self::Latest(implementation) => write!(f, "the latest {}", implementation.pretty()),
             Self::Key(request) => write!(f, "{request}"),
         }
     }
@@ -2551,6 +3002,7 @@ impl fmt::Display for PythonSource {
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
             Self::Managed => f.write_str("managed installations"),
             Self::ParentInterpreter => f.write_str("parent interpreter"),
+            Self::EnvironmentVariable(name) => write!(f, "`{name}` environment variable"),
         }
     }
 }
@@ -2634,26 +3086,74 @@ impl DiscoveryPreferences {
     }
 }
 
+impl PythonNotFound {
+    /// Display the reason discovery failed, without the [`DiscoveryTrace`].
+    pub fn message(&self) -> impl fmt::Display + '_ {
+        PythonNotFoundMessage(self)
+    }
+}
+
 impl fmt::Display for PythonNotFound {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.message(), self.trace)
+    }
+}
+
+/// The reason discovery failed, as displayed by [`PythonNotFound::message`].
+struct PythonNotFoundMessage<'a>(&'a PythonNotFound);
+
+impl fmt::Display for PythonNotFoundMessage<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Self(not_found) = self;
         let sources = DiscoveryPreferences {
-            python_preference: self.python_preference,
-            environment_preference: self.environment_preference,
+            python_preference: not_found.python_preference,
+            environment_preference: not_found.environment_preference,
         }
-        .sources(&self.request);
+        .sources(&not_found.request);
 
-        match self.request {
+        match not_found.request {
             PythonRequest::Default | PythonRequest::Any => {
-                write!(f, "No interpreter found in {sources}")
+                write!(f, "No interpreter found in {sources}")?;
             }
             PythonRequest::File(_) => {
-                write!(f, "No interpreter found at {}", self.request)
+                write!(f, "No interpreter found at {}", not_found.request)?;
             }
             PythonRequest::Directory(_) => {
-                write!(f, "No interpreter found in {}", self.request)
+                write!(f, "No interpreter found in {}", not_found.request)?;
             }
             _ => {
-                write!(f, "No interpreter found for {} in {sources}", self.request)
+                write!(
+                    f,
+                    "No interpreter found for {} in {sources}",
+                    not_found.request
+                )?;
+            }
+        }
+
+        if let Some(version) = &not_found.skipped_prerelease {
+            write!(
+                f,
+                "; the pre-release Python {version} was found, but pre-releases must be requested explicitly, e.g., `{version}`"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for PythonRequestsNotFound {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.as_slice() {
+            [] => write!(f, "No Python requests were provided"),
+            [not_found] => write!(f, "{not_found}"),
+            not_found => {
+                write!(f, "No interpreter found for any of the requests:")?;
+                let mut trace = DiscoveryTrace::default();
+                for not_found in not_found {
+                    write!(f, "\n- {}", not_found.message())?;
+                    trace.extend(not_found.trace.clone());
+                }
+                write!(f, "{trace}")
             }
         }
     }
@@ -2721,6 +3221,7 @@ mod tests {
     use uv_pep440::{Prerelease, PrereleaseKind, VersionSpecifiers};
 
     use crate::{
+        build_flavor::BuildFlavor,
         discovery::{PythonRequest, VersionRequest},
         downloads::PythonDownloadRequest,
         implementation::ImplementationName,
@@ -2733,6 +3234,18 @@ mod tests {
     fn interpreter_request_from_str() {
         assert_eq!(PythonRequest::parse("any"), PythonRequest::Any);
         assert_eq!(PythonRequest::parse("default"), PythonRequest::Default);
+        assert_eq!(
+            PythonRequest::parse("latest"),
+            PythonRequest::Latest(ImplementationName::CPython)
+        );
+        assert_eq!(
+            PythonRequest::parse("cpython@latest"),
+            PythonRequest::Latest(ImplementationName::CPython)
+        );
+        assert_eq!(
+            PythonRequest::parse("pypy@latest"),
+            PythonRequest::Latest(ImplementationName::PyPy)
+        );
         assert_eq!(
             PythonRequest::parse("3.12"),
             PythonRequest::Version(VersionRequest::from_str("3.12").unwrap())
@@ -2799,6 +3312,7 @@ mod tests {
                 arch: None,
                 os: None,
                 libc: None,
+                build_flavor: None,
                 prereleases: None
             })
         );
@@ -2818,6 +3332,22 @@ mod tests {
                 }),
                 os: Some(Os(target_lexicon::OperatingSystem::Darwin(None))),
                 libc: Some(Libc::None),
+                build_flavor: Some(BuildFlavor::Default),
+                prereleases: None
+            })
+        );
+        assert_eq!(
+            PythonRequest::parse("cpython-3.11-macos-x86_64-none"),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: Some(VersionRequest::MajorMinor(3, 11, PythonVariant::Default)),
+                implementation: Some(ImplementationName::CPython),
+                arch: Some(Arch {
+                    family: Architecture::X86_64,
+                    variant: None
+                }),
+                os: Some(Os(target_lexicon::OperatingSystem::Darwin(None))),
+                libc: Some(Libc::None),
+                build_flavor: None,
                 prereleases: None
             })
         );
@@ -2834,6 +3364,7 @@ mod tests {
                 arch: None,
                 os: None,
                 libc: None,
+                build_flavor: None,
                 prereleases: None
             })
         );
@@ -2853,6 +3384,7 @@ mod tests {
                 }),
                 os: None,
                 libc: None,
+                build_flavor: None,
                 prereleases: None
             })
         );
@@ -2973,6 +3505,14 @@ mod tests {
     fn interpreter_request_to_canonical_string() {
         assert_eq!(PythonRequest::Default.to_canonical_string(), "default");
         assert_eq!(PythonRequest::Any.to_canonical_string(), "any");
+        assert_eq!(
+            PythonRequest::Latest(ImplementationName::CPython).to_canonical_string(),
+            "latest"
+        );
+        assert_eq!(
+            PythonRequest::Latest(ImplementationName::PyPy).to_canonical_string(),
+            "pypy@latest"
+        );
         assert_eq!(
             PythonRequest::Version(VersionRequest::from_str("3.12").unwrap()).to_canonical_string(),
             "3.12"
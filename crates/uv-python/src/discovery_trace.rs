@@ -0,0 +1,333 @@
+//! A record of where Python discovery searched, and why each candidate was rejected.
+
+use std::cell::RefCell;
+use std::fmt::{self, Formatter};
+use std::path::{Path, PathBuf};
+
+use owo_colors::OwoColorize;
+
+use uv_fs::Simplified;
+use uv_pep440::Version;
+
+use crate::discovery::{EnvironmentPreference, PythonSource};
+use crate::implementation::LenientImplementationName;
+use crate::Interpreter;
+
+/// The locations searched during Python discovery, and the reason each candidate interpreter was
+/// rejected.
+///
+/// A compact summary is displayed by default; the full trace is displayed with `--verbose`.
+#[derive(Clone, Debug, Default)]
+pub struct DiscoveryTrace {
+    /// The locations that were searched, in order.
+    searched: Vec<SearchedLocation>,
+    /// The candidate interpreters that were rejected, in order.
+    rejected: Vec<RejectedInterpreter>,
+}
+
+/// A location that was searched for Python interpreters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchedLocation {
+    source: PythonSource,
+    /// The searched directory, if the source has one.
+    path: Option<PathBuf>,
+}
+
+/// A candidate interpreter that was rejected during discovery.
+#[derive(Clone, Debug)]
+pub struct RejectedInterpreter {
+    source: PythonSource,
+    path: PathBuf,
+    reason: Rejection,
+}
+
+/// The reason a candidate interpreter was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// The interpreter could not be queried, e.g., because it failed to run.
+    Failed(String),
+    /// The interpreter is excluded by the [`EnvironmentPreference`].
+    Environment(EnvironmentPreference),
+    /// The interpreter is not managed, but only managed interpreters are allowed.
+    Unmanaged,
+    /// The interpreter does not satisfy the request; contains a description of the interpreter.
+    Request(String),
+    /// The interpreter is a pre-release, which must be requested explicitly.
+    Prerelease(Version),
+    /// The interpreter is an alternative implementation, which must be requested explicitly.
+    AlternativeImplementation(String),
+}
+
+impl DiscoveryTrace {
+    /// The locations that were searched, in order.
+    pub fn searched(&self) -> &[SearchedLocation] {
+        &self.searched
+    }
+
+    /// The candidate interpreters that were rejected, in order.
+    pub fn rejected(&self) -> &[RejectedInterpreter] {
+        &self.rejected
+    }
+
+    /// Returns `true` if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.searched.is_empty() && self.rejected.is_empty()
+    }
+
+    /// Returns `true` if every rejected candidate failed to run, i.e., no usable interpreter was
+    /// found at all.
+    pub(crate) fn only_failed(&self) -> bool {
+        self.rejected
+            .iter()
+            .all(|rejected| matches!(rejected.reason, Rejection::Failed(_)))
+    }
+
+    /// Add the entries of another trace, skipping locations and candidates that are already
+    /// present.
+    pub(crate) fn extend(&mut self, other: DiscoveryTrace) {
+        for location in other.searched {
+            if !self.searched.contains(&location) {
+                self.searched.push(location);
+            }
+        }
+        for rejected in other.rejected {
+            if !self
+                .rejected
+                .iter()
+                .any(|existing| existing.path == rejected.path)
+            {
+                self.rejected.push(rejected);
+            }
+        }
+    }
+
+    /// The number of rejected candidates that were found in the given location.
+    fn candidates(&self, location: &SearchedLocation) -> usize {
+        self.rejected
+            .iter()
+            .filter(|rejected| location.contains(rejected))
+            .count()
+    }
+
+    /// Write a one-line summary of the candidates that failed to run, if any.
+    fn fmt_summary(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let failed = self
+            .rejected
+            .iter()
+            .filter(|rejected| matches!(rejected.reason, Rejection::Failed(_)))
+            .count();
+        if failed == 0 {
+            return Ok(());
+        }
+        write!(
+            f,
+            "\n\n{}{} {failed} candidate {} failed to run; use `{}` to show where uv searched and why each candidate was rejected",
+            "hint".bold().cyan(),
+            ":".bold(),
+            if failed == 1 {
+                "interpreter"
+            } else {
+                "interpreters"
+            },
+            "--verbose".green(),
+        )
+    }
+
+    /// Write every searched location and rejected candidate.
+    fn fmt_full(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.searched.is_empty() {
+            write!(f, "\n\nSearched:")?;
+            for location in &self.searched {
+                let candidates = match self.candidates(location) {
+                    0 => "no candidates".to_string(),
+                    1 => "1 candidate".to_string(),
+                    n => format!("{n} candidates"),
+                };
+                write!(f, "\n- {location} ({candidates})")?;
+            }
+        }
+        if !self.rejected.is_empty() {
+            write!(f, "\n\nRejected:")?;
+            for rejected in &self.rejected {
+                write!(
+                    f,
+                    "\n- `{}` from {}: {}",
+                    rejected.path.user_display(),
+                    rejected.source,
+                    rejected.reason
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DiscoveryTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            self.fmt_full(f)
+        } else {
+            self.fmt_summary(f)
+        }
+    }
+}
+
+impl SearchedLocation {
+    /// The source of the location.
+    pub fn source(&self) -> PythonSource {
+        self.source
+    }
+
+    /// The searched directory, if the source has one.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Whether the rejected candidate was found in this location.
+    fn contains(&self, rejected: &RejectedInterpreter) -> bool {
+        match (self.source, self.path.as_deref()) {
+            (PythonSource::SearchPath | PythonSource::SearchPathFirst, Some(dir)) => {
+                matches!(
+                    rejected.source,
+                    PythonSource::SearchPath | PythonSource::SearchPathFirst
+                ) && rejected.path.parent() == Some(dir)
+            }
+            (PythonSource::Registry | PythonSource::MicrosoftStore, _) => matches!(
+                rejected.source,
+                PythonSource::Registry | PythonSource::MicrosoftStore
+            ),
+            (source, Some(path)) => rejected.source == source && rejected.path.starts_with(path),
+            (source, None) => rejected.source == source,
+        }
+    }
+}
+
+impl fmt::Display for SearchedLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.source, self.path.as_deref()) {
+            (PythonSource::SearchPath | PythonSource::SearchPathFirst, Some(path)) => {
+                write!(f, "`{}` in the search path", path.user_display())
+            }
+            (source, Some(path)) => write!(f, "{source} in `{}`", path.user_display()),
+            (source, None) => write!(f, "{source}"),
+        }
+    }
+}
+
+impl RejectedInterpreter {
+    /// The source of the candidate.
+    pub fn source(&self) -> PythonSource {
+        self.source
+    }
+
+    /// The path to the candidate executable.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The reason the candidate was rejected.
+    pub fn reason(&self) -> &Rejection {
+        &self.reason
+    }
+}
+
+impl Rejection {
+    /// A rejection for an interpreter that does not satisfy the request.
+    pub(crate) fn request(interpreter: &Interpreter) -> Self {
+        Self::Request(describe(interpreter))
+    }
+
+    /// A rejection for an alternative implementation that was not requested.
+    pub(crate) fn alternative_implementation(interpreter: &Interpreter) -> Self {
+        Self::AlternativeImplementation(describe(interpreter))
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(err) => write!(f, "failed to run: {err}"),
+            Self::Environment(EnvironmentPreference::OnlyVirtual) => {
+                f.write_str("only virtual environments allowed")
+            }
+            Self::Environment(EnvironmentPreference::ExplicitSystem) => {
+                f.write_str("system interpreter not explicitly requested")
+            }
+            Self::Environment(EnvironmentPreference::OnlySystem) => {
+                f.write_str("system interpreter required")
+            }
+            Self::Environment(EnvironmentPreference::Any) => {
+                f.write_str("excluded by the environment preference")
+            }
+            Self::Unmanaged => f.write_str("only managed interpreters allowed"),
+            Self::Request(interpreter) => write!(f, "{interpreter} does not satisfy the request"),
+            Self::Prerelease(version) => write!(
+                f,
+                "the pre-release Python {version} must be requested explicitly"
+            ),
+            Self::AlternativeImplementation(interpreter) => {
+                write!(f, "{interpreter} must be requested explicitly")
+            }
+        }
+    }
+}
+
+/// Describe why an interpreter could not be queried, including the underlying causes.
+///
+/// Only the first line of each error is retained, to keep the trace compact.
+pub(crate) fn failure_reason(err: &dyn std::error::Error) -> String {
+    let mut reason = String::new();
+    for err in std::iter::successors(Some(err), |err| err.source()) {
+        let err = err.to_string();
+        let line = err.lines().next().unwrap_or_default().trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        if !reason.is_empty() {
+            reason.push_str(": ");
+        }
+        reason.push_str(line);
+    }
+    reason
+}
+
+/// Describe an interpreter by its implementation and version, e.g., `CPython 3.12.1`.
+fn describe(interpreter: &Interpreter) -> String {
+    format!(
+        "{} {}",
+        LenientImplementationName::from(interpreter.implementation_name()).pretty(),
+        interpreter.python_version()
+    )
+}
+
+/// Records a [`DiscoveryTrace`] while discovery is in progress.
+#[derive(Debug, Default)]
+pub(crate) struct DiscoveryRecorder(RefCell<DiscoveryTrace>);
+
+impl DiscoveryRecorder {
+    /// Record a location that was searched.
+    pub(crate) fn searched(&self, source: PythonSource, path: Option<&Path>) {
+        let location = SearchedLocation {
+            source,
+            path: path.map(Path::to_path_buf),
+        };
+        let mut trace = self.0.borrow_mut();
+        if !trace.searched.contains(&location) {
+            trace.searched.push(location);
+        }
+    }
+
+    /// Record a candidate interpreter that was rejected.
+    pub(crate) fn rejected(&self, source: PythonSource, path: &Path, reason: Rejection) {
+        self.0.borrow_mut().rejected.push(RejectedInterpreter {
+            source,
+            path: path.to_path_buf(),
+            reason,
+        });
+    }
+
+    /// Return a copy of the trace recorded so far.
+    pub(crate) fn trace(&self) -> DiscoveryTrace {
+        self.0.borrow().clone()
+    }
+}
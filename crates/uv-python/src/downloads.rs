@@ -13,7 +13,7 @@ use itertools::Itertools;
 use once_cell::sync::OnceCell;
 use owo_colors::OwoColorize;
 use reqwest_retry::RetryPolicy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncWriteExt, BufWriter, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
@@ -21,6 +21,8 @@ use tokio_util::either::Either;
 use tracing::{debug, instrument};
 use url::Url;
 
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness};
+use uv_cache_key::cache_digest;
 use uv_client::{is_extended_transient_error, BaseClient, WrappedReqwestError};
 use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
@@ -28,13 +30,16 @@ use uv_fs::{rename_with_retry, Simplified};
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_static::EnvVars;
 
+use crate::build_flavor::BuildFlavor;
+use crate::dylib::DylibPatchReport;
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
+use crate::install_mirrors::InstallMirrors;
 use crate::installation::PythonInstallationKey;
 use crate::libc::LibcDetectionError;
 use crate::managed::ManagedPythonInstallation;
-use crate::platform::{self, Arch, Libc, Os};
+use crate::platform::{self, Arch, Libc, Os, TargetPlatform};
 use crate::PythonVariant;
 use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
 
@@ -54,6 +59,35 @@ pub enum Error {
     NetworkError(Url, #[source] WrappedReqwestError),
     #[error("Failed to download {0}")]
     NetworkMiddlewareError(Url, #[source] anyhow::Error),
+    #[error("Failed to download {url}")]
+    ProxyError {
+        url: Url,
+        /// The environment variable that configured the proxy, if known.
+        variable: Option<String>,
+        #[source]
+        err: WrappedReqwestError,
+    },
+    #[error("Failed to download {url}")]
+    TlsError {
+        url: Url,
+        /// The reason the certificate was rejected, as reported by the TLS implementation.
+        reason: String,
+        #[source]
+        err: WrappedReqwestError,
+    },
+    #[error("Failed to download {url}")]
+    DnsError {
+        url: Url,
+        #[source]
+        err: WrappedReqwestError,
+    },
+    #[error("Failed to download {url}")]
+    HttpStatusError {
+        url: Url,
+        status: reqwest::StatusCode,
+        #[source]
+        err: WrappedReqwestError,
+    },
     #[error("Failed to extract archive: {0}")]
     ExtractError(String, #[source] uv_extract::Error),
     #[error("Failed to hash installation")]
@@ -88,10 +122,26 @@ pub enum Error {
     InvalidRequestPlatform(#[from] platform::Error),
     #[error("No download found for request: {}", _0.green())]
     NoDownloadFound(PythonDownloadRequest),
+    #[error(
+        "No stable download found for request: {}; the pre-release {} is available, but pre-releases must be requested explicitly, e.g., `{}`",
+        _0.green(),
+        _1.green(),
+        _1.version()
+    )]
+    OnlyPrereleaseDownloadFound(PythonDownloadRequest, PythonInstallationKey),
     #[error(
         "A mirror was provided via `{0}`, but the URL does not match the expected format: {0}"
     )]
     Mirror(&'static str, &'static str),
+    #[error(
+        "Failed to download {key} from any mirror, or from the upstream URL:\n{}",
+        format_mirror_failures(failures)
+    )]
+    MirrorsExhausted {
+        key: Box<PythonInstallationKey>,
+        /// The URL attempted at each mirror and upstream, and the error that occurred.
+        failures: Vec<(Url, Error)>,
+    },
     #[error(transparent)]
     LibcDetection(#[from] LibcDetectionError),
     #[error("Remote python downloads JSON is not yet supported, please use a local path (without `file://` prefix)")]
@@ -121,8 +171,11 @@ pub struct PythonDownloadRequest {
     pub(crate) os: Option<Os>,
     pub(crate) libc: Option<Libc>,
 
-    /// Whether to allow pre-releases or not. If not set, defaults to true if [`Self::version`] is
-    /// not None, and false otherwise.
+    /// The build flavor to select. If not set, the fastest available flavor is preferred.
+    pub(crate) build_flavor: Option<BuildFlavor>,
+
+    /// Whether to allow pre-releases or not. If not set, defaults to true if [`Self::version`]
+    /// explicitly requests a pre-release, and false otherwise.
     pub(crate) prereleases: Option<bool>,
 }
 
@@ -141,6 +194,7 @@ impl PythonDownloadRequest {
             arch,
             os,
             libc,
+            build_flavor: None,
             prereleases,
         }
     }
@@ -163,6 +217,15 @@ impl PythonDownloadRequest {
         self
     }
 
+    /// Request a download for the given platform, rather than the current one.
+    #[must_use]
+    pub fn with_platform(mut self, platform: TargetPlatform) -> Self {
+        self.os = Some(platform.os);
+        self.arch = Some(platform.arch);
+        self.libc = Some(platform.libc);
+        self
+    }
+
     #[must_use]
     pub fn with_any_arch(mut self) -> Self {
         self.arch = None;
@@ -181,6 +244,12 @@ impl PythonDownloadRequest {
         self
     }
 
+    #[must_use]
+    pub fn with_build_flavor(mut self, build_flavor: BuildFlavor) -> Self {
+        self.build_flavor = Some(build_flavor);
+        self
+    }
+
     #[must_use]
     pub fn with_prereleases(mut self, prereleases: bool) -> Self {
         self.prereleases = Some(prereleases);
@@ -202,6 +271,13 @@ impl PythonDownloadRequest {
                     .with_implementation(*implementation)
                     .with_version(version.clone()),
             ),
+            // The downloads are sorted from newest to oldest, so the first stable match is the latest
+            PythonRequest::Latest(implementation) => Some(
+                Self::default()
+                    .with_implementation(*implementation)
+                    .with_version(VersionRequest::Default)
+                    .with_prereleases(false),
+            ),
             PythonRequest::Key(request) => Some(request.clone()),
             PythonRequest::Default | PythonRequest::Any => Some(Self::default()),
             // We can't download a managed installation for these request kinds
@@ -213,7 +289,8 @@ impl PythonDownloadRequest {
 
     /// Fill empty entries with default values.
     ///
-    /// Platform information is pulled from the environment.
+    /// Platform information is pulled from the environment. On musl, the dynamically-linked
+    /// build is selected; statically-linked builds must be requested explicitly.
     pub fn fill_platform(mut self) -> Result<Self, Error> {
         if self.arch.is_none() {
             self.arch = Some(Arch::from_env());
@@ -267,13 +344,24 @@ impl PythonDownloadRequest {
         self.libc.as_ref()
     }
 
+    pub fn build_flavor(&self) -> Option<BuildFlavor> {
+        self.build_flavor
+    }
+
     /// Iterate over all [`PythonDownload`]'s that match this request.
+    ///
+    /// Downloads that run natively on the requested architecture are yielded before those that
+    /// require emulation, e.g., x86_64 builds under Rosetta 2 on Apple Silicon.
     pub fn iter_downloads(
         &self,
         python_downloads_json_url: Option<&str>,
     ) -> Result<impl Iterator<Item = &'static ManagedPythonDownload> + use<'_>, Error> {
         Ok(ManagedPythonDownload::iter_all(python_downloads_json_url)?
-            .filter(move |download| self.satisfied_by_download(download)))
+            .filter(move |download| self.satisfied_by_download(download))
+            .sorted_by_key(|download| {
+                self.arch
+                    .is_some_and(|arch| arch.is_emulated(download.key.arch))
+            }))
     }
 
     /// Whether this request is satisfied by an installation key.
@@ -295,6 +383,12 @@ impl PythonDownloadRequest {
                 return false;
             }
         }
+
+        if let Some(build_flavor) = self.build_flavor {
+            if key.build_flavor != build_flavor {
+                return false;
+            }
+        }
         if let Some(implementation) = &self.implementation {
             if key.implementation != LenientImplementationName::from(*implementation) {
                 return false;
@@ -336,6 +430,20 @@ impl PythonDownloadRequest {
         })
     }
 
+    /// Whether this download request specifies every component of an installation key.
+    fn is_exact(&self) -> bool {
+        self.implementation.is_some()
+            && self.version.as_ref().is_some_and(|version| {
+                matches!(
+                    version,
+                    VersionRequest::MajorMinorPatch(..) | VersionRequest::MajorMinorPrerelease(..)
+                )
+            })
+            && self.os.is_some()
+            && self.arch.is_some()
+            && self.libc.is_some()
+    }
+
     /// Whether this download request opts-in to alternative Python implementations.
     pub fn allows_alternative_implementations(&self) -> bool {
         self.implementation.is_some()
@@ -365,7 +473,24 @@ impl PythonDownloadRequest {
             }
         }
         if let Some(libc) = self.libc() {
-            if &Libc::from(interpreter.platform().os()) != libc {
+            // The interpreter doesn't report how it was linked, so a musl interpreter satisfies
+            // requests for both dynamically- and statically-linked builds.
+            if Libc::from(interpreter.platform().os()) != libc.family() {
+                return false;
+            }
+        }
+        if let Some(build_flavor) = self.build_flavor {
+            // The interpreter doesn't report its build flavor, but managed installations record
+            // it in the installation key, i.e., the name of the installation directory. Other
+            // interpreters are assumed to be default builds.
+            let flavor = interpreter
+                .sys_base_prefix()
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| PythonInstallationKey::from_str(name).ok())
+                .map(|key| key.build_flavor)
+                .unwrap_or_default();
+            if flavor != build_flavor {
                 return false;
             }
         }
@@ -387,6 +512,7 @@ impl From<&ManagedPythonInstallation> for PythonDownloadRequest {
             Some(key.libc),
             Some(key.prerelease.is_some()),
         )
+        .with_build_flavor(key.build_flavor)
     }
 }
 
@@ -399,7 +525,12 @@ impl Display for PythonDownloadRequest {
             parts.push("any".to_string());
         }
         if let Some(version) = &self.version {
-            parts.push(version.to_string());
+            parts.push(format!(
+                "{version}{}",
+                self.build_flavor
+                    .map(BuildFlavor::suffix)
+                    .unwrap_or_default()
+            ));
         } else {
             parts.push("any".to_string());
         }
@@ -426,12 +557,13 @@ impl FromStr for PythonDownloadRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split('-');
+        let mut parts = s.split('-').peekable();
         let mut version = None;
         let mut implementation = None;
         let mut os = None;
         let mut arch = None;
         let mut libc = None;
+        let mut build_flavor = None;
 
         let mut position = 0;
         loop {
@@ -446,6 +578,10 @@ impl FromStr for PythonDownloadRequest {
             match position {
                 1 => implementation = Some(ImplementationName::from_str(part)?),
                 2 => {
+                    let (part, flavor) = BuildFlavor::split_suffix(part);
+                    if flavor != BuildFlavor::Default {
+                        build_flavor = Some(flavor);
+                    }
                     version = Some(
                         VersionRequest::from_str(part)
                             .map_err(|_| Error::InvalidPythonVersion(part.to_string()))?,
@@ -453,11 +589,26 @@ impl FromStr for PythonDownloadRequest {
                 }
                 3 => os = Some(Os::from_str(part)?),
                 4 => arch = Some(Arch::from_str(part)?),
-                5 => libc = Some(Libc::from_str(part)?),
+                5 => {
+                    // A statically-linked libc spans two parts, e.g., `musl-static`.
+                    libc = Some(if parts.next_if_eq(&"static").is_some() {
+                        Libc::from_str(&format!("{part}-static"))?
+                    } else {
+                        Libc::from_str(part)?
+                    });
+                }
                 _ => return Err(Error::TooManyParts(s.to_string())),
             }
         }
-        Ok(Self::new(version, implementation, arch, os, libc, None))
+
+        let mut request = Self::new(version, implementation, arch, os, libc, None);
+        if let Some(build_flavor) = build_flavor {
+            request = request.with_build_flavor(build_flavor);
+        } else if request.is_exact() {
+            // An exact key without a flavor refers to the default build, rather than any flavor.
+            request = request.with_build_flavor(BuildFlavor::Default);
+        }
+        Ok(request)
     }
 }
 
@@ -465,25 +616,26 @@ const BUILTIN_PYTHON_DOWNLOADS_JSON: &str = include_str!("download-metadata-mini
 static PYTHON_DOWNLOADS: OnceCell<std::borrow::Cow<'static, [ManagedPythonDownload]>> =
     OnceCell::new();
 
-#[derive(Debug, Deserialize, Clone)]
-struct JsonPythonDownload {
-    name: String,
-    arch: JsonArch,
-    os: String,
-    libc: String,
-    major: u8,
-    minor: u8,
-    patch: u8,
-    prerelease: Option<String>,
-    url: String,
-    sha256: Option<String>,
-    variant: Option<String>,
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub(crate) struct JsonPythonDownload {
+    pub(crate) name: String,
+    pub(crate) arch: JsonArch,
+    pub(crate) os: String,
+    pub(crate) libc: String,
+    pub(crate) major: u8,
+    pub(crate) minor: u8,
+    pub(crate) patch: u8,
+    pub(crate) prerelease: Option<String>,
+    pub(crate) url: String,
+    pub(crate) sha256: Option<String>,
+    pub(crate) variant: Option<String>,
+    pub(crate) build: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
-struct JsonArch {
-    family: String,
-    variant: Option<String>,
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub(crate) struct JsonArch {
+    pub(crate) family: String,
+    pub(crate) variant: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -495,8 +647,9 @@ pub enum DownloadResult {
 impl ManagedPythonDownload {
     /// Return the first [`ManagedPythonDownload`] matching a request, if any.
     ///
-    /// If there is no stable version matching the request, a compatible pre-release version will
-    /// be searched for — even if a pre-release was not explicitly requested.
+    /// Pre-release versions are only considered if the request opts-in to them, i.e., it names a
+    /// pre-release version or [`PythonDownloadRequest::with_prereleases`] was used. If only a
+    /// pre-release matches the request, an error is returned that points to it.
     pub fn from_request(
         request: &PythonDownloadRequest,
         python_downloads_json_url: Option<&str>,
@@ -512,12 +665,47 @@ impl ManagedPythonDownload {
                 .iter_downloads(python_downloads_json_url)?
                 .next()
             {
-                return Ok(download);
+                return Err(Error::OnlyPrereleaseDownloadFound(
+                    request.clone(),
+                    download.key().clone(),
+                ));
             }
         }
 
         Err(Error::NoDownloadFound(request.clone()))
     }
+
+    /// Return the latest [`ManagedPythonDownload`] that is an upgrade of the given installation,
+    /// if any.
+    ///
+    /// Only downloads for the same platform and build flavor are considered, and the upgrade
+    /// rules match those of [`PythonInstallationKey::is_upgrade_of`].
+    pub fn latest_available_patch<'a>(
+        key: &PythonInstallationKey,
+        downloads: impl IntoIterator<Item = &'a ManagedPythonDownload>,
+    ) -> Option<&'a ManagedPythonDownload> {
+        downloads
+            .into_iter()
+            .filter(|download| {
+                download.key.os == key.os
+                    && download.key.arch == key.arch
+                    && download.key.libc == key.libc
+                    && download.key.build_flavor == key.build_flavor
+                    && download.key.is_upgrade_of(key)
+            })
+            .max_by(|a, b| a.key.cmp(&b.key))
+    }
+
+    /// Parse the [`ManagedPythonDownload`]s from the contents of a downloads JSON file, e.g., as
+    /// fetched from a remote `python-downloads-json-url`.
+    ///
+    /// The downloads are sorted from newest to oldest.
+    pub fn from_json(contents: &[u8], source: &str) -> Result<Vec<Self>, Error> {
+        let json_downloads: HashMap<String, JsonPythonDownload> = serde_json::from_slice(contents)
+            .map_err(|err| Error::InvalidPythonDownloadsJSON(source.to_string(), err))?;
+        Ok(parse_json_downloads(json_downloads))
+    }
+
     //noinspection RsUnresolvedPath - RustRover can't see through the `include!`
 
     /// Iterate over all [`ManagedPythonDownload`]s.
@@ -572,68 +760,158 @@ impl ManagedPythonDownload {
     }
 
     /// Download and extract a Python distribution, retrying on failure.
-    #[instrument(skip(client, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
+    ///
+    /// If multiple mirrors are provided, each is tried in turn, and the retry policy applies to
+    /// each mirror.
+    #[instrument(skip(client, cache, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
     pub async fn fetch_with_retry(
         &self,
         client: &BaseClient,
+        cache: &Cache,
         installation_dir: &Path,
         scratch_dir: &Path,
         reinstall: bool,
-        python_install_mirror: Option<&str>,
-        pypy_install_mirror: Option<&str>,
+        install_mirrors: &InstallMirrors,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
-        let mut n_past_retries = 0;
-        let start_time = SystemTime::now();
-        let retry_policy = client.retry_policy();
-        loop {
-            let result = self
-                .fetch(
-                    client,
-                    installation_dir,
-                    scratch_dir,
-                    reinstall,
-                    python_install_mirror,
-                    pypy_install_mirror,
-                    reporter,
-                )
-                .await;
-            if result
-                .as_ref()
-                .err()
-                .is_some_and(|err| is_extended_transient_error(err))
-            {
-                let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
-                if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
-                    debug!(
-                        "Transient failure while handling response for {}; retrying...",
-                        self.key()
-                    );
-                    let duration = execute_after
-                        .duration_since(SystemTime::now())
-                        .unwrap_or_else(|_| Duration::default());
-                    tokio::time::sleep(duration).await;
-                    n_past_retries += 1;
-                    continue;
+        self.fetch_from_mirrors(
+            client,
+            cache,
+            installation_dir,
+            scratch_dir,
+            reinstall,
+            install_mirrors,
+            true,
+            reporter,
+        )
+        .await
+    }
+
+    /// Download and extract a Python distribution.
+    ///
+    /// Unless the cache is temporary (i.e., `--no-cache`), the archive is persisted to the cache
+    /// and reused by subsequent installations of the same download.
+    #[instrument(skip(client, cache, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
+    pub async fn fetch(
+        &self,
+        client: &BaseClient,
+        cache: &Cache,
+        installation_dir: &Path,
+        scratch_dir: &Path,
+        reinstall: bool,
+        install_mirrors: &InstallMirrors,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        self.fetch_from_mirrors(
+            client,
+            cache,
+            installation_dir,
+            scratch_dir,
+            reinstall,
+            install_mirrors,
+            false,
+            reporter,
+        )
+        .await
+    }
+
+    /// Download and extract a Python distribution from each mirror in turn, falling back to the
+    /// upstream URL, until one succeeds.
+    ///
+    /// The next mirror is only tried if the download couldn't be retrieved from the current one;
+    /// other failures, like a hash mismatch, abort immediately.
+    async fn fetch_from_mirrors(
+        &self,
+        client: &BaseClient,
+        cache: &Cache,
+        installation_dir: &Path,
+        scratch_dir: &Path,
+        reinstall: bool,
+        install_mirrors: &InstallMirrors,
+        retry: bool,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        let urls = self.download_urls(install_mirrors)?;
+        let token = self.mirror_token(install_mirrors);
+
+        let mut failures = Vec::new();
+        for (index, url) in urls.iter().enumerate() {
+            // Send the token to every mirror, but never to the upstream URL, which is always last.
+            let token = if index + 1 < urls.len() {
+                token.as_deref()
+            } else {
+                None
+            };
+
+            let mut n_past_retries = 0;
+            let start_time = SystemTime::now();
+            let retry_policy = client.retry_policy();
+            let result = loop {
+                if let Some(reporter) = reporter {
+                    reporter.on_fetch_attempt();
+                }
+                let result = self
+                    .fetch_url(
+                        url,
+                        token,
+                        client,
+                        cache,
+                        installation_dir,
+                        scratch_dir,
+                        reinstall,
+                        reporter,
+                    )
+                    .await;
+                if retry
+                    && result
+                        .as_ref()
+                        .err()
+                        .is_some_and(|err| is_extended_transient_error(err))
+                {
+                    let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
+                    if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
+                        debug!(
+                            "Transient failure while handling response for {}; retrying...",
+                            self.key()
+                        );
+                        let duration = execute_after
+                            .duration_since(SystemTime::now())
+                            .unwrap_or_else(|_| Duration::default());
+                        tokio::time::sleep(duration).await;
+                        n_past_retries += 1;
+                        continue;
+                    }
+                }
+                break result;
+            };
+
+            match result {
+                Err(err) if urls.len() > 1 && err.is_mirror_unavailable() => {
+                    debug!("Failed to download {} from {url}: {err}", self.key());
+                    failures.push((url.clone(), err));
                 }
+                result => return result,
             }
-            return result;
         }
+
+        Err(Error::MirrorsExhausted {
+            key: Box::new(self.key.clone()),
+            failures,
+        })
     }
 
-    /// Download and extract a Python distribution.
-    #[instrument(skip(client, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
-    pub async fn fetch(
+    /// Download and extract a Python distribution from the given URL.
+    async fn fetch_url(
         &self,
+        url: &Url,
+        token: Option<&str>,
         client: &BaseClient,
+        cache: &Cache,
         installation_dir: &Path,
         scratch_dir: &Path,
         reinstall: bool,
-        python_install_mirror: Option<&str>,
-        pypy_install_mirror: Option<&str>,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
-        let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
         let path = installation_dir.join(self.key().to_string());
 
         // If it is not a reinstall and the dir already exists, return it.
@@ -641,20 +919,7 @@ impl ManagedPythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
-        // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
-        // decodes to.
-        let filename = url
-            .path_segments()
-            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
-            .next_back()
-            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
-            .replace("%2B", "-");
-        debug_assert!(
-            filename
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'),
-            "Unexpected char in filename: {filename}"
-        );
+        let filename = archive_filename(url)?;
         let ext = SourceDistExtension::from_path(&filename)
             .map_err(|err| Error::MissingExtension(url.to_string(), err))?;
 
@@ -691,13 +956,14 @@ impl ManagedPythonDownload {
                         if client.connectivity().is_offline() {
                             return Err(Error::OfflinePythonMissing {
                                 file: Box::new(self.key().clone()),
-                                url: Box::new(url),
+                                url: Box::new(url.clone()),
                                 python_builds_dir,
                             });
                         }
 
                         self.download_archive(
-                            &url,
+                            url,
+                            token,
                             client,
                             reporter,
                             &python_builds_dir,
@@ -725,6 +991,62 @@ impl ManagedPythonDownload {
                 Direction::Extract,
             )
             .await?;
+        } else if !cache.is_temporary() {
+            let entry = self.cache_entry(cache, url, &filename);
+
+            // Download the archive into the cache, unless a fresh copy is already present.
+            let fresh = entry.path().is_file()
+                && cache
+                    .freshness(&entry, None, None)
+                    .is_ok_and(Freshness::is_fresh);
+            if fresh {
+                debug!(
+                    "Using cached archive: `{}`",
+                    entry.path().simplified_display()
+                );
+            } else {
+                fs_err::create_dir_all(entry.dir())?;
+                self.download_archive(url, token, client, reporter, entry.dir(), entry.path())
+                    .await?;
+            }
+
+            // Link the archive into the scratch directory, such that the cache entry can be
+            // replaced concurrently while we extract.
+            let archive_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
+            let archive = archive_dir.path().join(&filename);
+            if let Err(err) = fs_err::hard_link(entry.path(), &archive) {
+                debug!("Failed to hard link cached archive, copying instead: {err}");
+                fs_err::copy(entry.path(), &archive)?;
+            }
+
+            debug!("Extracting `{}`", archive.simplified_display());
+            let file = fs_err::tokio::File::open(&archive).await?;
+            let size = file.metadata().await?.len();
+            let reader = tokio::io::BufReader::new(file);
+            let result = self
+                .extract_reader(
+                    reader,
+                    temp_dir.path(),
+                    &filename,
+                    ext,
+                    Some(size),
+                    reporter,
+                    Direction::Extract,
+                )
+                .await;
+
+            // If the cached archive doesn't match the expected digest, evict it so that the next
+            // attempt downloads a fresh copy.
+            if matches!(result, Err(Error::HashMismatch { .. })) {
+                debug!(
+                    "Removing cached archive with mismatched hash: `{}`",
+                    entry.path().simplified_display()
+                );
+                if let Err(err) = fs_err::remove_file(entry.path()) {
+                    debug!("Failed to remove cached archive: {err}");
+                }
+            }
+            result?;
         } else {
             // Avoid overlong log lines
             debug!("Downloading {url}");
@@ -733,7 +1055,7 @@ impl ManagedPythonDownload {
                 temp_dir.path().simplified_display()
             );
 
-            let (reader, size) = read_url(&url, client).await?;
+            let (reader, size) = read_url(url, token, client).await?;
             self.extract_reader(
                 reader,
                 temp_dir.path(),
@@ -791,10 +1113,114 @@ impl ManagedPythonDownload {
         Ok(DownloadResult::Fetched(path))
     }
 
+    /// Download the distribution's archive into a directory without extracting it, e.g., to
+    /// provision another machine.
+    ///
+    /// The archive is verified against the expected SHA256 digest, if known, and a
+    /// `<archive>.json` file describing the download is written alongside it.
+    #[instrument(skip(client, out_dir, reporter), fields(download = % self.key()))]
+    pub async fn fetch_archive(
+        &self,
+        client: &BaseClient,
+        out_dir: &Path,
+        install_mirrors: &InstallMirrors,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<PathBuf, Error> {
+        let urls = self.download_urls(install_mirrors)?;
+        let token = self.mirror_token(install_mirrors);
+
+        let mut failures = Vec::new();
+        for (index, url) in urls.iter().enumerate() {
+            // Send the token to every mirror, but never to the upstream URL, which is always last.
+            let token = if index + 1 < urls.len() {
+                token.as_deref()
+            } else {
+                None
+            };
+            match self
+                .fetch_archive_from(url, token, client, out_dir, reporter)
+                .await
+            {
+                Err(err) if urls.len() > 1 && err.is_mirror_unavailable() => {
+                    debug!("Failed to download {} from {url}: {err}", self.key());
+                    failures.push((url.clone(), err));
+                }
+                result => return result,
+            }
+        }
+
+        Err(Error::MirrorsExhausted {
+            key: Box::new(self.key.clone()),
+            failures,
+        })
+    }
+
+    /// Download the distribution's archive from the given URL into a directory.
+    async fn fetch_archive_from(
+        &self,
+        url: &Url,
+        token: Option<&str>,
+        client: &BaseClient,
+        out_dir: &Path,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<PathBuf, Error> {
+        let filename = archive_filename(url)?;
+
+        fs_err::create_dir_all(out_dir).map_err(Error::DownloadDirError)?;
+        let temp_dir = tempfile::tempdir_in(out_dir).map_err(Error::DownloadDirError)?;
+        let temp_file = temp_dir.path().join(&filename);
+        self.download_archive(url, token, client, reporter, temp_dir.path(), &temp_file)
+            .await?;
+
+        // Unlike an installation, the archive is never extracted, so hash it separately.
+        let file = fs_err::tokio::File::open(&temp_file).await?;
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        uv_extract::hash::HashReader::new(tokio::io::BufReader::new(file), &mut hashers)
+            .finish()
+            .await
+            .map_err(Error::HashExhaustion)?;
+        let actual = HashDigest::from(hashers.pop().unwrap()).digest;
+        if let Some(expected) = self.sha256 {
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(Error::HashMismatch {
+                    installation: self.key.to_string(),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+        }
+
+        let path = out_dir.join(&filename);
+        fs_err::rename(&temp_file, &path)?;
+
+        let metadata = serde_json::json!({
+            "key": self.key.to_string(),
+            "url": url.as_str(),
+            "sha256": actual.to_string(),
+        });
+        fs_err::write(
+            out_dir.join(format!("{filename}.json")),
+            format!("{metadata:#}\n"),
+        )?;
+
+        Ok(path)
+    }
+
+    /// Return the [`CacheEntry`] for the archive, keyed by the download URL and the expected
+    /// SHA256 digest.
+    fn cache_entry(&self, cache: &Cache, url: &Url, filename: &str) -> CacheEntry {
+        cache.entry(
+            CacheBucket::Python,
+            cache_digest(&(url.as_str(), self.sha256)),
+            filename,
+        )
+    }
+
     /// Download the managed Python archive into the cache directory.
     async fn download_archive(
         &self,
         url: &Url,
+        token: Option<&str>,
         client: &BaseClient,
         reporter: Option<&dyn Reporter>,
         python_builds_dir: &Path,
@@ -806,7 +1232,7 @@ impl ManagedPythonDownload {
             target_cache_file.simplified_display()
         );
 
-        let (mut reader, size) = read_url(url, client).await?;
+        let (mut reader, size) = read_url(url, token, client).await?;
         let temp_dir = tempfile::tempdir_in(python_builds_dir)?;
         let temp_file = temp_dir.path().join("download");
 
@@ -886,43 +1312,64 @@ impl ManagedPythonDownload {
         self.key.version()
     }
 
-    /// Return the [`Url`] to use when downloading the distribution. If a mirror is set via the
-    /// appropriate environment variable, use it instead.
-    fn download_url(
-        &self,
-        python_install_mirror: Option<&str>,
-        pypy_install_mirror: Option<&str>,
-    ) -> Result<Url, Error> {
-        match self.key.implementation {
-            LenientImplementationName::Known(ImplementationName::CPython) => {
-                if let Some(mirror) = python_install_mirror {
-                    let Some(suffix) = self.url.strip_prefix(
-                        "https://github.com/astral-sh/python-build-standalone/releases/download/",
-                    ) else {
-                        return Err(Error::Mirror(EnvVars::UV_PYTHON_INSTALL_MIRROR, self.url));
-                    };
-                    return Ok(Url::parse(
-                        format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
-                    )?);
-                }
-            }
+    /// Return the [`Url`]s to try, in order, when downloading the distribution.
+    ///
+    /// If mirrors are set via the appropriate environment variable, one URL is returned per
+    /// mirror, followed by the upstream URL as a fallback.
+    fn download_urls(&self, install_mirrors: &InstallMirrors) -> Result<Vec<Url>, Error> {
+        let (implementation, prefix, variable) = match self.key.implementation {
+            LenientImplementationName::Known(ImplementationName::CPython) => (
+                ImplementationName::CPython,
+                "https://github.com/astral-sh/python-build-standalone/releases/download/",
+                EnvVars::UV_PYTHON_INSTALL_MIRROR,
+            ),
+            LenientImplementationName::Known(ImplementationName::PyPy) => (
+                ImplementationName::PyPy,
+                "https://downloads.python.org/pypy/",
+                EnvVars::UV_PYPY_INSTALL_MIRROR,
+            ),
+            LenientImplementationName::Known(ImplementationName::GraalPy) => (
+                ImplementationName::GraalPy,
+                "https://github.com/oracle/graalpython/releases/download/",
+                EnvVars::UV_GRAALPY_INSTALL_MIRROR,
+            ),
+            LenientImplementationName::Unknown(_) => return Ok(vec![Url::parse(self.url)?]),
+        };
 
-            LenientImplementationName::Known(ImplementationName::PyPy) => {
-                if let Some(mirror) = pypy_install_mirror {
-                    let Some(suffix) = self.url.strip_prefix("https://downloads.python.org/pypy/")
-                    else {
-                        return Err(Error::Mirror(EnvVars::UV_PYPY_INSTALL_MIRROR, self.url));
-                    };
-                    return Ok(Url::parse(
-                        format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
-                    )?);
-                }
-            }
+        let Some(mirrors) = install_mirrors.get(implementation) else {
+            return Ok(vec![Url::parse(self.url)?]);
+        };
+        let Some(suffix) = self.url.strip_prefix(prefix) else {
+            return Err(Error::Mirror(variable, self.url));
+        };
+        let mut urls = mirrors
+            .iter()
+            .map(|mirror| {
+                Ok(Url::parse(
+                    format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
+                )?)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Fall back to the upstream URL, unless it's already configured as a mirror, in which case
+        // it's moved to the end so that the token is never sent to it.
+        let upstream = Url::parse(self.url)?;
+        urls.retain(|url| *url != upstream);
+        urls.push(upstream);
+        Ok(urls)
+    }
 
-            _ => {}
+    /// Return the token to authenticate with when downloading the distribution from the Python
+    /// install mirrors, if set via `UV_PYTHON_INSTALL_MIRROR_TOKEN`.
+    fn mirror_token(&self, install_mirrors: &InstallMirrors) -> Option<String> {
+        if self.key.implementation != LenientImplementationName::Known(ImplementationName::CPython)
+            || install_mirrors.get(ImplementationName::CPython).is_none()
+        {
+            return None;
         }
-
-        Ok(Url::parse(self.url)?)
+        env::var(EnvVars::UV_PYTHON_INSTALL_MIRROR_TOKEN)
+            .ok()
+            .filter(|token| !token.is_empty())
     }
 }
 
@@ -932,87 +1379,7 @@ fn parse_json_downloads(
     json_downloads
         .into_iter()
         .filter_map(|(key, entry)| {
-            let implementation = match entry.name.as_str() {
-                "cpython" => LenientImplementationName::Known(ImplementationName::CPython),
-                "pypy" => LenientImplementationName::Known(ImplementationName::PyPy),
-                "graalpy" => LenientImplementationName::Known(ImplementationName::GraalPy),
-                _ => LenientImplementationName::Unknown(entry.name.clone()),
-            };
-
-            let arch_str = match entry.arch.family.as_str() {
-                "armv5tel" => "armv5te".to_string(),
-                // The `gc` variant of riscv64 is the common base instruction set and
-                // is the target in `python-build-standalone`
-                // See https://github.com/astral-sh/python-build-standalone/issues/504
-                "riscv64" => "riscv64gc".to_string(),
-                value => value.to_string(),
-            };
-
-            let arch_str = if let Some(variant) = entry.arch.variant {
-                format!("{arch_str}_{variant}")
-            } else {
-                arch_str
-            };
-
-            let arch = match Arch::from_str(&arch_str) {
-                Ok(arch) => arch,
-                Err(e) => {
-                    debug!("Skipping entry {key}: Invalid arch '{arch_str}' - {e}");
-                    return None;
-                }
-            };
-
-            let os = match Os::from_str(&entry.os) {
-                Ok(os) => os,
-                Err(e) => {
-                    debug!("Skipping entry {}: Invalid OS '{}' - {}", key, entry.os, e);
-                    return None;
-                }
-            };
-
-            let libc = match Libc::from_str(&entry.libc) {
-                Ok(libc) => libc,
-                Err(e) => {
-                    debug!(
-                        "Skipping entry {}: Invalid libc '{}' - {}",
-                        key, entry.libc, e
-                    );
-                    return None;
-                }
-            };
-
-            let variant = match entry
-                .variant
-                .as_deref()
-                .map(PythonVariant::from_str)
-                .transpose()
-            {
-                Ok(Some(variant)) => variant,
-                Ok(None) => PythonVariant::default(),
-                Err(()) => {
-                    debug!(
-                        "Skipping entry {key}: Unknown python variant - {}",
-                        entry.variant.unwrap_or_default()
-                    );
-                    return None;
-                }
-            };
-
-            let version_str = format!(
-                "{}.{}.{}{}",
-                entry.major,
-                entry.minor,
-                entry.patch,
-                entry.prerelease.as_deref().unwrap_or_default()
-            );
-
-            let version = match PythonVersion::from_str(&version_str) {
-                Ok(version) => version,
-                Err(e) => {
-                    debug!("Skipping entry {key}: Invalid version '{version_str}' - {e}");
-                    return None;
-                }
-            };
+            let installation_key = parse_json_key(&key, &entry)?;
 
             let url = Box::leak(entry.url.into_boxed_str()) as &'static str;
             let sha256 = entry
@@ -1020,14 +1387,7 @@ fn parse_json_downloads(
                 .map(|s| Box::leak(s.into_boxed_str()) as &'static str);
 
             Some(ManagedPythonDownload {
-                key: PythonInstallationKey::new_from_version(
-                    implementation,
-                    &version,
-                    os,
-                    arch,
-                    libc,
-                    variant,
-                ),
+                key: installation_key,
                 url,
                 sha256,
             })
@@ -1036,21 +1396,342 @@ fn parse_json_downloads(
         .collect()
 }
 
+/// Parse the [`PythonInstallationKey`] of a download metadata entry.
+///
+/// Returns `None` if the entry is not supported by this version of uv.
+pub(crate) fn parse_json_key(
+    key: &str,
+    entry: &JsonPythonDownload,
+) -> Option<PythonInstallationKey> {
+    let implementation = match entry.name.as_str() {
+        "cpython" => LenientImplementationName::Known(ImplementationName::CPython),
+        "pypy" => LenientImplementationName::Known(ImplementationName::PyPy),
+        "graalpy" => LenientImplementationName::Known(ImplementationName::GraalPy),
+        _ => LenientImplementationName::Unknown(entry.name.clone()),
+    };
+
+    let arch_str = match entry.arch.family.as_str() {
+        "armv5tel" => "armv5te".to_string(),
+        // The `gc` variant of riscv64 is the common base instruction set and
+        // is the target in `python-build-standalone`
+        // See https://github.com/astral-sh/python-build-standalone/issues/504
+        "riscv64" => "riscv64gc".to_string(),
+        value => value.to_string(),
+    };
+
+    let arch_str = if let Some(variant) = &entry.arch.variant {
+        format!("{arch_str}_{variant}")
+    } else {
+        arch_str
+    };
+
+    let arch = match Arch::from_str(&arch_str) {
+        Ok(arch) => arch,
+        Err(e) => {
+            debug!("Skipping entry {key}: Invalid arch '{arch_str}' - {e}");
+            return None;
+        }
+    };
+
+    let os = match Os::from_str(&entry.os) {
+        Ok(os) => os,
+        Err(e) => {
+            debug!("Skipping entry {}: Invalid OS '{}' - {}", key, entry.os, e);
+            return None;
+        }
+    };
+
+    let libc = match Libc::from_str(&entry.libc) {
+        Ok(libc) => libc,
+        Err(e) => {
+            debug!(
+                "Skipping entry {}: Invalid libc '{}' - {}",
+                key, entry.libc, e
+            );
+            return None;
+        }
+    };
+
+    let variant = match entry
+        .variant
+        .as_deref()
+        .map(PythonVariant::from_str)
+        .transpose()
+    {
+        Ok(Some(variant)) => variant,
+        Ok(None) => PythonVariant::default(),
+        Err(()) => {
+            debug!(
+                "Skipping entry {key}: Unknown python variant - {}",
+                entry.variant.as_deref().unwrap_or_default()
+            );
+            return None;
+        }
+    };
+
+    let build_flavor = match entry
+        .build
+        .as_deref()
+        .map(BuildFlavor::from_str)
+        .transpose()
+    {
+        Ok(build_flavor) => build_flavor.unwrap_or_default(),
+        Err(e) => {
+            debug!("Skipping entry {key}: {e}");
+            return None;
+        }
+    };
+
+    let version_str = format!(
+        "{}.{}.{}{}",
+        entry.major,
+        entry.minor,
+        entry.patch,
+        entry.prerelease.as_deref().unwrap_or_default()
+    );
+
+    let version = match PythonVersion::from_str(&version_str) {
+        Ok(version) => version,
+        Err(e) => {
+            debug!("Skipping entry {key}: Invalid version '{version_str}' - {e}");
+            return None;
+        }
+    };
+
+    Some(
+        PythonInstallationKey::new_from_version(implementation, &version, os, arch, libc, variant)
+            .with_build_flavor(build_flavor),
+    )
+}
+
 impl Error {
     pub(crate) fn from_reqwest(url: Url, err: reqwest::Error) -> Self {
-        Self::NetworkError(url, WrappedReqwestError::from(err))
+        Self::classify(url, reqwest_middleware::Error::Reqwest(err))
     }
 
     pub(crate) fn from_reqwest_middleware(url: Url, err: reqwest_middleware::Error) -> Self {
         match err {
             reqwest_middleware::Error::Middleware(error) => {
-                Self::NetworkMiddlewareError(url, error)
+                // The middleware (e.g., for retries) may wrap an underlying request failure.
+                if error
+                    .chain()
+                    .any(|err| err.is::<reqwest::Error>() || err.is::<reqwest_middleware::Error>())
+                {
+                    Self::classify(url, reqwest_middleware::Error::Middleware(error))
+                } else {
+                    Self::NetworkMiddlewareError(url, error)
+                }
             }
             reqwest_middleware::Error::Reqwest(error) => {
-                Self::NetworkError(url, WrappedReqwestError::from(error))
+                Self::classify(url, reqwest_middleware::Error::Reqwest(error))
             }
         }
     }
+
+    /// Classify a failed request by its likely cause, so that we can suggest a remedy.
+    ///
+    /// `hyper` errors are opaque, so (like [`WrappedReqwestError`]) we have to inspect the
+    /// rendered messages in the error chain.
+    fn classify(url: Url, err: reqwest_middleware::Error) -> Self {
+        let Some(reqwest_err) = find_reqwest_error(&err) else {
+            return Self::NetworkError(url, WrappedReqwestError::from(err));
+        };
+
+        if let Some(status) = reqwest_err.status() {
+            return Self::HttpStatusError {
+                url,
+                status,
+                err: WrappedReqwestError::from(err),
+            };
+        }
+
+        let causes =
+            std::iter::successors(std::error::Error::source(reqwest_err), |err| err.source())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+        if let Some(reason) = causes
+            .iter()
+            .find_map(|cause| cause.strip_prefix("invalid peer certificate: "))
+        {
+            return Self::TlsError {
+                url,
+                reason: reason.to_string(),
+                err: WrappedReqwestError::from(err),
+            };
+        }
+
+        // Failures to establish a tunnel through the proxy are reported as such, but failures to
+        // connect to the proxy itself are indistinguishable from failures to connect to the host.
+        let variable = proxy_variable(&url);
+        if causes.iter().any(|cause| cause.contains("tunnel"))
+            || (reqwest_err.is_connect() && variable.is_some())
+        {
+            return Self::ProxyError {
+                url,
+                variable,
+                err: WrappedReqwestError::from(err),
+            };
+        }
+
+        if reqwest_err.is_connect() && causes.iter().any(|cause| cause.starts_with("dns error")) {
+            return Self::DnsError {
+                url,
+                err: WrappedReqwestError::from(err),
+            };
+        }
+
+        Self::NetworkError(url, WrappedReqwestError::from(err))
+    }
+
+    /// A suggestion for how to resolve the error, if we can offer one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::ProxyError { url, variable, .. } => {
+                let host = url.host_str().unwrap_or_default();
+                Some(match variable {
+                    Some(variable) => format!(
+                        "Failed to connect through the proxy configured by `{variable}`; check that the proxy is reachable, or add `{host}` to `NO_PROXY` to bypass it"
+                    ),
+                    None => "Failed to connect through the configured proxy; check that the proxy is reachable".to_string(),
+                })
+            }
+            Self::TlsError { url, reason, .. } => {
+                let host = url.host_str().unwrap_or_default();
+                Some(format!(
+                    "The certificate presented by `{host}` could not be verified ({reason}). If you're behind a proxy or firewall that intercepts TLS, use `--native-tls` to trust the system certificate store, or set `{}` to a bundle including its certificate authority",
+                    EnvVars::SSL_CERT_FILE
+                ))
+            }
+            Self::DnsError { url, .. } => {
+                let host = url.host_str().unwrap_or_default();
+                Some(format!(
+                    "Failed to resolve `{host}`; check your network connection and, if using a mirror (`{}` or `{}`), that its URL is correct",
+                    EnvVars::UV_PYTHON_INSTALL_MIRROR,
+                    EnvVars::UV_PYPY_INSTALL_MIRROR
+                ))
+            }
+            Self::HttpStatusError { url, status, .. } => {
+                let host = url.host_str().unwrap_or_default();
+                if *status == reqwest::StatusCode::NOT_FOUND {
+                    Some(format!(
+                        "`{host}` does not provide this download; if using a mirror (`{}` or `{}`), check that it includes the requested Python version",
+                        EnvVars::UV_PYTHON_INSTALL_MIRROR,
+                        EnvVars::UV_PYPY_INSTALL_MIRROR
+                    ))
+                } else if *status == reqwest::StatusCode::UNAUTHORIZED
+                    || *status == reqwest::StatusCode::FORBIDDEN
+                {
+                    Some(format!(
+                        "`{host}` denied access to the download (HTTP {status}); check that you have access to the mirror, and that `{}` is set if it requires a token",
+                        EnvVars::UV_PYTHON_INSTALL_MIRROR_TOKEN
+                    ))
+                } else if status.is_server_error() {
+                    Some(format!(
+                        "`{host}` failed to serve the download (HTTP {status}); try again later, or use a different mirror"
+                    ))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the download couldn't be retrieved from the mirror, such that the next mirror (if
+    /// any) should be tried.
+    ///
+    /// Errors in the download itself, like a hash mismatch, are not included: another mirror is
+    /// expected to serve the same archive.
+    fn is_mirror_unavailable(&self) -> bool {
+        match self {
+            Self::NetworkError(..)
+            | Self::NetworkMiddlewareError(..)
+            | Self::ProxyError { .. }
+            | Self::TlsError { .. }
+            | Self::DnsError { .. }
+            | Self::HttpStatusError { .. } => true,
+            Self::Io(err) => err.kind() == io::ErrorKind::NotFound,
+            _ => false,
+        }
+    }
+}
+
+/// Render the failure at each mirror, including the chain of causes, one mirror per line.
+fn format_mirror_failures(failures: &[(Url, Error)]) -> String {
+    failures
+        .iter()
+        .map(|(url, err)| {
+            let causes =
+                std::iter::successors(Some(err as &dyn std::error::Error), |err| err.source())
+                    .join(": ");
+            format!("- {url}: {causes}")
+        })
+        .join("\n")
+}
+
+/// Return the underlying [`reqwest::Error`] from a [`reqwest_middleware::Error`], if any.
+fn find_reqwest_error(err: &reqwest_middleware::Error) -> Option<&reqwest::Error> {
+    match err {
+        reqwest_middleware::Error::Reqwest(err) => Some(err),
+        reqwest_middleware::Error::Middleware(err) => err.chain().find_map(|err| {
+            if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+                Some(err)
+            } else if let Some(reqwest_middleware::Error::Reqwest(err)) =
+                err.downcast_ref::<reqwest_middleware::Error>()
+            {
+                Some(err)
+            } else {
+                None
+            }
+        }),
+    }
+}
+
+/// Return the environment variable that configures a proxy for the given URL, if any.
+///
+/// Returns `None` if the URL's host is exempted from the proxy by `NO_PROXY`.
+fn proxy_variable(url: &Url) -> Option<String> {
+    let variables = match url.scheme() {
+        "https" => [EnvVars::HTTPS_PROXY, EnvVars::ALL_PROXY],
+        "http" => [EnvVars::HTTP_PROXY, EnvVars::ALL_PROXY],
+        _ => return None,
+    };
+    if url.host_str().is_some_and(is_no_proxy) {
+        return None;
+    }
+    variables
+        .into_iter()
+        .flat_map(|variable| [variable.to_string(), variable.to_lowercase()])
+        .find(|variable| env::var_os(variable).is_some_and(|value| !value.is_empty()))
+}
+
+/// Returns `true` if the host is exempted from the proxy by `NO_PROXY`.
+///
+/// Like `reqwest`, an entry matches the host itself and any of its subdomains, and `*` matches
+/// every host.
+fn is_no_proxy(host: &str) -> bool {
+    let Some(no_proxy) = env::var(EnvVars::NO_PROXY)
+        .or_else(|_| env::var(EnvVars::NO_PROXY.to_lowercase()))
+        .ok()
+    else {
+        return false;
+    };
+    let host = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_ascii_lowercase();
+    no_proxy.split(',').any(|entry| {
+        let entry = entry.trim().to_ascii_lowercase();
+        if entry == "*" {
+            return true;
+        }
+        let entry = entry.trim_start_matches('.');
+        !entry.is_empty()
+            && host
+                .strip_suffix(entry)
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('.'))
+    })
 }
 
 impl Display for ManagedPythonDownload {
@@ -1089,6 +1770,11 @@ pub trait Reporter: Send + Sync {
     ) -> usize;
     fn on_request_progress(&self, id: usize, inc: u64);
     fn on_request_complete(&self, direction: Direction, id: usize);
+    /// Called before each attempt to fetch a distribution, including retries and fallbacks to
+    /// another mirror.
+    fn on_fetch_attempt(&self) {}
+    /// Called with the outcome of checking the shared libraries of a new installation.
+    fn on_dylib_patch(&self, _key: &PythonInstallationKey, _report: &DylibPatchReport) {}
 }
 
 /// An asynchronous reader that reports progress as bytes are read.
@@ -1118,18 +1804,42 @@ where
         cx: &mut Context<'_>,
         buf: &mut ReadBuf<'_>,
     ) -> Poll<io::Result<()>> {
+        // Only report the bytes read by this call, as the buffer may already be partially filled.
+        let before = buf.filled().len();
         Pin::new(&mut self.as_mut().reader)
             .poll_read(cx, buf)
             .map_ok(|()| {
                 self.reporter
-                    .on_request_progress(self.index, buf.filled().len() as u64);
+                    .on_request_progress(self.index, (buf.filled().len() - before) as u64);
             })
     }
 }
 
+/// Return the filename of the archive at the given URL.
+fn archive_filename(url: &Url) -> Result<String, Error> {
+    // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
+    // decodes to.
+    let filename = url
+        .path_segments()
+        .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+        .next_back()
+        .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+        .replace("%2B", "-");
+    debug_assert!(
+        filename
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'),
+        "Unexpected char in filename: {filename}"
+    );
+    Ok(filename)
+}
+
 /// Convert a [`Url`] into an [`AsyncRead`] stream.
+///
+/// If a `token` is provided, it's sent as a bearer token with HTTP requests.
 async fn read_url(
     url: &Url,
+    token: Option<&str>,
     client: &BaseClient,
 ) -> Result<(impl AsyncRead + Unpin, Option<u64>), Error> {
     if url.scheme() == "file" {
@@ -1143,9 +1853,11 @@ async fn read_url(
 
         Ok((Either::Left(reader), Some(size)))
     } else {
-        let response = client
-            .for_host(url)
-            .get(url.clone())
+        let mut request = client.for_host(url).get(url.clone());
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
             .send()
             .await
             .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
@@ -1164,3 +1876,861 @@ async fn read_url(
         Ok((Either::Right(stream.compat()), size))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_cache::Cache;
+    use uv_client::BaseClientBuilder;
+    use uv_extract::hash::Hasher;
+    use uv_pypi_types::{HashAlgorithm, HashDigest};
+    use uv_static::EnvVars;
+
+    use super::{
+        parse_json_downloads, read_url, Direction, DownloadResult, Error, ManagedPythonDownload,
+        PythonDownloadRequest, Reporter,
+    };
+    use crate::implementation::ImplementationName;
+    use crate::install_mirrors::InstallMirrors;
+    use crate::installation::PythonInstallationKey;
+    use crate::outcome::{FetchStatus, TransferRecorder};
+    use crate::platform::{Libc, TargetPlatform};
+
+    /// Write a minimal, uncompressed Python archive and return its SHA256 digest.
+    fn write_archive(path: &std::path::Path) -> anyhow::Result<String> {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(0);
+        header.set_mode(0o755);
+        header.set_cksum();
+        builder.append_data(&mut header, "python/bin/python3.12", std::io::empty())?;
+        let bytes = builder.into_inner()?;
+        fs_err::write(path, &bytes)?;
+
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        hasher.update(&bytes);
+        Ok(HashDigest::from(hasher).digest.to_string())
+    }
+
+    #[tokio::test]
+    async fn fetch_records_transfer() -> anyhow::Result<()> {
+        let source = tempfile::tempdir()?;
+        let archive = source
+            .path()
+            .join("cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar");
+        let sha256 = write_archive(&archive)?;
+        let size = fs_err::metadata(&archive)?.len();
+
+        let download = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: Box::leak(
+                url::Url::from_file_path(&archive)
+                    .unwrap()
+                    .to_string()
+                    .into_boxed_str(),
+            ),
+            sha256: Some(Box::leak(sha256.into_boxed_str())),
+        };
+
+        let cache_dir = tempfile::tempdir()?;
+        let cache = Cache::from_path(cache_dir.path()).init()?;
+        let client = BaseClientBuilder::new().build();
+
+        let installations = tempfile::tempdir()?;
+        let scratch = tempfile::tempdir()?;
+
+        // A fresh download is recorded, along with the extraction of the cached archive.
+        let recorder = TransferRecorder::new(None);
+        let result = download
+            .fetch(
+                &client,
+                &cache,
+                installations.path(),
+                scratch.path(),
+                false,
+                &InstallMirrors::default(),
+                Some(&recorder),
+            )
+            .await?;
+        let transfer = recorder.finish();
+        assert_eq!(FetchStatus::from(&result), FetchStatus::Fetched);
+        assert_eq!(transfer.downloaded_bytes(), size);
+        assert!(transfer.download().is_some());
+        assert!(transfer.extract().is_some());
+
+        // An existing installation is neither downloaded nor extracted.
+        let recorder = TransferRecorder::new(None);
+        let result = download
+            .fetch(
+                &client,
+                &cache,
+                installations.path(),
+                scratch.path(),
+                false,
+                &InstallMirrors::default(),
+                Some(&recorder),
+            )
+            .await?;
+        let transfer = recorder.finish();
+        assert_eq!(FetchStatus::from(&result), FetchStatus::AlreadyAvailable);
+        assert_eq!(transfer.downloaded_bytes(), 0);
+        assert!(transfer.download().is_none());
+        assert!(transfer.extract().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn transfer_recorder_resets_on_attempt() {
+        let key = PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu").unwrap();
+        let recorder = TransferRecorder::new(None);
+
+        // A partial download that fails with a transient error...
+        recorder.on_fetch_attempt();
+        let id = recorder.on_request_start(Direction::Download, &key, Some(100));
+        recorder.on_request_progress(id, 60);
+
+        // ...is not counted once the download is retried.
+        recorder.on_fetch_attempt();
+        let id = recorder.on_request_start(Direction::Download, &key, Some(100));
+        recorder.on_request_progress(id, 100);
+        recorder.on_request_complete(Direction::Download, id);
+
+        assert_eq!(recorder.finish().downloaded_bytes(), 100);
+    }
+
+    #[tokio::test]
+    async fn fetch_reuses_cached_archive() -> anyhow::Result<()> {
+        let source = tempfile::tempdir()?;
+        let archive = source
+            .path()
+            .join("cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar");
+        let sha256 = write_archive(&archive)?;
+
+        let download = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: Box::leak(
+                url::Url::from_file_path(&archive)
+                    .unwrap()
+                    .to_string()
+                    .into_boxed_str(),
+            ),
+            sha256: Some(Box::leak(sha256.into_boxed_str())),
+        };
+
+        // Use a persistent cache; `Cache::temp` would bypass the archive cache as if `--no-cache`
+        // were provided.
+        let cache_dir = tempfile::tempdir()?;
+        let cache = Cache::from_path(cache_dir.path()).init()?;
+        let client = BaseClientBuilder::new().build();
+
+        let installations = tempfile::tempdir()?;
+        let scratch = tempfile::tempdir()?;
+
+        let result = download
+            .fetch(
+                &client,
+                &cache,
+                installations.path(),
+                scratch.path(),
+                false,
+                &InstallMirrors::default(),
+                None,
+            )
+            .await?;
+        assert!(matches!(result, DownloadResult::Fetched(_)));
+
+        // Remove the source archive; a reinstall must be served from the cache.
+        fs_err::remove_file(&archive)?;
+
+        let result = download
+            .fetch(
+                &client,
+                &cache,
+                installations.path(),
+                scratch.path(),
+                true,
+                &InstallMirrors::default(),
+                None,
+            )
+            .await?;
+        let DownloadResult::Fetched(path) = result else {
+            panic!("Expected the installation to be re-extracted");
+        };
+        assert!(path.join("bin").join("python3.12").exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_without_cache_does_not_persist_archive() -> anyhow::Result<()> {
+        let source = tempfile::tempdir()?;
+        let archive = source
+            .path()
+            .join("cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar");
+        let sha256 = write_archive(&archive)?;
+
+        let download = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: Box::leak(
+                url::Url::from_file_path(&archive)
+                    .unwrap()
+                    .to_string()
+                    .into_boxed_str(),
+            ),
+            sha256: Some(Box::leak(sha256.into_boxed_str())),
+        };
+
+        // A temporary cache corresponds to `--no-cache`.
+        let cache = Cache::temp()?.init()?;
+        let client = BaseClientBuilder::new().build();
+
+        let installations = tempfile::tempdir()?;
+        let scratch = tempfile::tempdir()?;
+
+        download
+            .fetch(
+                &client,
+                &cache,
+                installations.path(),
+                scratch.path(),
+                false,
+                &InstallMirrors::default(),
+                None,
+            )
+            .await?;
+
+        assert!(!cache.bucket(uv_cache::CacheBucket::Python).exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_archive_writes_archive_and_metadata() -> anyhow::Result<()> {
+        let source = tempfile::tempdir()?;
+        let archive = source
+            .path()
+            .join("cpython-3.12.0-aarch64-unknown-linux-gnu-install_only.tar");
+        let sha256 = write_archive(&archive)?;
+        let url = url::Url::from_file_path(&archive).unwrap().to_string();
+
+        let mut download = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-aarch64-gnu")?,
+            url: Box::leak(url.clone().into_boxed_str()),
+            sha256: Some(Box::leak(sha256.clone().into_boxed_str())),
+        };
+        let client = BaseClientBuilder::new().build();
+
+        let out_dir = tempfile::tempdir()?;
+        let path = download
+            .fetch_archive(&client, out_dir.path(), &InstallMirrors::default(), None)
+            .await?;
+        assert_eq!(
+            path,
+            out_dir
+                .path()
+                .join("cpython-3.12.0-aarch64-unknown-linux-gnu-install_only.tar")
+        );
+        assert_eq!(fs_err::read(&path)?, fs_err::read(&archive)?);
+
+        let metadata: serde_json::Value =
+            serde_json::from_str(&fs_err::read_to_string(path.with_extension("tar.json"))?)?;
+        assert_eq!(
+            metadata,
+            serde_json::json!({
+                "key": "cpython-3.12.0-linux-aarch64-gnu",
+                "url": url,
+                "sha256": sha256,
+            })
+        );
+
+        // An archive that doesn't match the expected digest is not persisted.
+        download.sha256 = Some("0".repeat(64).leak());
+        let out_dir = tempfile::tempdir()?;
+        let result = download
+            .fetch_archive(&client, out_dir.path(), &InstallMirrors::default(), None)
+            .await;
+        assert!(matches!(result, Err(Error::HashMismatch { .. })));
+        assert_eq!(fs_err::read_dir(out_dir.path())?.count(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_archive_falls_back_across_mirrors() -> anyhow::Result<()> {
+        let filename = "cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar";
+
+        // A mirror that serves the expected archive.
+        let healthy = tempfile::tempdir()?;
+        fs_err::create_dir_all(healthy.path().join("20240101"))?;
+        let sha256 = write_archive(&healthy.path().join("20240101").join(filename))?;
+        let healthy = url::Url::from_directory_path(healthy.path())
+            .unwrap()
+            .to_string();
+
+        // A mirror that serves an archive with different contents.
+        let tampered = tempfile::tempdir()?;
+        fs_err::create_dir_all(tampered.path().join("20240101"))?;
+        fs_err::write(tampered.path().join("20240101").join(filename), b"tampered")?;
+        let tampered = url::Url::from_directory_path(tampered.path())
+            .unwrap()
+            .to_string();
+
+        // A mirror that refuses connections.
+        let unreachable = format!(
+            "http://{}",
+            std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?
+        );
+
+        let download = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: "https://github.com/astral-sh/python-build-standalone/releases/download/20240101/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar",
+            sha256: Some(Box::leak(sha256.into_boxed_str())),
+        };
+        let client = BaseClientBuilder::new().retries(0).build();
+
+        // If the first mirror is unavailable, the next one is used.
+        let out_dir = tempfile::tempdir()?;
+        let mirrors = cpython_mirrors(vec![unreachable.clone(), healthy.clone()]);
+        let path = download
+            .fetch_archive(&client, out_dir.path(), &mirrors, None)
+            .await?;
+        assert_eq!(path, out_dir.path().join(filename));
+
+        // A hash mismatch is not a reason to try the next mirror.
+        let out_dir = tempfile::tempdir()?;
+        let mirrors = cpython_mirrors(vec![tampered, healthy]);
+        let result = download
+            .fetch_archive(&client, out_dir.path(), &mirrors, None)
+            .await;
+        assert!(
+            matches!(result, Err(Error::HashMismatch { .. })),
+            "{result:?}"
+        );
+
+        // If every mirror and the upstream URL are unavailable, each failure is reported.
+        let missing = tempfile::tempdir()?;
+        let missing = url::Url::from_directory_path(missing.path())
+            .unwrap()
+            .to_string();
+        let out_dir = tempfile::tempdir()?;
+        let mirrors = cpython_mirrors(vec![unreachable, missing]);
+        let result = download
+            .fetch_archive(&client, out_dir.path(), &mirrors, None)
+            .await;
+        let Err(Error::MirrorsExhausted { failures, .. }) = result else {
+            panic!("Expected every mirror to fail: {result:?}");
+        };
+        assert_eq!(failures.len(), 3);
+        assert!(matches!(failures[1].1, Error::Io(_)), "{:?}", failures[1].1);
+        assert_eq!(failures[2].0.as_str(), download.url);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_archive_uses_implementation_mirrors() -> anyhow::Result<()> {
+        let cpython_filename = "cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar";
+        let pypy_filename = "pypy3.10-v7.3.17-linux64.tar";
+
+        // Each mirror only serves the archives for its own implementation.
+        let cpython_mirror = tempfile::tempdir()?;
+        fs_err::create_dir_all(cpython_mirror.path().join("20240101"))?;
+        let cpython_sha256 = write_archive(
+            &cpython_mirror
+                .path()
+                .join("20240101")
+                .join(cpython_filename),
+        )?;
+        let pypy_mirror = tempfile::tempdir()?;
+        let pypy_sha256 = write_archive(&pypy_mirror.path().join(pypy_filename))?;
+
+        let cpython = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: "https://github.com/astral-sh/python-build-standalone/releases/download/20240101/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar",
+            sha256: Some(Box::leak(cpython_sha256.into_boxed_str())),
+        };
+        let pypy = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("pypy-3.10.14-linux-x86_64-gnu")?,
+            url: "https://downloads.python.org/pypy/pypy3.10-v7.3.17-linux64.tar",
+            sha256: Some(Box::leak(pypy_sha256.into_boxed_str())),
+        };
+        let client = BaseClientBuilder::new().retries(0).build();
+
+        let mirrors = InstallMirrors::default()
+            .with_implementation(
+                ImplementationName::CPython,
+                Some(
+                    vec![url::Url::from_directory_path(cpython_mirror.path())
+                        .unwrap()
+                        .to_string()]
+                    .into(),
+                ),
+            )
+            .with_implementation(
+                ImplementationName::PyPy,
+                Some(
+                    vec![url::Url::from_directory_path(pypy_mirror.path())
+                        .unwrap()
+                        .to_string()]
+                    .into(),
+                ),
+            );
+
+        let out_dir = tempfile::tempdir()?;
+        let path = cpython
+            .fetch_archive(&client, out_dir.path(), &mirrors, None)
+            .await?;
+        assert_eq!(path, out_dir.path().join(cpython_filename));
+        let path = pypy
+            .fetch_archive(&client, out_dir.path(), &mirrors, None)
+            .await?;
+        assert_eq!(path, out_dir.path().join(pypy_filename));
+
+        // With the mirrors swapped, neither archive can be found.
+        let swapped = InstallMirrors::default()
+            .with_implementation(
+                ImplementationName::CPython,
+                mirrors
+                    .get(ImplementationName::PyPy)
+                    .map(|mirrors| mirrors.to_vec().into()),
+            )
+            .with_implementation(
+                ImplementationName::PyPy,
+                mirrors
+                    .get(ImplementationName::CPython)
+                    .map(|mirrors| mirrors.to_vec().into()),
+            );
+        let out_dir = tempfile::tempdir()?;
+        assert!(cpython
+            .fetch_archive(&client, out_dir.path(), &swapped, None)
+            .await
+            .is_err());
+        assert!(pypy
+            .fetch_archive(&client, out_dir.path(), &swapped, None)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    /// Configure the given mirrors for CPython downloads.
+    fn cpython_mirrors(mirrors: Vec<String>) -> InstallMirrors {
+        InstallMirrors::default()
+            .with_implementation(ImplementationName::CPython, Some(mirrors.into()))
+    }
+
+    #[test]
+    fn download_urls_per_implementation() -> anyhow::Result<()> {
+        let mirrors = InstallMirrors::default()
+            .with_default(Some(vec!["https://default.example.com".to_string()].into()))
+            .with_implementation(
+                ImplementationName::CPython,
+                Some(vec!["https://cpython.example.com/".to_string()].into()),
+            )
+            .with_implementation(
+                ImplementationName::PyPy,
+                Some(vec!["https://pypy.example.com".to_string()].into()),
+            );
+
+        let cpython = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?,
+            url: "https://github.com/astral-sh/python-build-standalone/releases/download/20240101/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            sha256: None,
+        };
+        assert_eq!(
+            cpython.download_urls(&mirrors)?,
+            vec![
+                url::Url::parse(
+                    "https://cpython.example.com/20240101/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz"
+                )?,
+                url::Url::parse(cpython.url)?,
+            ]
+        );
+
+        let pypy = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("pypy-3.10.14-linux-x86_64-gnu")?,
+            url: "https://downloads.python.org/pypy/pypy3.10-v7.3.17-linux64.tar.bz2",
+            sha256: None,
+        };
+        assert_eq!(
+            pypy.download_urls(&mirrors)?,
+            vec![
+                url::Url::parse("https://pypy.example.com/pypy3.10-v7.3.17-linux64.tar.bz2")?,
+                url::Url::parse(pypy.url)?,
+            ]
+        );
+
+        // Implementations without their own entry use the default mirrors.
+        let graalpy = ManagedPythonDownload {
+            key: PythonInstallationKey::from_str("graalpy-3.11.0-linux-x86_64-gnu")?,
+            url: "https://github.com/oracle/graalpython/releases/download/graal-24.2.1/graalpy-24.2.1-linux-amd64.tar.gz",
+            sha256: None,
+        };
+        assert_eq!(
+            graalpy.download_urls(&mirrors)?,
+            vec![
+                url::Url::parse(
+                    "https://default.example.com/graal-24.2.1/graalpy-24.2.1-linux-amd64.tar.gz"
+                )?,
+                url::Url::parse(graalpy.url)?,
+            ]
+        );
+
+        // The upstream URL is always tried last, even if it's also configured as a mirror.
+        let mirrors = cpython_mirrors(vec![
+            "https://github.com/astral-sh/python-build-standalone/releases/download".to_string(),
+            "https://cpython.example.com".to_string(),
+        ]);
+        assert_eq!(
+            cpython.download_urls(&mirrors)?,
+            vec![
+                url::Url::parse(
+                    "https://cpython.example.com/20240101/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz"
+                )?,
+                url::Url::parse(cpython.url)?,
+            ]
+        );
+
+        // Without any mirrors, the upstream URL is used.
+        assert_eq!(
+            pypy.download_urls(&InstallMirrors::default())?,
+            vec![url::Url::parse(pypy.url)?]
+        );
+
+        Ok(())
+    }
+
+    /// Respond to a single HTTP request on a local port with the given status, returning the URL.
+    fn serve_status(status: &'static str) -> anyhow::Result<url::Url> {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = url::Url::parse(&format!(
+            "http://{}/cpython-3.12.0.tar.gz",
+            listener.local_addr()?
+        ))?;
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buffer = [0; 4096];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(
+                    format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                        .as_bytes(),
+                );
+            }
+        });
+        Ok(url)
+    }
+
+    /// Respond to a single HTTP request on a local port, rejecting it unless it carries the given
+    /// bearer token, and return the URL.
+    fn serve_authenticated(token: &'static str) -> anyhow::Result<url::Url> {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = url::Url::parse(&format!(
+            "http://{}/cpython-3.12.0.tar.gz",
+            listener.local_addr()?
+        ))?;
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buffer = [0; 4096];
+                let n = stream.read(&mut buffer).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..n]).to_lowercase();
+                let status = if request.contains(&format!("authorization: bearer {token}\r\n")) {
+                    "200 OK"
+                } else {
+                    "401 Unauthorized"
+                };
+                let _ = stream.write_all(
+                    format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                        .as_bytes(),
+                );
+            }
+        });
+        Ok(url)
+    }
+
+    #[tokio::test]
+    async fn read_url_http_status_error() -> anyhow::Result<()> {
+        let url = serve_status("404 Not Found")?;
+        let client = BaseClientBuilder::new().retries(0).build();
+
+        let Err(err) = read_url(&url, None, &client).await else {
+            panic!("Expected the download to fail");
+        };
+        assert!(
+            matches!(
+                err,
+                Error::HttpStatusError { status, .. } if status == reqwest::StatusCode::NOT_FOUND
+            ),
+            "{err:?}"
+        );
+        assert!(err
+            .hint()
+            .is_some_and(|hint| hint.contains("`127.0.0.1` does not provide this download")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_url_bearer_token() -> anyhow::Result<()> {
+        let client = BaseClientBuilder::new().retries(0).build();
+
+        let url = serve_authenticated("secret")?;
+        assert!(read_url(&url, Some("secret"), &client).await.is_ok());
+
+        let url = serve_authenticated("secret")?;
+        let Err(err) = read_url(&url, None, &client).await else {
+            panic!("Expected the download to fail");
+        };
+        assert!(
+            matches!(
+                err,
+                Error::HttpStatusError { status, .. } if status == reqwest::StatusCode::UNAUTHORIZED
+            ),
+            "{err:?}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_url_proxy_error() {
+        // Reserve a local port and release it, such that connections to the proxy are refused.
+        let proxy = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        temp_env::with_var(
+            EnvVars::HTTPS_PROXY,
+            Some(format!("http://{proxy}")),
+            || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap();
+                runtime.block_on(async {
+                    let url =
+                        url::Url::parse("https://downloads.invalid/cpython-3.12.0.tar.gz").unwrap();
+                    let client = BaseClientBuilder::new().retries(0).build();
+
+                    let Err(err) = read_url(&url, None, &client).await else {
+                        panic!("Expected the download to fail");
+                    };
+                    let Error::ProxyError { variable, .. } = &err else {
+                        panic!("Expected a proxy error, got: {err:?}");
+                    };
+                    assert_eq!(variable.as_deref(), Some(EnvVars::HTTPS_PROXY));
+                    assert!(err
+                        .hint()
+                        .is_some_and(|hint| hint
+                            .contains("add `downloads.invalid` to `NO_PROXY` to bypass it")));
+                });
+            },
+        );
+    }
+
+    #[test]
+    fn proxy_variable_no_proxy() {
+        let url = url::Url::parse("https://downloads.example.com/cpython-3.12.0.tar.gz").unwrap();
+
+        temp_env::with_vars(
+            [
+                (EnvVars::HTTPS_PROXY, Some("http://proxy.invalid:3128")),
+                (EnvVars::NO_PROXY, None),
+            ],
+            || assert_eq!(proxy_variable(&url).as_deref(), Some(EnvVars::HTTPS_PROXY)),
+        );
+
+        for no_proxy in ["downloads.example.com", "example.com", ".example.com", "*"] {
+            temp_env::with_vars(
+                [
+                    (EnvVars::HTTPS_PROXY, Some("http://proxy.invalid:3128")),
+                    (EnvVars::NO_PROXY, Some(no_proxy)),
+                ],
+                || assert_eq!(proxy_variable(&url), None, "{no_proxy}"),
+            );
+        }
+
+        // Only whole labels are matched
+        temp_env::with_vars(
+            [
+                (EnvVars::HTTPS_PROXY, Some("http://proxy.invalid:3128")),
+                (EnvVars::NO_PROXY, Some("ample.com,other.example.com")),
+            ],
+            || assert_eq!(proxy_variable(&url).as_deref(), Some(EnvVars::HTTPS_PROXY)),
+        );
+    }
+
+    #[test]
+    fn from_request_requires_prerelease_opt_in() -> anyhow::Result<()> {
+        // Only pre-releases of 3.14 are available
+        let request = PythonDownloadRequest::from_str("cpython-3.14-linux-x86_64-gnu")?;
+        let Err(Error::OnlyPrereleaseDownloadFound(_, key)) =
+            ManagedPythonDownload::from_request(&request, None)
+        else {
+            panic!("Pre-releases should not be selected without opt-in");
+        };
+        assert!(key.prerelease.is_some());
+
+        // With opt-in, the latest pre-release is selected
+        let download = ManagedPythonDownload::from_request(&request.with_prereleases(true), None)?;
+        assert_eq!(download.key(), &key);
+
+        // Requesting a pre-release explicitly is an opt-in
+        let request = PythonDownloadRequest::from_str("cpython-3.14.0a4-linux-x86_64-gnu")?;
+        let download = ManagedPythonDownload::from_request(&request, None)?;
+        assert_eq!(
+            download.key().to_string(),
+            "cpython-3.14.0a4-linux-x86_64-gnu"
+        );
+
+        // Stable versions are unaffected
+        let request = PythonDownloadRequest::from_str("cpython-3.13-linux-x86_64-gnu")?;
+        let download = ManagedPythonDownload::from_request(&request, None)?;
+        assert!(download.key().prerelease.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_musl_linkage() -> anyhow::Result<()> {
+        let json = r#"{
+            "cpython-3.13.3-linux-x86_64-musl": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "musl", "major": 3, "minor": 13, "patch": 3,
+                "prerelease": "", "url": "https://example.com/musl.tar.gz",
+                "sha256": null, "variant": null
+            },
+            "cpython-3.13.3-linux-x86_64-musl-static": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "musl-static", "major": 3, "minor": 13, "patch": 3,
+                "prerelease": "", "url": "https://example.com/musl-static.tar.gz",
+                "sha256": null, "variant": null
+            }
+        }"#;
+        let downloads = parse_json_downloads(serde_json::from_str(json)?);
+        let select = |request: &PythonDownloadRequest| {
+            downloads
+                .iter()
+                .find(|download| request.satisfied_by_download(download))
+                .map(|download| download.key().to_string())
+        };
+
+        // The request for a musl platform selects the dynamically-linked build.
+        let request = PythonDownloadRequest::from_str("cpython-3.13")?
+            .with_platform(TargetPlatform::from_str("linux-x86_64-musl")?);
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.13.3-linux-x86_64-musl")
+        );
+
+        // Without a libc, the dynamically-linked build is still preferred.
+        let request = PythonDownloadRequest::from_str("cpython-3.13-linux-x86_64")?;
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.13.3-linux-x86_64-musl")
+        );
+
+        // The statically-linked build must be requested explicitly.
+        let request = PythonDownloadRequest::from_str("cpython-3.13-linux-x86_64-musl-static")?;
+        assert_eq!(request.libc(), Some(&Libc::MuslStatic));
+        assert_eq!(request.to_string(), "cpython-3.13-linux-x86_64-musl-static");
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.13.3-linux-x86_64-musl-static")
+        );
+
+        // Only `static` may follow the libc.
+        assert!(PythonDownloadRequest::from_str("cpython-3.13-linux-x86_64-musl-shared").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_build_flavor() -> anyhow::Result<()> {
+        let json = r#"{
+            "cpython-3.12.4+noopt-linux-x86_64-gnu": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "gnu", "major": 3, "minor": 12, "patch": 4,
+                "prerelease": "", "url": "https://example.com/3.12.4-noopt.tar.zst",
+                "sha256": null, "variant": null, "build": "noopt"
+            },
+            "cpython-3.12.4-linux-x86_64-gnu": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "gnu", "major": 3, "minor": 12, "patch": 4,
+                "prerelease": "", "url": "https://example.com/3.12.4.tar.gz",
+                "sha256": null, "variant": null
+            },
+            "cpython-3.11.9+noopt-linux-x86_64-gnu": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "gnu", "major": 3, "minor": 11, "patch": 9,
+                "prerelease": "", "url": "https://example.com/3.11.9-noopt.tar.zst",
+                "sha256": null, "variant": null, "build": "noopt"
+            },
+            "cpython-3.11.9+lto-linux-x86_64-gnu": {
+                "name": "cpython", "arch": {"family": "x86_64", "variant": null},
+                "os": "linux", "libc": "gnu", "major": 3, "minor": 11, "patch": 9,
+                "prerelease": "", "url": "https://example.com/3.11.9-lto.tar.zst",
+                "sha256": null, "variant": null, "build": "lto"
+            }
+        }"#;
+        let downloads = parse_json_downloads(serde_json::from_str(json)?);
+        assert_eq!(downloads.len(), 4);
+        let select = |request: &PythonDownloadRequest| {
+            downloads
+                .iter()
+                .find(|download| request.satisfied_by_download(download))
+                .map(|download| download.key().to_string())
+        };
+
+        // Without a flavor, the default build is preferred.
+        let request = PythonDownloadRequest::from_str("cpython-3.12-linux-x86_64-gnu")?;
+        assert_eq!(request.build_flavor(), None);
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.12.4-linux-x86_64-gnu")
+        );
+
+        // Otherwise, the fastest available flavor is preferred.
+        let request = PythonDownloadRequest::from_str("cpython-3.11-linux-x86_64-gnu")?;
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.11.9+lto-linux-x86_64-gnu")
+        );
+
+        // A flavor can be requested explicitly, in the version or via the builder.
+        let request = PythonDownloadRequest::from_str("cpython-3.12+noopt-linux-x86_64-gnu")?;
+        assert_eq!(request.build_flavor(), Some(BuildFlavor::Noopt));
+        assert_eq!(request.to_string(), "cpython-3.12+noopt-linux-x86_64-gnu");
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.12.4+noopt-linux-x86_64-gnu")
+        );
+        let request = PythonDownloadRequest::from_str("cpython-3.12-linux-x86_64-gnu")?
+            .with_build_flavor(BuildFlavor::Noopt);
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.12.4+noopt-linux-x86_64-gnu")
+        );
+        let request = PythonDownloadRequest::from_str("cpython-3.12-linux-x86_64-gnu")?
+            .with_build_flavor(BuildFlavor::PgoLto);
+        assert_eq!(select(&request), None);
+
+        // An exact key does not match other flavors of the same version.
+        let request = PythonDownloadRequest::from_str("cpython-3.11.9-linux-x86_64-gnu")?;
+        assert_eq!(request.build_flavor(), Some(BuildFlavor::Default));
+        assert_eq!(select(&request), None);
+        let request = PythonDownloadRequest::from_str("cpython-3.11.9+noopt-linux-x86_64-gnu")?;
+        assert_eq!(
+            select(&request).as_deref(),
+            Some("cpython-3.11.9+noopt-linux-x86_64-gnu")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+//! Checking and patching the shared libraries of managed Python installations.
+
+use std::path::{Path, PathBuf};
+
+use goblin::elf::Elf;
+use serde::Serialize;
+use tracing::trace;
+
+use uv_fs::Simplified;
+use uv_warnings::warn_user;
+
+use crate::macos_dylib;
+use crate::managed::ManagedPythonInstallation;
+
+/// The outcome of checking, and where necessary patching, the shared libraries of a managed
+/// Python installation.
+#[derive(Debug, Default, Serialize)]
+pub struct DylibPatchReport {
+    /// The libraries that were examined.
+    examined: Vec<PathBuf>,
+    /// The libraries that were patched.
+    patched: Vec<PathBuf>,
+    /// The libraries that could not be patched, or are missing.
+    failed: Vec<DylibPatchFailure>,
+}
+
+/// A shared library that could not be patched, or is missing.
+#[derive(Debug, Serialize)]
+pub struct DylibPatchFailure {
+    dylib: PathBuf,
+    severity: DylibPatchSeverity,
+    reason: String,
+}
+
+/// The impact of a [`DylibPatchFailure`] on the installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DylibPatchSeverity {
+    /// The interpreter runs, but building native extensions against it may fail.
+    Cosmetic,
+    /// The interpreter will fail to start.
+    Fatal,
+}
+
+impl DylibPatchReport {
+    /// Patch the given library, recording a cosmetic failure if patching fails.
+    pub(crate) fn patch(
+        &mut self,
+        dylib: PathBuf,
+        patch: impl FnOnce(PathBuf) -> Result<(), macos_dylib::Error>,
+    ) {
+        self.examined.push(dylib.clone());
+        match patch(dylib.clone()) {
+            Ok(()) => self.patched.push(dylib),
+            Err(err) => {
+                let reason = match &err {
+                    macos_dylib::Error::RenameError { stderr, .. } if !stderr.trim().is_empty() => {
+                        format!("{err}: {}", stderr.trim())
+                    }
+                    _ => err.to_string(),
+                };
+                self.failed.push(DylibPatchFailure {
+                    dylib,
+                    severity: DylibPatchSeverity::Cosmetic,
+                    reason,
+                });
+            }
+        }
+    }
+
+    /// Check that a library the interpreter links against exists, recording a fatal failure if
+    /// it does not.
+    pub(crate) fn require(&mut self, dylib: PathBuf) {
+        self.examined.push(dylib.clone());
+        if !dylib.exists() {
+            self.failed.push(DylibPatchFailure {
+                dylib,
+                severity: DylibPatchSeverity::Fatal,
+                reason: "The library is missing from the installation".to_string(),
+            });
+        }
+    }
+
+    /// The libraries that were examined.
+    pub fn examined(&self) -> &[PathBuf] {
+        &self.examined
+    }
+
+    /// The libraries that were patched.
+    pub fn patched(&self) -> &[PathBuf] {
+        &self.patched
+    }
+
+    /// The libraries that could not be patched, or are missing.
+    pub fn failed(&self) -> &[DylibPatchFailure] {
+        &self.failed
+    }
+
+    /// Whether any failure will prevent the interpreter from starting.
+    pub fn is_fatal(&self) -> bool {
+        self.failed
+            .iter()
+            .any(|failure| failure.severity == DylibPatchSeverity::Fatal)
+    }
+
+    /// Emit a concise warning for each failure.
+    pub fn warn_user(&self, installation: &ManagedPythonInstallation) {
+        for failure in &self.failed {
+            match failure.severity {
+                DylibPatchSeverity::Cosmetic => {
+                    let error = if tracing::enabled!(tracing::Level::DEBUG) {
+                        format!("\nUnderlying error: {}", failure.reason)
+                    } else {
+                        String::new()
+                    };
+                    warn_user!(
+                        "Failed to patch the install name of the dynamic library for {}. This may cause issues when building Python native extensions.{}",
+                        installation.executable(false).simplified_display(),
+                        error
+                    );
+                }
+                DylibPatchSeverity::Fatal => {
+                    warn_user!(
+                        "The Python installation at {} is missing the shared library `{}`, and will fail to start. Reinstall it with `uv python install --reinstall`.",
+                        installation.path().simplified_display(),
+                        failure.dylib.simplified_display(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl DylibPatchFailure {
+    /// The path to the library.
+    pub fn dylib(&self) -> &Path {
+        &self.dylib
+    }
+
+    /// The impact of the failure on the installation.
+    pub fn severity(&self) -> DylibPatchSeverity {
+        self.severity
+    }
+
+    /// A description of the failure.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// Return the names of the `libpython` libraries that an ELF executable links against.
+///
+/// Returns an empty list if the executable can't be read or parsed.
+pub(crate) fn needed_libpython(executable: &Path) -> Vec<String> {
+    let bytes = match fs_err::read(executable) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            trace!("Failed to read `{}`: {err}", executable.user_display());
+            return Vec::new();
+        }
+    };
+    let elf = match Elf::parse(&bytes) {
+        Ok(elf) => elf,
+        Err(err) => {
+            trace!("Failed to parse `{}`: {err}", executable.user_display());
+            return Vec::new();
+        }
+    };
+    elf.libraries
+        .iter()
+        .filter(|library| library.starts_with("libpython"))
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::macos_dylib;
+
+    use super::{DylibPatchReport, DylibPatchSeverity};
+
+    #[test]
+    fn patch_failure_is_cosmetic() {
+        let mut report = DylibPatchReport::default();
+        report.patch(PathBuf::from("lib/libpython3.12.dylib"), |_| Ok(()));
+        report.patch(PathBuf::from("lib/libpython3.12t.dylib"), |dylib| {
+            Err(macos_dylib::Error::RenameError {
+                dylib,
+                stderr: "error: not a Mach-O file\n".to_string(),
+            })
+        });
+
+        assert_eq!(
+            report.examined(),
+            [
+                PathBuf::from("lib/libpython3.12.dylib"),
+                PathBuf::from("lib/libpython3.12t.dylib"),
+            ]
+        );
+        assert_eq!(report.patched(), [PathBuf::from("lib/libpython3.12.dylib")]);
+
+        let [failure] = report.failed() else {
+            panic!("expected a single failure: {report:?}");
+        };
+        assert_eq!(failure.dylib(), PathBuf::from("lib/libpython3.12t.dylib"));
+        assert_eq!(failure.severity(), DylibPatchSeverity::Cosmetic);
+        assert!(failure.reason().ends_with(": error: not a Mach-O file"));
+        assert!(!report.is_fatal());
+    }
+
+    #[test]
+    fn missing_library_is_fatal() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let present = root.path().join("libpython3.12.so.1.0");
+        let missing = root.path().join("libpython3.13.so.1.0");
+        fs_err::write(&present, "")?;
+
+        let mut report = DylibPatchReport::default();
+        report.require(present.clone());
+        assert!(report.failed().is_empty());
+
+        report.require(missing.clone());
+        assert_eq!(report.examined(), [present, missing.clone()]);
+        assert!(report.patched().is_empty());
+        let [failure] = report.failed() else {
+            panic!("expected a single failure: {report:?}");
+        };
+        assert_eq!(failure.dylib(), missing);
+        assert_eq!(failure.severity(), DylibPatchSeverity::Fatal);
+        assert!(report.is_fatal());
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_json() {
+        let mut report = DylibPatchReport::default();
+        report.patch(PathBuf::from("lib/libpython3.12.dylib"), |dylib| {
+            Err(macos_dylib::Error::RenameError {
+                dylib,
+                stderr: String::new(),
+            })
+        });
+        report.require(PathBuf::from("lib/libpython3.12.so.1.0"));
+
+        let value = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "examined": ["lib/libpython3.12.dylib", "lib/libpython3.12.so.1.0"],
+                "patched": [],
+                "failed": [
+                    {
+                        "dylib": "lib/libpython3.12.dylib",
+                        "severity": "cosmetic",
+                        "reason": "Failed to update the install name of the Python dynamic library located at `lib/libpython3.12.dylib`",
+                    },
+                    {
+                        "dylib": "lib/libpython3.12.so.1.0",
+                        "severity": "fatal",
+                        "reason": "The library is missing from the installation",
+                    },
+                ],
+            })
+        );
+    }
+}
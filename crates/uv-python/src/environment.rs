@@ -13,11 +13,12 @@ use uv_fs::{LockedFile, Simplified};
 use uv_pep440::Version;
 
 use crate::discovery::find_python_installation;
+use crate::discovery_trace::DiscoveryTrace;
 use crate::installation::PythonInstallation;
 use crate::virtualenv::{virtualenv_python_executable, PyVenvConfiguration};
 use crate::{
     EnvironmentPreference, Error, Interpreter, Prefix, PythonNotFound, PythonPreference,
-    PythonRequest, Target,
+    PythonRequest, Target, UserScheme,
 };
 
 /// A Python environment, consisting of a Python [`Interpreter`] and its associated paths.
@@ -37,6 +38,7 @@ struct PythonEnvironmentShared {
 pub struct EnvironmentNotFound {
     request: PythonRequest,
     preference: EnvironmentPreference,
+    trace: DiscoveryTrace,
 }
 
 #[derive(Clone, Debug, Error)]
@@ -56,6 +58,7 @@ impl From<PythonNotFound> for EnvironmentNotFound {
         Self {
             request: value.request,
             preference: value.environment_preference,
+            trace: value.trace,
         }
     }
 }
@@ -111,7 +114,7 @@ impl fmt::Display for EnvironmentNotFound {
             SearchType::System => {}
         }
 
-        Ok(())
+        write!(f, "{}", self.trace)
     }
 }
 
@@ -175,6 +178,7 @@ impl PythonEnvironment {
                 return Err(Error::MissingEnvironment(EnvironmentNotFound {
                     preference: EnvironmentPreference::Any,
                     request: PythonRequest::Directory(root.as_ref().to_owned()),
+                    trace: DiscoveryTrace::default(),
                 }));
             }
             Err(err) => return Err(Error::Discovery(err.into())),
@@ -253,6 +257,15 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Create a [`PythonEnvironment`] from an existing [`Interpreter`] and `--user` scheme.
+    pub fn with_user(self, user: UserScheme) -> std::io::Result<Self> {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Ok(Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.with_user(user)?,
+            ..inner
+        })))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
@@ -272,12 +285,15 @@ impl PythonEnvironment {
     }
 
     /// Set a key-value pair in the `pyvenv.cfg` file.
+    ///
+    /// The file is left untouched if it already contains the pair, to avoid churning its
+    /// modification time.
     pub fn set_pyvenv_cfg(&self, key: &str, value: &str) -> Result<(), Error> {
         let content = fs_err::read_to_string(self.0.root.join("pyvenv.cfg"))?;
-        fs_err::write(
-            self.0.root.join("pyvenv.cfg"),
-            PyVenvConfiguration::set(&content, key, value),
-        )?;
+        let updated = PyVenvConfiguration::set(&content, key, value);
+        if updated != content {
+            fs_err::write(self.0.root.join("pyvenv.cfg"), updated)?;
+        }
         Ok(())
     }
 
@@ -315,6 +331,14 @@ impl PythonEnvironment {
         } else if let Some(prefix) = self.0.interpreter.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
             LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+        } else if let Some(user) = self.0.interpreter.user() {
+            // If we're installing into the user scheme, use a lockfile specific to the user base,
+            // rather than writing into the user's home directory.
+            LockedFile::acquire(
+                env::temp_dir().join(format!("uv-{}.lock", cache_digest(user.root()))),
+                user.root().user_display(),
+            )
+            .await
         } else if self.0.interpreter.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
             LockedFile::acquire(self.0.root.join(".lock"), self.0.root.user_display()).await
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::implementation::ImplementationName;
+use crate::mirror_list::MirrorList;
+
+/// Mirrors for downloading managed Python installations, by implementation.
+///
+/// In configuration files, the mirrors are provided as a table keyed by implementation name, e.g.,
+/// `cpython` or `pypy`, with `default` used for any implementation without its own entry.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InstallMirrors {
+    /// The mirrors to use for implementations without a more specific entry.
+    default: Option<MirrorList>,
+    /// The mirrors to use for each implementation.
+    implementations: BTreeMap<ImplementationName, MirrorList>,
+}
+
+impl InstallMirrors {
+    /// Set the mirrors to use for implementations without a more specific entry.
+    #[must_use]
+    pub fn with_default(mut self, mirrors: Option<MirrorList>) -> Self {
+        if let Some(mirrors) = mirrors {
+            self.default = Some(mirrors);
+        }
+        self
+    }
+
+    /// Set the mirrors to use for the given implementation.
+    ///
+    /// If `mirrors` is `None`, any existing entry is retained.
+    #[must_use]
+    pub fn with_implementation(
+        mut self,
+        implementation: ImplementationName,
+        mirrors: Option<MirrorList>,
+    ) -> Self {
+        if let Some(mirrors) = mirrors {
+            self.implementations.insert(implementation, mirrors);
+        }
+        self
+    }
+
+    /// Return the mirrors to use for the given implementation, if any.
+    ///
+    /// An entry for the implementation takes precedence over the default entry. Empty entries are
+    /// ignored.
+    pub fn get(&self, implementation: ImplementationName) -> Option<&[String]> {
+        self.implementations
+            .get(&implementation)
+            .or(self.default.as_ref())
+            .map(|mirrors| &**mirrors)
+            .filter(|mirrors| !mirrors.is_empty())
+    }
+
+    /// Returns `true` if no mirrors are configured.
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.implementations.is_empty()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InstallMirrors {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = BTreeMap::<String, MirrorList>::deserialize(deserializer)?;
+        let mut mirrors = Self::default();
+        for (name, list) in entries {
+            if name == "default" {
+                mirrors.default = Some(list);
+            } else {
+                let implementation =
+                    ImplementationName::from_str(&name).map_err(serde::de::Error::custom)?;
+                mirrors.implementations.insert(implementation, list);
+            }
+        }
+        Ok(mirrors)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for InstallMirrors {
+    fn schema_name() -> String {
+        String::from("InstallMirrors")
+    }
+
+    fn json_schema(r#gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mirrors = r#gen.subschema_for::<MirrorList>();
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties: ["default", "cpython", "pypy", "graalpy"]
+                    .into_iter()
+                    .map(|name| (name.to_string(), mirrors.clone()))
+                    .collect(),
+                additional_properties: Some(Box::new(false.into())),
+                ..schemars::schema::ObjectValidation::default()
+            })),
+            ..schemars::schema::SchemaObject::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InstallMirrors;
+    use crate::implementation::ImplementationName;
+
+    #[test]
+    fn get() {
+        let mirrors: InstallMirrors = serde_json::from_str(
+            r#"{"default": "https://a.example.com", "pypy": ["https://b.example.com"]}"#,
+        )
+        .unwrap();
+
+        // The implementation-specific entry takes precedence over the default.
+        assert_eq!(
+            mirrors.get(ImplementationName::PyPy),
+            Some(["https://b.example.com".to_string()].as_slice())
+        );
+        assert_eq!(
+            mirrors.get(ImplementationName::CPython),
+            Some(["https://a.example.com".to_string()].as_slice())
+        );
+
+        // Without a default, only the configured implementations have mirrors.
+        let mirrors = InstallMirrors::default().with_implementation(
+            ImplementationName::CPython,
+            Some(vec!["https://c.example.com".to_string()].into()),
+        );
+        assert_eq!(
+            mirrors.get(ImplementationName::CPython),
+            Some(["https://c.example.com".to_string()].as_slice())
+        );
+        assert_eq!(mirrors.get(ImplementationName::GraalPy), None);
+    }
+
+    #[test]
+    fn deserialize_unknown_implementation() {
+        let result = serde_json::from_str::<InstallMirrors>(r#"{"jython": "https://example.com"}"#);
+        assert!(result.is_err());
+    }
+}
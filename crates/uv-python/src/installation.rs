@@ -1,24 +1,99 @@
+use std::borrow::Cow;
 use std::fmt;
 use std::str::FromStr;
 
 use tracing::{debug, info};
 
 use uv_cache::Cache;
-use uv_client::BaseClientBuilder;
+use uv_client::{BaseClientBuilder, Connectivity};
 use uv_pep440::{Prerelease, Version};
 
 use crate::discovery::{
-    find_best_python_installation, find_python_installation, EnvironmentPreference, PythonRequest,
+    find_best_python_installation, find_first_python_installation, find_python_installation_with,
+    EnvironmentPreference, PythonRequest, PythonRequestsNotFound, QueriedInterpreters,
 };
 use crate::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest, Reporter};
 use crate::implementation::LenientImplementationName;
-use crate::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
-use crate::platform::{Arch, Libc, Os};
+use crate::install_mirrors::InstallMirrors;
+use crate::managed::{KeyFilter, ManagedPythonInstallation, ManagedPythonInstallations};
+use crate::outcome::{FetchStatus, InstallOutcome, PostInstallReport, TransferRecorder};
+use crate::platform::{self, Arch, Libc, Os};
 use crate::{
-    downloads, Error, ImplementationName, Interpreter, PythonDownloads, PythonPreference,
-    PythonSource, PythonVariant, PythonVersion,
+    downloads, BuildFlavor, Error, ImplementationName, Interpreter, PythonDownloads,
+    PythonPreference, PythonSource, PythonVariant, PythonVersion,
 };
 
+/// Options for finding a [`PythonInstallation`].
+///
+/// The options are used as given for a single call, e.g., the [`PythonPreference`] is never
+/// re-derived from the user's settings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FindOptions {
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    downloads: PythonDownloads,
+    variable: Option<&'static str>,
+}
+
+impl FindOptions {
+    /// Set the [`EnvironmentPreference`] to use when searching for an installation.
+    #[must_use]
+    pub fn environments(mut self, environments: EnvironmentPreference) -> Self {
+        self.environments = environments;
+        self
+    }
+
+    /// Set the [`PythonPreference`] to use when searching for an installation.
+    #[must_use]
+    pub fn preference(mut self, preference: PythonPreference) -> Self {
+        self.preference = preference;
+        self
+    }
+
+    /// Set the [`PythonDownloads`] policy to use when an installation is not found.
+    #[must_use]
+    pub fn downloads(mut self, downloads: PythonDownloads) -> Self {
+        self.downloads = downloads;
+        self
+    }
+
+    /// Set the environment variable the request was read from, e.g., `UV_PYTHON`.
+    ///
+    /// An interpreter at a path provided by the request is attributed to the variable.
+    #[must_use]
+    pub fn request_variable(mut self, variable: Option<&'static str>) -> Self {
+        self.variable = variable;
+        self
+    }
+
+    /// Return the [`EnvironmentPreference`] to use when searching for an installation.
+    pub fn environment_preference(self) -> EnvironmentPreference {
+        self.environments
+    }
+
+    /// Return the [`PythonPreference`] to use when searching for an installation.
+    pub fn python_preference(self) -> PythonPreference {
+        self.preference
+    }
+
+    /// Return the [`PythonDownloads`] policy to use when an installation is not found.
+    pub fn python_downloads(self) -> PythonDownloads {
+        self.downloads
+    }
+
+    /// Return the environment variable the request was read from, if any.
+    pub fn python_request_variable(self) -> Option<&'static str> {
+        self.variable
+    }
+
+    /// Whether a missing installation may be downloaded automatically.
+    fn allows_downloads(self, connectivity: Connectivity) -> bool {
+        self.preference.allows_managed()
+            && self.downloads.is_automatic()
+            && connectivity.is_online()
+    }
+}
+
 /// A Python interpreter and accompanying tools.
 #[derive(Clone, Debug)]
 pub struct PythonInstallation {
@@ -51,26 +126,67 @@ impl PythonInstallation {
     /// See [`find_installation`] for implementation details.
     pub fn find(
         request: &PythonRequest,
-        environments: EnvironmentPreference,
-        preference: PythonPreference,
+        options: FindOptions,
         cache: &Cache,
     ) -> Result<Self, Error> {
-        let installation = find_python_installation(request, environments, preference, cache)??;
+        let installation = find_python_installation_with(
+            request,
+            options.environment_preference(),
+            options.python_preference(),
+            options.python_request_variable(),
+            cache,
+            None,
+        )??;
         Ok(installation)
     }
 
+    /// Find an installed [`PythonInstallation`] that satisfies one of the given requests.
+    ///
+    /// Requests are tried in priority order, e.g., an explicit request, then a version file, then
+    /// [`PythonRequest::Default`]. Unlike calling [`PythonInstallation::find`] for each request,
+    /// each interpreter is only queried once.
+    ///
+    /// Returns the installation and the index of the request it satisfies.
+    pub fn find_first(
+        requests: &[PythonRequest],
+        options: FindOptions,
+        cache: &Cache,
+    ) -> Result<(usize, Self), Error> {
+        let requests = if requests.is_empty() {
+            std::slice::from_ref(&PythonRequest::Default)
+        } else {
+            requests
+        };
+        let queried = QueriedInterpreters::default();
+        match find_first_python_installation(
+            requests,
+            options.environment_preference(),
+            options.python_preference(),
+            options.python_request_variable(),
+            cache,
+            &queried,
+        )? {
+            Ok(found) => Ok(found),
+            // Retain the error for a single request, for consistency with `find`.
+            Err(PythonRequestsNotFound(mut not_found)) if not_found.len() == 1 => {
+                Err(Error::MissingPython(not_found.remove(0)))
+            }
+            Err(err) => Err(Error::MissingPythons(err)),
+        }
+    }
+
     /// Find an installed [`PythonInstallation`] that satisfies a requested version, if the request cannot
     /// be satisfied, fallback to the best available Python installation.
     pub fn find_best(
         request: &PythonRequest,
-        environments: EnvironmentPreference,
-        preference: PythonPreference,
+        options: FindOptions,
         cache: &Cache,
     ) -> Result<Self, Error> {
         Ok(find_best_python_installation(
             request,
-            environments,
-            preference,
+            options.environment_preference(),
+            options.python_preference(),
+            options.python_request_variable(),
             cache,
         )??)
     }
@@ -80,43 +196,74 @@ impl PythonInstallation {
     /// Unlike [`PythonInstallation::find`], if the required Python is not installed it will be installed automatically.
     pub async fn find_or_download(
         request: Option<&PythonRequest>,
-        environments: EnvironmentPreference,
-        preference: PythonPreference,
-        python_downloads: PythonDownloads,
+        options: FindOptions,
         client_builder: &BaseClientBuilder<'_>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
-        python_install_mirror: Option<&str>,
-        pypy_install_mirror: Option<&str>,
+        install_mirrors: &InstallMirrors,
         python_downloads_json_url: Option<&str>,
     ) -> Result<Self, Error> {
         let request = request.unwrap_or(&PythonRequest::Default);
+        let (_, installation) = Self::find_or_download_first(
+            std::slice::from_ref(request),
+            options,
+            client_builder,
+            cache,
+            reporter,
+            install_mirrors,
+            python_downloads_json_url,
+        )
+        .await?;
+        Ok(installation)
+    }
 
-        // Search for the installation
-        let err = match Self::find(request, environments, preference, cache) {
-            Ok(installation) => return Ok(installation),
-            Err(err) => err,
+    /// Find or fetch a [`PythonInstallation`] that satisfies one of the given requests.
+    ///
+    /// Like [`PythonInstallation::find_first`], but if none of the requests are satisfied by an
+    /// installed Python, the highest-priority request that can be downloaded is installed
+    /// automatically.
+    ///
+    /// Returns the installation and the index of the request it satisfies.
+    pub async fn find_or_download_first(
+        requests: &[PythonRequest],
+        options: FindOptions,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: &Cache,
+        reporter: Option<&dyn Reporter>,
+        install_mirrors: &InstallMirrors,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<(usize, Self), Error> {
+        let requests = if requests.is_empty() {
+            std::slice::from_ref(&PythonRequest::Default)
+        } else {
+            requests
         };
 
-        let downloads_enabled = preference.allows_managed()
-            && python_downloads.is_automatic()
-            && client_builder.connectivity.is_online();
+        // Search for the installation, with exactly the preferences that were provided
+        let err = match Self::find_first(requests, options, cache) {
+            Ok(found) => return Ok(found),
+            Err(err) => err,
+        };
 
-        if !downloads_enabled {
+        if !options.allows_downloads(client_builder.connectivity) {
             return Err(err);
         }
 
         match err {
             // If Python is missing, we should attempt a download
-            Error::MissingPython(_) => {}
-            // If we raised a non-critical error, we should attempt a download
+            Error::MissingPython(_) | Error::MissingPythons(_) => {}
+            // If every candidate interpreter was broken, e.g., it failed to run, we should attempt
+            // a download
             Error::Discovery(ref err) if !err.is_critical() => {}
             // Otherwise, this is fatal
             _ => return Err(err),
         }
 
-        // If we can't convert the request to a download, throw the original error
-        let Some(request) = PythonDownloadRequest::from_request(request) else {
+        // Download the highest-priority request that can be converted to a download, if any;
+        // otherwise, throw the original error
+        let Some((index, request)) = requests.iter().enumerate().find_map(|(index, request)| {
+            Some((index, PythonDownloadRequest::from_request(request)?))
+        }) else {
             return Err(err);
         };
 
@@ -126,13 +273,12 @@ impl PythonInstallation {
             client_builder,
             cache,
             reporter,
-            python_install_mirror,
-            pypy_install_mirror,
+            install_mirrors,
             python_downloads_json_url,
         )
         .await
         {
-            Ok(installation) => Ok(installation),
+            Ok((installation, _)) => Ok((index, installation)),
             // Throw the original error if we couldn't find a download
             Err(Error::Download(downloads::Error::NoDownloadFound(_))) => Err(err),
             // But if the download failed, throw that error
@@ -141,53 +287,71 @@ impl PythonInstallation {
     }
 
     /// Download and install the requested installation.
+    ///
+    /// Returns the installation, along with an [`InstallOutcome`] describing what was done.
     pub async fn fetch(
         request: PythonDownloadRequest,
         client_builder: &BaseClientBuilder<'_>,
         cache: &Cache,
         reporter: Option<&dyn Reporter>,
-        python_install_mirror: Option<&str>,
-        pypy_install_mirror: Option<&str>,
+        install_mirrors: &InstallMirrors,
         python_downloads_json_url: Option<&str>,
-    ) -> Result<Self, Error> {
+    ) -> Result<(Self, InstallOutcome), Error> {
         let installations = ManagedPythonInstallations::from_settings(None)?.init()?;
         let installations_dir = installations.root();
         let scratch_dir = installations.scratch();
         let _lock = installations.lock().await?;
+        let existing = installations
+            .find_matching(&KeyFilter::default())?
+            .into_iter()
+            .collect::<Vec<_>>();
 
         let download = ManagedPythonDownload::from_request(&request, python_downloads_json_url)?;
         let client = client_builder.build();
 
         info!("Fetching requested Python...");
+        let recorder = TransferRecorder::new(reporter);
         let result = download
             .fetch_with_retry(
                 &client,
+                cache,
                 installations_dir,
                 &scratch_dir,
                 false,
-                python_install_mirror,
-                pypy_install_mirror,
-                reporter,
+                install_mirrors,
+                Some(&recorder),
             )
             .await?;
+        let transfer = recorder.finish();
 
+        let status = FetchStatus::from(&result);
         let path = match result {
             DownloadResult::AlreadyAvailable(path) => path,
             DownloadResult::Fetched(path) => path,
         };
 
         let installed = ManagedPythonInstallation::new(path, download);
-        installed.ensure_externally_managed()?;
-        installed.ensure_sysconfig_patched()?;
-        installed.ensure_canonical_executables()?;
-        if let Err(e) = installed.ensure_dylib_patched() {
-            e.warn_user(&installed);
+
+        // Preserve the `EXTERNALLY-MANAGED` state of an existing installation, or of the
+        // installation being upgraded.
+        let externally_managed = match status {
+            FetchStatus::AlreadyAvailable => installed.is_externally_managed(),
+            FetchStatus::Fetched => installed
+                .upgrade_of(&existing)
+                .is_none_or(ManagedPythonInstallation::is_externally_managed),
+        };
+        let post_install = PostInstallReport::run(&installed, externally_managed)?;
+        post_install.dylib().warn_user(&installed);
+        if let Some(reporter) = reporter {
+            reporter.on_dylib_patch(installed.key(), post_install.dylib());
         }
+        let outcome = InstallOutcome::new(&installed, status, transfer, post_install);
 
-        Ok(Self {
+        let installation = Self {
             source: PythonSource::Managed,
             interpreter: Interpreter::query(installed.executable(false), cache)?,
-        })
+        };
+        Ok((installation, outcome))
     }
 
     /// Create a [`PythonInstallation`] from an existing [`Interpreter`].
@@ -255,8 +419,76 @@ impl PythonInstallation {
 
 #[derive(Error, Debug)]
 pub enum PythonInstallationKeyError {
-    #[error("Failed to parse Python installation key `{0}`: {1}")]
-    ParseError(String, String),
+    #[error("Failed to parse Python installation key `{0}`: not enough `-`-separated values")]
+    MissingComponents(String),
+    #[error("Failed to parse Python installation key `{key}`: invalid {component}: {reason}")]
+    ParseError {
+        key: String,
+        component: KeyComponent,
+        reason: String,
+        /// The canonical spelling that was most likely intended for the invalid component, e.g.,
+        /// `x86_64` for `amd64`.
+        suggestion: Option<&'static str>,
+    },
+}
+
+impl PythonInstallationKeyError {
+    fn invalid(key: &str, component: KeyComponent, reason: String) -> Self {
+        Self::ParseError {
+            key: key.to_string(),
+            component,
+            reason,
+            suggestion: None,
+        }
+    }
+
+    fn invalid_platform(key: &str, component: KeyComponent, err: &platform::Error) -> Self {
+        Self::ParseError {
+            key: key.to_string(),
+            component,
+            reason: err.to_string(),
+            suggestion: err.suggestion(),
+        }
+    }
+
+    /// The component of the key that could not be parsed, if any.
+    pub fn component(&self) -> Option<KeyComponent> {
+        match self {
+            Self::MissingComponents(_) => None,
+            Self::ParseError { component, .. } => Some(*component),
+        }
+    }
+
+    /// The canonical spelling that was most likely intended for the invalid component, if any.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            Self::MissingComponents(_) => None,
+            Self::ParseError { suggestion, .. } => *suggestion,
+        }
+    }
+}
+
+/// A component of a [`PythonInstallationKey`], e.g., the architecture in
+/// `cpython-3.12.0-linux-x86_64-gnu`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyComponent {
+    Version,
+    Variant,
+    Os,
+    Arch,
+    Libc,
+}
+
+impl fmt::Display for KeyComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version => f.write_str("Python version"),
+            Self::Variant => f.write_str("Python variant"),
+            Self::Os => f.write_str("OS"),
+            Self::Arch => f.write_str("architecture"),
+            Self::Libc => f.write_str("libc"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -270,6 +502,7 @@ pub struct PythonInstallationKey {
     pub(crate) arch: Arch,
     pub(crate) libc: Libc,
     pub(crate) variant: PythonVariant,
+    pub(crate) build_flavor: BuildFlavor,
 }
 
 impl PythonInstallationKey {
@@ -294,6 +527,7 @@ impl PythonInstallationKey {
             arch,
             libc,
             variant,
+            build_flavor: BuildFlavor::Default,
         }
     }
 
@@ -315,6 +549,7 @@ impl PythonInstallationKey {
             arch,
             libc,
             variant,
+            build_flavor: BuildFlavor::Default,
         }
     }
 
@@ -323,16 +558,13 @@ impl PythonInstallationKey {
     }
 
     pub fn version(&self) -> PythonVersion {
-        PythonVersion::from_str(&format!(
-            "{}.{}.{}{}",
-            self.major,
-            self.minor,
-            self.patch,
-            self.prerelease
-                .map(|pre| pre.to_string())
-                .unwrap_or_default()
-        ))
-        .expect("Python installation keys must have valid Python versions")
+        PythonVersion::from_parts(self.major, self.minor, self.patch, self.prerelease)
+    }
+
+    /// The version as a [`Version`], e.g., for comparisons that don't need a string
+    /// representation.
+    fn pep440_version(&self) -> Version {
+        Version::new([self.major, self.minor, self.patch].map(u64::from)).with_pre(self.prerelease)
     }
 
     /// The version in `x.y.z` format.
@@ -340,6 +572,18 @@ impl PythonInstallationKey {
         format!("{}.{}.{}", self.major, self.minor, self.patch)
     }
 
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u8 {
+        self.patch
+    }
+
     pub fn arch(&self) -> &Arch {
         &self.arch
     }
@@ -356,6 +600,47 @@ impl PythonInstallationKey {
         &self.variant
     }
 
+    pub fn build_flavor(&self) -> BuildFlavor {
+        self.build_flavor
+    }
+
+    /// Set the [`BuildFlavor`] of the installation.
+    #[must_use]
+    pub fn with_build_flavor(mut self, build_flavor: BuildFlavor) -> Self {
+        self.build_flavor = build_flavor;
+        self
+    }
+
+    /// Returns `true` if this key is a suitable upgrade of the other, i.e., a newer patch version
+    /// of the same implementation, variant, and minor version.
+    pub fn is_upgrade_of(&self, other: &PythonInstallationKey) -> bool {
+        // Require matching implementation
+        if self.implementation != other.implementation {
+            return false;
+        }
+        // Require a matching variant
+        if self.variant != other.variant {
+            return false;
+        }
+        // Require matching minor version
+        if (self.major, self.minor) != (other.major, other.minor) {
+            return false;
+        }
+        // Require a newer, or equal patch version (for pre-release upgrades)
+        if self.patch <= other.patch {
+            return false;
+        }
+        if let Some(other_pre) = other.prerelease {
+            if let Some(self_pre) = self.prerelease {
+                return self_pre > other_pre;
+            }
+            // Do not upgrade from non-prerelease to prerelease
+            return false;
+        }
+        // Do not upgrade if the patch versions are the same
+        self.patch != other.patch
+    }
+
     /// Return a canonical name for a minor versioned executable.
     pub fn executable_name_minor(&self) -> String {
         format!(
@@ -367,6 +652,19 @@ impl PythonInstallationKey {
         )
     }
 
+    /// Return a canonical name for a minor versioned windowed executable, e.g., `pythonw3.12.exe`.
+    ///
+    /// Windowed executables only exist on Windows.
+    pub fn windowed_executable_name_minor(&self) -> String {
+        format!(
+            "pythonw{maj}.{min}{var}{exe}",
+            maj = self.major,
+            min = self.minor,
+            var = self.variant.suffix(),
+            exe = std::env::consts::EXE_SUFFIX
+        )
+    }
+
     /// Return a canonical name for a major versioned executable.
     pub fn executable_name_major(&self) -> String {
         format!(
@@ -385,6 +683,11 @@ impl PythonInstallationKey {
             exe = std::env::consts::EXE_SUFFIX
         )
     }
+
+    /// Return the components of the key, e.g., for serializing them as separate JSON fields.
+    pub fn fields(&self) -> PythonInstallationKeyFields {
+        PythonInstallationKeyFields::from(self)
+    }
 }
 
 impl fmt::Display for PythonInstallationKey {
@@ -395,7 +698,7 @@ impl fmt::Display for PythonInstallationKey {
         };
         write!(
             f,
-            "{}-{}.{}.{}{}{}-{}-{}-{}",
+            "{}-{}.{}.{}{}{}{}-{}-{}-{}",
             self.implementation,
             self.major,
             self.minor,
@@ -404,6 +707,7 @@ impl fmt::Display for PythonInstallationKey {
                 .map(|pre| pre.to_string())
                 .unwrap_or_default(),
             variant,
+            self.build_flavor.suffix(),
             self.os,
             self.arch,
             self.libc
@@ -416,58 +720,116 @@ impl FromStr for PythonInstallationKey {
 
     fn from_str(key: &str) -> Result<Self, Self::Err> {
         let parts = key.split('-').collect::<Vec<_>>();
-        let [implementation, version, os, arch, libc] = parts.as_slice() else {
-            return Err(PythonInstallationKeyError::ParseError(
-                key.to_string(),
-                "not enough `-`-separated values".to_string(),
-            ));
+        // A statically-linked libc spans two values, e.g., `musl-static`.
+        let (implementation, version, os, arch, libc) = match parts.as_slice() {
+            [implementation, version, os, arch, libc] => {
+                (*implementation, *version, *os, *arch, Cow::Borrowed(*libc))
+            }
+            [implementation, version, os, arch, libc, "static"] => (
+                *implementation,
+                *version,
+                *os,
+                *arch,
+                Cow::Owned(format!("{libc}-static")),
+            ),
+            _ => {
+                return Err(PythonInstallationKeyError::MissingComponents(
+                    key.to_string(),
+                ));
+            }
         };
 
-        let implementation = LenientImplementationName::from(*implementation);
+        let implementation = LenientImplementationName::from(implementation);
 
         let os = Os::from_str(os).map_err(|err| {
-            PythonInstallationKeyError::ParseError(key.to_string(), format!("invalid OS: {err}"))
+            PythonInstallationKeyError::invalid_platform(key, KeyComponent::Os, &err)
         })?;
 
         let arch = Arch::from_str(arch).map_err(|err| {
-            PythonInstallationKeyError::ParseError(
-                key.to_string(),
-                format!("invalid architecture: {err}"),
-            )
+            PythonInstallationKeyError::invalid_platform(key, KeyComponent::Arch, &err)
         })?;
 
-        let libc = Libc::from_str(libc).map_err(|err| {
-            PythonInstallationKeyError::ParseError(key.to_string(), format!("invalid libc: {err}"))
+        let libc = Libc::from_str(&libc).map_err(|err| {
+            PythonInstallationKeyError::invalid_platform(key, KeyComponent::Libc, &err)
         })?;
 
+        // The build flavor is the trailing segment of the version, e.g., `3.12.4+pgo+lto`.
+        let (version, build_flavor) = BuildFlavor::split_suffix(version);
+
         let (version, variant) = match version.split_once('+') {
             Some((version, variant)) => {
                 let variant = PythonVariant::from_str(variant).map_err(|()| {
-                    PythonInstallationKeyError::ParseError(
-                        key.to_string(),
-                        format!("invalid Python variant: {variant}"),
+                    PythonInstallationKeyError::invalid(
+                        key,
+                        KeyComponent::Variant,
+                        variant.to_string(),
                     )
                 })?;
                 (version, variant)
             }
-            None => (*version, PythonVariant::Default),
+            None => (version, PythonVariant::Default),
         };
 
-        let version = PythonVersion::from_str(version).map_err(|err| {
-            PythonInstallationKeyError::ParseError(
-                key.to_string(),
-                format!("invalid Python version: {err}"),
-            )
-        })?;
+        let version = PythonVersion::from_str(version)
+            .map_err(|err| PythonInstallationKeyError::invalid(key, KeyComponent::Version, err))?;
 
-        Ok(Self::new_from_version(
-            implementation,
-            &version,
-            os,
-            arch,
-            libc,
-            variant,
-        ))
+        Ok(
+            Self::new_from_version(implementation, &version, os, arch, libc, variant)
+                .with_build_flavor(build_flavor),
+        )
+    }
+}
+
+impl serde::Serialize for PythonInstallationKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PythonInstallationKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = PythonInstallationKey;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a Python installation key, e.g., `cpython-3.12.4-linux-x86_64-gnu`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                PythonInstallationKey::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// The components of a [`PythonInstallationKey`], for outputs that want individual fields rather
+/// than the canonical string form.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PythonInstallationKeyFields {
+    pub key: String,
+    pub implementation: String,
+    pub version: String,
+    pub os: String,
+    pub arch: String,
+    pub libc: String,
+    pub variant: String,
+}
+
+impl From<&PythonInstallationKey> for PythonInstallationKeyFields {
+    fn from(key: &PythonInstallationKey) -> Self {
+        Self {
+            key: key.to_string(),
+            implementation: key.implementation.to_string(),
+            version: key.version().to_string(),
+            os: key.os.to_string(),
+            arch: key.arch.to_string(),
+            libc: key.libc.to_string(),
+            variant: key.variant.to_string(),
+        }
     }
 }
 
@@ -481,10 +843,297 @@ impl Ord for PythonInstallationKey {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.implementation
             .cmp(&other.implementation)
-            .then_with(|| self.version().cmp(&other.version()))
+            .then_with(|| self.pep440_version().cmp(&other.pep440_version()))
             .then_with(|| self.os.to_string().cmp(&other.os.to_string()))
             .then_with(|| self.arch.to_string().cmp(&other.arch.to_string()))
-            .then_with(|| self.libc.to_string().cmp(&other.libc.to_string()))
+            .then_with(|| {
+                self.libc
+                    .family()
+                    .to_string()
+                    .cmp(&other.libc.family().to_string())
+            })
+            // we want dynamically-linked builds to come first, as static builds can't load
+            // compiled extension modules
+            .then_with(|| self.libc.is_static().cmp(&other.libc.is_static()).reverse())
             .then_with(|| self.variant.cmp(&other.variant).reverse()) // we want Default to come first
+            // we want the fastest build flavor to come first
+            .then_with(|| {
+                self.build_flavor
+                    .priority()
+                    .cmp(&other.build_flavor.priority())
+                    .reverse()
+            })
+            .then_with(|| self.build_flavor.cmp(&other.build_flavor).reverse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_client::Connectivity;
+    use uv_pep440::{Prerelease, PrereleaseKind};
+
+    use super::{FindOptions, KeyComponent, PythonInstallationKey};
+    use crate::implementation::{ImplementationName, LenientImplementationName};
+    use crate::platform::{Arch, Libc, Os};
+    use crate::{BuildFlavor, PythonDownloads, PythonPreference, PythonVariant, PythonVersion};
+
+    /// Construct the version of a key by formatting and re-parsing it, i.e., the previous
+    /// implementation of [`PythonInstallationKey::version`].
+    fn version_from_string(key: &PythonInstallationKey) -> PythonVersion {
+        PythonVersion::from_str(&format!(
+            "{}.{}.{}{}",
+            key.major,
+            key.minor,
+            key.patch,
+            key.prerelease
+                .map(|pre| pre.to_string())
+                .unwrap_or_default()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn key_version_matches_string_round_trip() {
+        let prereleases = [
+            PrereleaseKind::Alpha,
+            PrereleaseKind::Beta,
+            PrereleaseKind::Rc,
+        ]
+        .into_iter()
+        .flat_map(|kind| [0, 1, 10].map(|number| Some(Prerelease { kind, number })))
+        .chain([None])
+        .collect::<Vec<_>>();
+
+        let mut keys = Vec::new();
+        for major in [2, 3] {
+            for minor in [0, 7, 13, 255] {
+                for patch in [0, 1, 12, 255] {
+                    for prerelease in &prereleases {
+                        let key = PythonInstallationKey::new(
+                            LenientImplementationName::Known(ImplementationName::CPython),
+                            major,
+                            minor,
+                            patch,
+                            *prerelease,
+                            Os::from_str("linux").unwrap(),
+                            Arch::from_str("x86_64").unwrap(),
+                            Libc::from_str("gnu").unwrap(),
+                            PythonVariant::Default,
+                        );
+                        let expected = version_from_string(&key);
+                        assert_eq!(key.version(), expected, "{key}");
+                        assert_eq!(key.version().to_string(), expected.to_string(), "{key}");
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+
+        // Keys are ordered consistently with the versions they'd have produced previously
+        let mut sorted = keys.clone();
+        sorted.sort();
+        keys.sort_by_key(|key| version_from_string(key).into_version());
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn key_serde_round_trip() {
+        for implementation in ["cpython", "pypy", "graalpy", "foo"] {
+            for version in ["3.12.4", "3.13.0a1", "3.13.0b3", "3.14.0rc2"] {
+                for variant in ["", "+freethreaded"] {
+                    for platform in [
+                        "linux-x86_64-gnu",
+                        "macos-aarch64-none",
+                        "macos-x86_64-none",
+                    ] {
+                        let key = format!("{implementation}-{version}{variant}-{platform}");
+                        let parsed = PythonInstallationKey::from_str(&key).unwrap();
+
+                        let json = serde_json::to_string(&parsed).unwrap();
+                        assert_eq!(json, format!("\"{key}\""));
+                        let deserialized: PythonInstallationKey =
+                            serde_json::from_str(&json).unwrap();
+                        assert_eq!(deserialized, parsed, "{key}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn key_cross_arch() {
+        // An x86_64 build installed on Apple Silicon is distinct from the native build
+        let native = PythonInstallationKey::from_str("cpython-3.11.9-macos-aarch64-none").unwrap();
+        let emulated = PythonInstallationKey::from_str("cpython-3.11.9-macos-x86_64-none").unwrap();
+        assert_ne!(native, emulated);
+        assert_eq!(emulated.to_string(), "cpython-3.11.9-macos-x86_64-none");
+        assert_eq!(emulated.arch().to_string(), "x86_64");
+    }
+
+    #[test]
+    fn key_static_musl() {
+        let musl = PythonInstallationKey::from_str("cpython-3.13.3-linux-x86_64-musl").unwrap();
+        let musl_static =
+            PythonInstallationKey::from_str("cpython-3.13.3-linux-x86_64-musl-static").unwrap();
+        assert_eq!(*musl.libc(), Libc::Some(target_lexicon::Environment::Musl));
+        assert_eq!(*musl_static.libc(), Libc::MuslStatic);
+        assert_eq!(
+            musl_static.to_string(),
+            "cpython-3.13.3-linux-x86_64-musl-static"
+        );
+
+        // Only `static` may follow the libc
+        assert!(
+            PythonInstallationKey::from_str("cpython-3.13.3-linux-x86_64-musl-shared").is_err()
+        );
+
+        // Dynamically-linked builds are preferred, i.e., sort higher
+        assert!(musl > musl_static);
+        let gnu = PythonInstallationKey::from_str("cpython-3.13.3-linux-x86_64-gnu").unwrap();
+        assert!(gnu < musl_static);
+    }
+
+    #[test]
+    fn key_build_flavor() {
+        let default = PythonInstallationKey::from_str("cpython-3.12.4-linux-x86_64-gnu").unwrap();
+        let pgo_lto =
+            PythonInstallationKey::from_str("cpython-3.12.4+pgo+lto-linux-x86_64-gnu").unwrap();
+        let noopt =
+            PythonInstallationKey::from_str("cpython-3.12.4+noopt-linux-x86_64-gnu").unwrap();
+        assert_eq!(default.build_flavor(), BuildFlavor::Default);
+        assert_eq!(pgo_lto.build_flavor(), BuildFlavor::PgoLto);
+        assert_eq!(noopt.build_flavor(), BuildFlavor::Noopt);
+        assert_eq!(noopt.to_string(), "cpython-3.12.4+noopt-linux-x86_64-gnu");
+
+        // Flavors of the same version are distinct installations
+        assert_ne!(default, noopt);
+        assert_ne!(default, pgo_lto);
+
+        // The flavor follows the variant
+        let freethreaded =
+            PythonInstallationKey::from_str("cpython-3.13.0+freethreaded+pgo+lto-linux-x86_64-gnu")
+                .unwrap();
+        assert_eq!(*freethreaded.variant(), PythonVariant::Freethreaded);
+        assert_eq!(freethreaded.build_flavor(), BuildFlavor::PgoLto);
+        assert_eq!(
+            freethreaded.to_string(),
+            "cpython-3.13.0+freethreaded+pgo+lto-linux-x86_64-gnu"
+        );
+
+        // Faster flavors are preferred, i.e., sort higher
+        assert!(default > pgo_lto);
+        assert!(pgo_lto > noopt);
+    }
+
+    #[test]
+    fn key_fields() {
+        let key =
+            PythonInstallationKey::from_str("cpython-3.13.0rc2+freethreaded-linux-x86_64-gnu")
+                .unwrap();
+        insta::assert_snapshot!(serde_json::to_string_pretty(&key.fields()).unwrap(), @r#"
+        {
+          "key": "cpython-3.13.0rc2+freethreaded-linux-x86_64-gnu",
+          "implementation": "cpython",
+          "version": "3.13.0rc2",
+          "os": "linux",
+          "arch": "x86_64",
+          "libc": "gnu",
+          "variant": "freethreaded"
+        }
+        "#);
+    }
+
+    #[test]
+    fn key_invalid_toml() {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Settings {
+            python: PythonInstallationKey,
+        }
+
+        let err = toml::from_str::<Settings>(r#"python = "cpython-3.12.4-linux-x86_64""#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("python = "), "{err}");
+        assert!(
+            err.contains(
+                "Failed to parse Python installation key `cpython-3.12.4-linux-x86_64`: \
+                not enough `-`-separated values"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn key_platform_aliases() {
+        // Unambiguous aliases are accepted, but the key is always displayed canonically.
+        let key = PythonInstallationKey::from_str("cpython-3.12.4-macos-arm64-none").unwrap();
+        assert_eq!(key.to_string(), "cpython-3.12.4-macos-aarch64-none");
+
+        let key = PythonInstallationKey::from_str("cpython-3.12.4-win32-amd64-none").unwrap();
+        assert_eq!(key.to_string(), "cpython-3.12.4-windows-x86_64-none");
+
+        let key = PythonInstallationKey::from_str("cpython-3.12.4-linux-x64-glibc").unwrap();
+        assert_eq!(key.to_string(), "cpython-3.12.4-linux-x86_64-gnu");
+    }
+
+    #[test]
+    fn key_platform_suggestions() {
+        let err = PythonInstallationKey::from_str("cpython-3.12.4-linux-x68_64-gnu").unwrap_err();
+        assert_eq!(err.component(), Some(KeyComponent::Arch));
+        assert_eq!(err.suggestion(), Some("x86_64"));
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Python installation key `cpython-3.12.4-linux-x68_64-gnu`: \
+            invalid architecture: Unknown architecture: x68_64; did you mean `x86_64`?"
+        );
+
+        let err = PythonInstallationKey::from_str("cpython-3.12.4-mcos-aarch64-none").unwrap_err();
+        assert_eq!(err.component(), Some(KeyComponent::Os));
+        assert_eq!(err.suggestion(), Some("macos"));
+
+        let err = PythonInstallationKey::from_str("cpython-3.12.4-linux-x86_64-mus").unwrap_err();
+        assert_eq!(err.component(), Some(KeyComponent::Libc));
+        assert_eq!(err.suggestion(), Some("musl"));
+
+        // Values that aren't close to any known spelling have no suggestion.
+        let err = PythonInstallationKey::from_str("cpython-3.12.4-linux-foo-gnu").unwrap_err();
+        assert_eq!(err.component(), Some(KeyComponent::Arch));
+        assert_eq!(err.suggestion(), None);
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Python installation key `cpython-3.12.4-linux-foo-gnu`: \
+            invalid architecture: Unknown architecture: foo"
+        );
+
+        let err = PythonInstallationKey::from_str("cpython-3.12.4-linux-x86_64").unwrap_err();
+        assert_eq!(err.component(), None);
+    }
+
+    #[test]
+    fn find_options_allows_downloads() {
+        let options = FindOptions::default().downloads(PythonDownloads::Automatic);
+        assert!(options.allows_downloads(Connectivity::Online));
+        assert!(!options.allows_downloads(Connectivity::Offline));
+
+        // Downloads are only allowed if the preference allows managed installations.
+        assert!(options
+            .preference(PythonPreference::OnlyManaged)
+            .allows_downloads(Connectivity::Online));
+        assert!(!options
+            .preference(PythonPreference::OnlySystem)
+            .allows_downloads(Connectivity::Online));
+
+        // And if downloads are automatic.
+        assert!(!options
+            .preference(PythonPreference::OnlyManaged)
+            .downloads(PythonDownloads::Manual)
+            .allows_downloads(Connectivity::Online));
+        assert!(!options
+            .preference(PythonPreference::OnlyManaged)
+            .downloads(PythonDownloads::Never)
+            .allows_downloads(Connectivity::Online));
     }
 }
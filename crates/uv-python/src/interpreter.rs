@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 use std::env::consts::ARCH;
 use std::fmt::{Display, Formatter};
-use std::io;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
 use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use configparser::ini::Ini;
 use fs_err as fs;
@@ -24,13 +26,16 @@ use uv_pep508::{MarkerEnvironment, StringVersion};
 use uv_platform_tags::Platform;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::implementation::LenientImplementationName;
 use crate::platform::{Arch, Libc, Os};
 use crate::pointer_size::PointerSize;
+use crate::sysconfig_vars::SysconfigVars;
 use crate::{
-    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, VersionRequest,
-    VirtualEnvironment,
+    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, UserScheme,
+    VersionRequest, VirtualEnvironment,
 };
 
 /// A Python executable and its associated platform markers.
@@ -52,6 +57,10 @@ pub struct Interpreter {
     tags: OnceLock<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: Option<UserScheme>,
+    user_scheme: Option<Scheme>,
+    user_base: Option<PathBuf>,
+    sysconfig_vars: SysconfigVars,
     pointer_size: PointerSize,
     gil_disabled: bool,
     real_executable: PathBuf,
@@ -87,6 +96,10 @@ impl Interpreter {
             tags: OnceLock::new(),
             target: None,
             prefix: None,
+            user: None,
+            user_scheme: info.user_scheme,
+            user_base: info.user_base,
+            sysconfig_vars: info.sysconfig_vars,
             real_executable: executable.as_ref().to_path_buf(),
         })
     }
@@ -101,6 +114,7 @@ impl Interpreter {
             sys_prefix: virtualenv.root,
             target: None,
             prefix: None,
+            user: None,
             ..self
         }
     }
@@ -123,6 +137,15 @@ impl Interpreter {
         })
     }
 
+    /// Return a new [`Interpreter`] to install into the user `site-packages` directory.
+    pub fn with_user(self, user: UserScheme) -> io::Result<Self> {
+        user.init()?;
+        Ok(Self {
+            user: Some(user),
+            ..self
+        })
+    }
+
     /// Return the base Python executable; that is, the Python executable that should be
     /// considered the "base" for the virtual environment. This is typically the Python executable
     /// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
@@ -263,6 +286,11 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
+    /// Returns `true` if the environment is a `--user` environment.
+    pub fn is_user(&self) -> bool {
+        self.user.is_some()
+    }
+
     /// Returns `Some` if the environment is externally managed, optionally including an error
     /// message from the `EXTERNALLY-MANAGED` file.
     ///
@@ -446,6 +474,27 @@ impl Interpreter {
         &self.virtualenv
     }
 
+    /// Return the [`Scheme`] for an environment rooted at the given prefix, based on the
+    /// interpreter's own virtual environment path templates.
+    ///
+    /// For example, this is the layout used for virtual environments created by this
+    /// [`Interpreter`].
+    pub fn scheme_for(&self, prefix: &Path) -> Scheme {
+        Scheme {
+            purelib: prefix.join(&self.virtualenv.purelib),
+            platlib: prefix.join(&self.virtualenv.platlib),
+            scripts: prefix.join(&self.virtualenv.scripts),
+            data: prefix.join(&self.virtualenv.data),
+            include: prefix.join(&self.virtualenv.include),
+        }
+    }
+
+    /// Return the `sysconfig` paths and configuration variables reported by this [`Interpreter`],
+    /// such as `platstdlib`, `EXT_SUFFIX`, and `SOABI`.
+    pub fn sysconfig_vars(&self) -> &SysconfigVars {
+        &self.sysconfig_vars
+    }
+
     /// Return whether this interpreter is `manylinux` compatible.
     pub fn manylinux_compatible(&self) -> bool {
         self.manylinux_compatible
@@ -475,6 +524,20 @@ impl Interpreter {
         self.prefix.as_ref()
     }
 
+    /// Return the `--user` scheme for this interpreter, if any.
+    pub fn user(&self) -> Option<&UserScheme> {
+        self.user.as_ref()
+    }
+
+    /// Return the user installation scheme (i.e., `--user`) for this interpreter, if the
+    /// interpreter supports user installations.
+    pub fn user_scheme(&self) -> Option<UserScheme> {
+        Some(UserScheme::from_template(
+            self.user_scheme.as_ref()?,
+            self.user_base.as_deref()?,
+        ))
+    }
+
     /// Returns `true` if an [`Interpreter`] may be a `python-build-standalone` interpreter.
     ///
     /// This method may return false positives, but it should not return false negatives. In other
@@ -496,7 +559,9 @@ impl Interpreter {
             scheme: if let Some(target) = self.target.as_ref() {
                 target.scheme()
             } else if let Some(prefix) = self.prefix.as_ref() {
-                prefix.scheme(&self.virtualenv)
+                self.scheme_for(prefix.root())
+            } else if let Some(user) = self.user.as_ref() {
+                user.scheme()
             } else {
                 Scheme {
                     purelib: self.purelib().to_path_buf(),
@@ -533,7 +598,9 @@ impl Interpreter {
             .prefix()
             .map(|prefix| prefix.site_packages(self.virtualenv()));
 
-        let interpreter = if target.is_none() && prefix.is_none() {
+        let user = self.user().map(UserScheme::site_packages);
+
+        let interpreter = if target.is_none() && prefix.is_none() && user.is_none() {
             let purelib = self.purelib();
             let platlib = self.platlib();
             Some(std::iter::once(purelib).chain(
@@ -552,6 +619,7 @@ impl Interpreter {
             .flatten()
             .map(Cow::Borrowed)
             .chain(prefix.into_iter().flatten().map(Cow::Owned))
+            .chain(user.into_iter().flatten().map(Cow::Borrowed))
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
     }
 
@@ -687,6 +755,14 @@ pub enum Error {
         #[source]
         err: io::Error,
     },
+    #[error("Failed to access Python interpreter at `{path}`")]
+    Inaccessible {
+        path: PathBuf,
+        #[source]
+        err: io::Error,
+    },
+    #[error(transparent)]
+    QueryTimeout(QueryTimeout),
     #[error("{0}")]
     UnexpectedResponse(UnexpectedResponseError),
     #[error("{0}")]
@@ -728,6 +804,46 @@ impl Display for BrokenSymlink {
     }
 }
 
+#[derive(Debug, Error)]
+pub struct QueryTimeout {
+    pub path: PathBuf,
+    /// The time waited for the interpreter before it was killed.
+    pub elapsed: Duration,
+}
+
+impl Display for QueryTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Querying Python at `{}` did not complete after {}s",
+            self.path.user_display(),
+            self.elapsed.as_secs()
+        )
+    }
+}
+
+impl QueryTimeout {
+    /// A suggestion for how to resolve the timeout.
+    pub fn hint(&self) -> String {
+        format!(
+            "A `{}` module or `{}` script that blocks (e.g., on a network mount or a prompt) is a common cause; the timeout can be increased with `{}`, or disabled by setting it to `0`",
+            "sitecustomize".green(),
+            "PYTHONSTARTUP".green(),
+            EnvVars::UV_PYTHON_QUERY_TIMEOUT.green()
+        )
+    }
+}
+
+impl Error {
+    /// A suggestion for how to resolve the error, if we can offer one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::QueryTimeout(err) => Some(err.hint()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "result", rename_all = "lowercase")]
 enum InterpreterInfoResult {
@@ -761,6 +877,10 @@ struct InterpreterInfo {
     markers: MarkerEnvironment,
     scheme: Scheme,
     virtualenv: Scheme,
+    user_scheme: Option<Scheme>,
+    user_base: Option<PathBuf>,
+    #[serde(default)]
+    sysconfig_vars: SysconfigVars,
     manylinux_compatible: bool,
     sys_prefix: PathBuf,
     sys_base_exec_prefix: PathBuf,
@@ -787,17 +907,37 @@ impl InterpreterInfo {
             r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import main; main()"#,
             tempdir.path().escape_for_python()
         );
-        let output = Command::new(interpreter)
+        let child = Command::new(interpreter)
             .arg("-I") // Isolated mode.
             .arg("-B") // Don't write bytecode.
             .arg("-c")
             .arg(script)
-            .output()
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
             .map_err(|err| Error::SpawnFailed {
                 path: interpreter.to_path_buf(),
                 err,
             })?;
 
+        // A hung interpreter would otherwise block discovery indefinitely.
+        let timeout = query_timeout();
+        let output = match timeout {
+            Some(timeout) => wait_with_timeout(child, timeout),
+            None => child.wait_with_output().map(Some),
+        }
+        .map_err(|err| Error::SpawnFailed {
+            path: interpreter.to_path_buf(),
+            err,
+        })?
+        .ok_or_else(|| {
+            Error::QueryTimeout(QueryTimeout {
+                path: interpreter.to_path_buf(),
+                elapsed: timeout.unwrap_or_default(),
+            })
+        })?;
+
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
 
@@ -927,7 +1067,10 @@ impl InterpreterInfo {
                         Error::NotFound(executable.to_path_buf())
                     }
                 } else {
-                    err.into()
+                    Error::Inaccessible {
+                        path: executable.to_path_buf(),
+                        err,
+                    }
                 }
             })?;
 
@@ -1078,6 +1221,74 @@ fn find_base_python(
     }
 }
 
+/// The maximum time to wait for an interpreter query, as configured by `UV_PYTHON_QUERY_TIMEOUT`.
+///
+/// Returns [`None`] if the timeout is disabled (i.e., set to `0`).
+fn query_timeout() -> Option<Duration> {
+    const DEFAULT: Option<Duration> = Some(Duration::from_secs(30));
+    let Ok(value) = std::env::var(EnvVars::UV_PYTHON_QUERY_TIMEOUT) else {
+        return DEFAULT;
+    };
+    match value.parse::<u64>() {
+        Ok(0) => None,
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => {
+            warn_user_once!("Ignoring invalid value from environment for `UV_PYTHON_QUERY_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
+            DEFAULT
+        }
+    }
+}
+
+/// Wait for a child process to exit and collect its output, killing it if it's still running
+/// after `timeout`.
+///
+/// Returns `Ok(None)` if the child was killed.
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> io::Result<Option<Output>> {
+    fn read_to_end(
+        mut pipe: impl Read + Send + 'static,
+    ) -> thread::JoinHandle<io::Result<Vec<u8>>> {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            pipe.read_to_end(&mut buf)?;
+            Ok(buf)
+        })
+    }
+
+    // Read the output on separate threads, so the child can't block on a full pipe.
+    let stdout = child.stdout.take().map(read_to_end);
+    let stderr = child.stderr.take().map(read_to_end);
+
+    let start = Instant::now();
+    let mut interval = Duration::from_millis(1);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            // The readers are left to finish on their own, as the pipes may be held open by
+            // processes the interpreter spawned.
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(interval.min(timeout - elapsed));
+        interval = (interval * 2).min(Duration::from_millis(10));
+    };
+
+    let join = |reader: Option<thread::JoinHandle<io::Result<Vec<u8>>>>| {
+        reader.map_or_else(
+            || Ok(Vec::new()),
+            |reader| reader.join().expect("reader thread panicked"),
+        )
+    };
+    Ok(Some(Output {
+        status,
+        stdout: join(stdout)?,
+        stderr: join(stderr)?,
+    }))
+}
+
 #[cfg(unix)]
 #[cfg(test)]
 mod tests {
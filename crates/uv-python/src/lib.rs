@@ -4,29 +4,46 @@ use thiserror::Error;
 #[cfg(test)]
 use uv_static::EnvVars;
 
+pub use crate::build_flavor::{BuildFlavor, InvalidBuildFlavor};
 pub use crate::discovery::{
     find_python_installations, EnvironmentPreference, Error as DiscoveryError, PythonDownloads,
-    PythonNotFound, PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
+    PythonNotFound, PythonPreference, PythonRequest, PythonRequestsNotFound, PythonSource,
+    PythonVariant, VersionRequest,
+};
+pub use crate::discovery_trace::{
+    DiscoveryTrace, RejectedInterpreter, Rejection, SearchedLocation,
 };
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
 pub use crate::implementation::ImplementationName;
-pub use crate::installation::{PythonInstallation, PythonInstallationKey};
-pub use crate::interpreter::{BrokenSymlink, Error as InterpreterError, Interpreter};
+pub use crate::install_mirrors::InstallMirrors;
+pub use crate::installation::{
+    FindOptions, KeyComponent, PythonInstallation, PythonInstallationKey,
+    PythonInstallationKeyError, PythonInstallationKeyFields,
+};
+pub use crate::interpreter::{BrokenSymlink, Error as InterpreterError, Interpreter, QueryTimeout};
+pub use crate::mirror_list::MirrorList;
+pub use crate::mirror_manifest::{Error as MirrorManifestError, MirrorManifest, RejectedArchive};
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
 pub use crate::python_version::PythonVersion;
+pub use crate::sysconfig_vars::SysconfigVars;
 pub use crate::target::Target;
+pub use crate::user_scheme::UserScheme;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
     PythonVersionFile, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
+mod build_flavor;
 mod cpuinfo;
 mod discovery;
+mod discovery_trace;
 pub mod downloads;
+pub mod dylib;
 mod environment;
 mod implementation;
+mod install_mirrors;
 mod installation;
 mod interpreter;
 mod libc;
@@ -34,12 +51,17 @@ pub mod macos_dylib;
 pub mod managed;
 #[cfg(windows)]
 mod microsoft_store;
+mod mirror_list;
+mod mirror_manifest;
+pub mod outcome;
 pub mod platform;
 mod pointer_size;
 mod prefix;
 mod python_version;
 mod sysconfig;
+mod sysconfig_vars;
 mod target;
+mod user_scheme;
 mod version_files;
 mod virtualenv;
 #[cfg(windows)]
@@ -90,6 +112,9 @@ pub enum Error {
     #[error(transparent)]
     MissingPython(#[from] PythonNotFound),
 
+    #[error(transparent)]
+    MissingPythons(#[from] PythonRequestsNotFound),
+
     #[error(transparent)]
     MissingEnvironment(#[from] environment::EnvironmentNotFound),
 
@@ -119,14 +144,17 @@ mod tests {
 
     use crate::{
         discovery::{
-            self, find_best_python_installation, find_python_installation, EnvironmentPreference,
+            self, find_best_python_installation, find_first_python_installation,
+            find_python_installation, EnvironmentPreference, QueriedInterpreters,
         },
         PythonPreference,
     };
     use crate::{
-        implementation::ImplementationName, installation::PythonInstallation,
-        managed::ManagedPythonInstallations, virtualenv::virtualenv_python_executable,
-        PythonNotFound, PythonRequest, PythonSource, PythonVersion,
+        implementation::ImplementationName,
+        installation::{FindOptions, PythonInstallation},
+        managed::ManagedPythonInstallations,
+        virtualenv::virtualenv_python_executable,
+        PythonNotFound, PythonRequest, PythonSource, PythonVersion, Rejection,
     };
 
     struct TestContext {
@@ -649,6 +677,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_not_found_trace() -> Result<()> {
+        let mut context = TestContext::new()?;
+
+        let python2_dir = context.new_search_path_directory("python2")?;
+        let python2 = python2_dir.child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python2)?;
+
+        let python3_dir = context.new_search_path_directory("python3")?;
+        let python3 = python3_dir.child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_interpreter(
+            &python3,
+            &PythonVersion::from_str("3.11.1").unwrap(),
+            ImplementationName::default(),
+            true,
+            false,
+        )?;
+
+        // Since a usable interpreter was found, the broken interpreter is included in the trace
+        // rather than raised
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::OnlySystem,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })?;
+        let Err(not_found) = result else {
+            panic!("No Python installation should be found; got {result:?}");
+        };
+
+        let searched = not_found
+            .trace
+            .searched()
+            .iter()
+            .map(|location| (location.source(), location.path().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            searched,
+            [
+                (PythonSource::SearchPath, python2_dir.path()),
+                (PythonSource::SearchPath, python3_dir.path()),
+            ]
+        );
+
+        let [failed, unsatisfied] = not_found.trace.rejected() else {
+            panic!("Two candidates should be rejected; got {not_found:?}");
+        };
+        assert_eq!(failed.path(), python2.path());
+        assert_eq!(failed.source(), PythonSource::SearchPathFirst);
+        assert!(
+            matches!(failed.reason(), Rejection::Failed(_)),
+            "The Python 2 interpreter should fail to run; got {failed:?}"
+        );
+        assert_eq!(unsatisfied.path(), python3.path());
+        assert_eq!(unsatisfied.source(), PythonSource::SearchPath);
+        assert_eq!(
+            unsatisfied.reason(),
+            &Rejection::Request("CPython 3.11.1".to_string())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_system_python_allowed() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -778,6 +871,88 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_first_python_version_in_priority_order() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.11.1", "3.12.2"])?;
+
+        let requests = ["3.13", "3.12", "3.11"].map(PythonRequest::parse);
+        let queried = QueriedInterpreters::default();
+
+        let (index, python) = context
+            .run(|| {
+                find_first_python_installation(
+                    &requests,
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    None,
+                    &context.cache,
+                    &queried,
+                )
+            })?
+            .expect("A request should be satisfied");
+        assert_eq!(index, 1, "We should satisfy the first request with a match");
+        assert_eq!(
+            &python.interpreter().python_full_version().to_string(),
+            "3.12.2",
+            "We should find the interpreter for the winning request"
+        );
+        assert_eq!(
+            queried.queries(),
+            2,
+            "We should query each interpreter once across all of the requests"
+        );
+
+        // Repeating the search should not query any interpreters again
+        context
+            .run(|| {
+                find_first_python_installation(
+                    &requests,
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    None,
+                    &context.cache,
+                    &queried,
+                )
+            })?
+            .expect("A request should be satisfied");
+        assert_eq!(
+            queried.queries(),
+            2,
+            "We should reuse previously queried interpreters"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_first_python_version_none_satisfied() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.11.1"])?;
+
+        let requests = ["3.13", "3.12"].map(PythonRequest::parse);
+        let result = context.run(|| {
+            find_first_python_installation(
+                &requests,
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                None,
+                &context.cache,
+                &QueriedInterpreters::default(),
+            )
+        })?;
+        let Err(not_found) = result else {
+            panic!("No request should be satisfied; got {result:?}");
+        };
+        assert_eq!(
+            not_found.0.len(),
+            2,
+            "We should report every unsatisfied request"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_version_patch() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -832,6 +1007,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_only_managed() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.10.1", "3.11.2", "3.12.3"])?;
+
+        let options = FindOptions::default()
+            .environments(EnvironmentPreference::Any)
+            .preference(PythonPreference::OnlyManaged);
+
+        // System interpreters on the search path are never selected
+        let result = context.run(|| {
+            PythonInstallation::find(&PythonRequest::parse("3.11"), options, &context.cache)
+        });
+        assert!(
+            matches!(result, Err(crate::Error::MissingPython(..))),
+            "We should not find a system python; got {result:?}"
+        );
+
+        let result = context.run(|| {
+            PythonInstallation::find_best(&PythonRequest::parse("3.11"), options, &context.cache)
+        });
+        assert!(
+            matches!(result, Err(crate::Error::MissingPython(..))),
+            "We should not find a system python; got {result:?}"
+        );
+
+        // Nor is a system interpreter from the parent process
+        let parent = context.tempdir.child("python").to_path_buf();
+        TestContext::create_mock_interpreter(
+            &parent,
+            &PythonVersion::from_str("3.11.4").unwrap(),
+            ImplementationName::CPython,
+            true,
+            false,
+        )?;
+        let vars = [(
+            EnvVars::UV_INTERNAL__PARENT_INTERPRETER,
+            Some(parent.as_os_str()),
+        )];
+        let result = context.run_with_vars(&vars, || {
+            PythonInstallation::find(&PythonRequest::parse("3.11"), options, &context.cache)
+        });
+        assert!(
+            matches!(result, Err(crate::Error::MissingPython(..))),
+            "We should not find the system parent interpreter; got {result:?}"
+        );
+
+        // Unless the options allow system interpreters
+        let python = context.run_with_vars(&vars, || {
+            PythonInstallation::find(
+                &PythonRequest::parse("3.11"),
+                options.preference(PythonPreference::System),
+                &context.cache,
+            )
+        })?;
+        assert_eq!(python.source(), &PythonSource::ParentInterpreter);
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.11.4"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_version_patch_no_match() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -863,6 +1102,7 @@ mod tests {
                 &PythonRequest::parse("3.11.3"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -896,6 +1136,7 @@ mod tests {
                 &PythonRequest::parse("3.11.11"),
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
+                None,
                 &context.cache,
             )
         })??;
@@ -932,6 +1173,7 @@ mod tests {
                     &PythonRequest::parse("3.10"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -962,6 +1204,7 @@ mod tests {
                     &PythonRequest::parse("3.10.2"),
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
+                    None,
                     &context.cache,
                 )
             })??;
@@ -1683,6 +1926,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_from_environment_variable_path() -> Result<()> {
+        let context = TestContext::new()?;
+        let python_path = context.tempdir.child("foo").join("bar");
+        TestContext::create_mock_interpreter(
+            &python_path,
+            &PythonVersion::from_str("3.10.0").unwrap(),
+            ImplementationName::default(),
+            true,
+            false,
+        )?;
+
+        let request = PythonRequest::parse(python_path.to_str().unwrap());
+        let options = FindOptions::default()
+            .environments(EnvironmentPreference::Any)
+            .preference(PythonPreference::OnlySystem);
+
+        let python = context.run(|| {
+            PythonInstallation::find(
+                &request,
+                options.request_variable(Some(EnvVars::UV_PYTHON)),
+                &context.cache,
+            )
+        })?;
+        assert_eq!(
+            python.source(),
+            &PythonSource::EnvironmentVariable(EnvVars::UV_PYTHON),
+            "We should attribute the interpreter to `UV_PYTHON`"
+        );
+
+        // If the request was not read from the environment variable, it is a provided path, even
+        // though `UV_PYTHON` is set
+        let python = context.run_with_vars(
+            &[(EnvVars::UV_PYTHON, Some(python_path.as_os_str()))],
+            || PythonInstallation::find(&request, options, &context.cache),
+        )?;
+        assert_eq!(
+            python.source(),
+            &PythonSource::ProvidedPath,
+            "We should attribute the interpreter to the provided path"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_allows_venv_directory_path() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -2077,6 +2365,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_latest() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[
+            (true, ImplementationName::CPython, "python3.11", "3.11.2"),
+            (true, ImplementationName::CPython, "python3.13", "3.13.0b1"),
+            (true, ImplementationName::CPython, "python3.12", "3.12.3"),
+            (true, ImplementationName::PyPy, "pypy3", "3.14.1"),
+            (true, ImplementationName::CPython, "python3.10", "3.10.1"),
+        ])?;
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("latest"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.12.3",
+            "We should find the newest stable CPython, skipping the pre-release and PyPy"
+        );
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("pypy@latest"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.14.1",
+            "We should find the newest PyPy"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_latest_only_prerelease() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[(
+            true,
+            ImplementationName::CPython,
+            "python3.13",
+            "3.13.0b1",
+        )])?;
+
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("latest"),
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "We should never select a pre-release for the latest version; got {result:?}"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_all_minors_prerelease_next() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -4,19 +4,21 @@ use std::ffi::OsStr;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use fs_err as fs;
-use itertools::Itertools;
 use same_file::is_same_file;
 use thiserror::Error;
 use tracing::{debug, warn};
 
-use uv_fs::{symlink_or_copy_file, LockedFile, Simplified};
+use uv_fs::{symlink_or_copy_file, LockWait, LockedFile, Simplified};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{windows_python_launcher, Launcher};
+use uv_warnings::warn_user_once;
 
 use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
+use crate::dylib::{self, DylibPatchReport};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -107,8 +109,34 @@ impl ManagedPythonInstallations {
 
     /// Grab a file lock for the managed Python distribution directory to prevent concurrent access
     /// across processes.
+    ///
+    /// Waits for up to `UV_PYTHON_INSTALL_LOCK_TIMEOUT` seconds if set, or indefinitely otherwise.
     pub async fn lock(&self) -> Result<LockedFile, Error> {
-        Ok(LockedFile::acquire(self.root.join(".lock"), self.root.user_display()).await?)
+        self.lock_with(Self::lock_wait()).await
+    }
+
+    /// Grab a file lock for the managed Python distribution directory, waiting for another process
+    /// to release it according to the given [`LockWait`].
+    pub async fn lock_with(&self, wait: LockWait) -> Result<LockedFile, Error> {
+        Ok(
+            LockedFile::acquire_with(self.root.join(".lock"), self.root.user_display(), wait)
+                .await?,
+        )
+    }
+
+    /// The [`LockWait`] to use for the managed Python distribution directory, as configured by
+    /// `UV_PYTHON_INSTALL_LOCK_TIMEOUT`.
+    fn lock_wait() -> LockWait {
+        let Ok(value) = std::env::var(EnvVars::UV_PYTHON_INSTALL_LOCK_TIMEOUT) else {
+            return LockWait::Forever;
+        };
+        match value.parse::<u64>() {
+            Ok(seconds) => LockWait::Timeout(Duration::from_secs(seconds)),
+            Err(_) => {
+                warn_user_once!("Ignoring invalid value from environment for `UV_PYTHON_INSTALL_LOCK_TIMEOUT`. Expected an integer number of seconds, got \"{value}\".");
+                LockWait::Forever
+            }
+        }
     }
 
     /// Prefer, in order:
@@ -185,13 +213,16 @@ impl ManagedPythonInstallations {
         Ok(self)
     }
 
-    /// Iterate over each Python installation in this directory.
+    /// Find the Python installations in this directory that match the given [`KeyFilter`].
     ///
-    /// Pythons are sorted by [`PythonInstallationKey`], for the same implementation name, the newest versions come first.
-    /// This ensures a consistent ordering across all platforms.
-    pub fn find_all(
-        &self,
-    ) -> Result<impl DoubleEndedIterator<Item = ManagedPythonInstallation>, Error> {
+    /// Installations are sorted by [`PythonInstallationKey`] with the newest versions first, so
+    /// the ordering is consistent across platforms. If the filter constrains the architecture,
+    /// installations that run natively are yielded before those that require emulation, e.g.,
+    /// x86_64 builds under Rosetta 2 on Apple Silicon.
+    ///
+    /// Directories that cannot be parsed as an installation are not yielded, but are collected
+    /// in [`MatchingInstallations::malformed`].
+    pub fn find_matching(&self, filter: &KeyFilter) -> Result<MatchingInstallations, Error> {
         let dirs = match fs_err::read_dir(&self.root) {
             Ok(installation_dirs) => {
                 // Collect sorted directory paths; `read_dir` is not stable across platforms
@@ -218,48 +249,61 @@ impl ManagedPythonInstallations {
                 })
             }
         };
+
         let scratch = self.scratch();
-        Ok(dirs
-            .into_iter()
+        let mut installations = Vec::new();
+        let mut malformed = Vec::new();
+        for path in dirs {
             // Ignore the scratch directory
-            .filter(|path| *path != scratch)
+            if path == scratch {
+                continue;
+            }
             // Ignore any `.` prefixed directories
-            .filter(|path| {
-                path.file_name()
-                    .and_then(OsStr::to_str)
-                    .map(|name| !name.starts_with('.'))
-                    .unwrap_or(true)
-            })
-            .filter_map(|path| {
-                ManagedPythonInstallation::from_path(path)
-                    .inspect_err(|err| {
-                        warn!("Ignoring malformed managed Python entry:\n    {err}");
-                    })
-                    .ok()
-            })
-            .sorted_unstable_by_key(|installation| Reverse(installation.key().clone())))
+            if path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| name.starts_with('.'))
+            {
+                continue;
+            }
+            match ManagedPythonInstallation::from_path(path.clone()) {
+                Ok(installation) => {
+                    if filter.matches(installation.key()) {
+                        installations.push(installation);
+                    }
+                }
+                Err(err) => malformed.push(MalformedInstallation { path, err }),
+            }
+        }
+
+        installations.sort_by_key(|installation| {
+            (
+                filter
+                    .arch
+                    .is_some_and(|arch| arch.is_emulated(installation.key.arch)),
+                Reverse(installation.key.clone()),
+            )
+        });
+        malformed.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(MatchingInstallations {
+            installations,
+            malformed,
+        })
     }
 
     /// Iterate over Python installations that support the current platform.
+    ///
+    /// Installations that run natively are yielded before those that require emulation, e.g.,
+    /// x86_64 builds under Rosetta 2 on Apple Silicon.
     pub fn find_matching_current_platform(
         &self,
     ) -> Result<impl DoubleEndedIterator<Item = ManagedPythonInstallation>, Error> {
-        let os = Os::from_env();
-        let arch = Arch::from_env();
-        let libc = Libc::from_env()?;
-
-        let iter = ManagedPythonInstallations::from_settings(None)?
-            .find_all()?
-            .filter(move |installation| {
-                installation.key.os == os
-                    && (arch.supports(installation.key.arch)
-                        // TODO(zanieb): Allow inequal variants, as `Arch::supports` does not
-                        // implement this yet. See https://github.com/astral-sh/uv/pull/9788
-                        || arch.family == installation.key.arch.family)
-                    && installation.key.libc == libc
-            });
-
-        Ok(iter)
+        let matching = self.find_matching(&KeyFilter::current_platform()?)?;
+        for malformed in matching.malformed() {
+            warn!("Ignoring malformed managed Python entry:\n    {malformed}");
+        }
+        Ok(matching.into_iter())
     }
 
     /// Iterate over managed Python installations that satisfy the requested version on this platform.
@@ -288,6 +332,171 @@ impl ManagedPythonInstallations {
     }
 }
 
+/// A filter over [`PythonInstallationKey`]s, used to select managed Python installations.
+///
+/// Constraints that are not set match any key.
+#[derive(Debug, Clone, Default)]
+pub struct KeyFilter {
+    implementation: Option<LenientImplementationName>,
+    minor_version: Option<(u8, u8)>,
+    variant: Option<PythonVariant>,
+    os: Option<Os>,
+    arch: Option<Arch>,
+    libc: Option<Libc>,
+}
+
+impl KeyFilter {
+    /// A filter that matches installations which can run on the current platform.
+    pub fn current_platform() -> Result<Self, Error> {
+        Ok(Self::default()
+            .with_os(Os::from_env())
+            .with_arch(Arch::from_env())
+            .with_libc(Libc::from_env()?))
+    }
+
+    /// Only match installations of the given implementation, e.g., `cpython`.
+    #[must_use]
+    pub fn with_implementation(mut self, implementation: LenientImplementationName) -> Self {
+        self.implementation = Some(implementation);
+        self
+    }
+
+    /// Only match installations with the given major and minor version, e.g., `3.12`.
+    #[must_use]
+    pub fn with_minor_version(mut self, major: u8, minor: u8) -> Self {
+        self.minor_version = Some((major, minor));
+        self
+    }
+
+    /// Only match installations of the given variant, e.g., free-threaded builds.
+    #[must_use]
+    pub fn with_variant(mut self, variant: PythonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Only match installations for the given operating system.
+    #[must_use]
+    pub fn with_os(mut self, os: Os) -> Self {
+        self.os = Some(os);
+        self
+    }
+
+    /// Only match installations that can run on the given architecture, either natively or via
+    /// emulation.
+    #[must_use]
+    pub fn with_arch(mut self, arch: Arch) -> Self {
+        self.arch = Some(arch);
+        self
+    }
+
+    /// Only match installations for the given libc.
+    ///
+    /// A dynamically-linked libc also matches statically-linked installations of the same libc.
+    #[must_use]
+    pub fn with_libc(mut self, libc: Libc) -> Self {
+        self.libc = Some(libc);
+        self
+    }
+
+    /// Whether the given key satisfies every constraint in this filter.
+    pub fn matches(&self, key: &PythonInstallationKey) -> bool {
+        if self
+            .implementation
+            .as_ref()
+            .is_some_and(|implementation| key.implementation != *implementation)
+        {
+            return false;
+        }
+        if self
+            .minor_version
+            .is_some_and(|(major, minor)| key.major != major || key.minor != minor)
+        {
+            return false;
+        }
+        if self.variant.is_some_and(|variant| key.variant != variant) {
+            return false;
+        }
+        if self.os.is_some_and(|os| key.os != os) {
+            return false;
+        }
+        // TODO(zanieb): Allow inequal variants, as `Arch::supports` does not implement this yet.
+        // See https://github.com/astral-sh/uv/pull/9788
+        if self
+            .arch
+            .is_some_and(|arch| !(arch.supports(key.arch) || arch.family == key.arch.family))
+        {
+            return false;
+        }
+        if self.libc.is_some_and(|libc| !libc.supports(key.libc)) {
+            return false;
+        }
+        true
+    }
+}
+
+/// The managed Python installations found by [`ManagedPythonInstallations::find_matching`].
+#[derive(Debug)]
+pub struct MatchingInstallations {
+    installations: Vec<ManagedPythonInstallation>,
+    malformed: Vec<MalformedInstallation>,
+}
+
+impl MatchingInstallations {
+    /// The directories that could not be read as a managed Python installation.
+    pub fn malformed(&self) -> &[MalformedInstallation] {
+        &self.malformed
+    }
+
+    /// Iterate over the matching installations.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &ManagedPythonInstallation> {
+        self.installations.iter()
+    }
+}
+
+impl IntoIterator for MatchingInstallations {
+    type Item = ManagedPythonInstallation;
+    type IntoIter = std::vec::IntoIter<ManagedPythonInstallation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.installations.into_iter()
+    }
+}
+
+/// A directory in the managed Python installation directory that could not be parsed as an
+/// installation.
+#[derive(Debug)]
+pub struct MalformedInstallation {
+    path: PathBuf,
+    err: Error,
+}
+
+impl MalformedInstallation {
+    /// The path to the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The reason the directory could not be parsed.
+    pub fn error(&self) -> &Error {
+        &self.err
+    }
+}
+
+impl fmt::Display for MalformedInstallation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.user_display(), self.err)
+    }
+}
+
+/// The name of the marker file that protects an installation from removal, see
+/// [`ManagedPythonInstallation::is_kept`].
+const KEEP_MARKER: &str = ".uv-keep";
+
+/// The name of the marker file that records that the executables of an installation should not be
+/// linked into the bin directory, see [`ManagedPythonInstallation::is_bin_disabled`].
+const NO_BIN_MARKER: &str = ".uv-no-bin";
+
 static EXTERNALLY_MANAGED: &str = "[externally-managed]
 Error=This Python installation is managed by uv and should not be modified.
 ";
@@ -401,7 +610,8 @@ impl ManagedPythonInstallation {
         {
             // This is the alternative executable name for the freethreaded variant
             return self.python_dir().join(format!(
-                "python{}.{}t{}",
+                "python{}{}.{}t{}",
+                if windowed { "w" } else { "" },
                 self.key.major,
                 self.key.minor,
                 std::env::consts::EXE_SUFFIX
@@ -458,6 +668,9 @@ impl ManagedPythonInstallation {
                 implementation == self.implementation() && version.matches_version(&self.version())
             }
             PythonRequest::Version(version) => version.matches_version(&self.version()),
+            PythonRequest::Latest(implementation) => {
+                implementation == self.implementation() && self.key().prerelease.is_none()
+            }
             PythonRequest::Key(request) => request.satisfied_by_key(self.key()),
         }
     }
@@ -503,9 +716,8 @@ impl ManagedPythonInstallation {
         Ok(())
     }
 
-    /// Ensure the environment is marked as externally managed with the
-    /// standard `EXTERNALLY-MANAGED` file.
-    pub fn ensure_externally_managed(&self) -> Result<(), Error> {
+    /// Returns the path to the standard `EXTERNALLY-MANAGED` file of the installation.
+    fn externally_managed_marker(&self) -> PathBuf {
         // Construct the path to the `stdlib` directory.
         let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
@@ -522,9 +734,71 @@ impl ManagedPythonInstallation {
             self.python_dir().join("lib").join(python)
         };
 
-        let file = stdlib.join("EXTERNALLY-MANAGED");
-        fs_err::write(file, EXTERNALLY_MANAGED)?;
+        stdlib.join("EXTERNALLY-MANAGED")
+    }
+
+    /// Returns `true` if the installation is marked as externally managed with the standard
+    /// `EXTERNALLY-MANAGED` file.
+    pub fn is_externally_managed(&self) -> bool {
+        self.externally_managed_marker().is_file()
+    }
 
+    /// Mark the installation as externally managed with the standard `EXTERNALLY-MANAGED` file,
+    /// or remove an existing mark.
+    pub fn set_externally_managed(&self, externally_managed: bool) -> Result<(), Error> {
+        let marker = self.externally_managed_marker();
+        if externally_managed {
+            fs_err::write(marker, EXTERNALLY_MANAGED)?;
+        } else {
+            match fs_err::remove_file(marker) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the installation is marked as kept, i.e., it should only be removed when
+    /// explicitly forced.
+    pub fn is_kept(&self) -> bool {
+        self.path.join(KEEP_MARKER).is_file()
+    }
+
+    /// Mark the installation as kept, or remove an existing mark.
+    pub fn set_kept(&self, kept: bool) -> Result<(), Error> {
+        let marker = self.path.join(KEEP_MARKER);
+        if kept {
+            fs_err::write(marker, "")?;
+        } else {
+            match fs_err::remove_file(marker) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the installation opted out of linking its executables into the bin
+    /// directory.
+    pub fn is_bin_disabled(&self) -> bool {
+        self.path.join(NO_BIN_MARKER).is_file()
+    }
+
+    /// Record that the executables of the installation should not be linked into the bin
+    /// directory, or remove an existing record.
+    pub fn set_bin_disabled(&self, disabled: bool) -> Result<(), Error> {
+        let marker = self.path.join(NO_BIN_MARKER);
+        if disabled {
+            fs_err::write(marker, "")?;
+        } else {
+            match fs_err::remove_file(marker) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
         Ok(())
     }
 
@@ -549,28 +823,100 @@ impl ManagedPythonInstallation {
     /// link to the correct location for the Python library.
     ///
     /// See <https://github.com/astral-sh/uv/issues/10598> for more information.
-    pub fn ensure_dylib_patched(&self) -> Result<(), macos_dylib::Error> {
-        if cfg!(target_os = "macos") {
-            if self.key().os.is_like_darwin() {
-                if *self.implementation() == ImplementationName::CPython {
-                    let dylib_path = self.python_dir().join("lib").join(format!(
-                        "{}python{}{}{}",
-                        std::env::consts::DLL_PREFIX,
-                        self.key.version().python_version(),
-                        self.key.variant().suffix(),
-                        std::env::consts::DLL_SUFFIX
-                    ));
-                    macos_dylib::patch_dylib_install_name(dylib_path)?;
-                }
+    ///
+    /// On Linux, ensure that the `libpython` libraries the interpreter links against are present,
+    /// since the interpreter will fail to start without them.
+    pub fn ensure_dylib_patched(&self) -> DylibPatchReport {
+        let mut report = DylibPatchReport::default();
+        if *self.implementation() != ImplementationName::CPython {
+            return report;
+        }
+        if cfg!(target_os = "macos") && self.key().os.is_like_darwin() {
+            let dylib_path = self.python_dir().join("lib").join(format!(
+                "{}python{}{}{}",
+                std::env::consts::DLL_PREFIX,
+                self.key.version().python_version(),
+                self.key.variant().suffix(),
+                std::env::consts::DLL_SUFFIX
+            ));
+            report.patch(dylib_path, macos_dylib::patch_dylib_install_name);
+        } else if cfg!(target_os = "linux")
+            && matches!(self.key.os, Os(target_lexicon::OperatingSystem::Linux))
+        {
+            let lib = self.python_dir().join("lib");
+            for library in dylib::needed_libpython(&self.executable(false)) {
+                report.require(lib.join(library));
             }
         }
-        Ok(())
+        report
     }
 
     /// Create a link to the managed Python executable.
     ///
     /// If the file already exists at the target path, an error will be returned.
-    pub fn create_bin_link(&self, target: &Path) -> Result<(), Error> {
+    ///
+    /// On Unix, a symbolic link is created. If symbolic links are not permitted at the target,
+    /// e.g., due to the filesystem, a launcher script is written instead. On Windows, a launcher is
+    /// always used.
+    pub fn create_bin_link(&self, target: &Path) -> Result<BinLinkKind, Error> {
+        // Note this will never copy on Unix — we use it here to allow compilation on Windows
+        self.create_bin_link_with(target, |python, target| {
+            symlink_or_copy_file(python, target)
+        })
+    }
+
+    /// Replace the existing executable at `target` with a link to the managed Python executable.
+    ///
+    /// The link is created under a temporary name in the same directory and then moved into
+    /// place, such that concurrent invocations of `target` always find either the previous or the
+    /// new executable.
+    pub fn replace_bin_link(&self, target: &Path) -> Result<BinLinkKind, Error> {
+        Self::replace_bin_link_with(target, self.executable(false), |temp| {
+            self.create_bin_link(temp)
+        })
+    }
+
+    /// Replace the existing executable at `target` with a GUI launcher for the managed windowed
+    /// Python executable.
+    ///
+    /// Like [`ManagedPythonInstallation::replace_bin_link`], the launcher is moved into place.
+    pub fn replace_windowed_bin_link(&self, target: &Path) -> Result<BinLinkKind, Error> {
+        Self::replace_bin_link_with(target, self.executable(true), |temp| {
+            self.create_windowed_bin_link(temp)
+        })
+    }
+
+    /// Replace the existing executable at `target` with a link created by `create` at a
+    /// temporary path.
+    fn replace_bin_link_with(
+        target: &Path,
+        python: PathBuf,
+        create: impl FnOnce(&Path) -> Result<BinLinkKind, Error>,
+    ) -> Result<BinLinkKind, Error> {
+        let bin = target.parent().ok_or(Error::NoExecutableDirectory)?;
+        let name = target.file_name().ok_or(Error::NoExecutableDirectory)?;
+        let temp_dir = tempfile::tempdir_in(bin)?;
+        let temp = temp_dir.path().join(name);
+
+        let kind = create(&temp)?;
+        uv_fs::with_retry_sync(&temp, target, "moving", || fs_err::rename(&temp, target)).map_err(
+            |err| Error::LinkExecutable {
+                from: python,
+                to: target.to_path_buf(),
+                err,
+            },
+        )?;
+
+        Ok(kind)
+    }
+
+    /// Create a link to the managed Python executable, using the given function to create
+    /// symbolic links.
+    fn create_bin_link_with(
+        &self,
+        target: &Path,
+        symlink: impl FnOnce(&Path, &Path) -> io::Result<()>,
+    ) -> Result<BinLinkKind, Error> {
         let python = self.executable(false);
 
         let bin = target.parent().ok_or(Error::NoExecutableDirectory)?;
@@ -580,12 +926,31 @@ impl ManagedPythonInstallation {
         })?;
 
         if cfg!(unix) {
-            // Note this will never copy on Unix — we use it here to allow compilation on Windows
-            match symlink_or_copy_file(&python, target) {
-                Ok(()) => Ok(()),
+            match symlink(&python, target) {
+                Ok(()) => Ok(BinLinkKind::Symlink),
                 Err(err) if err.kind() == io::ErrorKind::NotFound => {
                     Err(Error::MissingExecutable(python.clone()))
                 }
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        io::ErrorKind::PermissionDenied | io::ErrorKind::Unsupported
+                    ) =>
+                {
+                    warn_user_once!(
+                        "Symbolic links are not supported in `{}` ({err}); Python executables will be installed as launcher scripts instead. To use symbolic links, set `{}` to a directory on a filesystem that supports them.",
+                        bin.simplified_display(),
+                        EnvVars::UV_PYTHON_BIN_DIR,
+                    );
+                    match write_bin_script(&python, target) {
+                        Ok(()) => Ok(BinLinkKind::Script),
+                        Err(err) => Err(Error::LinkExecutable {
+                            from: python,
+                            to: target.to_path_buf(),
+                            err,
+                        }),
+                    }
+                }
                 Err(err) => Err(Error::LinkExecutable {
                     from: python,
                     to: target.to_path_buf(),
@@ -593,31 +958,38 @@ impl ManagedPythonInstallation {
                 }),
             }
         } else if cfg!(windows) {
-            // TODO(zanieb): Install GUI launchers as well
-            let launcher = windows_python_launcher(&python, false)?;
-
-            // OK to use `std::fs` here, `fs_err` does not support `File::create_new` and we attach
-            // error context anyway
-            #[allow(clippy::disallowed_types)]
-            {
-                std::fs::File::create_new(target)
-                    .and_then(|mut file| file.write_all(launcher.as_ref()))
-                    .map_err(|err| Error::LinkExecutable {
-                        from: python,
-                        to: target.to_path_buf(),
-                        err,
-                    })
-            }
+            write_launcher(python, target, false)
         } else {
             unimplemented!("Only Windows and Unix systems are supported.")
         }
     }
 
+    /// Create a GUI launcher for the managed windowed Python executable, i.e., `pythonw.exe`.
+    ///
+    /// If the file already exists at the target path, an error will be returned.
+    ///
+    /// Only supported on Windows.
+    pub fn create_windowed_bin_link(&self, target: &Path) -> Result<BinLinkKind, Error> {
+        let python = self.executable(true);
+        if !python.is_file() {
+            return Err(Error::MissingExecutable(python));
+        }
+
+        let bin = target.parent().ok_or(Error::NoExecutableDirectory)?;
+        fs_err::create_dir_all(bin).map_err(|err| Error::ExecutableDirectory {
+            to: bin.to_path_buf(),
+            err,
+        })?;
+
+        write_launcher(python, target, true)
+    }
+
     /// Returns `true` if the path is a link to this installation's binary, e.g., as created by
     /// [`ManagedPythonInstallation::create_bin_link`].
     pub fn is_bin_link(&self, path: &Path) -> bool {
         if cfg!(unix) {
             is_same_file(path, self.executable(false)).unwrap_or_default()
+                || read_bin_script(path).is_some_and(|python| python == self.executable(false))
         } else if cfg!(windows) {
             let Some(launcher) = Launcher::try_from_path(path).unwrap_or_default() else {
                 return false;
@@ -626,6 +998,7 @@ impl ManagedPythonInstallation {
                 return false;
             }
             launcher.python_path == self.executable(false)
+                || launcher.python_path == self.executable(true)
         } else {
             unreachable!("Only Windows and Unix are supported")
         }
@@ -633,31 +1006,19 @@ impl ManagedPythonInstallation {
 
     /// Returns `true` if self is a suitable upgrade of other.
     pub fn is_upgrade_of(&self, other: &ManagedPythonInstallation) -> bool {
-        // Require matching implementation
-        if self.key.implementation != other.key.implementation {
-            return false;
-        }
-        // Require a matching variant
-        if self.key.variant != other.key.variant {
-            return false;
-        }
-        // Require matching minor version
-        if (self.key.major, self.key.minor) != (other.key.major, other.key.minor) {
-            return false;
-        }
-        // Require a newer, or equal patch version (for pre-release upgrades)
-        if self.key.patch <= other.key.patch {
-            return false;
-        }
-        if let Some(other_pre) = other.key.prerelease {
-            if let Some(self_pre) = self.key.prerelease {
-                return self_pre > other_pre;
-            }
-            // Do not upgrade from non-prerelease to prerelease
-            return false;
-        }
-        // Do not upgrade if the patch versions are the same
-        self.key.patch != other.key.patch
+        self.key.is_upgrade_of(&other.key)
+    }
+
+    /// Returns the latest of the given installations that this installation is an upgrade of, if
+    /// any.
+    pub fn upgrade_of<'a>(
+        &self,
+        installations: impl IntoIterator<Item = &'a ManagedPythonInstallation>,
+    ) -> Option<&'a ManagedPythonInstallation> {
+        installations
+            .into_iter()
+            .filter(|installation| self.is_upgrade_of(installation))
+            .max_by(|a, b| a.key.cmp(&b.key))
     }
 
     pub fn url(&self) -> Option<&'static str> {
@@ -669,6 +1030,94 @@ impl ManagedPythonInstallation {
     }
 }
 
+/// The mechanism used to link a managed Python executable into the bin directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinLinkKind {
+    /// A symbolic link to the executable.
+    Symlink,
+    /// A shell script that executes the executable, used when symbolic links are not permitted.
+    Script,
+    /// A Windows launcher that executes the executable.
+    Launcher,
+}
+
+/// The line identifying a launcher script written by [`write_bin_script`], followed by the path to
+/// the Python executable.
+const BIN_SCRIPT_MARKER: &str = "# uv-managed Python executable: ";
+
+/// Write a shell script to `target` that executes the given Python executable.
+///
+/// Unlike a copy or a hard link of the executable, the script preserves the executable's location,
+/// which Python uses to find its standard library.
+fn write_bin_script(python: &Path, target: &Path) -> io::Result<()> {
+    let Some(path) = python.to_str() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Python executable path is not valid UTF-8: {}",
+                python.display()
+            ),
+        ));
+    };
+    let script = format!(
+        "#!/bin/sh\n{BIN_SCRIPT_MARKER}{path}\nexec '{}' \"$@\"\n",
+        path.replace('\'', r"'\''")
+    );
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use fs_err::os::unix::fs::OpenOptionsExt;
+        options.mode(0o755);
+    }
+    options.open(target)?.write_all(script.as_bytes())
+}
+
+/// Write a Windows launcher to `target` that executes the given Python executable.
+///
+/// If `is_gui` is set, the launcher doesn't open a console window.
+fn write_launcher(python: PathBuf, target: &Path, is_gui: bool) -> Result<BinLinkKind, Error> {
+    let launcher = windows_python_launcher(&python, is_gui)?;
+
+    // OK to use `std::fs` here, `fs_err` does not support `File::create_new` and we attach
+    // error context anyway
+    #[allow(clippy::disallowed_types)]
+    {
+        std::fs::File::create_new(target)
+            .and_then(|mut file| file.write_all(launcher.as_ref()))
+            .map(|()| BinLinkKind::Launcher)
+            .map_err(|err| Error::LinkExecutable {
+                from: python,
+                to: target.to_path_buf(),
+                err,
+            })
+    }
+}
+
+/// Read the Python executable referenced by a launcher script written by [`write_bin_script`].
+///
+/// Returns [`None`] if the path is not such a script.
+pub fn read_bin_script(path: &Path) -> Option<PathBuf> {
+    if path.is_symlink() {
+        return None;
+    }
+    // Avoid reading arbitrarily large files, e.g., a Python executable
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() || metadata.len() > 4096 {
+        return None;
+    }
+    let contents = fs::read_to_string(path).ok()?;
+    let mut lines = contents.lines();
+    if lines.next() != Some("#!/bin/sh") {
+        return None;
+    }
+    lines
+        .next()?
+        .strip_prefix(BIN_SCRIPT_MARKER)
+        .map(PathBuf::from)
+}
+
 // TODO(zanieb): Only used in tests now.
 /// Generate a platform portion of a key from the environment.
 pub fn platform_key_from_env() -> Result<String, Error> {
@@ -696,3 +1145,353 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::io;
+    use std::path::Path;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use super::{
+        BinLinkKind, Error, KeyFilter, ManagedPythonInstallation, ManagedPythonInstallations,
+    };
+    #[cfg(target_os = "macos")]
+    use crate::dylib::DylibPatchSeverity;
+    use crate::implementation::LenientImplementationName;
+    use crate::installation::PythonInstallationKey;
+    use crate::platform::{Arch, Libc, Os};
+    use crate::{PythonRequest, PythonVariant};
+
+    /// Create a mock managed installation with an executable in the given directory.
+    fn mock_installation(root: &Path, key: &str) -> anyhow::Result<ManagedPythonInstallation> {
+        let installation = ManagedPythonInstallation::from_path(root.join(key))?;
+        let executable = installation.executable(false);
+        fs_err::create_dir_all(executable.parent().unwrap())?;
+        fs_err::write(&executable, "")?;
+        Ok(installation)
+    }
+
+    /// Simulate a filesystem that does not permit symbolic links.
+    fn deny_symlink(_python: &Path, _target: &Path) -> io::Result<()> {
+        Err(io::Error::from(io::ErrorKind::PermissionDenied))
+    }
+
+    #[test]
+    fn create_bin_link_symlink() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let installation = mock_installation(root.path(), "cpython-3.12.0-linux-x86_64-gnu")?;
+        let target = root.path().join("bin").join("python3.12");
+
+        assert_eq!(installation.create_bin_link(&target)?, BinLinkKind::Symlink);
+        assert!(target.is_symlink());
+        assert!(installation.is_bin_link(&target));
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_bin_link_falls_back_to_script() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let installation = mock_installation(root.path(), "cpython-3.12.0-linux-x86_64-gnu")?;
+        let upgrade = mock_installation(root.path(), "cpython-3.12.1-linux-x86_64-gnu")?;
+        let target = root.path().join("bin").join("python3.12");
+
+        assert_eq!(
+            installation.create_bin_link_with(&target, deny_symlink)?,
+            BinLinkKind::Script
+        );
+        assert!(!target.is_symlink());
+        assert!(installation.is_bin_link(&target));
+        assert!(!upgrade.is_bin_link(&target));
+
+        // An existing script is not overwritten
+        let err = upgrade
+            .create_bin_link_with(&target, deny_symlink)
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::LinkExecutable { err, .. } if err.kind() == io::ErrorKind::AlreadyExists),
+            "Expected the executable to exist already; got {err:?}"
+        );
+
+        // On upgrade, the script is removed and rewritten for the new installation
+        fs_err::remove_file(&target)?;
+        assert_eq!(
+            upgrade.create_bin_link_with(&target, deny_symlink)?,
+            BinLinkKind::Script
+        );
+        assert!(upgrade.is_bin_link(&target));
+        assert!(!installation.is_bin_link(&target));
+
+        Ok(())
+    }
+
+    #[test]
+    fn windowed_executable() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let installation = mock_installation(root.path(), "cpython-3.12.0-linux-x86_64-gnu")?;
+
+        // There's no separate windowed executable on Unix.
+        assert_eq!(
+            installation.executable(true),
+            installation.executable(false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn replace_bin_link_is_atomic() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let installation = mock_installation(root.path(), "cpython-3.12.0-linux-x86_64-gnu")?;
+        let upgrade = mock_installation(root.path(), "cpython-3.12.1-linux-x86_64-gnu")?;
+        let target = root.path().join("bin").join("python3.12");
+        installation.create_bin_link(&target)?;
+
+        // Watch the link while it's repeatedly replaced; it should never be missing
+        let done = Arc::new(AtomicBool::new(false));
+        let watcher = std::thread::spawn({
+            let done = done.clone();
+            let target = target.clone();
+            move || {
+                let mut missing = 0;
+                while !done.load(Ordering::Relaxed) {
+                    if !target.exists() {
+                        missing += 1;
+                    }
+                }
+                missing
+            }
+        });
+        for i in 0..200 {
+            let next = if i % 2 == 0 { &upgrade } else { &installation };
+            assert_eq!(next.replace_bin_link(&target)?, BinLinkKind::Symlink);
+        }
+        done.store(true, Ordering::Relaxed);
+        assert_eq!(watcher.join().unwrap(), 0);
+
+        assert!(installation.is_bin_link(&target));
+        assert!(!upgrade.is_bin_link(&target));
+
+        // No temporary files are left behind
+        assert_eq!(fs_err::read_dir(target.parent().unwrap())?.count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bin_script_quotes_path() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        // Use a path that requires quoting in the script
+        let installation = mock_installation(
+            &root.path().join("it's $HOME"),
+            "cpython-3.12.0-linux-x86_64-gnu",
+        )?;
+        let target = root.path().join("bin").join("python3.12");
+        installation.create_bin_link_with(&target, deny_symlink)?;
+
+        let executable = installation.executable(false);
+        let script = fs_err::read_to_string(&target)?;
+        assert!(script.contains(&format!(
+            "exec '{}' \"$@\"",
+            executable.display().to_string().replace('\'', r"'\''")
+        )));
+        assert!(installation.is_bin_link(&target));
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_filter_matches() -> anyhow::Result<()> {
+        let keys = [
+            "cpython-3.12.4-linux-x86_64-gnu",
+            "cpython-3.12.4+freethreaded-linux-x86_64-gnu",
+            "cpython-3.13.1-linux-x86_64-gnu",
+            "cpython-3.12.4-linux-x86_64-musl",
+            "cpython-3.12.4-macos-aarch64-none",
+            "pypy-3.10.14-linux-x86_64-gnu",
+            "cpython-3.12.4-linux-x86_64-musl-static",
+        ]
+        .into_iter()
+        .map(PythonInstallationKey::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let linux_gnu = KeyFilter::default()
+            .with_os(Os::from_str("linux")?)
+            .with_arch(Arch::from_str("x86_64")?)
+            .with_libc(Libc::from_str("gnu")?);
+
+        let cases = [
+            (KeyFilter::default(), vec![0, 1, 2, 3, 4, 5, 6]),
+            (
+                KeyFilter::default().with_implementation(LenientImplementationName::from("pypy")),
+                vec![5],
+            ),
+            (
+                KeyFilter::default().with_minor_version(3, 12),
+                vec![0, 1, 3, 4, 6],
+            ),
+            (
+                KeyFilter::default().with_variant(PythonVariant::Freethreaded),
+                vec![1],
+            ),
+            (
+                KeyFilter::default().with_os(Os::from_str("macos")?),
+                vec![4],
+            ),
+            (
+                KeyFilter::default().with_libc(Libc::from_str("musl")?),
+                vec![3, 6],
+            ),
+            (
+                KeyFilter::default().with_libc(Libc::from_str("musl-static")?),
+                vec![6],
+            ),
+            (linux_gnu.clone(), vec![0, 1, 2, 5]),
+            (
+                linux_gnu
+                    .with_implementation(LenientImplementationName::from("cpython"))
+                    .with_minor_version(3, 12)
+                    .with_variant(PythonVariant::Default),
+                vec![0],
+            ),
+        ];
+
+        for (filter, expected) in cases {
+            let matched = keys
+                .iter()
+                .enumerate()
+                .filter(|(_, key)| filter.matches(key))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            assert_eq!(matched, expected, "Unexpected matches for {filter:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_matching_reports_malformed() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        for name in [
+            "cpython-3.12.4-linux-x86_64-gnu",
+            "cpython-3.13.1-linux-x86_64-gnu",
+            "pypy-3.10.14-linux-x86_64-gnu",
+            "cpython-3.12",
+            "not-a-python",
+            ".lock-dir",
+            ".temp",
+        ] {
+            fs_err::create_dir_all(root.path().join(name))?;
+        }
+        fs_err::write(root.path().join("cpython-3.11.0-linux-x86_64-gnu"), "")?;
+
+        let installations = ManagedPythonInstallations::from_path(root.path());
+
+        let matching = installations.find_matching(&KeyFilter::default())?;
+        let malformed = matching
+            .malformed()
+            .iter()
+            .map(|malformed| malformed.path().file_name().unwrap().to_string_lossy())
+            .collect::<Vec<_>>();
+        assert_eq!(malformed, ["cpython-3.12", "not-a-python"]);
+
+        // Installations are sorted by key, with the newest versions of each implementation first
+        let keys = matching
+            .iter()
+            .map(|installation| installation.key().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                "cpython-3.13.1-linux-x86_64-gnu",
+                "cpython-3.12.4-linux-x86_64-gnu",
+                "pypy-3.10.14-linux-x86_64-gnu",
+            ]
+        );
+
+        let matching =
+            installations.find_matching(&KeyFilter::default().with_minor_version(3, 12))?;
+        assert_eq!(matching.malformed().len(), 2);
+        let keys = matching
+            .into_iter()
+            .map(|installation| installation.key().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["cpython-3.12.4-linux-x86_64-gnu"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_matching_build_flavors() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        for name in [
+            "cpython-3.12.4+noopt-linux-x86_64-gnu",
+            "cpython-3.12.4-linux-x86_64-gnu",
+            "cpython-3.12.4+pgo+lto-linux-x86_64-gnu",
+        ] {
+            fs_err::create_dir_all(root.path().join(name))?;
+        }
+
+        let installations = ManagedPythonInstallations::from_path(root.path());
+        let matching = installations.find_matching(&KeyFilter::default())?;
+        assert!(matching.malformed().is_empty());
+
+        // Flavors of the same version coexist, with the fastest flavors first
+        let keys = matching
+            .iter()
+            .map(|installation| installation.key().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                "cpython-3.12.4-linux-x86_64-gnu",
+                "cpython-3.12.4+pgo+lto-linux-x86_64-gnu",
+                "cpython-3.12.4+noopt-linux-x86_64-gnu",
+            ]
+        );
+
+        // An exact key only matches the installation of that flavor
+        for key in &keys {
+            let request = PythonRequest::parse(key);
+            let satisfied = matching
+                .iter()
+                .filter(|installation| installation.satisfies(&request))
+                .map(|installation| installation.key().to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(satisfied, [key.clone()]);
+        }
+
+        // Otherwise, any flavor matches
+        let request = PythonRequest::parse("cpython-3.12");
+        assert!(matching
+            .iter()
+            .all(|installation| installation.satisfies(&request)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn ensure_dylib_patched_unpatchable() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let installation = mock_installation(root.path(), "cpython-3.12.0-macos-aarch64-none")?;
+        let lib = installation.python_dir().join("lib");
+        fs_err::create_dir_all(&lib)?;
+        let dylib = lib.join("libpython3.12.dylib");
+        fs_err::write(&dylib, "not a Mach-O file")?;
+
+        let report = installation.ensure_dylib_patched();
+        assert_eq!(report.examined(), [dylib.clone()]);
+        assert!(report.patched().is_empty());
+        let [failure] = report.failed() else {
+            panic!("expected a single failure: {report:?}");
+        };
+        assert_eq!(failure.dylib(), dylib);
+        assert_eq!(failure.severity(), DylibPatchSeverity::Cosmetic);
+        assert!(!report.is_fatal());
+
+        Ok(())
+    }
+}
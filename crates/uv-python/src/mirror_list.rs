@@ -0,0 +1,109 @@
+use std::convert::Infallible;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// One or more mirror URLs for downloading managed Python installations, tried in order.
+///
+/// In configuration files, a single mirror can be provided as a string, and multiple mirrors as an
+/// array. On the command line and in environment variables, multiple mirrors are separated by
+/// whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorList(Vec<String>);
+
+impl Deref for MirrorList {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<String>> for MirrorList {
+    fn from(mirrors: Vec<String>) -> Self {
+        Self(mirrors)
+    }
+}
+
+impl FromStr for MirrorList {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split_whitespace().map(ToString::to_string).collect(),
+        ))
+    }
+}
+
+/// The serialized form of a [`MirrorList`]: either a single URL or a list of URLs.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+enum SerdeMirrorList {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl<'de> serde::Deserialize<'de> for MirrorList {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match SerdeMirrorList::deserialize(deserializer)? {
+            SerdeMirrorList::Single(mirror) => Ok(Self(vec![mirror])),
+            SerdeMirrorList::Multiple(mirrors) => Ok(Self(mirrors)),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MirrorList {
+    fn schema_name() -> String {
+        String::from("MirrorList")
+    }
+
+    fn json_schema(r#gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        SerdeMirrorList::json_schema(r#gen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::MirrorList;
+
+    #[test]
+    fn from_str() {
+        let mirrors =
+            MirrorList::from_str("https://mirror.example.com/python  file:///opt/python-builds\n")
+                .unwrap();
+        assert_eq!(
+            &*mirrors,
+            [
+                "https://mirror.example.com/python".to_string(),
+                "file:///opt/python-builds".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        #[derive(serde::Deserialize)]
+        struct Options {
+            mirror: MirrorList,
+        }
+
+        let options: Options =
+            serde_json::from_str(r#"{"mirror": "https://a.example.com"}"#).unwrap();
+        assert_eq!(&*options.mirror, ["https://a.example.com".to_string()]);
+
+        let options: Options = serde_json::from_str(
+            r#"{"mirror": ["https://a.example.com", "https://b.example.com"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            &*options.mirror,
+            [
+                "https://a.example.com".to_string(),
+                "https://b.example.com".to_string()
+            ]
+        );
+    }
+}
@@ -0,0 +1,598 @@
+//! Generate Python download metadata for a mirror of `python-build-standalone` archives.
+//!
+//! The generated document uses the same schema as the bundled `download-metadata.json`, such that
+//! it can be passed to uv via `--python-downloads-json-url`.
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+use regex::Regex;
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_extract::hash::Hasher;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::build_flavor::BuildFlavor;
+use crate::downloads::{parse_json_key, JsonArch, JsonPythonDownload};
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid mirror URL: {0}")]
+    InvalidUrl(String, #[source] url::ParseError),
+    #[error("Unable to parse Python downloads JSON at `{0}`")]
+    InvalidUpstream(String, #[source] serde_json::Error),
+    #[error("Failed to serialize Python downloads JSON")]
+    Serialize(#[source] serde_json::Error),
+}
+
+/// The archive flavors published by `python-build-standalone`, in order of preference.
+const FLAVOR_PREFERENCES: &[&str] = &[
+    "install_only_stripped",
+    "install_only",
+    "shared-pgo",
+    "shared-noopt",
+    "static-noopt",
+];
+
+/// The first `python-build-standalone` release in which the default musl builds are dynamically
+/// linked, with the statically-linked builds published separately.
+const MUSL_DYNAMIC_RELEASE: u64 = 20_250_311;
+
+/// The filename of a `python-build-standalone` CPython archive, e.g.,
+/// `cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz`.
+static ARCHIVE_FILENAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?x)
+        ^
+            cpython-
+            (?P<version>\d+\.\d+\.\d+(?:(?:a|b|rc)\d+)?)(?:\+\d+)?\+
+            (?P<release>\d+)-
+            (?P<triple>[a-z\d_]+-[a-z\d]+(?:-[a-z\d]+)?-[a-z\d]+)-
+            (?:(?P<options>.+)-)?
+            (?P<flavor>[a-z_]+)
+            \.tar\.(?:gz|zst)
+        $
+        ",
+    )
+    .unwrap()
+});
+
+/// An archive that could not be included in a [`MirrorManifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedArchive {
+    filename: String,
+    reason: String,
+}
+
+impl RejectedArchive {
+    /// The filename of the archive.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Why the archive was rejected.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// Python download metadata for a mirror of `python-build-standalone` archives.
+#[derive(Debug)]
+pub struct MirrorManifest {
+    downloads: BTreeMap<String, JsonPythonDownload>,
+    rejected: Vec<RejectedArchive>,
+}
+
+impl MirrorManifest {
+    /// Generate the download metadata for the archives in the given directory, which are served
+    /// from `base_url`.
+    ///
+    /// Like the upstream metadata, a single archive is selected for each Python version and
+    /// platform, preferring the `install_only_stripped` archives, along with an archive for each
+    /// optimization flavor that's available, e.g., `pgo+lto`.
+    ///
+    /// Archives whose filename can't be parsed into a supported installation key are rejected,
+    /// and can be retrieved with [`MirrorManifest::rejected`].
+    pub fn from_directory(directory: &Path, base_url: &Url) -> Result<Self, Error> {
+        let base_url = directory_url(base_url);
+
+        let mut filenames = Vec::new();
+        for entry in fs_err::read_dir(directory)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+                debug!("Skipping non-UTF-8 filename: {}", entry.path().display());
+                continue;
+            };
+            if !(filename.ends_with(".tar.gz") || filename.ends_with(".tar.zst")) {
+                debug!("Skipping non-archive file: {filename}");
+                continue;
+            }
+            filenames.push(filename);
+        }
+        filenames.sort();
+
+        let mut rejected = Vec::new();
+        let mut archives = Vec::new();
+        for filename in filenames {
+            match Archive::from_filename(&filename) {
+                Ok(archive) => archives.push(archive),
+                Err(reason) => rejected.push(RejectedArchive { filename, reason }),
+            }
+        }
+
+        // Select the preferred archive for each Python version and platform, and for each
+        // optimization flavor thereof.
+        let mut selected: BTreeMap<String, (&Archive, BuildFlavor)> = BTreeMap::new();
+        for archive in &archives {
+            for build in std::iter::once(BuildFlavor::Default).chain(archive.build) {
+                let key = archive.key(build);
+                match selected.get(&key) {
+                    Some((existing, _)) if existing.priority <= archive.priority => {
+                        debug!(
+                            "Skipping {} for {key}: lower priority than {}",
+                            archive.filename, existing.filename
+                        );
+                    }
+                    _ => {
+                        selected.insert(key, (archive, build));
+                    }
+                }
+            }
+        }
+
+        let mut digests: BTreeMap<&str, String> = BTreeMap::new();
+        let mut downloads = BTreeMap::new();
+        for (key, (archive, build)) in selected {
+            let url = base_url
+                .join(&archive.filename.replace('%', "%25").replace('+', "%2B"))
+                .map_err(|err| Error::InvalidUrl(base_url.to_string(), err))?;
+
+            let mut entry = archive.to_json(build, url);
+            if parse_json_key(&key, &entry).is_none() {
+                if !rejected
+                    .iter()
+                    .any(|rejected| rejected.filename == archive.filename)
+                {
+                    rejected.push(RejectedArchive {
+                        filename: archive.filename.clone(),
+                        reason: "the platform or version is not supported by uv".to_string(),
+                    });
+                }
+                continue;
+            }
+
+            let sha256 = if let Some(sha256) = digests.get(archive.filename.as_str()) {
+                sha256.clone()
+            } else {
+                debug!("Computing SHA-256 digest of {}", archive.filename);
+                let sha256 = sha256_digest(&directory.join(&archive.filename))?;
+                digests.insert(&archive.filename, sha256.clone());
+                sha256
+            };
+            entry.sha256 = Some(sha256);
+
+            downloads.insert(key, entry);
+        }
+
+        rejected.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+        Ok(Self {
+            downloads,
+            rejected,
+        })
+    }
+
+    /// Merge the download metadata with an existing Python downloads JSON document, e.g., the
+    /// upstream `download-metadata.json`.
+    ///
+    /// The URLs of the existing entries are rewritten to point to the same filename at
+    /// `base_url`, retaining their SHA-256 digests. The entries generated from the archives in
+    /// the mirror directory take precedence over existing entries with the same key.
+    pub fn merge(&mut self, upstream: &Path, base_url: &Url) -> Result<(), Error> {
+        let base_url = directory_url(base_url);
+
+        let file = fs_err::File::open(upstream)?;
+        let entries: BTreeMap<String, JsonPythonDownload> = serde_json::from_reader(file)
+            .map_err(|err| Error::InvalidUpstream(upstream.display().to_string(), err))?;
+
+        for (key, mut entry) in entries {
+            if self.downloads.contains_key(&key) {
+                continue;
+            }
+            let url =
+                Url::parse(&entry.url).map_err(|err| Error::InvalidUrl(entry.url.clone(), err))?;
+            if let Some(filename) = url
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|filename| !filename.is_empty())
+            {
+                entry.url = base_url
+                    .join(filename)
+                    .map_err(|err| Error::InvalidUrl(base_url.to_string(), err))?
+                    .to_string();
+            }
+            self.downloads.insert(key, entry);
+        }
+
+        Ok(())
+    }
+
+    /// The archives that were rejected.
+    pub fn rejected(&self) -> &[RejectedArchive] {
+        &self.rejected
+    }
+
+    /// The number of downloads in the manifest.
+    pub fn len(&self) -> usize {
+        self.downloads.len()
+    }
+
+    /// Whether the manifest contains no downloads.
+    pub fn is_empty(&self) -> bool {
+        self.downloads.is_empty()
+    }
+
+    /// Serialize the manifest as a Python downloads JSON document.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(&self.downloads).map_err(Error::Serialize)
+    }
+}
+
+/// A `python-build-standalone` CPython archive, as described by its filename.
+#[derive(Debug)]
+struct Archive {
+    filename: String,
+    major: u8,
+    minor: u8,
+    patch: u8,
+    prerelease: String,
+    os: String,
+    arch: JsonArch,
+    libc: String,
+    variant: Option<&'static str>,
+    /// The optimization flavor of the archive, if it can be requested explicitly.
+    build: Option<BuildFlavor>,
+    /// The preference for the archive among archives of the same key, where lower values are
+    /// preferred.
+    priority: (usize, i8),
+}
+
+impl Archive {
+    /// Parse an archive from its filename, returning the reason for rejecting it on failure.
+    fn from_filename(filename: &str) -> Result<Self, String> {
+        // Archives downloaded from GitHub may retain the URL-encoded `+`.
+        let decoded = filename.replace("%2B", "+");
+        let Some(captures) = ARCHIVE_FILENAME.captures(&decoded) else {
+            return Err(
+                "the filename is not a `python-build-standalone` CPython archive".to_string(),
+            );
+        };
+
+        let (major, minor, patch) = captures["version"]
+            .splitn(3, '.')
+            .collect_tuple()
+            .expect("the version has three components");
+        let (patch, prerelease) = patch.split_at(
+            patch
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(patch.len()),
+        );
+        let parse = |component: &str| {
+            u8::from_str(component)
+                .map_err(|_| format!("invalid Python version `{}`", &captures["version"]))
+        };
+        let (major, minor, patch) = (parse(major)?, parse(minor)?, parse(patch)?);
+
+        let triple = &captures["triple"];
+        if triple.contains("-static") {
+            return Err(format!("unsupported platform `{triple}`"));
+        }
+        let pieces = triple.split('-').collect::<Vec<_>>();
+        let os = pieces[2].to_string();
+        let mut libc = if os == "linux" {
+            let Some(libc) = pieces.get(3) else {
+                return Err(format!("unsupported platform `{triple}`"));
+            };
+            (*libc).to_string()
+        } else {
+            "none".to_string()
+        };
+
+        let arch = match pieces[0] {
+            "ppc64" => "powerpc64",
+            "ppc64le" => "powerpc64le",
+            arch => arch,
+        };
+        let arch = match arch.splitn(3, '_').collect::<Vec<_>>().as_slice() {
+            [first, second, variant] => JsonArch {
+                family: format!("{first}_{second}"),
+                variant: Some((*variant).to_string()),
+            },
+            _ => JsonArch {
+                family: arch.to_string(),
+                variant: None,
+            },
+        };
+
+        let options = captures
+            .name("options")
+            .map(|options| options.as_str().split('+').collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let variant = if options.contains(&"freethreaded") {
+            Some("freethreaded")
+        } else if options.contains(&"debug") {
+            return Err("debug builds are not supported".to_string());
+        } else {
+            None
+        };
+
+        if libc == "musl" {
+            let release = u64::from_str(&captures["release"]).unwrap_or_default();
+            if release < MUSL_DYNAMIC_RELEASE {
+                return Err(format!(
+                    "musl builds published before {MUSL_DYNAMIC_RELEASE} are not supported"
+                ));
+            }
+            // The statically-linked musl builds are tracked separately, as they cannot load
+            // compiled extension modules.
+            if options.contains(&"static") {
+                libc = "musl-static".to_string();
+            }
+        }
+
+        let build = options
+            .iter()
+            .filter(|option| !matches!(**option, "static" | "freethreaded" | "debug"))
+            .join("+");
+        let build = BuildFlavor::from_str(&build)
+            .ok()
+            .filter(|build| *build != BuildFlavor::Default);
+
+        let flavor = &captures["flavor"];
+        let flavor_priority = FLAVOR_PREFERENCES
+            .iter()
+            .position(|preference| *preference == flavor)
+            .unwrap_or(FLAVOR_PREFERENCES.len() + 1);
+        // Prefer optimized builds.
+        let option_priority = -[
+            options.contains(&"lto"),
+            options.contains(&"pgo"),
+            !options.contains(&"static"),
+        ]
+        .into_iter()
+        .map(i8::from)
+        .sum::<i8>();
+
+        Ok(Self {
+            filename: filename.to_string(),
+            major,
+            minor,
+            patch,
+            prerelease: prerelease.to_string(),
+            os,
+            arch,
+            libc,
+            variant,
+            build,
+            priority: (flavor_priority, option_priority),
+        })
+    }
+
+    /// The key of the download for the given optimization flavor, e.g.,
+    /// `cpython-3.13.3+freethreaded+pgo-linux-x86_64-gnu`.
+    fn key(&self, build: BuildFlavor) -> String {
+        let arch = if let Some(variant) = &self.arch.variant {
+            format!("{}_{variant}", self.arch.family)
+        } else {
+            self.arch.family.clone()
+        };
+        format!(
+            "cpython-{}.{}.{}{}{}{}-{}-{arch}-{}",
+            self.major,
+            self.minor,
+            self.patch,
+            self.prerelease,
+            self.variant
+                .map(|variant| format!("+{variant}"))
+                .unwrap_or_default(),
+            build.suffix(),
+            self.os,
+            self.libc,
+        )
+    }
+
+    fn to_json(&self, build: BuildFlavor, url: Url) -> JsonPythonDownload {
+        JsonPythonDownload {
+            name: "cpython".to_string(),
+            arch: self.arch.clone(),
+            os: self.os.clone(),
+            libc: self.libc.clone(),
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            prerelease: Some(self.prerelease.clone()),
+            url: url.to_string(),
+            sha256: None,
+            variant: self.variant.map(ToString::to_string),
+            build: (build != BuildFlavor::Default).then(|| build.to_string()),
+        }
+    }
+}
+
+/// Ensure the URL refers to a directory, such that joining a filename appends to it.
+fn directory_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+    url
+}
+
+/// Compute the SHA-256 digest of the file at the given path.
+fn sha256_digest(path: &Path) -> Result<String, std::io::Error> {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut file = fs_err::File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(HashDigest::from(hasher).digest.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use assert_fs::prelude::*;
+    use url::Url;
+
+    use super::MirrorManifest;
+
+    fn write_archives(dir: &Path, filenames: &[&str]) {
+        for filename in filenames {
+            fs_err::write(dir.join(filename), filename.as_bytes()).unwrap();
+        }
+    }
+
+    #[test]
+    fn from_directory() -> anyhow::Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        write_archives(
+            &dir,
+            &[
+                "cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-install_only.tar.gz",
+                "cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+                "cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-pgo+lto-full.tar.zst",
+                "cpython-3.13.3%2B20250409-aarch64-apple-darwin-freethreaded+pgo-full.tar.zst",
+                "cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-debug-full.tar.zst",
+                "cpython-3.12.10-x86_64-unknown-linux-gnu.tar.gz",
+            ],
+        );
+        dir.child("README.md").touch()?;
+
+        let base_url = Url::parse("https://mirror.example.com/python")?;
+        let manifest = MirrorManifest::from_directory(&dir, &base_url)?;
+
+        let json: serde_json::Value = serde_json::from_str(&manifest.to_json()?)?;
+        let urls = json
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, entry)| format!("{key} {}", entry["url"].as_str().unwrap()))
+            .collect::<Vec<_>>();
+        insta::assert_debug_snapshot!(urls, @r#"
+        [
+            "cpython-3.12.10+pgo+lto-linux-x86_64-gnu https://mirror.example.com/python/cpython-3.12.10%2B20250409-x86_64-unknown-linux-gnu-pgo%2Blto-full.tar.zst",
+            "cpython-3.12.10-linux-x86_64-gnu https://mirror.example.com/python/cpython-3.12.10%2B20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+            "cpython-3.13.3+freethreaded+pgo-darwin-aarch64-none https://mirror.example.com/python/cpython-3.13.3%252B20250409-aarch64-apple-darwin-freethreaded%2Bpgo-full.tar.zst",
+            "cpython-3.13.3+freethreaded-darwin-aarch64-none https://mirror.example.com/python/cpython-3.13.3%252B20250409-aarch64-apple-darwin-freethreaded%2Bpgo-full.tar.zst",
+        ]
+        "#);
+
+        let rejected = manifest
+            .rejected()
+            .iter()
+            .map(|rejected| format!("{}: {}", rejected.filename(), rejected.reason()))
+            .collect::<Vec<_>>();
+        insta::assert_debug_snapshot!(rejected, @r#"
+        [
+            "cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-debug-full.tar.zst: debug builds are not supported",
+            "cpython-3.12.10-x86_64-unknown-linux-gnu.tar.gz: the filename is not a `python-build-standalone` CPython archive",
+        ]
+        "#);
+
+        // The digests are computed from the archive contents.
+        assert_eq!(
+            json["cpython-3.12.10-linux-x86_64-gnu"]["sha256"],
+            "c9a5e7bd5efd8b6e474d948affd2e9d4647214f21af8ea612dadcec3e1f13e27"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge() -> anyhow::Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        write_archives(
+            &dir,
+            &["cpython-3.12.10+20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz"],
+        );
+        let upstream = dir.child("download-metadata.json");
+        upstream.write_str(
+            r#"{
+              "cpython-3.12.10-linux-x86_64-gnu": {
+                "name": "cpython",
+                "arch": {"family": "x86_64", "variant": null},
+                "os": "linux",
+                "libc": "gnu",
+                "major": 3,
+                "minor": 12,
+                "patch": 10,
+                "prerelease": "",
+                "url": "https://github.com/astral-sh/python-build-standalone/releases/download/20250409/cpython-3.12.10%2B20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+                "sha256": "0000000000000000000000000000000000000000000000000000000000000000",
+                "variant": null
+              },
+              "cpython-3.12.10-darwin-aarch64-none": {
+                "name": "cpython",
+                "arch": {"family": "aarch64", "variant": null},
+                "os": "darwin",
+                "libc": "none",
+                "major": 3,
+                "minor": 12,
+                "patch": 10,
+                "prerelease": "",
+                "url": "https://github.com/astral-sh/python-build-standalone/releases/download/20250409/cpython-3.12.10%2B20250409-aarch64-apple-darwin-install_only_stripped.tar.gz",
+                "sha256": "1111111111111111111111111111111111111111111111111111111111111111",
+                "variant": null
+              }
+            }"#,
+        )?;
+
+        let base_url = Url::parse("https://mirror.example.com/python/")?;
+        let mut manifest = MirrorManifest::from_directory(&dir, &base_url)?;
+        manifest.merge(&upstream, &base_url)?;
+        assert_eq!(manifest.len(), 2);
+
+        let json: serde_json::Value = serde_json::from_str(&manifest.to_json()?)?;
+
+        // The mirrored archive takes precedence.
+        let mirrored = &json["cpython-3.12.10-linux-x86_64-gnu"];
+        assert_eq!(
+            mirrored["url"],
+            "https://mirror.example.com/python/cpython-3.12.10%2B20250409-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz"
+        );
+        assert_ne!(
+            mirrored["sha256"],
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        // Other upstream entries are retargeted to the mirror.
+        let upstream = &json["cpython-3.12.10-darwin-aarch64-none"];
+        assert_eq!(
+            upstream["url"],
+            "https://mirror.example.com/python/cpython-3.12.10%2B20250409-aarch64-apple-darwin-install_only_stripped.tar.gz"
+        );
+        assert_eq!(
+            upstream["sha256"],
+            "1111111111111111111111111111111111111111111111111111111111111111"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+//! A machine-readable summary of what fetching a managed Python installation did.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::downloads::{Direction, DownloadResult, Reporter};
+use crate::dylib::DylibPatchReport;
+use crate::installation::PythonInstallationKey;
+use crate::managed::{Error, ManagedPythonInstallation};
+
+/// The outcome of fetching a managed Python installation.
+#[derive(Debug, Serialize)]
+pub struct InstallOutcome {
+    key: PythonInstallationKey,
+    path: PathBuf,
+    status: FetchStatus,
+    #[serde(flatten)]
+    transfer: TransferStats,
+    #[serde(flatten)]
+    post_install: PostInstallReport,
+}
+
+/// Whether an installation was downloaded, or already present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FetchStatus {
+    /// The installation was already present, and was not downloaded.
+    AlreadyAvailable,
+    /// The installation was downloaded and extracted.
+    Fetched,
+}
+
+/// The bytes transferred while fetching an installation, and the time spent in each phase.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TransferStats {
+    /// The number of bytes downloaded.
+    downloaded_bytes: u64,
+    /// The time spent downloading, if anything was downloaded.
+    ///
+    /// When the archive is extracted as it's downloaded, this includes the extraction.
+    #[serde(rename = "download_seconds", serialize_with = "optional_seconds")]
+    download: Option<Duration>,
+    /// The time spent extracting a previously downloaded archive, if any.
+    #[serde(rename = "extract_seconds", serialize_with = "optional_seconds")]
+    extract: Option<Duration>,
+}
+
+/// The steps run to complete an installation after it was extracted.
+#[derive(Debug, Serialize)]
+pub struct PostInstallReport {
+    #[serde(rename = "post_install_seconds", serialize_with = "seconds")]
+    duration: Duration,
+    steps: Vec<PostInstallStep>,
+    dylib: DylibPatchReport,
+}
+
+/// A step run to complete an installation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PostInstallStep {
+    name: &'static str,
+    /// Whether the step emitted a warning, e.g., because it could only partially complete.
+    warned: bool,
+}
+
+impl InstallOutcome {
+    pub fn new(
+        installation: &ManagedPythonInstallation,
+        status: FetchStatus,
+        transfer: TransferStats,
+        post_install: PostInstallReport,
+    ) -> Self {
+        Self {
+            key: installation.key().clone(),
+            path: installation.path().to_path_buf(),
+            status,
+            transfer,
+            post_install,
+        }
+    }
+
+    /// The key of the installation.
+    pub fn key(&self) -> &PythonInstallationKey {
+        &self.key
+    }
+
+    /// The path to the installation.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the installation was downloaded, or already present.
+    pub fn status(&self) -> FetchStatus {
+        self.status
+    }
+
+    /// The bytes transferred while fetching the installation.
+    pub fn transfer(&self) -> &TransferStats {
+        &self.transfer
+    }
+
+    /// The steps run to complete the installation.
+    pub fn post_install(&self) -> &PostInstallReport {
+        &self.post_install
+    }
+}
+
+impl From<&DownloadResult> for FetchStatus {
+    fn from(result: &DownloadResult) -> Self {
+        match result {
+            DownloadResult::AlreadyAvailable(_) => Self::AlreadyAvailable,
+            DownloadResult::Fetched(_) => Self::Fetched,
+        }
+    }
+}
+
+impl TransferStats {
+    /// The number of bytes downloaded.
+    pub fn downloaded_bytes(&self) -> u64 {
+        self.downloaded_bytes
+    }
+
+    /// The time spent downloading, if anything was downloaded.
+    pub fn download(&self) -> Option<Duration> {
+        self.download
+    }
+
+    /// The time spent extracting a previously downloaded archive, if any.
+    pub fn extract(&self) -> Option<Duration> {
+        self.extract
+    }
+}
+
+impl PostInstallReport {
+    /// Run the steps that complete an installation, recording each and the total time spent.
+    ///
+    /// If `externally_managed` is `false`, the `EXTERNALLY-MANAGED` marker is removed rather than
+    /// written, and the step is not recorded.
+    ///
+    /// Shared library patching failures are recorded rather than shown; see
+    /// [`DylibPatchReport::warn_user`].
+    pub fn run(
+        installation: &ManagedPythonInstallation,
+        externally_managed: bool,
+    ) -> Result<Self, Error> {
+        let start = Instant::now();
+        let mut steps = Vec::with_capacity(4);
+
+        installation.set_externally_managed(externally_managed)?;
+        if externally_managed {
+            steps.push(PostInstallStep::new("externally-managed", false));
+        }
+        installation.ensure_sysconfig_patched()?;
+        steps.push(PostInstallStep::new("sysconfig-patched", false));
+        installation.ensure_canonical_executables()?;
+        steps.push(PostInstallStep::new("canonical-executables", false));
+        let dylib = installation.ensure_dylib_patched();
+        steps.push(PostInstallStep::new(
+            "dylib-patched",
+            !dylib.failed().is_empty(),
+        ));
+
+        Ok(Self {
+            duration: start.elapsed(),
+            steps,
+            dylib,
+        })
+    }
+
+    /// The time spent completing the installation.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The steps that were run, in order.
+    pub fn steps(&self) -> &[PostInstallStep] {
+        &self.steps
+    }
+
+    /// The outcome of checking, and patching, the shared libraries of the installation.
+    pub fn dylib(&self) -> &DylibPatchReport {
+        &self.dylib
+    }
+}
+
+impl PostInstallStep {
+    fn new(name: &'static str, warned: bool) -> Self {
+        Self { name, warned }
+    }
+
+    /// The name of the step, e.g., `sysconfig-patched`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Whether the step emitted a warning.
+    pub fn warned(&self) -> bool {
+        self.warned
+    }
+}
+
+/// A [`Reporter`] that records the bytes transferred and the time spent in each phase of a
+/// fetch, forwarding all events to an inner reporter, if any.
+pub struct TransferRecorder<'a> {
+    inner: Option<&'a dyn Reporter>,
+    state: Mutex<RecorderState>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    /// The next identifier to assign to a request, if there's no inner reporter.
+    next_id: usize,
+    /// The direction and start of each in-flight request.
+    started: HashMap<usize, (Direction, Instant)>,
+    stats: TransferStats,
+}
+
+impl<'a> TransferRecorder<'a> {
+    pub fn new(inner: Option<&'a dyn Reporter>) -> Self {
+        Self {
+            inner,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Return the recorded statistics.
+    pub fn finish(self) -> TransferStats {
+        self.state
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .stats
+    }
+}
+
+impl Reporter for TransferRecorder<'_> {
+    fn on_request_start(
+        &self,
+        direction: Direction,
+        name: &PythonInstallationKey,
+        size: Option<u64>,
+    ) -> usize {
+        let id = self
+            .inner
+            .map(|inner| inner.on_request_start(direction, name, size));
+        let mut state = self.state.lock().unwrap();
+        let id = id.unwrap_or_else(|| {
+            state.next_id += 1;
+            state.next_id
+        });
+        state.started.insert(id, (direction, Instant::now()));
+        id
+    }
+
+    fn on_request_progress(&self, id: usize, inc: u64) {
+        if let Some(inner) = self.inner {
+            inner.on_request_progress(id, inc);
+        }
+        // Bytes read while extracting an archive from disk weren't downloaded.
+        let mut state = self.state.lock().unwrap();
+        if matches!(state.started.get(&id), Some((Direction::Download, _))) {
+            state.stats.downloaded_bytes += inc;
+        }
+    }
+
+    fn on_request_complete(&self, direction: Direction, id: usize) {
+        if let Some(inner) = self.inner {
+            inner.on_request_complete(direction, id);
+        }
+        let mut state = self.state.lock().unwrap();
+        let Some((_, start)) = state.started.remove(&id) else {
+            return;
+        };
+        let phase = match direction {
+            Direction::Download => &mut state.stats.download,
+            Direction::Extract => &mut state.stats.extract,
+        };
+        *phase = Some(phase.unwrap_or_default() + start.elapsed());
+    }
+
+    fn on_fetch_attempt(&self) {
+        if let Some(inner) = self.inner {
+            inner.on_fetch_attempt();
+        }
+        // Only count the bytes of the final attempt, not those of a partial download that was
+        // retried.
+        self.state.lock().unwrap().stats.downloaded_bytes = 0;
+    }
+
+    fn on_dylib_patch(&self, key: &PythonInstallationKey, report: &DylibPatchReport) {
+        if let Some(inner) = self.inner {
+            inner.on_dylib_patch(key, report);
+        }
+    }
+}
+
+/// Serialize a [`Duration`] as fractional seconds.
+fn seconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Serialize an optional [`Duration`] as fractional seconds.
+#[allow(clippy::ref_option)]
+fn optional_seconds<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration
+        .map(|duration| duration.as_secs_f64())
+        .serialize(serializer)
+}
@@ -2,19 +2,110 @@ use crate::cpuinfo::detect_hardware_floating_point_support;
 use crate::libc::{detect_linux_libc, LibcDetectionError, LibcVersion};
 use std::fmt::Display;
 use std::ops::Deref;
+use std::path::Path;
+use std::sync::OnceLock;
 use std::{fmt, str::FromStr};
 use thiserror::Error;
+use tracing::debug;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Unknown operating system: {0}")]
-    UnknownOs(String),
-    #[error("Unknown architecture: {0}")]
-    UnknownArch(String),
-    #[error("Unknown libc environment: {0}")]
-    UnknownLibc(String),
+    #[error("Unknown operating system: {0}{}", did_you_mean(*_1))]
+    UnknownOs(String, Option<&'static str>),
+    #[error("Unknown architecture: {0}{}", did_you_mean(*_1))]
+    UnknownArch(String, Option<&'static str>),
+    #[error("Unknown libc environment: {0}{}", did_you_mean(*_1))]
+    UnknownLibc(String, Option<&'static str>),
     #[error("Unsupported variant `{0}` for architecture `{1}`")]
     UnsupportedVariant(String, String),
+    #[error("Invalid platform `{0}`; expected `<os>-<arch>[-<libc>]`, e.g., `linux-aarch64-gnu`")]
+    InvalidPlatform(String),
+}
+
+impl Error {
+    /// The canonical spelling of the operating system, architecture, or libc that was most likely
+    /// intended, if the value was not recognized.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            Self::UnknownOs(_, suggestion)
+            | Self::UnknownArch(_, suggestion)
+            | Self::UnknownLibc(_, suggestion) => *suggestion,
+            Self::UnsupportedVariant(..) | Self::InvalidPlatform(_) => None,
+        }
+    }
+}
+
+/// Format a suggestion for an unknown value, e.g., "; did you mean `x86_64`?".
+fn did_you_mean(suggestion: Option<&str>) -> String {
+    suggestion
+        .map(|suggestion| format!("; did you mean `{suggestion}`?"))
+        .unwrap_or_default()
+}
+
+/// Common alternative spellings of operating systems, mapped to the spelling used by uv.
+const OS_ALIASES: &[(&str, &str)] = &[
+    ("darwin", "macos"),
+    ("osx", "macos"),
+    ("win", "windows"),
+    ("win32", "windows"),
+    ("win64", "windows"),
+];
+
+/// Common alternative spellings of architectures, mapped to the spelling used by uv.
+const ARCH_ALIASES: &[(&str, &str)] = &[
+    ("amd64", "x86_64"),
+    ("arm64", "aarch64"),
+    ("ppc64le", "powerpc64le"),
+    ("x64", "x86_64"),
+];
+
+/// Common alternative spellings of libc environments, mapped to the spelling used by uv.
+const LIBC_ALIASES: &[(&str, &str)] = &[("glibc", "gnu"), ("musllinux", "musl")];
+
+/// The canonical operating system names considered when suggesting a correction.
+const OS_NAMES: &[&str] = &["linux", "macos", "windows"];
+
+/// The canonical architecture names considered when suggesting a correction.
+const ARCH_NAMES: &[&str] = &[
+    "aarch64",
+    "armv7",
+    "powerpc64le",
+    "riscv64",
+    "s390x",
+    "x86",
+    "x86_64",
+];
+
+/// The canonical libc names considered when suggesting a correction.
+const LIBC_NAMES: &[&str] = &["gnu", "gnueabi", "gnueabihf", "musl", "musl-static", "none"];
+
+/// Resolve an alias, e.g., `amd64`, to the spelling used by uv, e.g., `x86_64`.
+fn resolve_alias<'a>(aliases: &[(&str, &'static str)], value: &'a str) -> &'a str {
+    aliases
+        .iter()
+        .find(|(alias, _)| *alias == value)
+        .map_or(value, |(_, canonical)| canonical)
+}
+
+/// Return the canonical name or alias most similar to the given value, if any is close enough.
+///
+/// If an alias is the closest match, the canonical name it refers to is returned instead.
+fn suggest(
+    names: &[&'static str],
+    aliases: &[(&str, &'static str)],
+    value: &str,
+) -> Option<&'static str> {
+    // Allow up to one edit per three characters, e.g., a single typo in a short name.
+    let max_distance = (value.len() / 3).max(1);
+    let value = value.to_lowercase();
+    names
+        .iter()
+        .map(|name| (*name, *name))
+        .chain(aliases.iter().copied())
+        .map(|(candidate, canonical)| (strsim::levenshtein(&value, candidate), canonical))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, canonical)| canonical)
 }
 
 /// Architecture variants, e.g., with support for different instruction sets
@@ -40,12 +131,35 @@ pub struct Arch {
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct Os(pub(crate) target_lexicon::OperatingSystem);
 
+/// The ability of the host to transparently run binaries built for another architecture.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum Emulation {
+    /// Only binaries for the host architecture can be run.
+    None,
+    /// Windows ARM64 runs x86_64 binaries transparently.
+    WindowsArm64,
+    /// Apple Silicon runs x86_64 binaries under Rosetta 2.
+    Rosetta,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum Libc {
     Some(target_lexicon::Environment),
+    /// A statically-linked musl build, which cannot load compiled extension modules.
+    ///
+    /// Distinct from `Some(Musl)`, which is dynamically linked.
+    MuslStatic,
     None,
 }
 
+/// The platform to download a Python distribution for, e.g., `linux-aarch64-gnu`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct TargetPlatform {
+    pub os: Os,
+    pub arch: Arch,
+    pub libc: Libc,
+}
+
 impl Libc {
     pub(crate) fn from_env() -> Result<Self, LibcDetectionError> {
         match std::env::consts::OS {
@@ -69,8 +183,32 @@ impl Libc {
         }
     }
 
+    /// Whether this is musl, either dynamically or statically linked.
     pub fn is_musl(&self) -> bool {
-        matches!(self, Self::Some(target_lexicon::Environment::Musl))
+        matches!(
+            self,
+            Self::Some(target_lexicon::Environment::Musl) | Self::MuslStatic
+        )
+    }
+
+    /// Whether this is a statically-linked libc.
+    pub fn is_static(&self) -> bool {
+        matches!(self, Self::MuslStatic)
+    }
+
+    /// The libc implementation, regardless of how it is linked.
+    pub(crate) fn family(self) -> Self {
+        match self {
+            Self::MuslStatic => Self::Some(target_lexicon::Environment::Musl),
+            libc => libc,
+        }
+    }
+
+    /// Whether builds for the other libc can run where this libc is available.
+    ///
+    /// Statically-linked builds run wherever the dynamically-linked build of the same libc does.
+    pub(crate) fn supports(self, other: Self) -> bool {
+        self == other || (!self.is_static() && self == other.family())
     }
 }
 
@@ -78,13 +216,17 @@ impl FromStr for Libc {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+        match resolve_alias(LIBC_ALIASES, s) {
             "gnu" => Ok(Self::Some(target_lexicon::Environment::Gnu)),
             "gnueabi" => Ok(Self::Some(target_lexicon::Environment::Gnueabi)),
             "gnueabihf" => Ok(Self::Some(target_lexicon::Environment::Gnueabihf)),
             "musl" => Ok(Self::Some(target_lexicon::Environment::Musl)),
+            "musl-static" => Ok(Self::MuslStatic),
             "none" => Ok(Self::None),
-            _ => Err(Error::UnknownLibc(s.to_string())),
+            _ => Err(Error::UnknownLibc(
+                s.to_string(),
+                suggest(LIBC_NAMES, LIBC_ALIASES, s),
+            )),
         }
     }
 }
@@ -109,18 +251,40 @@ impl Arch {
     /// architecture is transparently emulated or is a microarchitecture with worse performance
     /// characteristics.
     pub(crate) fn supports(self, other: Self) -> bool {
+        self.supports_with(other, Emulation::from_env())
+    }
+
+    /// Does the current architecture support running the other, given the host's [`Emulation`]?
+    pub(crate) fn supports_with(self, other: Self, emulation: Emulation) -> bool {
         if self == other {
             return true;
         }
 
         // TODO: Implement `variant` support checks
 
-        // Windows ARM64 runs emulated x86_64 binaries transparently
-        if cfg!(windows) && matches!(self.family, target_lexicon::Architecture::Aarch64(_)) {
-            return other.family == target_lexicon::Architecture::X86_64;
+        match emulation {
+            Emulation::None => false,
+            // Windows ARM64 runs emulated x86_64 binaries transparently, as does Apple Silicon with
+            // Rosetta 2
+            Emulation::WindowsArm64 | Emulation::Rosetta => {
+                matches!(self.family, target_lexicon::Architecture::Aarch64(_))
+                    && other.family == target_lexicon::Architecture::X86_64
+            }
         }
+    }
 
-        false
+    /// Whether binaries for this architecture can be run on the current machine, either natively
+    /// or via emulation.
+    pub fn is_supported_by_host(self) -> bool {
+        let host = Self::from_env();
+        // TODO: Implement `variant` support checks
+        host.family == self.family || host.supports(self)
+    }
+
+    /// Whether running the other architecture on this one requires emulation, i.e., it is
+    /// supported, but is not the same architecture family.
+    pub(crate) fn is_emulated(self, other: Self) -> bool {
+        self.family != other.family && self.supports(other)
     }
 
     pub fn family(&self) -> target_lexicon::Architecture {
@@ -132,10 +296,45 @@ impl Arch {
     }
 }
 
+impl Emulation {
+    /// Detect the emulation capabilities of the current host.
+    pub(crate) fn from_env() -> Self {
+        static EMULATION: OnceLock<Emulation> = OnceLock::new();
+        *EMULATION.get_or_init(|| {
+            if !matches!(
+                target_lexicon::HOST.architecture,
+                target_lexicon::Architecture::Aarch64(_)
+            ) {
+                Self::None
+            } else if cfg!(windows) {
+                Self::WindowsArm64
+            } else if cfg!(target_os = "macos") && is_rosetta_available() {
+                Self::Rosetta
+            } else {
+                Self::None
+            }
+        })
+    }
+}
+
+/// Whether Rosetta 2 can be used to run x86_64 binaries on this macOS host.
+///
+/// Rosetta 2 is an optional component on Apple Silicon; its runtime is only present once it has
+/// been installed.
+fn is_rosetta_available() -> bool {
+    let available = Path::new("/Library/Apple/usr/libexec/oah/libRosettaRuntime").exists();
+    debug!(
+        "Rosetta 2 is {}available",
+        if available { "" } else { "not " }
+    );
+    available
+}
+
 impl Display for Libc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Some(env) => write!(f, "{env}"),
+            Self::MuslStatic => write!(f, "musl-static"),
             Self::None => write!(f, "none"),
         }
     }
@@ -169,13 +368,13 @@ impl FromStr for Os {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let inner = match s {
+        let unknown = || Error::UnknownOs(s.to_string(), suggest(OS_NAMES, OS_ALIASES, s));
+        let inner = match resolve_alias(OS_ALIASES, s) {
             "macos" => target_lexicon::OperatingSystem::Darwin(None),
-            _ => target_lexicon::OperatingSystem::from_str(s)
-                .map_err(|()| Error::UnknownOs(s.to_string()))?,
+            os => target_lexicon::OperatingSystem::from_str(os).map_err(|()| unknown())?,
         };
         if matches!(inner, target_lexicon::OperatingSystem::Unknown) {
-            return Err(Error::UnknownOs(s.to_string()));
+            return Err(unknown());
         }
         Ok(Self(inner))
     }
@@ -186,17 +385,18 @@ impl FromStr for Arch {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         fn parse_family(s: &str) -> Result<target_lexicon::Architecture, Error> {
-            let inner = match s {
+            let unknown =
+                || Error::UnknownArch(s.to_string(), suggest(ARCH_NAMES, ARCH_ALIASES, s));
+            let inner = match resolve_alias(ARCH_ALIASES, s) {
                 // Allow users to specify "x86" as a shorthand for the "i686" variant, they should not need
                 // to specify the exact architecture and this variant is what we have downloads for.
                 "x86" => {
                     target_lexicon::Architecture::X86_32(target_lexicon::X86_32Architecture::I686)
                 }
-                _ => target_lexicon::Architecture::from_str(s)
-                    .map_err(|()| Error::UnknownArch(s.to_string()))?,
+                arch => target_lexicon::Architecture::from_str(arch).map_err(|()| unknown())?,
             };
             if matches!(inner, target_lexicon::Architecture::Unknown) {
-                return Err(Error::UnknownArch(s.to_string()));
+                return Err(unknown());
             }
             Ok(inner)
         }
@@ -251,6 +451,41 @@ impl Display for ArchVariant {
     }
 }
 
+impl FromStr for TargetPlatform {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split('-').collect::<Vec<_>>();
+        let (os, arch, libc) = match parts.as_slice() {
+            [os, arch] => (Os::from_str(os)?, Arch::from_str(arch)?, None),
+            [os, arch, libc] => (
+                Os::from_str(os)?,
+                Arch::from_str(arch)?,
+                Some(Libc::from_str(libc)?),
+            ),
+            [os, arch, libc, "static"] => (
+                Os::from_str(os)?,
+                Arch::from_str(arch)?,
+                Some(Libc::from_str(&format!("{libc}-static"))?),
+            ),
+            _ => return Err(Error::InvalidPlatform(s.to_string())),
+        };
+        // If omitted, Linux distributions use glibc
+        let libc = libc.unwrap_or(if matches!(*os, target_lexicon::OperatingSystem::Linux) {
+            Libc::Some(target_lexicon::Environment::Gnu)
+        } else {
+            Libc::None
+        });
+        Ok(Self { os, arch, libc })
+    }
+}
+
+impl Display for TargetPlatform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.os, self.arch, self.libc)
+    }
+}
+
 impl Deref for Os {
     type Target = target_lexicon::OperatingSystem;
 
@@ -351,3 +586,137 @@ impl From<&uv_platform_tags::Os> for Os {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{
+        Arch, Emulation, Libc, Os, TargetPlatform, ARCH_ALIASES, ARCH_NAMES, LIBC_ALIASES,
+        LIBC_NAMES, OS_ALIASES, OS_NAMES,
+    };
+
+    #[test]
+    fn arch_supports_emulation() {
+        let aarch64 = Arch::from_str("aarch64").unwrap();
+        let x86_64 = Arch::from_str("x86_64").unwrap();
+
+        // Without emulation, only the same architecture is supported
+        assert!(aarch64.supports_with(aarch64, Emulation::None));
+        assert!(!aarch64.supports_with(x86_64, Emulation::None));
+
+        // With Rosetta 2, x86_64 is supported on aarch64, but not the other way around
+        assert!(aarch64.supports_with(x86_64, Emulation::Rosetta));
+        assert!(!x86_64.supports_with(aarch64, Emulation::Rosetta));
+
+        // Windows ARM64 runs x86_64 binaries transparently too
+        assert!(aarch64.supports_with(x86_64, Emulation::WindowsArm64));
+        assert!(!x86_64.supports_with(aarch64, Emulation::WindowsArm64));
+
+        // Emulation does not extend to other architectures
+        let x86 = Arch::from_str("x86").unwrap();
+        assert!(!aarch64.supports_with(x86, Emulation::Rosetta));
+    }
+
+    #[test]
+    fn arch_display_round_trip() {
+        for arch in ["x86_64", "aarch64", "x86", "x86_64_v3"] {
+            assert_eq!(Arch::from_str(arch).unwrap().to_string(), arch);
+        }
+    }
+
+    #[test]
+    fn platform_aliases() {
+        for (alias, canonical) in OS_ALIASES {
+            assert_eq!(Os::from_str(alias).unwrap().to_string(), *canonical);
+        }
+        for (alias, canonical) in ARCH_ALIASES {
+            assert_eq!(Arch::from_str(alias).unwrap().to_string(), *canonical);
+        }
+        for (alias, canonical) in LIBC_ALIASES {
+            assert_eq!(Libc::from_str(alias).unwrap().to_string(), *canonical);
+        }
+
+        // Aliases are supported alongside architecture variants.
+        assert_eq!(Arch::from_str("amd64_v3").unwrap().to_string(), "x86_64_v3");
+    }
+
+    #[test]
+    fn platform_names_round_trip() {
+        for name in OS_NAMES {
+            assert_eq!(Os::from_str(name).unwrap().to_string(), *name);
+        }
+        for name in ARCH_NAMES {
+            assert_eq!(Arch::from_str(name).unwrap().to_string(), *name);
+        }
+        for name in LIBC_NAMES {
+            assert_eq!(Libc::from_str(name).unwrap().to_string(), *name);
+        }
+    }
+
+    #[test]
+    fn platform_suggestions() {
+        let suggest_arch = |arch| Arch::from_str(arch).unwrap_err().suggestion();
+        assert_eq!(suggest_arch("x86-64"), Some("x86_64"));
+        assert_eq!(suggest_arch("X86_64"), Some("x86_64"));
+        assert_eq!(suggest_arch("aarch46"), Some("aarch64"));
+        assert_eq!(suggest_arch("amd63"), Some("x86_64"));
+        assert_eq!(suggest_arch("foo"), None);
+
+        let suggest_os = |os| Os::from_str(os).unwrap_err().suggestion();
+        assert_eq!(suggest_os("linx"), Some("linux"));
+        assert_eq!(suggest_os("macOS"), Some("macos"));
+        assert_eq!(suggest_os("windos"), Some("windows"));
+        assert_eq!(suggest_os("foo"), None);
+
+        let suggest_libc = |libc| Libc::from_str(libc).unwrap_err().suggestion();
+        assert_eq!(suggest_libc("gnu-eabihf"), Some("gnueabihf"));
+        assert_eq!(suggest_libc("glic"), Some("gnu"));
+        assert_eq!(suggest_libc("musl_static"), Some("musl-static"));
+        assert_eq!(suggest_libc("foo"), None);
+
+        assert_eq!(
+            Arch::from_str("x86-64").unwrap_err().to_string(),
+            "Unknown architecture: x86-64; did you mean `x86_64`?"
+        );
+    }
+
+    #[test]
+    fn libc_static_musl() {
+        let musl = Libc::from_str("musl").unwrap();
+        let musl_static = Libc::from_str("musl-static").unwrap();
+        let gnu = Libc::from_str("gnu").unwrap();
+
+        assert_eq!(musl_static, Libc::MuslStatic);
+        assert_eq!(musl_static.to_string(), "musl-static");
+        assert!(musl.is_musl() && musl_static.is_musl());
+        assert!(!musl.is_static() && musl_static.is_static());
+
+        // Static builds run wherever the dynamic builds do, but not the other way around
+        assert!(musl.supports(musl_static));
+        assert!(!musl_static.supports(musl));
+        assert!(!gnu.supports(musl_static));
+
+        let platform = TargetPlatform::from_str("linux-x86_64-musl-static").unwrap();
+        assert_eq!(platform.libc, Libc::MuslStatic);
+        assert_eq!(platform.to_string(), "linux-x86_64-musl-static");
+    }
+
+    /// On Apple Silicon, x86_64 builds can run under Rosetta 2, but are never native.
+    #[test]
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    fn rosetta_detection() {
+        let host = Arch::from_env();
+        let x86_64 = Arch::from_str("x86_64").unwrap();
+
+        assert!(!host.is_emulated(host));
+        assert_eq!(
+            host.is_emulated(x86_64),
+            Emulation::from_env() == Emulation::Rosetta
+        );
+        assert_eq!(
+            x86_64.is_supported_by_host(),
+            Emulation::from_env() == Emulation::Rosetta
+        );
+    }
+}
@@ -8,20 +8,14 @@ use uv_pypi_types::Scheme;
 pub struct Prefix(PathBuf);
 
 impl Prefix {
-    /// Return the [`Scheme`] for the `--prefix` directory.
-    pub fn scheme(&self, virtualenv: &Scheme) -> Scheme {
-        Scheme {
-            purelib: self.0.join(&virtualenv.purelib),
-            platlib: self.0.join(&virtualenv.platlib),
-            scripts: self.0.join(&virtualenv.scripts),
-            data: self.0.join(&virtualenv.data),
-            include: self.0.join(&virtualenv.include),
-        }
-    }
-
     /// Return an iterator over the `site-packages` directories inside the environment.
+    ///
+    /// On some platforms, e.g., Fedora, platform-specific packages are installed into a separate
+    /// `lib64` directory, in which case both directories are included.
     pub fn site_packages(&self, virtualenv: &Scheme) -> impl Iterator<Item = PathBuf> {
-        std::iter::once(self.0.join(&virtualenv.purelib))
+        std::iter::once(self.0.join(&virtualenv.purelib)).chain(
+            (virtualenv.platlib != virtualenv.purelib).then(|| self.0.join(&virtualenv.platlib)),
+        )
     }
 
     /// Initialize the `--prefix` directory.
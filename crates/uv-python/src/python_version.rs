@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::str::FromStr;
 
-use uv_pep440::Version;
+use uv_pep440::{Prerelease, Version};
 use uv_pep508::{MarkerEnvironment, StringVersion};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -115,6 +115,13 @@ impl Display for PythonVersion {
 }
 
 impl PythonVersion {
+    /// Create a [`PythonVersion`] from its release segments and pre-release, without parsing.
+    pub fn from_parts(major: u8, minor: u8, patch: u8, prerelease: Option<Prerelease>) -> Self {
+        Self(StringVersion::from(
+            Version::new([major, minor, patch].map(u64::from)).with_pre(prerelease),
+        ))
+    }
+
     /// Return a [`MarkerEnvironment`] compatible with the given [`PythonVersion`], based on
     /// a base [`MarkerEnvironment`].
     ///
@@ -248,4 +255,33 @@ mod tests {
         );
         assert_eq!(version.python_full_version().to_string(), "3.11.8a1");
     }
+
+    #[test]
+    fn from_parts() {
+        for (major, minor, patch) in [(3, 12, 0), (3, 13, 4), (2, 7, 18)] {
+            for prerelease in [
+                None,
+                Some(Prerelease {
+                    kind: PrereleaseKind::Alpha,
+                    number: 1,
+                }),
+                Some(Prerelease {
+                    kind: PrereleaseKind::Beta,
+                    number: 0,
+                }),
+                Some(Prerelease {
+                    kind: PrereleaseKind::Rc,
+                    number: 12,
+                }),
+            ] {
+                let version = PythonVersion::from_parts(major, minor, patch, prerelease);
+                let string = format!(
+                    "{major}.{minor}.{patch}{}",
+                    prerelease.map(|pre| pre.to_string()).unwrap_or_default()
+                );
+                assert_eq!(version, PythonVersion::from_str(&string).unwrap());
+                assert_eq!(version.to_string(), string);
+            }
+        }
+    }
 }
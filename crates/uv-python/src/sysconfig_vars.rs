@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// A subset of the `sysconfig` paths and configuration variables reported by an interpreter.
+///
+/// All values are stored as strings. Variables that are unset in the interpreter are omitted.
+/// Well-known variables are exposed via typed accessors; any other variables reported by the
+/// interpreter are preserved and available via [`SysconfigVars::get`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SysconfigVars(BTreeMap<String, String>);
+
+impl SysconfigVars {
+    /// The directory containing platform-specific standard library modules, i.e., the
+    /// `platstdlib` path.
+    pub fn platstdlib(&self) -> Option<&Path> {
+        self.get("platstdlib").map(Path::new)
+    }
+
+    /// The directory containing the interpreter's C headers, including any ABI flags (e.g.,
+    /// `include/python3.13t`), i.e., the `include` path.
+    pub fn include(&self) -> Option<&Path> {
+        self.get("include").map(Path::new)
+    }
+
+    /// The suffix for extension modules (e.g., `.cpython-312-x86_64-linux-gnu.so`), i.e.,
+    /// `EXT_SUFFIX`.
+    pub fn ext_suffix(&self) -> Option<&str> {
+        self.get("EXT_SUFFIX")
+    }
+
+    /// The ABI tag used in extension module filenames (e.g., `cpython-312-x86_64-linux-gnu`),
+    /// i.e., `SOABI`.
+    pub fn soabi(&self) -> Option<&str> {
+        self.get("SOABI")
+    }
+
+    /// The ABI flags of the interpreter (e.g., `t` for free-threaded builds), i.e., `abiflags`.
+    pub fn abiflags(&self) -> Option<&str> {
+        self.get("abiflags")
+    }
+
+    /// Return the value of an arbitrary path or configuration variable, if it was reported by the
+    /// interpreter.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Return an iterator over all reported paths and configuration variables.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
@@ -0,0 +1,68 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use uv_pypi_types::Scheme;
+use uv_static::EnvVars;
+
+/// The user installation scheme (i.e., `--user`), which installs packages into the user
+/// `site-packages` directory (e.g., `~/.local/lib/python3.12/site-packages`), rather than into a
+/// virtual or system Python environment.
+#[derive(Debug, Clone)]
+pub struct UserScheme {
+    /// The user base directory, e.g., `~/.local`.
+    base: PathBuf,
+    scheme: Scheme,
+}
+
+impl UserScheme {
+    /// Expand the user scheme reported by the interpreter, in which the user base is represented
+    /// by a `{userbase}` placeholder.
+    ///
+    /// Like Python, `PYTHONUSERBASE` takes precedence over the interpreter's default user base.
+    pub(crate) fn from_template(template: &Scheme, default_base: &Path) -> Self {
+        let base = env::var_os(EnvVars::PYTHONUSERBASE)
+            .filter(|base| !base.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| default_base.to_path_buf());
+
+        let expand = |path: &Path| match path.strip_prefix("{userbase}") {
+            Ok(relative) => base.join(relative),
+            Err(_) => path.to_path_buf(),
+        };
+
+        let scheme = Scheme {
+            purelib: expand(&template.purelib),
+            platlib: expand(&template.platlib),
+            scripts: expand(&template.scripts),
+            data: expand(&template.data),
+            include: expand(&template.include),
+        };
+
+        Self { base, scheme }
+    }
+
+    /// Return the [`Scheme`] for the user installation.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme.clone()
+    }
+
+    /// Return an iterator over the user `site-packages` directories.
+    pub fn site_packages(&self) -> impl Iterator<Item = &Path> {
+        std::iter::once(self.scheme.purelib.as_path()).chain(
+            (self.scheme.platlib != self.scheme.purelib).then_some(self.scheme.platlib.as_path()),
+        )
+    }
+
+    /// Initialize the user `site-packages` directories.
+    pub fn init(&self) -> std::io::Result<()> {
+        for site_packages in self.site_packages() {
+            fs_err::create_dir_all(site_packages)?;
+        }
+        Ok(())
+    }
+
+    /// Return the path to the user base directory.
+    pub fn root(&self) -> &Path {
+        &self.base
+    }
+}
@@ -91,12 +91,17 @@ impl PythonVersionFile {
                 );
             }
             // Not found in directory or its ancestors. Looking in user-level config.
-            return Ok(match user_uv_config_dir() {
-                Some(user_dir) => Self::discover_user_config(user_dir, options)
-                    .await?
-                    .or(None),
-                None => None,
-            });
+            let Some(user_dir) = user_uv_config_dir() else {
+                return Ok(None);
+            };
+            let file = Self::discover_user_config(user_dir, options).await?;
+            if let Some(file) = &file {
+                debug!(
+                    "Using global Python version file at `{}`",
+                    file.path().user_display()
+                );
+            }
+            return Ok(file);
         };
 
         if options.no_config {
@@ -215,6 +220,11 @@ impl PythonVersionFile {
         }
     }
 
+    /// Returns `true` if this is the global version file in the user-level config directory.
+    pub fn is_global(&self) -> bool {
+        user_uv_config_dir().is_some_and(|dir| self.path.parent() == Some(dir.as_path()))
+    }
+
     /// Return the first request declared in the file, if any.
     pub fn version(&self) -> Option<&PythonRequest> {
         self.versions.first()
@@ -46,6 +46,14 @@ pub struct PyVenvConfiguration {
     pub(crate) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(crate) version: Option<PythonVersion>,
+    /// The directory containing the base interpreter, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
+    /// The prompt prefix for the virtual environment, if set.
+    pub(crate) prompt: Option<String>,
+    /// The installation key of the base interpreter, i.e., the `uv-python-key` key.
+    pub(crate) python_key: Option<String>,
+    /// The seed packages installed into the virtual environment, i.e., the `seed-packages` key.
+    pub(crate) seed_packages: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -199,6 +207,10 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut home = None;
+        let mut prompt = None;
+        let mut python_key = None;
+        let mut seed_packages = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -231,6 +243,18 @@ impl PyVenvConfiguration {
                             .map_err(|e| io::Error::new(std::io::ErrorKind::InvalidData, e))?,
                     );
                 }
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
+                "prompt" => {
+                    prompt = Some(value.trim().to_string());
+                }
+                "uv-python-key" => {
+                    python_key = Some(value.trim().to_string());
+                }
+                "seed-packages" => {
+                    seed_packages = Some(value.trim().to_string());
+                }
                 _ => {}
             }
         }
@@ -242,6 +266,10 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            home,
+            prompt,
+            python_key,
+            seed_packages,
         })
     }
 
@@ -270,6 +298,31 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the directory containing the base interpreter of the virtual environment.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
+    /// Returns the Python version the virtual environment was created with.
+    pub fn version(&self) -> Option<&PythonVersion> {
+        self.version.as_ref()
+    }
+
+    /// Returns the prompt prefix of the virtual environment, if set.
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    /// Returns the installation key of the base interpreter, if recorded by uv.
+    pub fn python_key(&self) -> Option<&str> {
+        self.python_key.as_deref()
+    }
+
+    /// Returns the seed packages installed into the virtual environment, if recorded by uv.
+    pub fn seed_packages(&self) -> Option<&str> {
+        self.seed_packages.as_deref()
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
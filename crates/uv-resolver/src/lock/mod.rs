@@ -20,7 +20,7 @@ use tracing::debug;
 use url::Url;
 
 use uv_cache_key::RepositoryUrl;
-use uv_configuration::{BuildOptions, Constraints};
+use uv_configuration::{BuildOptions, Constraints, TargetTriple};
 use uv_distribution::{DistributionDatabase, FlatRequiresDist};
 use uv_distribution_filename::{
     BuildTag, DistExtension, ExtensionError, SourceDistExtension, WheelFilename,
@@ -668,6 +668,16 @@ impl Lock {
         &self.manifest.members
     }
 
+    /// Returns the packages that were excluded from this lock, as they're provided externally.
+    pub fn external(&self) -> &BTreeSet<PackageName> {
+        &self.manifest.external
+    }
+
+    /// Returns the target platform for which this lock was generated, if any.
+    pub fn python_platform(&self) -> Option<TargetTriple> {
+        self.manifest.python_platform
+    }
+
     /// Returns the dependency groups that were used to generate this lock.
     pub fn requirements(&self) -> &BTreeSet<Requirement> {
         &self.manifest.requirements
@@ -1026,6 +1036,28 @@ impl Lock {
                 manifest_table.insert("dependency-metadata", Item::ArrayOfTables(tables));
             }
 
+            if !self.manifest.external.is_empty() {
+                manifest_table.insert(
+                    "external",
+                    value(each_element_on_its_line_array(
+                        self.manifest
+                            .external
+                            .iter()
+                            .map(std::string::ToString::to_string),
+                    )),
+                );
+            }
+
+            if let Some(python_platform) = self.manifest.python_platform {
+                manifest_table.insert(
+                    "python-platform",
+                    value(serde::Serialize::serialize(
+                        &python_platform,
+                        toml_edit::ser::ValueSerializer::new(),
+                    )?),
+                );
+            }
+
             if !manifest_table.is_empty() {
                 doc.insert("manifest", Item::Table(manifest_table));
             }
@@ -1226,6 +1258,7 @@ impl Lock {
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
+        external: &[PackageName],
         indexes: Option<&IndexLocations>,
         tags: &Tags,
         hasher: &HashStrategy,
@@ -1387,6 +1420,15 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same external packages.
+        {
+            let expected = external.iter().cloned().collect::<BTreeSet<_>>();
+            let actual = &self.manifest.external;
+            if expected != *actual {
+                return Ok(SatisfiesResult::MismatchedExternal(expected, actual));
+            }
+        }
+
         // Collect the set of available indexes (both `--index-url` and `--find-links` entries).
         let remotes = indexes.map(|locations| {
             locations
@@ -1751,6 +1793,8 @@ pub enum SatisfiesResult<'lock> {
     ),
     /// The lockfile uses different static metadata.
     MismatchedStaticMetadata(BTreeSet<StaticMetadata>, &'lock BTreeSet<StaticMetadata>),
+    /// The lockfile uses a different set of external packages.
+    MismatchedExternal(BTreeSet<PackageName>, &'lock BTreeSet<PackageName>),
     /// The lockfile is missing a workspace member.
     MissingRoot(PackageName),
     /// The lockfile referenced a remote index that was not provided
@@ -1830,6 +1874,12 @@ pub struct ResolverManifest {
     /// The static metadata provided to the resolver.
     #[serde(default)]
     dependency_metadata: BTreeSet<StaticMetadata>,
+    /// The packages that were excluded from the resolution, as they're provided externally.
+    #[serde(default)]
+    external: BTreeSet<PackageName>,
+    /// The target platform for which the resolution was performed, if any.
+    #[serde(default)]
+    python_platform: Option<TargetTriple>,
 }
 
 impl ResolverManifest {
@@ -1843,6 +1893,8 @@ impl ResolverManifest {
         build_constraints: impl IntoIterator<Item = Requirement>,
         dependency_groups: impl IntoIterator<Item = (GroupName, Vec<Requirement>)>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
+        external: impl IntoIterator<Item = PackageName>,
+        python_platform: Option<TargetTriple>,
     ) -> Self {
         Self {
             members: members.into_iter().collect(),
@@ -1855,6 +1907,8 @@ impl ResolverManifest {
                 .map(|(group, requirements)| (group, requirements.into_iter().collect()))
                 .collect(),
             dependency_metadata: dependency_metadata.into_iter().collect(),
+            external: external.into_iter().collect(),
+            python_platform,
         }
     }
 
@@ -1896,6 +1950,8 @@ impl ResolverManifest {
                 })
                 .collect::<Result<BTreeMap<_, _>, _>>()?,
             dependency_metadata: self.dependency_metadata,
+            external: self.external,
+            python_platform: self.python_platform,
         })
     }
 }
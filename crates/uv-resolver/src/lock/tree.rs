@@ -8,6 +8,7 @@ use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use petgraph::{Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use serde::Serialize;
 
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -18,12 +19,28 @@ use uv_pypi_types::ResolverMarkerEnvironment;
 use crate::lock::PackageId;
 use crate::{Lock, PackageMap};
 
+/// The maximum number of paths to collect for each package with `--why`.
+///
+/// The number of paths to a package can grow exponentially with the depth of the graph, so the
+/// traversal stops once the limit is reached.
+const MAX_WHY_PATHS: usize = 100;
+
+/// The maximum number of dependency cycles to report with `--cycles`.
+///
+/// Like the paths to a package, the number of cycles can grow exponentially with the size of the
+/// graph.
+const MAX_CYCLES: usize = 100;
+
 #[derive(Debug)]
 pub struct TreeDisplay<'env> {
     /// The constructed dependency graph.
     graph: petgraph::graph::Graph<Node<'env>, Edge<'env>, petgraph::Directed>,
+    /// The root project of the lockfile, if any.
+    root: Option<&'env PackageId>,
     /// The packages considered as roots of the dependency tree.
     roots: Vec<NodeIndex>,
+    /// The packages for which to display the paths from the roots, rather than the tree.
+    why: Vec<NodeIndex>,
     /// The latest known version of each package.
     latest: &'env PackageMap<Version>,
     /// Maximum display depth of the dependency tree.
@@ -41,6 +58,7 @@ impl<'env> TreeDisplay<'env> {
         depth: usize,
         prune: &[PackageName],
         packages: &[PackageName],
+        why: &[PackageName],
         dev: &DependencyGroupsWithDefaults,
         no_dedupe: bool,
         invert: bool,
@@ -332,7 +350,7 @@ impl<'env> TreeDisplay<'env> {
 
         // Filter the graph to those nodes reachable from the target packages.
         if !packages.is_empty() {
-            let mut reachable = graph
+            let targets = graph
                 .node_indices()
                 .filter(|index| {
                     let Node::Package(package_id) = graph[*index] else {
@@ -341,6 +359,7 @@ impl<'env> TreeDisplay<'env> {
                     packages.contains(&package_id.name)
                 })
                 .collect::<FxHashSet<_>>();
+            let mut reachable = targets.clone();
             let mut stack = reachable.iter().copied().collect::<VecDeque<_>>();
             while let Some(node) = stack.pop_front() {
                 for edge in graph.edges_directed(node, Direction::Outgoing) {
@@ -350,6 +369,32 @@ impl<'env> TreeDisplay<'env> {
                 }
             }
 
+            // Unless the graph is inverted, retain the paths from the roots to the target
+            // packages, i.e., the packages that depend on them.
+            if !invert {
+                let mut ancestors = FxHashSet::default();
+                let mut stack = targets.iter().copied().collect::<VecDeque<_>>();
+                while let Some(node) = stack.pop_front() {
+                    for edge in graph.edges_directed(node, Direction::Incoming) {
+                        if ancestors.insert(edge.source()) {
+                            stack.push_back(edge.source());
+                        }
+                    }
+                }
+
+                // Remove any edges from the ancestors that lead away from the target packages.
+                graph.retain_edges(|graph, edge| {
+                    let Some((source, target)) = graph.edge_endpoints(edge) else {
+                        return false;
+                    };
+                    reachable.contains(&source)
+                        || targets.contains(&target)
+                        || ancestors.contains(&target)
+                });
+
+                reachable.extend(ancestors);
+            }
+
             // Remove the unreachable nodes from the graph.
             graph.retain_nodes(|_, index| reachable.contains(&index));
         }
@@ -392,9 +437,23 @@ impl<'env> TreeDisplay<'env> {
             roots
         };
 
+        // Identify the packages whose paths should be displayed, if any.
+        let why = graph
+            .node_indices()
+            .filter(|index| {
+                let Node::Package(package_id) = graph[*index] else {
+                    return false;
+                };
+                why.contains(&package_id.name)
+            })
+            .sorted_by_key(|index| &graph[*index])
+            .collect();
+
         Self {
             graph,
+            root: lock.root().map(|package| &package.id),
             roots,
+            why,
             latest,
             depth,
             no_dedupe,
@@ -418,37 +477,7 @@ impl<'env> TreeDisplay<'env> {
         };
         let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
 
-        let line = {
-            let mut line = format!("{}", package_id.name);
-
-            if let Some(extras) = edge.and_then(Edge::extras) {
-                if !extras.is_empty() {
-                    line.push('[');
-                    line.push_str(extras.iter().join(", ").as_str());
-                    line.push(']');
-                }
-            }
-
-            if let Some(version) = package_id.version.as_ref() {
-                line.push(' ');
-                line.push('v');
-                let _ = write!(line, "{version}");
-            }
-
-            if let Some(edge) = edge {
-                match edge {
-                    Edge::Prod(_) => {}
-                    Edge::Optional(extra, _) => {
-                        let _ = write!(line, " (extra: {extra})");
-                    }
-                    Edge::Dev(group, _) => {
-                        let _ = write!(line, " (group: {group})");
-                    }
-                }
-            }
-
-            line
-        };
+        let line = format_package(package_id, edge);
 
         // Skip the traversal if:
         // 1. The package is in the current traversal path (i.e., a dependency cycle).
@@ -470,22 +499,15 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
-        let mut dependencies = self
-            .graph
-            .edges_directed(cursor.node(), Direction::Outgoing)
-            .filter_map(|edge| match self.graph[edge.target()] {
-                Node::Root => None,
-                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
-            })
-            .collect::<Vec<_>>();
-        dependencies.sort_by_key(|cursor| {
-            let node = &self.graph[cursor.node()];
-            let edge = cursor
-                .edge()
-                .map(|edge_id| &self.graph[edge_id])
-                .map(Edge::kind);
-            (edge, node)
-        });
+        let dependencies = self.dependencies(cursor.node());
+
+        // If we've reached the maximum depth, summarize the dependencies rather than displaying
+        // them.
+        if path.len() == self.depth && !dependencies.is_empty() {
+            let count = dependencies.iter().map(Cursor::node).unique().count();
+            let s = if count == 1 { "y" } else { "ies" };
+            return vec![line, format!("└── [{count} more dependenc{s}...]")];
+        }
 
         let mut lines = vec![line];
 
@@ -543,8 +565,220 @@ impl<'env> TreeDisplay<'env> {
         lines
     }
 
+    /// Perform a depth-first traversal of the given package and its dependencies, collecting them
+    /// into a [`TreeNode`].
+    fn visit_json(
+        &'env self,
+        cursor: Cursor,
+        visited: &mut FxHashMap<&'env PackageId, Vec<&'env PackageId>>,
+        path: &mut Vec<&'env PackageId>,
+    ) -> Option<TreeNode<'env>> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return None;
+        }
+
+        let Node::Package(package_id) = self.graph[cursor.node()] else {
+            return None;
+        };
+        let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
+
+        let mut node = TreeNode {
+            id: package_id,
+            name: &package_id.name,
+            version: package_id.version.as_ref(),
+            extras: edge.and_then(Edge::extras).into_iter().flatten().collect(),
+            latest: None,
+            deduped: false,
+            dependencies: Vec::new(),
+        };
+
+        // Skip the traversal if the package is part of a dependency cycle, or has already been
+        // visited and de-duplication is enabled (default).
+        if let Some(requirements) = visited.get(package_id) {
+            if !self.no_dedupe || path.contains(&package_id) {
+                node.deduped = !requirements.is_empty();
+                return Some(node);
+            }
+        }
+
+        // Incorporate the latest version of the package, if known.
+        node.latest = self.latest.get(package_id);
+
+        let dependencies = self.dependencies(cursor.node());
+
+        // Keep track of the dependency path to avoid cycles.
+        visited.insert(
+            package_id,
+            dependencies
+                .iter()
+                .filter_map(|node| match self.graph[node.node()] {
+                    Node::Package(package_id) => Some(package_id),
+                    Node::Root => None,
+                })
+                .collect(),
+        );
+        path.push(package_id);
+
+        for dep in &dependencies {
+            node.dependencies
+                .extend(self.visit_json(*dep, visited, path));
+        }
+
+        path.pop();
+
+        Some(node)
+    }
+
+    /// Extend the current path from the given package, collecting every elementary cycle that
+    /// returns to `start` without visiting any package that ranks before it.
+    fn visit_cycles(
+        &self,
+        start: NodeIndex,
+        node: NodeIndex,
+        rank: &FxHashMap<NodeIndex, usize>,
+        path: &mut Vec<NodeIndex>,
+        cycles: &mut Vec<Vec<NodeIndex>>,
+    ) {
+        path.push(node);
+
+        for dependency in self
+            .dependencies(node)
+            .iter()
+            .map(Cursor::node)
+            .unique()
+            // A package that depends on its own extras is not a cycle.
+            .filter(|dependency| *dependency != node)
+        {
+            if cycles.len() >= MAX_CYCLES {
+                break;
+            }
+            if dependency == start {
+                let mut cycle = path.clone();
+                cycle.push(start);
+                cycles.push(cycle);
+            } else if rank
+                .get(&dependency)
+                .is_some_and(|rank_of| *rank_of > rank[&start])
+                && !path.contains(&dependency)
+            {
+                self.visit_cycles(start, dependency, rank, path, cycles);
+            }
+        }
+
+        path.pop();
+    }
+
+    /// Return the dependencies of the given node, sorted by the kind of edge and the package.
+    fn dependencies(&self, node: NodeIndex) -> Vec<Cursor> {
+        let mut dependencies = self
+            .graph
+            .edges_directed(node, Direction::Outgoing)
+            .filter_map(|edge| match self.graph[edge.target()] {
+                Node::Root => None,
+                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|cursor| {
+            let node = &self.graph[cursor.node()];
+            let edge = cursor
+                .edge()
+                .map(|edge_id| &self.graph[edge_id])
+                .map(Edge::kind);
+            (edge, node)
+        });
+        dependencies
+    }
+
+    /// Collect the paths from the roots to the given node, by traversing the graph in reverse.
+    ///
+    /// Each path is stored from the root to the target, with the edge that led to each node. At
+    /// most [`MAX_WHY_PATHS`] paths are collected.
+    fn collect_paths(
+        &self,
+        path: &mut Vec<(NodeIndex, Option<EdgeIndex>)>,
+        paths: &mut Vec<Vec<(NodeIndex, Option<EdgeIndex>)>>,
+    ) {
+        let Some(&(node, _)) = path.last() else {
+            return;
+        };
+
+        for edge in self.graph.edges_directed(node, Direction::Incoming) {
+            if paths.len() >= MAX_WHY_PATHS {
+                return;
+            }
+
+            // Skip any dependency cycles.
+            if path.iter().any(|(node, _)| *node == edge.source()) {
+                continue;
+            }
+
+            if let Some(last) = path.last_mut() {
+                last.1 = Some(edge.id());
+            }
+            match self.graph[edge.source()] {
+                Node::Root => {
+                    paths.push(path.iter().rev().copied().collect());
+                }
+                Node::Package(_) => {
+                    path.push((edge.source(), None));
+                    self.collect_paths(path, paths);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Render the paths from the roots to each of the requested packages.
+    fn render_why(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for target in &self.why {
+            let mut paths = Vec::new();
+            self.collect_paths(&mut vec![(*target, None)], &mut paths);
+            let truncated = paths.len() >= MAX_WHY_PATHS;
+
+            lines.extend(
+                paths
+                    .into_iter()
+                    .map(|path| {
+                        path.into_iter()
+                            .filter_map(|(node, edge)| match self.graph[node] {
+                                Node::Package(package_id) => Some(format_package(
+                                    package_id,
+                                    edge.map(|edge| &self.graph[edge]),
+                                )),
+                                Node::Root => None,
+                            })
+                            .join(" → ")
+                    })
+                    .sorted()
+                    .dedup(),
+            );
+
+            if truncated {
+                if let Node::Package(package_id) = self.graph[*target] {
+                    lines.push(
+                        format!(
+                            "(only the first {MAX_WHY_PATHS} paths to {} are shown)",
+                            package_id.name
+                        )
+                        .dimmed()
+                        .to_string(),
+                    );
+                }
+            }
+        }
+
+        lines
+    }
+
     /// Depth-first traverse the nodes to render the tree.
     fn render(&self) -> Vec<String> {
+        if !self.why.is_empty() {
+            return self.render_why();
+        }
+
         let mut path = Vec::new();
         let mut lines = Vec::with_capacity(self.graph.node_count());
         let mut visited =
@@ -572,6 +806,179 @@ impl<'env> TreeDisplay<'env> {
 
         lines
     }
+
+    /// Find the elementary dependency cycles among the packages reachable from the roots, i.e.,
+    /// the cycles that don't pass through any package more than once.
+    ///
+    /// Each cycle is reported once, starting from its first package in sorted order, and rendered
+    /// as a single line, e.g., `a v1.0.0 → b v1.0.0 → a v1.0.0`. At most [`MAX_CYCLES`] cycles are
+    /// reported.
+    pub fn cycles(&self) -> Vec<String> {
+        // Find the packages that are reachable from the roots.
+        let mut reachable = FxHashSet::default();
+        let mut queue = self.roots.clone();
+        while let Some(node) = queue.pop() {
+            for edge in self.graph.edges_directed(node, Direction::Outgoing) {
+                if matches!(self.graph[edge.target()], Node::Package(_))
+                    && reachable.insert(edge.target())
+                {
+                    queue.push(edge.target());
+                }
+            }
+            if matches!(self.graph[node], Node::Package(_)) {
+                reachable.insert(node);
+            }
+        }
+
+        // Search for the cycles through each package in turn, only visiting packages that rank
+        // after it, such that each cycle is found from its first package, and only once.
+        let ranked = reachable
+            .into_iter()
+            .sorted_by(|a, b| self.graph[*a].cmp(&self.graph[*b]))
+            .collect::<Vec<_>>();
+        let rank = ranked
+            .iter()
+            .enumerate()
+            .map(|(rank, node)| (*node, rank))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut path = Vec::new();
+        let mut cycles = Vec::new();
+        for node in ranked {
+            if cycles.len() >= MAX_CYCLES {
+                break;
+            }
+            self.visit_cycles(node, node, &rank, &mut path, &mut cycles);
+        }
+
+        cycles
+            .into_iter()
+            .map(|cycle| {
+                cycle
+                    .into_iter()
+                    .filter_map(|node| match self.graph[node] {
+                        Node::Package(package_id) => Some(format_package(package_id, None)),
+                        Node::Root => None,
+                    })
+                    .join(" → ")
+            })
+            .collect()
+    }
+
+    /// Depth-first traverse the nodes to build a [`TreeJson`] for serialization.
+    pub fn to_json(&self) -> TreeJson<'_> {
+        let mut path = Vec::new();
+        let mut nodes = Vec::with_capacity(self.roots.len());
+        let mut visited =
+            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+
+        for node in &self.roots {
+            match self.graph[*node] {
+                Node::Root => {
+                    for edge in self.graph.edges_directed(*node, Direction::Outgoing) {
+                        let node = edge.target();
+                        path.clear();
+                        nodes.extend(self.visit_json(
+                            Cursor::new(node, edge.id()),
+                            &mut visited,
+                            &mut path,
+                        ));
+                    }
+                }
+                Node::Package(_) => {
+                    path.clear();
+                    nodes.extend(self.visit_json(Cursor::root(*node), &mut visited, &mut path));
+                }
+            }
+        }
+
+        // If the tree consists solely of the root project, use it as the top-level object.
+        // Otherwise, nest the displayed packages under the root project.
+        if let [node] = nodes.as_mut_slice() {
+            if self.root == Some(node.id) {
+                return TreeJson {
+                    name: Some(node.name),
+                    version: node.version,
+                    dependencies: std::mem::take(&mut node.dependencies),
+                };
+            }
+        }
+
+        TreeJson {
+            name: self.root.map(|root| &root.name),
+            version: self.root.and_then(|root| root.version.as_ref()),
+            dependencies: nodes,
+        }
+    }
+}
+
+/// The dependency tree, in a form suitable for JSON serialization.
+#[derive(Debug, Serialize)]
+pub struct TreeJson<'env> {
+    /// The name of the root project, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'env PackageName>,
+    /// The version of the root project, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'env Version>,
+    /// The top-level dependencies.
+    dependencies: Vec<TreeNode<'env>>,
+}
+
+/// A package in the dependency tree, in a form suitable for JSON serialization.
+#[derive(Debug, Serialize)]
+pub struct TreeNode<'env> {
+    #[serde(skip)]
+    id: &'env PackageId,
+    /// The name of the package.
+    name: &'env PackageName,
+    /// The version of the package, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'env Version>,
+    /// The extras that were requested for the package.
+    extras: Vec<&'env ExtraName>,
+    /// The latest version of the package, if it's outdated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<&'env Version>,
+    /// Whether the package's dependencies were omitted, as they're displayed elsewhere in the tree.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deduped: bool,
+    /// The dependencies of the package.
+    dependencies: Vec<TreeNode<'env>>,
+}
+
+/// Format a package for display, along with the extras and group or extra of the edge that led
+/// to it.
+fn format_package(package_id: &PackageId, edge: Option<&Edge>) -> String {
+    let mut line = format!("{}", package_id.name);
+
+    if let Some(extras) = edge.and_then(Edge::extras) {
+        if !extras.is_empty() {
+            line.push('[');
+            line.push_str(extras.iter().join(", ").as_str());
+            line.push(']');
+        }
+    }
+
+    if let Some(version) = package_id.version.as_ref() {
+        line.push(' ');
+        line.push('v');
+        let _ = write!(line, "{version}");
+    }
+
+    if let Some(edge) = edge {
+        match edge {
+            Edge::Prod(_) => {}
+            Edge::Optional(extra, _) => {
+                let _ = write!(line, " (extra: {extra})");
+            }
+            Edge::Dev(group, _) => {
+                let _ = write!(line, " (group: {group})");
+            }
+        }
+    }
+
+    line
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
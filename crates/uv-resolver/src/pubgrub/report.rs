@@ -828,6 +828,10 @@ impl PubGrubReportFormatter<'_> {
             Some(UnavailablePackage::NoIndex) => {
                 if no_find_links {
                     hints.insert(PubGrubHint::NoIndex);
+                } else {
+                    hints.insert(PubGrubHint::NotFoundInLocalSources {
+                        package: name.clone(),
+                    });
                 }
             }
             Some(UnavailablePackage::Offline) => {
@@ -1005,6 +1009,9 @@ pub(crate) enum PubGrubHint {
     /// Requirements were unavailable due to lookups in the index being disabled and no extra
     /// index was provided via `--find-links`
     NoIndex,
+    /// A package was not found in the `--find-links` locations, and lookups in the index were
+    /// disabled.
+    NotFoundInLocalSources { package: PackageName },
     /// A package was not found in the registry, but network access was disabled.
     Offline,
     /// Metadata for a package could not be parsed.
@@ -1151,6 +1158,9 @@ enum PubGrubHintCore {
         package: PackageName,
     },
     NoIndex,
+    NotFoundInLocalSources {
+        package: PackageName,
+    },
     Offline,
     InvalidPackageMetadata {
         package: PackageName,
@@ -1220,6 +1230,9 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 Self::PrereleaseRequested { package }
             }
             PubGrubHint::NoIndex => Self::NoIndex,
+            PubGrubHint::NotFoundInLocalSources { package } => {
+                Self::NotFoundInLocalSources { package }
+            }
             PubGrubHint::Offline => Self::Offline,
             PubGrubHint::InvalidPackageMetadata { package, .. } => {
                 Self::InvalidPackageMetadata { package }
@@ -1322,6 +1335,16 @@ impl std::fmt::Display for PubGrubHint {
                     "--find-links <uri>".green(),
                 )
             }
+            Self::NotFoundInLocalSources { package } => {
+                write!(
+                    f,
+                    "{}{} Package `{}` not found in local sources and `{}` is set",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    "--no-index".green(),
+                )
+            }
             Self::Offline => {
                 write!(
                     f,
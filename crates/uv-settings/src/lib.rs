@@ -3,13 +3,16 @@ use std::path::{Path, PathBuf};
 
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_fs::Simplified;
+use uv_options_metadata::OptionsMetadata;
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
 pub use crate::settings::*;
+pub use crate::unknown::UnknownKey;
 
 mod combine;
 mod settings;
+mod unknown;
 
 /// The [`Options`] as loaded from a configuration file on disk.
 #[derive(Debug, Clone)]
@@ -71,6 +74,13 @@ impl FilesystemOptions {
         Ok(Some(Self(options)))
     }
 
+    /// Parse and validate the contents of a `uv.toml` file located at the given path.
+    pub fn from_uv_toml(path: &Path, content: &str) -> Result<Self, Error> {
+        let options = parse_uv_toml(path, content)?;
+        validate_uv_toml(path, &options)?;
+        Ok(Self(options))
+    }
+
     /// Find the [`FilesystemOptions`] for the given path.
     ///
     /// The search starts at the given path and goes up the directory tree until a `uv.toml` file or
@@ -108,9 +118,8 @@ impl FilesystemOptions {
         let path = dir.join("uv.toml");
         match fs_err::read_to_string(&path) {
             Ok(content) => {
-                let options = toml::from_str::<Options>(&content)
-                    .map_err(|err| Error::UvToml(path.clone(), Box::new(err)))?
-                    .relative_to(&std::path::absolute(dir)?)?;
+                let options =
+                    parse_uv_toml(&path, &content)?.relative_to(&std::path::absolute(dir)?)?;
 
                 // If the directory also contains a `[tool.uv]` table in a `pyproject.toml` file,
                 // warn.
@@ -183,8 +192,7 @@ impl From<Options> for FilesystemOptions {
 /// Load [`Options`] from a `uv.toml` file.
 fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
-    let options = toml::from_str::<Options>(&content)
-        .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+    let options = parse_uv_toml(path, &content)?;
     let options = if let Some(parent) = std::path::absolute(path)?.parent() {
         options.relative_to(parent)?
     } else {
@@ -193,6 +201,40 @@ fn read_file(path: &Path) -> Result<Options, Error> {
     Ok(options)
 }
 
+/// Parse the contents of a `uv.toml` file.
+fn parse_uv_toml(path: &Path, content: &str) -> Result<Options, Error> {
+    toml::from_str::<Options>(content).map_err(|err| {
+        // Serde stops at the first unknown key, so parse again to collect all of them, along with
+        // a suggestion for each.
+        let keys = toml::Deserializer::new(content);
+        match unknown::unknown_keys::<Options, _>(keys) {
+            Ok(keys) if keys.iter().any(|key| key.suggestion.is_some()) => Error::UnknownKeys {
+                path: path.to_path_buf(),
+                keys,
+                err: Box::new(err),
+            },
+            _ => Error::UvToml(path.to_path_buf(), Box::new(err)),
+        }
+    })
+}
+
+/// Format a list of unknown keys, e.g., "keys: `cache_dir` (did you mean `cache-dir`?), `foo`".
+fn format_unknown_keys(keys: &[UnknownKey]) -> String {
+    let s = if keys.len() == 1 { "" } else { "s" };
+    format!(
+        "key{s}: {}",
+        keys.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Returns `true` if the dotted key (e.g., `pip.index-url`) names a known setting.
+pub fn is_setting(key: &str) -> bool {
+    Options::metadata().has(key)
+}
+
 /// Validate that an [`Options`] schema is compatible with `uv.toml`.
 fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -239,6 +281,14 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to parse: `{}`. Unknown configuration {}", path.user_display(), format_unknown_keys(keys))]
+    UnknownKeys {
+        path: PathBuf,
+        keys: Vec<UnknownKey>,
+        #[source]
+        err: Box<toml::de::Error>,
+    },
+
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1)]
     PyprojectOnlyField(PathBuf, &'static str),
 }
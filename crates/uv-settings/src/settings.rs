@@ -5,8 +5,8 @@ use url::Url;
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing,
+    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PythonPin,
+    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing,
 };
 use uv_distribution_types::{
     Index, IndexUrl, IndexUrlError, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
@@ -16,7 +16,10 @@ use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::Requirement;
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{
+    ImplementationName, InstallMirrors, MirrorList, PythonDownloads, PythonPreference,
+    PythonVersion,
+};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode};
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -100,6 +103,21 @@ pub struct Options {
     )]
     cache_keys: Option<Vec<CacheKey>>,
 
+    /// The version to record in the `.python-version` file created by `uv init`.
+    ///
+    /// By default, uv pins the minor version of the discovered Python interpreter (`minor`). Use
+    /// `patch` to pin the exact patch version of the interpreter instead, or `none` to avoid
+    /// creating a `.python-version` file, in which case only `requires-python` is recorded.
+    #[option(
+        default = "\"minor\"",
+        value_type = "str",
+        example = r#"
+            python-pin = "patch"
+        "#,
+        possible_values = true
+    )]
+    pub python_pin: Option<PythonPin>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -790,35 +808,69 @@ impl ResolverInstallerOptions {
 pub struct PythonInstallMirrors {
     /// Mirror URL for downloading managed Python installations.
     ///
+    /// Deprecated alias for the `cpython` entry of [`python-install-mirrors`](#python-install-mirrors),
+    /// which takes precedence over the table when both are set.
+    ///
     /// By default, managed Python installations are downloaded from [`python-build-standalone`](https://github.com/astral-sh/python-build-standalone).
     /// This variable can be set to a mirror URL to use a different source for Python installations.
     /// The provided URL will replace `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g., `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
     ///
+    /// A list of mirrors can be provided, in which case each mirror is tried in order until the
+    /// download succeeds. If every mirror is unavailable, the default source is used.
+    ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     #[option(
         default = "None",
-        value_type = "str",
+        value_type = "str | list[str]",
         example = r#"
-            python-install-mirror = "https://github.com/astral-sh/python-build-standalone/releases/download"
+            python-install-mirror = [
+                "https://mirror.example.com/python-build-standalone",
+                "https://backup.example.com/python-build-standalone",
+            ]
         "#
     )]
-    pub python_install_mirror: Option<String>,
+    pub python_install_mirror: Option<MirrorList>,
     /// Mirror URL to use for downloading managed PyPy installations.
     ///
+    /// Deprecated alias for the `pypy` entry of [`python-install-mirrors`](#python-install-mirrors),
+    /// which takes precedence over the table when both are set.
+    ///
     /// By default, managed PyPy installations are downloaded from [downloads.python.org](https://downloads.python.org/).
     /// This variable can be set to a mirror URL to use a different source for PyPy installations.
     /// The provided URL will replace `https://downloads.python.org/pypy` in, e.g., `https://downloads.python.org/pypy/pypy3.8-v7.3.7-osx64.tar.bz2`.
     ///
+    /// A list of mirrors can be provided, in which case each mirror is tried in order until the
+    /// download succeeds. If every mirror is unavailable, the default source is used.
+    ///
     /// Distributions can be read from a
     /// local directory by using the `file://` URL scheme.
     #[option(
         default = "None",
-        value_type = "str",
+        value_type = "str | list[str]",
         example = r#"
             pypy-install-mirror = "https://downloads.python.org/pypy"
         "#
     )]
-    pub pypy_install_mirror: Option<String>,
+    pub pypy_install_mirror: Option<MirrorList>,
+    /// Mirror URLs for downloading managed Python installations, by implementation.
+    ///
+    /// Each key is an implementation name (`cpython`, `pypy`, or `graalpy`), or `default` to
+    /// provide mirrors for any implementation without its own entry. Each value is a mirror URL or
+    /// a list of mirror URLs, which replace the upstream download location for that implementation,
+    /// as described for [`python-install-mirror`](#python-install-mirror).
+    ///
+    /// Implementation-specific environment variables, e.g., `UV_PYPY_INSTALL_MIRROR`, take
+    /// precedence over the corresponding entry.
+    #[option(
+        default = "None",
+        value_type = "dict[str, str | list[str]]",
+        scope = "python-install-mirrors",
+        example = r#"
+            cpython = "https://cpython.mirror.example.com/python-build-standalone"
+            pypy = "https://pypy.mirror.example.com/pypy"
+        "#
+    )]
+    pub python_install_mirrors: Option<InstallMirrors>,
 
     /// URL pointing to JSON of custom Python installations.
     ///
@@ -831,30 +883,80 @@ pub struct PythonInstallMirrors {
         "#
     )]
     pub python_downloads_json_url: Option<String>,
+
+    /// Whether to mark managed Python installations as externally managed.
+    ///
+    /// By default, `uv python install` writes the standard `EXTERNALLY-MANAGED` file ([PEP 668](https://peps.python.org/pep-0668/))
+    /// to each installation, such that `pip` refuses to install packages into the managed
+    /// interpreter. Set to `false` to allow `pip` to modify the installations instead.
+    ///
+    /// Installations that are already present, or that upgrade an installation of the same minor
+    /// version, retain the state of the existing installation unless this setting is provided.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            python-externally-managed = false
+        "#
+    )]
+    pub python_externally_managed: Option<bool>,
 }
 
 impl Default for PythonInstallMirrors {
     fn default() -> Self {
-        PythonInstallMirrors::resolve(None, None, None)
+        PythonInstallMirrors::resolve(None, None, None, None)
     }
 }
 
 impl PythonInstallMirrors {
     pub fn resolve(
-        python_mirror: Option<String>,
-        pypy_mirror: Option<String>,
+        python_mirror: Option<MirrorList>,
+        pypy_mirror: Option<MirrorList>,
+        mirrors: Option<InstallMirrors>,
         python_downloads_json_url: Option<String>,
     ) -> Self {
-        let python_mirror_env = std::env::var(EnvVars::UV_PYTHON_INSTALL_MIRROR).ok();
-        let pypy_mirror_env = std::env::var(EnvVars::UV_PYPY_INSTALL_MIRROR).ok();
+        let python_mirror_env = std::env::var(EnvVars::UV_PYTHON_INSTALL_MIRROR)
+            .ok()
+            .and_then(|mirror| mirror.parse().ok());
+        let pypy_mirror_env = std::env::var(EnvVars::UV_PYPY_INSTALL_MIRROR)
+            .ok()
+            .and_then(|mirror| mirror.parse().ok());
+        let graalpy_mirror_env = std::env::var(EnvVars::UV_GRAALPY_INSTALL_MIRROR)
+            .ok()
+            .and_then(|mirror| mirror.parse().ok());
         let python_downloads_json_url_env =
             std::env::var(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL).ok();
+        let mirrors = match graalpy_mirror_env {
+            Some(mirror) => Some(
+                mirrors
+                    .unwrap_or_default()
+                    .with_implementation(ImplementationName::GraalPy, Some(mirror)),
+            ),
+            None => mirrors,
+        };
         PythonInstallMirrors {
             python_install_mirror: python_mirror_env.or(python_mirror),
             pypy_install_mirror: pypy_mirror_env.or(pypy_mirror),
+            python_install_mirrors: mirrors,
             python_downloads_json_url: python_downloads_json_url_env.or(python_downloads_json_url),
+            python_externally_managed: None,
         }
     }
+
+    /// Return the mirrors to use for each implementation.
+    ///
+    /// The `python-install-mirror` and `pypy-install-mirror` settings override the `cpython` and
+    /// `pypy` entries of `python-install-mirrors`, respectively.
+    pub fn mirrors(&self) -> InstallMirrors {
+        self.python_install_mirrors
+            .clone()
+            .unwrap_or_default()
+            .with_implementation(
+                ImplementationName::CPython,
+                self.python_install_mirror.clone(),
+            )
+            .with_implementation(ImplementationName::PyPy, self.pypy_install_mirror.clone())
+    }
 }
 
 /// Settings that are specific to the `uv pip` command-line interface.
@@ -1831,9 +1933,11 @@ pub struct OptionsWire {
 
     // #[serde(flatten)]
     // install_mirror: PythonInstallMirrors,
-    python_install_mirror: Option<String>,
-    pypy_install_mirror: Option<String>,
+    python_install_mirror: Option<MirrorList>,
+    pypy_install_mirror: Option<MirrorList>,
+    python_install_mirrors: Option<InstallMirrors>,
     python_downloads_json_url: Option<String>,
+    python_externally_managed: Option<bool>,
 
     // #[serde(flatten)]
     // publish: PublishOptions
@@ -1843,6 +1947,7 @@ pub struct OptionsWire {
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    python_pin: Option<PythonPin>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -1881,7 +1986,9 @@ impl From<OptionsWire> for Options {
             python_downloads,
             python_install_mirror,
             pypy_install_mirror,
+            python_install_mirrors,
             python_downloads_json_url,
+            python_externally_managed,
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
@@ -1914,6 +2021,7 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             pip,
             cache_keys,
+            python_pin,
             override_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
@@ -1979,17 +2087,22 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            python_pin,
             build_backend,
             override_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
             environments,
             required_environments,
-            install_mirrors: PythonInstallMirrors::resolve(
-                python_install_mirror,
-                pypy_install_mirror,
-                python_downloads_json_url,
-            ),
+            install_mirrors: PythonInstallMirrors {
+                python_externally_managed,
+                ..PythonInstallMirrors::resolve(
+                    python_install_mirror,
+                    pypy_install_mirror,
+                    python_install_mirrors,
+                    python_downloads_json_url,
+                )
+            },
             conflicts,
             publish: PublishOptions {
                 publish_url,
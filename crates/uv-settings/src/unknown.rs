@@ -0,0 +1,353 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use serde::de::value::StringDeserializer;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+/// A key that is not part of the schema, e.g., `cache_dir`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKey {
+    /// The dotted key, e.g., `pip.index_url`.
+    pub key: String,
+    /// The most similar known key, e.g., `pip.index-url`, if any.
+    pub suggestion: Option<String>,
+}
+
+impl UnknownKey {
+    fn new(prefix: &str, key: &str, fields: &[&str]) -> Self {
+        // Allow up to one edit per three characters, e.g., a transposition in a short key, or a
+        // mix-up of underscores and hyphens in a long one.
+        let max_distance = (key.len() / 3).max(1);
+        let normalized = key.to_lowercase().replace('_', "-");
+        let suggestion = fields
+            .iter()
+            .map(|candidate| (strsim::levenshtein(&normalized, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| join(prefix, candidate));
+        Self {
+            key: join(prefix, key),
+            suggestion,
+        }
+    }
+}
+
+impl fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.suggestion {
+            Some(suggestion) => write!(f, "`{}` (did you mean `{suggestion}`?)", self.key),
+            None => write!(f, "`{}`", self.key),
+        }
+    }
+}
+
+/// Deserialize a value with the given deserializer, returning every key that is not a field of
+/// the struct that contains it.
+///
+/// Unlike `#[serde(deny_unknown_fields)]`, which fails on the first unknown key, unknown keys are
+/// skipped, such that all of them can be reported at once.
+pub(crate) fn unknown_keys<'de, T, D>(deserializer: D) -> Result<Vec<UnknownKey>, D::Error>
+where
+    T: de::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let unknown = RefCell::new(Vec::new());
+    T::deserialize(Collect {
+        inner: deserializer,
+        prefix: String::new(),
+        unknown: &unknown,
+    })?;
+    Ok(unknown.into_inner())
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+/// A [`Deserializer`] that records unknown keys in any struct it deserializes.
+struct Collect<'a, D> {
+    inner: D,
+    prefix: String,
+    unknown: &'a RefCell<Vec<UnknownKey>>,
+}
+
+impl<'a, D> Collect<'a, D> {
+    fn wrap<V>(&self, visitor: V, fields: Option<&'static [&'static str]>) -> Wrap<'a, V> {
+        Wrap {
+            visitor,
+            prefix: self.prefix.clone(),
+            unknown: self.unknown,
+            fields,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let visitor = self.wrap(visitor, None);
+                self.inner.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Collect<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor, None);
+        self.inner.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor, None);
+        self.inner.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor, None);
+        self.inner.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor, None);
+        self.inner.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor, Some(fields));
+        self.inner.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// A [`Visitor`] that propagates the [`Collect`] wrapper to nested values.
+struct Wrap<'a, V> {
+    visitor: V,
+    prefix: String,
+    unknown: &'a RefCell<Vec<UnknownKey>>,
+    /// The fields of the struct being visited, if any.
+    fields: Option<&'static [&'static str]>,
+}
+
+impl<'a, V> Wrap<'a, V> {
+    fn collect<D>(&self, inner: D) -> Collect<'a, D> {
+        Collect {
+            inner,
+            prefix: self.prefix.clone(),
+            unknown: self.unknown,
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.visitor.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Wrap<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool) visit_i8(i8) visit_i16(i16) visit_i32(i32) visit_i64(i64)
+        visit_i128(i128) visit_u8(u8) visit_u16(u16) visit_u32(u32) visit_u64(u64)
+        visit_u128(u128) visit_f32(f32) visit_f64(f64) visit_char(char) visit_str(&str)
+        visit_borrowed_str(&'de str) visit_string(String) visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8]) visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.visitor.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.collect(deserializer);
+        self.visitor.visit_some(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = self.collect(deserializer);
+        self.visitor.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = self.collect(seq);
+        self.visitor.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let map = CollectMap {
+            inner: map,
+            prefix: self.prefix,
+            unknown: self.unknown,
+            fields: self.fields,
+            key: None,
+        };
+        self.visitor.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.visitor.visit_enum(data)
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Collect<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.inner.next_element_seed(CollectSeed {
+            seed,
+            prefix: self.prefix.clone(),
+            unknown: self.unknown,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+/// A [`MapAccess`] that skips and records the keys that are not fields of the struct, if any.
+struct CollectMap<'a, A> {
+    inner: A,
+    prefix: String,
+    unknown: &'a RefCell<Vec<UnknownKey>>,
+    fields: Option<&'static [&'static str]>,
+    /// The key of the value that is about to be visited.
+    key: Option<String>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for CollectMap<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(fields) = self.fields else {
+            self.key = None;
+            return self.inner.next_key_seed(seed);
+        };
+        loop {
+            let Some(key) = self.inner.next_key::<String>()? else {
+                return Ok(None);
+            };
+            if fields.contains(&key.as_str()) {
+                self.key = Some(key.clone());
+                return seed
+                    .deserialize(StringDeserializer::<Self::Error>::new(key))
+                    .map(Some);
+            }
+            self.inner.next_value::<IgnoredAny>()?;
+            self.unknown
+                .borrow_mut()
+                .push(UnknownKey::new(&self.prefix, &key, fields));
+        }
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        let prefix = match self.key.take() {
+            Some(key) => join(&self.prefix, &key),
+            None => self.prefix.clone(),
+        };
+        self.inner.next_value_seed(CollectSeed {
+            seed,
+            prefix,
+            unknown: self.unknown,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+/// A [`DeserializeSeed`] that deserializes its value with the [`Collect`] wrapper.
+struct CollectSeed<'a, T> {
+    seed: T,
+    prefix: String,
+    unknown: &'a RefCell<Vec<UnknownKey>>,
+}
+
+impl<'de, T: DeserializeSeed<'de>> DeserializeSeed<'de> for CollectSeed<'_, T> {
+    type Value = T::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.seed.deserialize(Collect {
+            inner: deserializer,
+            prefix: self.prefix,
+            unknown: self.unknown,
+        })
+    }
+}
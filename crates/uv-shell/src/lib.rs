@@ -2,7 +2,7 @@ pub mod runnable;
 mod shlex;
 pub mod windows;
 
-pub use shlex::{escape_posix_for_single_quotes, shlex_posix, shlex_windows};
+pub use shlex::{escape_posix_for_single_quotes, shlex_posix, shlex_split, shlex_windows};
 
 use std::path::{Path, PathBuf};
 use uv_fs::Simplified;
@@ -10,6 +10,7 @@ use uv_static::EnvVars;
 
 /// Shells for which virtualenv activation scripts are available.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[allow(clippy::doc_markdown)]
 pub enum Shell {
     /// Bourne Again SHell (bash)
@@ -17,14 +18,17 @@ pub enum Shell {
     /// Friendly Interactive SHell (fish)
     Fish,
     /// PowerShell
+    #[cfg_attr(feature = "clap", value(alias = "pwsh"))]
     Powershell,
     /// Cmd (Command Prompt)
     Cmd,
     /// Z SHell (zsh)
     Zsh,
     /// Nushell
+    #[cfg_attr(feature = "clap", value(alias = "nu"))]
     Nushell,
     /// C SHell (csh)
+    #[cfg_attr(feature = "clap", value(alias = "tcsh"))]
     Csh,
     /// Korn SHell (ksh)
     Ksh,
@@ -36,8 +40,8 @@ impl Shell {
     /// This will read the `SHELL` environment variable and try to determine which shell is in use
     /// from that.
     ///
-    /// If `SHELL` is not set, then on windows, it will default to powershell, and on
-    /// other `OSes` it will return `None`.
+    /// If `SHELL` is not set, then on windows, it will inspect `PROMPT`, `PSModulePath`, and
+    /// `ComSpec`, defaulting to powershell, and on other `OSes` it will return `None`.
     ///
     /// If `SHELL` is set, but contains a value that doesn't correspond to one of the supported
     /// shell types, then return `None`.
@@ -55,19 +59,74 @@ impl Shell {
         } else if let Some(env_shell) = std::env::var_os(EnvVars::SHELL) {
             Shell::from_shell_path(env_shell)
         } else if cfg!(windows) {
-            // Command Prompt relies on PROMPT for its appearance whereas PowerShell does not.
-            // See: https://stackoverflow.com/a/66415037.
-            if std::env::var_os(EnvVars::PROMPT).is_some() {
-                Some(Shell::Cmd)
-            } else {
-                // Fallback to PowerShell if the PROMPT environment variable is not set.
-                Some(Shell::Powershell)
-            }
+            Some(Shell::from_windows_env())
         } else {
             None
         }
     }
 
+    /// Determine the user's current shell on Windows, where `SHELL` is typically not set.
+    fn from_windows_env() -> Shell {
+        // Command Prompt relies on PROMPT for its appearance whereas PowerShell does not.
+        // See: https://stackoverflow.com/a/66415037.
+        if std::env::var_os(EnvVars::PROMPT).is_some() {
+            return Shell::Cmd;
+        }
+
+        // PowerShell prepends the user's module directory to the system-wide `PSModulePath` when
+        // it starts, so a PowerShell session has at least three entries, rather than two.
+        if std::env::var_os(EnvVars::PSMODULEPATH)
+            .is_some_and(|paths| std::env::split_paths(&paths).count() >= 3)
+        {
+            return Shell::Powershell;
+        }
+
+        // Otherwise, fall back to the default command interpreter, e.g., `cmd.exe`.
+        if let Some(shell) = std::env::var_os(EnvVars::COMSPEC).and_then(Shell::from_shell_path) {
+            return shell;
+        }
+
+        Shell::Powershell
+    }
+
+    /// Determine the shell that invoked uv, e.g., to show the command to activate a virtual
+    /// environment.
+    ///
+    /// Unlike [`Shell::from_env`], this prefers the parent process (on Unix), as `SHELL` refers
+    /// to the user's login shell, which may differ from the shell uv was invoked from.
+    pub fn from_parent_or_env() -> Option<Shell> {
+        Shell::from_parent_process().or_else(Shell::from_env)
+    }
+
+    /// Determine the shell from the parent process, i.e., the shell that invoked uv, if any.
+    ///
+    /// This is only supported on Unix. On Linux, the parent executable is read from `/proc`;
+    /// elsewhere, `ps` is used to determine the command name.
+    #[cfg(unix)]
+    fn from_parent_process() -> Option<Shell> {
+        let ppid = std::os::unix::process::parent_id();
+        if cfg!(target_os = "linux") {
+            let exe = fs_err::read_link(format!("/proc/{ppid}/exe")).ok()?;
+            Shell::from_shell_path(exe)
+        } else {
+            let output = std::process::Command::new("ps")
+                .args(["-o", "comm=", "-p", &ppid.to_string()])
+                .output()
+                .ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            let comm = String::from_utf8(output.stdout).ok()?;
+            // Login shells are reported with a leading dash, e.g., `-zsh`.
+            Shell::from_shell_path(comm.trim().trim_start_matches('-'))
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn from_parent_process() -> Option<Shell> {
+        None
+    }
+
     /// Parse a shell from a path to the executable for the shell.
     ///
     /// # Examples
@@ -206,6 +265,21 @@ impl Shell {
             .any(|p| same_file::is_same_file(path, p).unwrap_or(false))
     }
 
+    /// Returns the command necessary to activate the virtual environment with the given scripts
+    /// directory in this shell.
+    pub fn activate_command(self, scripts: &Path) -> String {
+        match self {
+            Shell::Bash | Shell::Zsh | Shell::Ksh => {
+                format!("source {}", shlex_posix(scripts.join("activate")))
+            }
+            Shell::Fish => format!("source {}", shlex_posix(scripts.join("activate.fish"))),
+            Shell::Nushell => format!("overlay use {}", shlex_posix(scripts.join("activate.nu"))),
+            Shell::Csh => format!("source {}", shlex_posix(scripts.join("activate.csh"))),
+            Shell::Powershell => shlex_windows(scripts.join("activate.ps1"), Shell::Powershell),
+            Shell::Cmd => shlex_windows(scripts.join("activate.bat"), Shell::Cmd),
+        }
+    }
+
     /// Returns the command necessary to prepend a directory to the `PATH` in this shell.
     pub fn prepend_path(self, path: &Path) -> Option<String> {
         match self {
@@ -256,9 +330,11 @@ fn parse_shell_from_path(path: &Path) -> Option<Shell> {
         "bash" => Some(Shell::Bash),
         "zsh" => Some(Shell::Zsh),
         "fish" => Some(Shell::Fish),
-        "csh" => Some(Shell::Csh),
+        "csh" | "tcsh" => Some(Shell::Csh),
         "ksh" => Some(Shell::Ksh),
-        "powershell" | "powershell_ise" => Some(Shell::Powershell),
+        "nu" => Some(Shell::Nushell),
+        "powershell" | "powershell_ise" | "pwsh" => Some(Shell::Powershell),
+        "cmd" => Some(Shell::Cmd),
         _ => None,
     }
 }
@@ -48,3 +48,96 @@ pub fn shlex_windows(executable: impl AsRef<Path>, shell: Shell) -> String {
         executable
     }
 }
+
+/// Split a string into arguments, like a POSIX-compatible shell (or Python's `shlex.split`).
+///
+/// Arguments are separated by unquoted whitespace. Single quotes preserve their contents
+/// literally; within double quotes, a backslash only escapes `"` and `\`. Outside of quotes, a
+/// backslash escapes the following character.
+///
+/// Returns `None` if the string contains an unterminated quote or a trailing backslash.
+pub fn shlex_split(input: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let arg = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        c => arg.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let arg = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            c @ ('"' | '\\') => arg.push(c),
+                            c => {
+                                arg.push('\\');
+                                arg.push(c);
+                            }
+                        },
+                        c => arg.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                current.get_or_insert_with(String::new).push(chars.next()?);
+            }
+            c if c.is_whitespace() => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+            }
+            c => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if let Some(arg) = current {
+        args.push(arg);
+    }
+
+    Some(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shlex_split;
+
+    #[test]
+    fn split_whitespace() {
+        assert_eq!(
+            shlex_split("  -X  dev\t-W error ").unwrap(),
+            vec!["-X", "dev", "-W", "error"]
+        );
+        assert_eq!(shlex_split("").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn split_quotes() {
+        assert_eq!(
+            shlex_split(r#"-W 'ignore::Deprecation Warning' -c "print(\"a b\")""#).unwrap(),
+            vec!["-W", "ignore::Deprecation Warning", "-c", r#"print("a b")"#]
+        );
+        assert_eq!(
+            shlex_split(r"'C:\Temp\x' a\ b").unwrap(),
+            vec![r"C:\Temp\x", "a b"]
+        );
+        assert_eq!(shlex_split(r#"'' """#).unwrap(), vec!["", ""]);
+        assert_eq!(shlex_split(r#"-Xa"b c"'d'"#).unwrap(), vec!["-Xab cd"]);
+    }
+
+    #[test]
+    fn split_unterminated() {
+        assert_eq!(shlex_split("-c 'print(1)"), None);
+        assert_eq!(shlex_split(r#"-c "print(1)"#), None);
+        assert_eq!(shlex_split(r"-X dev\"), None);
+    }
+}
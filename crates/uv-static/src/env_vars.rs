@@ -259,9 +259,21 @@ impl EnvVars {
     /// Specifies the directory to place links to installed, managed Python executables.
     pub const UV_PYTHON_BIN_DIR: &'static str = "UV_PYTHON_BIN_DIR";
 
+    /// Equivalent to the `--bin` command-line argument. If set to `false`, uv will not link
+    /// the executables of installed Python versions into the bin directory.
+    pub const UV_PYTHON_INSTALL_BIN: &'static str = "UV_PYTHON_INSTALL_BIN";
+
     /// Specifies the directory for storing managed Python installations.
     pub const UV_PYTHON_INSTALL_DIR: &'static str = "UV_PYTHON_INSTALL_DIR";
 
+    /// Timeout (in seconds) to wait for another uv process to release the lock on the managed
+    /// Python installation directory. By default, uv waits indefinitely.
+    pub const UV_PYTHON_INSTALL_LOCK_TIMEOUT: &'static str = "UV_PYTHON_INSTALL_LOCK_TIMEOUT";
+
+    /// Timeout (in seconds) to wait for a Python interpreter to report its version and
+    /// configuration during discovery. Set to `0` to wait indefinitely. (default: 30 s)
+    pub const UV_PYTHON_QUERY_TIMEOUT: &'static str = "UV_PYTHON_QUERY_TIMEOUT";
+
     /// Managed Python installations information is hardcoded in the `uv` binary.
     ///
     /// This variable can be set to a URL pointing to JSON to use as a list for Python installations.
@@ -271,7 +283,7 @@ impl EnvVars {
     pub const UV_PYTHON_DOWNLOADS_JSON_URL: &'static str = "UV_PYTHON_DOWNLOADS_JSON_URL";
 
     /// Specifies the directory for caching the archives of managed Python installations before
-    /// installation.
+    /// installation. If unset, archives are cached in the uv cache directory.
     pub const UV_PYTHON_CACHE_DIR: &'static str = "UV_PYTHON_CACHE_DIR";
 
     /// Managed Python installations are downloaded from the Astral
@@ -280,24 +292,54 @@ impl EnvVars {
     /// This variable can be set to a mirror URL to use a different source for Python installations.
     /// The provided URL will replace `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g.,
     /// `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
+    /// Multiple mirrors can be provided, separated by whitespace, in which case each mirror is
+    /// tried in order until the download succeeds. If every mirror is unavailable, the default
+    /// source is used.
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     pub const UV_PYTHON_INSTALL_MIRROR: &'static str = "UV_PYTHON_INSTALL_MIRROR";
 
+    /// A token to send as a bearer token in the `Authorization` header when downloading managed
+    /// Python installations from the mirror set via `UV_PYTHON_INSTALL_MIRROR`.
+    ///
+    /// If multiple mirrors are set, the token is sent to each of them, but never to the default
+    /// source.
+    pub const UV_PYTHON_INSTALL_MIRROR_TOKEN: &'static str = "UV_PYTHON_INSTALL_MIRROR_TOKEN";
+
     /// Managed PyPy installations are downloaded from [python.org](https://downloads.python.org/).
     ///
     /// This variable can be set to a mirror URL to use a
     /// different source for PyPy installations. The provided URL will replace
     /// `https://downloads.python.org/pypy` in, e.g.,
     /// `https://downloads.python.org/pypy/pypy3.8-v7.3.7-osx64.tar.bz2`.
+    /// Multiple mirrors can be provided, separated by whitespace, in which case each mirror is
+    /// tried in order until the download succeeds. If every mirror is unavailable, the default
+    /// source is used.
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     pub const UV_PYPY_INSTALL_MIRROR: &'static str = "UV_PYPY_INSTALL_MIRROR";
 
+    /// Managed GraalPy installations are downloaded from
+    /// [GitHub](https://github.com/oracle/graalpython/releases).
+    ///
+    /// This variable can be set to a mirror URL to use a different source for GraalPy
+    /// installations. The provided URL will replace
+    /// `https://github.com/oracle/graalpython/releases/download` in, e.g.,
+    /// `https://github.com/oracle/graalpython/releases/download/graal-24.2.1/graalpy-24.2.1-linux-amd64.tar.gz`.
+    /// Multiple mirrors can be provided, separated by whitespace, in which case each mirror is
+    /// tried in order until the download succeeds. If every mirror is unavailable, the default
+    /// source is used.
+    /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    pub const UV_GRAALPY_INSTALL_MIRROR: &'static str = "UV_GRAALPY_INSTALL_MIRROR";
+
     /// Install seed packages (one or more of: `pip`, `setuptools`, and `wheel`) into the virtual environment
     /// created by `uv venv`.
     ///
     /// Note that `setuptools` and `wheel` are not included in Python 3.12+ environments.
     pub const UV_VENV_SEED: &'static str = "UV_VENV_SEED";
 
+    /// The shell to show the activation command for after creating a virtual environment with
+    /// `uv venv`, overriding shell detection.
+    pub const UV_VENV_SHELL: &'static str = "UV_VENV_SHELL";
+
     /// Used to override `PATH` to limit Python executable availability in the test suite.
     #[attr_hidden]
     pub const UV_TEST_PYTHON_PATH: &'static str = "UV_TEST_PYTHON_PATH";
@@ -403,6 +445,9 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Comma-separated list of hosts for which the proxy should be bypassed.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
@@ -433,6 +478,12 @@ impl EnvVars {
     /// Used to detect the use of the Windows Command Prompt (as opposed to PowerShell).
     pub const PROMPT: &'static str = "PROMPT";
 
+    /// Used to detect the use of PowerShell on Windows, which extends the module search path.
+    pub const PSMODULEPATH: &'static str = "PSModulePath";
+
+    /// Used to detect the default command interpreter on Windows, e.g., `cmd.exe`.
+    pub const COMSPEC: &'static str = "ComSpec";
+
     /// Used to detect `NuShell` usage.
     pub const NU_VERSION: &'static str = "NU_VERSION";
 
@@ -555,6 +606,10 @@ impl EnvVars {
     /// Adds directories to Python module search path (e.g., `PYTHONPATH=/path/to/modules`).
     pub const PYTHONPATH: &'static str = "PYTHONPATH";
 
+    /// The base directory for user installations, i.e., `uv pip install --user`
+    /// (e.g., `PYTHONUSERBASE=/path/to/base`).
+    pub const PYTHONUSERBASE: &'static str = "PYTHONUSERBASE";
+
     /// Used in tests to enforce a consistent locale setting.
     #[attr_hidden]
     pub const LC_ALL: &'static str = "LC_ALL";
@@ -1,9 +1,11 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_python::{
+    Interpreter, PyVenvConfiguration, PythonEnvironment, PythonVersion, VirtualEnvError,
+};
 
 mod virtualenv;
 
@@ -13,6 +15,10 @@ pub enum Error {
     Io(#[from] io::Error),
     #[error("Could not find a suitable Python executable for the virtual environment based on the interpreter: {0}")]
     NotFound(String),
+    #[error(transparent)]
+    Environment(#[from] VirtualEnvError),
+    #[error("The virtual environment was created with Python {0}, which is incompatible with Python {1}; recreate the environment to change the minor version")]
+    IncompatibleVersion(PythonVersion, String),
 }
 
 /// The value to use for the shell prompt when inside a virtual environment.
@@ -64,3 +70,74 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// The changes made to an existing virtual environment by [`relink_venv`].
+#[derive(Debug)]
+pub struct Relinked {
+    /// The directory containing the previous base interpreter, i.e., the previous `home` key.
+    pub previous_home: Option<PathBuf>,
+    /// The directory containing the new base interpreter.
+    pub home: PathBuf,
+    /// The Python version the environment was previously linked to.
+    pub previous_version: Option<PythonVersion>,
+    /// The Python version the environment is now linked to.
+    pub version: PythonVersion,
+}
+
+/// Re-link an existing virtual environment to the given interpreter, e.g., after its base
+/// interpreter was moved, replaced, or upgraded to a new patch version.
+///
+/// The executables, activation scripts, and `pyvenv.cfg` are regenerated; any installed packages
+/// are left untouched. Settings recorded in the existing `pyvenv.cfg` are retained, e.g., the
+/// existing prompt is used unless a static prompt is provided.
+pub fn relink_venv(
+    location: &Path,
+    interpreter: Interpreter,
+    prompt: Prompt,
+    system_site_packages: bool,
+    relocatable: bool,
+) -> Result<(PythonEnvironment, Relinked), Error> {
+    let cfg = PyVenvConfiguration::parse(location.join("pyvenv.cfg"))?;
+
+    // Installed packages (and the standard library) are specific to the minor version.
+    if let Some(version) = cfg.version() {
+        if (version.major(), version.minor())
+            != (interpreter.python_major(), interpreter.python_minor())
+        {
+            return Err(Error::IncompatibleVersion(
+                version.clone(),
+                interpreter.python_version().to_string(),
+            ));
+        }
+    }
+
+    let prompt = match (prompt, cfg.prompt()) {
+        (Prompt::Static(prompt), _) => Prompt::Static(prompt),
+        (_, Some(existing)) => Prompt::Static(existing.to_string()),
+        (prompt, None) => prompt,
+    };
+
+    let virtualenv = virtualenv::create(
+        location,
+        &interpreter,
+        prompt,
+        system_site_packages || cfg.include_system_site_packages(),
+        true,
+        relocatable || cfg.is_relocatable(),
+        cfg.is_seed(),
+    )?;
+
+    let relinked = Relinked {
+        previous_home: cfg.home().map(Path::to_path_buf),
+        home: virtualenv
+            .base_executable
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default(),
+        previous_version: cfg.version().cloned(),
+        version: PythonVersion::from(interpreter.python_full_version().clone()),
+    };
+
+    let interpreter = interpreter.with_virtualenv(virtualenv);
+    Ok((PythonEnvironment::from_interpreter(interpreter), relinked))
+}
@@ -2,17 +2,16 @@
 
 use std::env::consts::EXE_SUFFIX;
 use std::io;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::Path;
 
 use fs_err as fs;
-use fs_err::File;
 use itertools::Itertools;
 use tracing::debug;
 
 use uv_fs::{cachedir, Simplified, CWD};
-use uv_pypi_types::Scheme;
-use uv_python::{Interpreter, VirtualEnvironment};
+use uv_python::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
+use uv_python::{Interpreter, PyVenvConfiguration, VirtualEnvironment};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_version::version;
 
@@ -43,6 +42,52 @@ fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
     Ok(())
 }
 
+/// Writes the files of a virtual environment.
+///
+/// When reusing an existing environment, files that already have the expected contents (and
+/// symlinks that already point to the expected target) are left untouched, to avoid churning
+/// modification times.
+#[derive(Debug)]
+struct VenvWriter {
+    allow_existing: bool,
+    unchanged: usize,
+    updated: usize,
+}
+
+impl VenvWriter {
+    fn new(allow_existing: bool) -> Self {
+        Self {
+            allow_existing,
+            unchanged: 0,
+            updated: 0,
+        }
+    }
+
+    /// Write the given contents to the file at `path`, unless it already has those contents.
+    fn write(&mut self, path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+        let contents = contents.as_ref();
+        if self.allow_existing && fs::read(path).is_ok_and(|existing| existing == contents) {
+            self.unchanged += 1;
+            return Ok(());
+        }
+        fs::write(path, contents)?;
+        self.updated += 1;
+        Ok(())
+    }
+
+    /// Create a symlink at `dst` pointing to `src`, unless one already exists.
+    #[cfg(unix)]
+    fn symlink(&mut self, src: impl AsRef<Path>, dst: &Path) -> io::Result<()> {
+        if self.allow_existing && fs::read_link(dst).is_ok_and(|target| target == src.as_ref()) {
+            self.unchanged += 1;
+            return Ok(());
+        }
+        uv_fs::replace_symlink(src, dst)?;
+        self.updated += 1;
+        Ok(())
+    }
+}
+
 /// Create a [`VirtualEnvironment`] at the given location.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn create(
@@ -137,11 +182,13 @@ pub(crate) fn create(
         Prompt::None => None,
     };
 
+    let mut writer = VenvWriter::new(allow_existing);
+
     // Add the CACHEDIR.TAG.
     cachedir::ensure_tag(&location)?;
 
     // Create a `.gitignore` file to ignore all files in the venv.
-    fs::write(location.join(".gitignore"), "*")?;
+    writer.write(&location.join(".gitignore"), "*")?;
 
     // Per PEP 405, the Python `home` is the parent directory of the interpreter.
     let python_home = base_python.parent().ok_or_else(|| {
@@ -157,14 +204,14 @@ pub(crate) fn create(
 
     #[cfg(unix)]
     {
-        uv_fs::replace_symlink(&base_python, &executable)?;
-        uv_fs::replace_symlink(
+        writer.symlink(&base_python, &executable)?;
+        writer.symlink(
             "python",
-            scripts.join(format!("python{}", interpreter.python_major())),
+            &scripts.join(format!("python{}", interpreter.python_major())),
         )?;
-        uv_fs::replace_symlink(
+        writer.symlink(
             "python",
-            scripts.join(format!(
+            &scripts.join(format!(
                 "python{}.{}",
                 interpreter.python_major(),
                 interpreter.python_minor(),
@@ -172,15 +219,15 @@ pub(crate) fn create(
         )?;
 
         if interpreter.markers().implementation_name() == "pypy" {
-            uv_fs::replace_symlink(
+            writer.symlink(
                 "python",
-                scripts.join(format!("pypy{}", interpreter.python_major())),
+                &scripts.join(format!("pypy{}", interpreter.python_major())),
             )?;
-            uv_fs::replace_symlink("python", scripts.join("pypy"))?;
+            writer.symlink("python", &scripts.join("pypy"))?;
         }
 
         if interpreter.markers().implementation_name() == "graalpy" {
-            uv_fs::replace_symlink("python", scripts.join("graalpy"))?;
+            writer.symlink("python", &scripts.join("graalpy"))?;
         }
     }
 
@@ -317,7 +364,7 @@ pub(crate) fn create(
             )
             .replace("{{ PATH_SEP }}", path_sep)
             .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
-        fs::write(scripts.join(name), activator)?;
+        writer.write(&scripts.join(name), activator)?;
     }
 
     let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
@@ -333,6 +380,7 @@ pub(crate) fn create(
                 .to_string(),
         ),
         ("uv".to_string(), version().to_string()),
+        ("uv-python-key".to_string(), interpreter.key().to_string()),
         (
             "version_info".to_string(),
             interpreter.markers().python_full_version().string.clone(),
@@ -369,9 +417,20 @@ pub(crate) fn create(
         ));
     }
 
-    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
+    // The seed packages are recorded after seeding; retain those of an existing environment, such
+    // that `pyvenv.cfg` is only rewritten if they change.
+    if seed && allow_existing {
+        if let Some(seed_packages) = PyVenvConfiguration::parse(location.join("pyvenv.cfg"))
+            .ok()
+            .and_then(|cfg| cfg.seed_packages().map(ToString::to_string))
+        {
+            pyvenv_cfg_data.push(("seed-packages".to_string(), seed_packages));
+        }
+    }
+
+    let mut pyvenv_cfg = Vec::new();
     write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
-    drop(pyvenv_cfg);
+    writer.write(&location.join("pyvenv.cfg"), pyvenv_cfg)?;
 
     // Construct the path to the `site-packages` directory.
     let site_packages = location.join(&interpreter.virtualenv().purelib);
@@ -394,17 +453,18 @@ pub(crate) fn create(
     }
 
     // Populate `site-packages` with a `_virtualenv.py` file.
-    fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
-    fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
+    writer.write(&site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
+    writer.write(&site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
+
+    if allow_existing {
+        debug!(
+            "Updated {} files in the virtual environment ({} unchanged)",
+            writer.updated, writer.unchanged
+        );
+    }
 
     Ok(VirtualEnvironment {
-        scheme: Scheme {
-            purelib: location.join(&interpreter.virtualenv().purelib),
-            platlib: location.join(&interpreter.virtualenv().platlib),
-            scripts: location.join(&interpreter.virtualenv().scripts),
-            data: location.join(&interpreter.virtualenv().data),
-            include: location.join(&interpreter.virtualenv().include),
-        },
+        scheme: interpreter.scheme_for(&location),
         root: location,
         executable,
         base_executable: base_python,
@@ -494,6 +554,19 @@ impl WindowsExecutable {
     }
 }
 
+/// Find the uv-managed installation that provides the interpreter, if any.
+fn find_managed_installation(interpreter: &Interpreter) -> Option<ManagedPythonInstallation> {
+    ManagedPythonInstallations::from_settings(None)
+        .ok()?
+        .find_matching_current_platform()
+        .ok()?
+        .find(|installation| {
+            interpreter
+                .sys_base_prefix()
+                .starts_with(installation.path())
+        })
+}
+
 /// <https://github.com/python/cpython/blob/d457345bbc6414db0443819290b04a9a4333313d/Lib/venv/__init__.py#L261-L267>
 /// <https://github.com/pypa/virtualenv/blob/d9fdf48d69f0d0ca56140cf0381edbb5d6fe09f5/src/virtualenv/create/via_global_ref/builtin/cpython/cpython3.py#L78-L83>
 ///
@@ -552,10 +625,16 @@ fn copy_launcher_windows(
     // Fourth priority: if the launcher shim doesn't exist, assume this is
     // an embedded Python. Copy the Python executable itself, along with
     // the DLLs, `.pyd` files, and `.zip` files in the same directory.
-    match fs_err::copy(
-        base_python.with_file_name(executable.exe(interpreter)),
-        scripts.join(executable.exe(interpreter)),
-    ) {
+    //
+    // For a uv-managed Python, the windowed executable may have a versioned name, e.g.,
+    // `pythonw3.13t.exe` in free-threaded builds, so ask the installation for it.
+    let source = match executable {
+        WindowsExecutable::Pythonw => find_managed_installation(interpreter)
+            .map(|installation| installation.executable(true))
+            .unwrap_or_else(|| base_python.with_file_name(executable.exe(interpreter))),
+        _ => base_python.with_file_name(executable.exe(interpreter)),
+    };
+    match fs_err::copy(source, scripts.join(executable.exe(interpreter))) {
         Ok(_) => {
             // Copy `.dll` and `.pyd` files from the top-level, and from the
             // `DLLs` subdirectory (if it exists).
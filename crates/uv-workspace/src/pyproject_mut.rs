@@ -10,9 +10,9 @@ use toml_edit::{
 use url::Url;
 
 use uv_cache_key::CanonicalUrl;
-use uv_distribution_types::Index;
+use uv_distribution_types::{Index, IndexName};
 use uv_fs::PortablePath;
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 
@@ -239,155 +239,7 @@ impl PyProjectTomlMut {
             .as_array_of_tables_mut()
             .ok_or(Error::MalformedSources)?;
 
-        // If there's already an index with the same name or URL, update it (and move it to the top).
-        let mut table = existing
-            .iter()
-            .find(|table| {
-                // If the index has the same name, reuse it.
-                if let Some(index) = index.name.as_deref() {
-                    if table
-                        .get("name")
-                        .and_then(|name| name.as_str())
-                        .is_some_and(|name| name == index)
-                    {
-                        return true;
-                    }
-                }
-
-                // If the index is the default, and there's another default index, reuse it.
-                if index.default
-                    && table
-                        .get("default")
-                        .is_some_and(|default| default.as_bool() == Some(true))
-                {
-                    return true;
-                }
-
-                // If there's another index with the same URL, reuse it.
-                if table
-                    .get("url")
-                    .and_then(|item| item.as_str())
-                    .and_then(|url| Url::parse(url).ok())
-                    .is_some_and(|url| {
-                        CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url())
-                    })
-                {
-                    return true;
-                }
-
-                false
-            })
-            .cloned()
-            .unwrap_or_default();
-
-        // If necessary, update the name.
-        if let Some(index) = index.name.as_deref() {
-            if table
-                .get("name")
-                .and_then(|name| name.as_str())
-                .is_none_or(|name| name != index)
-            {
-                let mut formatted = Formatted::new(index.to_string());
-                if let Some(value) = table.get("name").and_then(Item::as_value) {
-                    if let Some(prefix) = value.decor().prefix() {
-                        formatted.decor_mut().set_prefix(prefix.clone());
-                    }
-                    if let Some(suffix) = value.decor().suffix() {
-                        formatted.decor_mut().set_suffix(suffix.clone());
-                    }
-                }
-                table.insert("name", Value::String(formatted).into());
-            }
-        }
-
-        // If necessary, update the URL.
-        if table
-            .get("url")
-            .and_then(|item| item.as_str())
-            .and_then(|url| Url::parse(url).ok())
-            .is_none_or(|url| CanonicalUrl::new(&url) != CanonicalUrl::new(index.url.url()))
-        {
-            let mut formatted = Formatted::new(index.url.redacted().to_string());
-            if let Some(value) = table.get("url").and_then(Item::as_value) {
-                if let Some(prefix) = value.decor().prefix() {
-                    formatted.decor_mut().set_prefix(prefix.clone());
-                }
-                if let Some(suffix) = value.decor().suffix() {
-                    formatted.decor_mut().set_suffix(suffix.clone());
-                }
-            }
-            table.insert("url", Value::String(formatted).into());
-        }
-
-        // If necessary, update the default.
-        if index.default {
-            if !table
-                .get("default")
-                .and_then(Item::as_bool)
-                .is_some_and(|default| default)
-            {
-                let mut formatted = Formatted::new(true);
-                if let Some(value) = table.get("default").and_then(Item::as_value) {
-                    if let Some(prefix) = value.decor().prefix() {
-                        formatted.decor_mut().set_prefix(prefix.clone());
-                    }
-                    if let Some(suffix) = value.decor().suffix() {
-                        formatted.decor_mut().set_suffix(suffix.clone());
-                    }
-                }
-                table.insert("default", Value::Boolean(formatted).into());
-            }
-        }
-
-        // Remove any replaced tables.
-        existing.retain(|table| {
-            // If the index has the same name, skip it.
-            if let Some(index) = index.name.as_deref() {
-                if table
-                    .get("name")
-                    .and_then(|name| name.as_str())
-                    .is_some_and(|name| name == index)
-                {
-                    return false;
-                }
-            }
-
-            // If there's another default index, skip it.
-            if index.default
-                && table
-                    .get("default")
-                    .is_some_and(|default| default.as_bool() == Some(true))
-            {
-                return false;
-            }
-
-            // If there's another index with the same URL, skip it.
-            if table
-                .get("url")
-                .and_then(|item| item.as_str())
-                .and_then(|url| Url::parse(url).ok())
-                .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
-            {
-                return false;
-            }
-
-            true
-        });
-
-        // Set the position to the minimum, if it's not already the first element.
-        if let Some(min) = existing.iter().filter_map(Table::position).min() {
-            table.set_position(min);
-
-            // Increment the position of all existing elements.
-            for table in existing.iter_mut() {
-                if let Some(position) = table.position() {
-                    table.set_position(position + 1);
-                }
-            }
-        }
-
-        // Push the item to the table.
-        existing.push(table);
+        add_index(index, existing);
 
         Ok(())
     }
@@ -870,6 +722,69 @@ impl PyProjectTomlMut {
             .is_some()
     }
 
+    /// Returns the names of all dependencies of the given type, in order of appearance.
+    ///
+    /// Development dependencies include both `tool.uv.dev-dependencies` and
+    /// `dependency-groups.dev`.
+    pub fn dependency_names(&self, dependency_type: &DependencyType) -> Vec<PackageName> {
+        let project = match self.target {
+            DependencyTarget::Script => Some(self.doc.as_table()),
+            DependencyTarget::PyProjectToml => self.doc.get("project").and_then(Item::as_table),
+        };
+        let group = |name: &GroupName| {
+            self.doc
+                .get("dependency-groups")
+                .and_then(Item::as_table)
+                .and_then(|groups| {
+                    groups.iter().find_map(|(key, value)| {
+                        if GroupName::from_str(key).is_ok_and(|key| key == *name) {
+                            value.as_array()
+                        } else {
+                            None
+                        }
+                    })
+                })
+        };
+
+        let arrays = match dependency_type {
+            DependencyType::Production => vec![project
+                .and_then(|project| project.get("dependencies"))
+                .and_then(Item::as_array)],
+            DependencyType::Optional(extra) => vec![project
+                .and_then(|project| project.get("optional-dependencies"))
+                .and_then(Item::as_table)
+                .and_then(|extras| {
+                    extras.iter().find_map(|(key, value)| {
+                        if ExtraName::from_str(key).is_ok_and(|key| key == *extra) {
+                            value.as_array()
+                        } else {
+                            None
+                        }
+                    })
+                })],
+            DependencyType::Group(name) if *name != *DEV_DEPENDENCIES => vec![group(name)],
+            DependencyType::Dev | DependencyType::Group(_) => vec![
+                self.doc
+                    .get("tool")
+                    .and_then(Item::as_table)
+                    .and_then(|tool| tool.get("uv"))
+                    .and_then(Item::as_table)
+                    .and_then(|uv| uv.get("dev-dependencies"))
+                    .and_then(Item::as_array),
+                group(&DEV_DEPENDENCIES),
+            ],
+        };
+
+        arrays
+            .into_iter()
+            .flatten()
+            .flat_map(Array::iter)
+            .filter_map(|dep| dep.as_str().and_then(try_parse_requirement))
+            .map(|requirement| requirement.name)
+            .unique()
+            .collect()
+    }
+
     /// Returns all the places in this `pyproject.toml` that contain a dependency with the given
     /// name.
     ///
@@ -992,6 +907,174 @@ fn implicit() -> Item {
     Item::Table(table)
 }
 
+/// Adds an [`Index`] to the given array of `index` tables.
+///
+/// If there's already an index with the same name or URL (or, for a default index, another
+/// default index), it's updated in-place and moved to the top, such that it takes precedence.
+pub fn add_index(index: &Index, existing: &mut ArrayOfTables) {
+    // If there's already an index with the same name or URL, update it (and move it to the top).
+    let mut table = existing
+        .iter()
+        .find(|table| {
+            // If the index has the same name, reuse it.
+            if let Some(index) = index.name.as_deref() {
+                if table
+                    .get("name")
+                    .and_then(|name| name.as_str())
+                    .is_some_and(|name| name == index)
+                {
+                    return true;
+                }
+            }
+
+            // If the index is the default, and there's another default index, reuse it.
+            if index.default
+                && table
+                    .get("default")
+                    .is_some_and(|default| default.as_bool() == Some(true))
+            {
+                return true;
+            }
+
+            // If there's another index with the same URL, reuse it.
+            if table
+                .get("url")
+                .and_then(|item| item.as_str())
+                .and_then(|url| Url::parse(url).ok())
+                .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
+            {
+                return true;
+            }
+
+            false
+        })
+        .cloned()
+        .unwrap_or_default();
+
+    // If necessary, update the name.
+    if let Some(index) = index.name.as_deref() {
+        if table
+            .get("name")
+            .and_then(|name| name.as_str())
+            .is_none_or(|name| name != index)
+        {
+            let mut formatted = Formatted::new(index.to_string());
+            if let Some(value) = table.get("name").and_then(Item::as_value) {
+                if let Some(prefix) = value.decor().prefix() {
+                    formatted.decor_mut().set_prefix(prefix.clone());
+                }
+                if let Some(suffix) = value.decor().suffix() {
+                    formatted.decor_mut().set_suffix(suffix.clone());
+                }
+            }
+            table.insert("name", Value::String(formatted).into());
+        }
+    }
+
+    // If necessary, update the URL.
+    if table
+        .get("url")
+        .and_then(|item| item.as_str())
+        .and_then(|url| Url::parse(url).ok())
+        .is_none_or(|url| CanonicalUrl::new(&url) != CanonicalUrl::new(index.url.url()))
+    {
+        let mut formatted = Formatted::new(index.url.redacted().to_string());
+        if let Some(value) = table.get("url").and_then(Item::as_value) {
+            if let Some(prefix) = value.decor().prefix() {
+                formatted.decor_mut().set_prefix(prefix.clone());
+            }
+            if let Some(suffix) = value.decor().suffix() {
+                formatted.decor_mut().set_suffix(suffix.clone());
+            }
+        }
+        table.insert("url", Value::String(formatted).into());
+    }
+
+    // If necessary, update the default.
+    if index.default {
+        if !table
+            .get("default")
+            .and_then(Item::as_bool)
+            .is_some_and(|default| default)
+        {
+            let mut formatted = Formatted::new(true);
+            if let Some(value) = table.get("default").and_then(Item::as_value) {
+                if let Some(prefix) = value.decor().prefix() {
+                    formatted.decor_mut().set_prefix(prefix.clone());
+                }
+                if let Some(suffix) = value.decor().suffix() {
+                    formatted.decor_mut().set_suffix(suffix.clone());
+                }
+            }
+            table.insert("default", Value::Boolean(formatted).into());
+        }
+    }
+
+    // Remove any replaced tables.
+    existing.retain(|table| {
+        // If the index has the same name, skip it.
+        if let Some(index) = index.name.as_deref() {
+            if table
+                .get("name")
+                .and_then(|name| name.as_str())
+                .is_some_and(|name| name == index)
+            {
+                return false;
+            }
+        }
+
+        // If there's another default index, skip it.
+        if index.default
+            && table
+                .get("default")
+                .is_some_and(|default| default.as_bool() == Some(true))
+        {
+            return false;
+        }
+
+        // If there's another index with the same URL, skip it.
+        if table
+            .get("url")
+            .and_then(|item| item.as_str())
+            .and_then(|url| Url::parse(url).ok())
+            .is_some_and(|url| CanonicalUrl::new(&url) == CanonicalUrl::new(index.url.url()))
+        {
+            return false;
+        }
+
+        true
+    });
+
+    // Set the position to the minimum, if it's not already the first element.
+    if let Some(min) = existing.iter().filter_map(Table::position).min() {
+        table.set_position(min);
+
+        // Increment the position of all existing elements.
+        for table in existing.iter_mut() {
+            if let Some(position) = table.position() {
+                table.set_position(position + 1);
+            }
+        }
+    }
+
+    // Push the item to the table.
+    existing.push(table);
+}
+
+/// Removes the [`Index`] with the given name from the given array of `index` tables.
+///
+/// Returns `true` if the index was removed, `false` if no index has the given name.
+pub fn remove_index(name: &IndexName, existing: &mut ArrayOfTables) -> bool {
+    let len = existing.len();
+    existing.retain(|table| {
+        table
+            .get("name")
+            .and_then(|name| name.as_str())
+            .is_none_or(|existing| existing != name.as_ref())
+    });
+    existing.len() != len
+}
+
 /// Adds a dependency to the given `deps` array.
 ///
 /// Returns `true` if the dependency was added, `false` if it was updated.
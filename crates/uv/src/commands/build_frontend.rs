@@ -28,7 +28,7 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    EnvironmentPreference, FindOptions, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
     VersionRequest,
 };
@@ -474,14 +474,14 @@ async fn build_package(
     // Locate the Python interpreter to use in the environment.
     let interpreter = PythonInstallation::find_or_download(
         interpreter_request.as_ref(),
-        EnvironmentPreference::Any,
-        python_preference,
-        python_downloads,
+        FindOptions::default()
+            .environments(EnvironmentPreference::Any)
+            .preference(python_preference)
+            .downloads(python_downloads),
         &client_builder,
         cache,
         Some(&PythonDownloadReporter::single(printer)),
-        install_mirrors.python_install_mirror.as_deref(),
-        install_mirrors.pypy_install_mirror.as_deref(),
+        &install_mirrors.mirrors(),
         install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
@@ -0,0 +1,472 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::CommandFactory;
+use owo_colors::OwoColorize;
+use toml_edit::{DocumentMut, InlineTable, Item, Key, Table, TableLike, Value};
+use url::Url;
+
+use uv_cli::Cli;
+use uv_dirs::{system_config_file, user_uv_config_dir};
+use uv_fs::Simplified;
+use uv_redacted::redacted_url;
+use uv_settings::FilesystemOptions;
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the value of a setting in a `uv.toml` file.
+pub(crate) fn config_get(
+    key: &str,
+    user: bool,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = config_file(user, project_dir)?;
+    let keys = parse_key(key)?;
+
+    let Some(doc) = read_toml(&path)? else {
+        bail!("No configuration file found at: `{}`", path.user_display());
+    };
+
+    let mut item = doc.as_item();
+    for key in &keys {
+        item = item
+            .as_table_like()
+            .and_then(|table| table.get(key.get()))
+            .ok_or_else(|| {
+                anyhow!(
+                    "`{}` is not set in `{}`",
+                    format_key(&keys),
+                    path.user_display()
+                )
+            })?;
+    }
+
+    // Print strings verbatim, and everything else (including tables) as an inline TOML value.
+    match item.clone().into_value() {
+        Ok(Value::String(value)) => {
+            writeln!(printer.stdout(), "{}", value.value())?;
+        }
+        Ok(mut value) => {
+            value.decor_mut().clear();
+            writeln!(printer.stdout(), "{value}")?;
+        }
+        Err(_) => {
+            bail!(
+                "`{}` is not set in `{}`",
+                format_key(&keys),
+                path.user_display()
+            );
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Update the value of a setting in a `uv.toml` file, preserving its existing formatting.
+pub(crate) async fn config_set(
+    key: &str,
+    value: &str,
+    user: bool,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = config_file(user, project_dir)?;
+    let keys = parse_key(key)?;
+
+    let mut doc = read_toml(&path)?.unwrap_or_default();
+
+    // Interpret the value as TOML if possible (e.g., `true` or `["foo"]`), and as a string
+    // otherwise (e.g., `https://example.com/simple`). If the setting expects a string, the TOML
+    // value may be rejected (e.g., `3.12` for `pip.python-version`), in which case the raw string
+    // is used instead.
+    let content = match value.parse::<Value>() {
+        Ok(parsed) if !parsed.is_str() => {
+            let mut typed = doc.clone();
+            set_value(&mut typed, &keys, parsed)?;
+            match validate_uv_toml(&path, &typed) {
+                Ok(content) => content,
+                Err(err) => {
+                    set_value(&mut doc, &keys, Value::from(value))?;
+                    validate_uv_toml(&path, &doc).map_err(|_| err)?
+                }
+            }
+        }
+        _ => {
+            set_value(&mut doc, &keys, Value::from(value))?;
+            validate_uv_toml(&path, &doc)?
+        }
+    };
+
+    write_toml(&path, content).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Set `{}` in `{}`",
+        format_key(&keys).cyan(),
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Set the value at the given keys in the document, creating any missing tables.
+///
+/// Existing values are replaced in-place, to retain any surrounding comments.
+fn set_value(doc: &mut DocumentMut, keys: &[Key], value: Value) -> Result<()> {
+    // Find (or create) the table that contains the setting.
+    let (last, parents) = keys.split_last().expect("keys are non-empty");
+    let mut item = doc.as_item_mut();
+    for (index, key) in parents.iter().enumerate() {
+        let inline = item.is_inline_table();
+        let table = item.as_table_like_mut().ok_or_else(|| {
+            anyhow!(
+                "Failed to set `{}`: `{}` is not a table",
+                format_key(keys),
+                format_key(&keys[..index])
+            )
+        })?;
+        if table.get(key.get()).is_none() {
+            let child = if inline {
+                Item::Value(Value::InlineTable(InlineTable::new()))
+            } else {
+                let mut child = Table::new();
+                child.set_implicit(true);
+                Item::Table(child)
+            };
+            table.insert(key.get(), child);
+        }
+        item = table.get_mut(key.get()).expect("table entry was inserted");
+    }
+    let table = item.as_table_like_mut().ok_or_else(|| {
+        anyhow!(
+            "Failed to set `{}`: `{}` is not a table",
+            format_key(keys),
+            format_key(parents)
+        )
+    })?;
+
+    match table.get_mut(last.get()) {
+        Some(Item::Value(existing)) => {
+            let decor = existing.decor().clone();
+            *existing = value;
+            *existing.decor_mut() = decor;
+        }
+        _ => {
+            table.insert(last.get(), Item::Value(value));
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the settings that can be provided via an environment variable, which takes precedence
+/// over configuration files, as a map from each variable to the name of its setting.
+///
+/// Includes every command-line option that can be provided via an environment variable and
+/// corresponds to a setting in `uv.toml` (e.g., `UV_INDEX_URL` for `--index-url`, or
+/// `UV_SYSTEM_PYTHON` for `pip.system`), along with every documented variable that does (e.g.,
+/// `UV_CONCURRENT_BUILDS` for `concurrent-builds`). Options without a setting, like `UV_PYTHON`,
+/// are omitted.
+fn env_settings() -> BTreeMap<String, String> {
+    fn visit(command: &clap::Command, settings: &mut BTreeMap<String, String>) {
+        for arg in command.get_arguments() {
+            let (Some(var), Some(long)) = (arg.get_env(), arg.get_long()) else {
+                continue;
+            };
+            let Some(key) = [long.to_string(), format!("pip.{long}")]
+                .into_iter()
+                .find(|key| uv_settings::is_setting(key))
+            else {
+                continue;
+            };
+            settings
+                .entry(var.to_string_lossy().into_owned())
+                .or_insert(key);
+        }
+        for subcommand in command.get_subcommands() {
+            visit(subcommand, settings);
+        }
+    }
+
+    let mut settings = BTreeMap::new();
+    visit(&Cli::command(), &mut settings);
+    for &(var, _) in EnvVars::metadata() {
+        let Some(name) = var.strip_prefix("UV_") else {
+            continue;
+        };
+        let key = name.to_ascii_lowercase().replace('_', "-");
+        if uv_settings::is_setting(&key) {
+            settings.entry(var.to_string()).or_insert(key);
+        }
+    }
+    settings
+}
+
+/// Where a setting was read from.
+#[derive(Debug)]
+enum ConfigSource {
+    /// An environment variable.
+    Env(String),
+    /// A `uv.toml` file, or the `[tool.uv]` table of a `pyproject.toml` file.
+    File(PathBuf),
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(var) => write!(f, "{var}"),
+            Self::File(path) => write!(f, "{}", path.user_display()),
+        }
+    }
+}
+
+/// A setting, along with the source that set it.
+#[derive(Debug)]
+struct ConfigEntry {
+    key: String,
+    value: Value,
+    source: ConfigSource,
+}
+
+/// List the active settings, annotated with the environment variable or file that set each one.
+pub(crate) async fn config_list(
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Collect the settings in order of precedence, starting with environment variables.
+    let mut entries = Vec::new();
+    for (var, key) in env_settings() {
+        let Some(value) = std::env::var_os(&var).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        // Avoid displaying secrets, e.g., `UV_PUBLISH_PASSWORD`.
+        let value = if var.contains("PASSWORD") || var.contains("TOKEN") {
+            Value::from("****")
+        } else {
+            let value = value.to_string_lossy();
+            Value::from(redact_credentials(&value).unwrap_or_else(|| value.into_owned()))
+        };
+        entries.push(ConfigEntry {
+            key,
+            value,
+            source: ConfigSource::Env(var),
+        });
+    }
+
+    // Follow the same discovery as settings resolution: an explicit `--config-file` replaces all
+    // other configuration files.
+    let files = if let Some(config_file) = config_file {
+        vec![config_file.to_path_buf()]
+    } else if no_config {
+        vec![]
+    } else {
+        project_config_file(project_dir)
+            .await
+            .into_iter()
+            .chain(
+                user_uv_config_dir()
+                    .map(|dir| dir.join("uv.toml"))
+                    .filter(|path| path.is_file()),
+            )
+            .chain(system_config_file())
+            .collect()
+    };
+
+    for path in files {
+        let Some(doc) = read_toml(&path)? else {
+            continue;
+        };
+        let table = if path
+            .file_name()
+            .is_some_and(|name| name == "pyproject.toml")
+        {
+            doc.get("tool")
+                .and_then(|tool| tool.get("uv"))
+                .and_then(Item::as_table_like)
+        } else {
+            Some(doc.as_table() as &dyn TableLike)
+        };
+        if let Some(table) = table {
+            flatten(table, "", &path, &mut entries);
+        }
+    }
+
+    if entries.is_empty() {
+        writeln!(printer.stderr(), "No settings found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Group the entries by key, retaining the order of precedence within each key.
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let mut previous: Option<&str> = None;
+    for entry in &entries {
+        // Arrays are merged across sources; any other value is overridden by the first source
+        // that sets it.
+        let overridden = previous == Some(entry.key.as_str()) && !entry.value.is_array();
+        previous = Some(entry.key.as_str());
+
+        if overridden {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                format!(
+                    "{} = {} ({}, overridden)",
+                    entry.key, entry.value, entry.source
+                )
+                .dimmed()
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{} = {} {}",
+                entry.key.cyan(),
+                entry.value,
+                format!("({})", entry.source).dimmed()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the project-level configuration file, i.e., the nearest `uv.toml` or `pyproject.toml`
+/// with a `[tool.uv]` table, starting from the workspace root if there is one.
+async fn project_config_file(project_dir: &Path) -> Option<PathBuf> {
+    let workspace_cache = WorkspaceCache::default();
+    let root = match Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &workspace_cache,
+    )
+    .await
+    {
+        Ok(workspace) => workspace.install_path().clone(),
+        Err(_) => project_dir.to_path_buf(),
+    };
+
+    for ancestor in root.ancestors() {
+        let uv_toml = ancestor.join("uv.toml");
+        if uv_toml.is_file() {
+            return Some(uv_toml);
+        }
+        let pyproject_toml = ancestor.join("pyproject.toml");
+        if fs_err::read_to_string(&pyproject_toml)
+            .ok()
+            .and_then(|content| content.parse::<DocumentMut>().ok())
+            .is_some_and(|doc| doc.get("tool").and_then(|tool| tool.get("uv")).is_some())
+        {
+            return Some(pyproject_toml);
+        }
+    }
+    None
+}
+
+/// Add an entry for every value in the table, using dotted keys for nested tables.
+fn flatten(table: &dyn TableLike, prefix: &str, path: &Path, entries: &mut Vec<ConfigEntry>) {
+    for (key, item) in table.iter() {
+        let key = if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        if let Item::Table(table) = item {
+            flatten(table, &key, path, entries);
+        } else if let Ok(mut value) = item.clone().into_value() {
+            if let Some(redacted) = value.as_str().and_then(redact_credentials) {
+                value = Value::from(redacted);
+            }
+            value.decor_mut().clear();
+            entries.push(ConfigEntry {
+                key,
+                value,
+                source: ConfigSource::File(path.to_path_buf()),
+            });
+        }
+    }
+}
+
+/// If the value is a URL with credentials (e.g., an index URL with a password), return it with the
+/// credentials removed.
+fn redact_credentials(value: &str) -> Option<String> {
+    let url = Url::parse(value).ok()?;
+    match redacted_url(&url) {
+        Cow::Owned(url) => Some(url.to_string()),
+        Cow::Borrowed(_) => None,
+    }
+}
+
+/// Return the path to the `uv.toml` file to read or update.
+fn config_file(user: bool, project_dir: &Path) -> Result<PathBuf> {
+    if user {
+        user_config_file()
+    } else {
+        Ok(project_dir.join("uv.toml"))
+    }
+}
+
+/// Return the path to the user-level `uv.toml` file.
+pub(crate) fn user_config_file() -> Result<PathBuf> {
+    let dir = user_uv_config_dir()
+        .ok_or_else(|| anyhow!("Failed to determine the user configuration directory"))?;
+    Ok(dir.join("uv.toml"))
+}
+
+/// Read a TOML configuration file, returning `None` if it does not exist.
+pub(crate) fn read_toml(path: &Path) -> Result<Option<DocumentMut>> {
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let doc = content
+        .parse::<DocumentMut>()
+        .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+    Ok(Some(doc))
+}
+
+/// Validate an updated `uv.toml` document, returning the contents to write.
+pub(crate) fn validate_uv_toml(path: &Path, doc: &DocumentMut) -> Result<String> {
+    let content = doc.to_string();
+    FilesystemOptions::from_uv_toml(path, &content)?;
+    Ok(content)
+}
+
+/// Write the contents of a configuration file, creating its parent directory if necessary.
+pub(crate) async fn write_toml(path: &Path, content: String) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    uv_fs::write_atomic(path, content)
+        .await
+        .with_context(|| format!("Failed to write: `{}`", path.user_display()))
+}
+
+/// Parse a dotted key (e.g., `pip.index-url`) into its components.
+///
+/// Keys may be spelled as in a `pyproject.toml` (e.g., `tool.uv.cache-dir`), in which case the
+/// `tool.uv` prefix is dropped, since `uv.toml` files have no such table.
+fn parse_key(key: &str) -> Result<Vec<Key>> {
+    let mut keys = Key::parse(key).with_context(|| format!("Invalid key: `{key}`"))?;
+    if keys.len() > 2 && keys[0].get() == "tool" && keys[1].get() == "uv" {
+        keys.drain(..2);
+    }
+    if keys.is_empty() {
+        bail!("Invalid key: `{key}`");
+    }
+    Ok(keys)
+}
+
+/// Format a list of keys as a dotted key.
+fn format_key(keys: &[Key]) -> String {
+    keys.iter().map(Key::get).collect::<Vec<_>>().join(".")
+}
@@ -0,0 +1,309 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use owo_colors::OwoColorize;
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table};
+
+use uv_auth::KeyringProvider;
+use uv_distribution_types::{Index, IndexName, IndexUrl};
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+use uv_workspace::pyproject::PyProjectToml;
+use uv_workspace::pyproject_mut;
+
+use crate::commands::config::{read_toml, user_config_file, validate_uv_toml, write_toml};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Add a package index to a `uv.toml` or `pyproject.toml` file.
+pub(crate) async fn index_add(
+    name: &IndexName,
+    url: &IndexUrl,
+    default: bool,
+    user: bool,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let file = IndexFile::find(user, project_dir)?;
+    let mut doc = file.read()?;
+
+    let index = Index {
+        name: Some(name.clone()),
+        default,
+        ..Index::from_extra_index_url(url.clone())
+    };
+    pyproject_mut::add_index(&index, file.indexes_mut(&mut doc)?);
+
+    file.write(&doc).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Added index `{}` to `{}`",
+        name.cyan(),
+        file.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove a package index from a `uv.toml` or `pyproject.toml` file.
+pub(crate) async fn index_remove(
+    name: &IndexName,
+    user: bool,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let file = IndexFile::find(user, project_dir)?;
+    let mut doc = file.read()?;
+
+    let removed = file.indexes(&doc).is_some()
+        && pyproject_mut::remove_index(name, file.indexes_mut(&mut doc)?);
+    if !removed {
+        bail!(
+            "No index named `{name}` found in `{}`",
+            file.path().user_display()
+        );
+    }
+
+    file.write(&doc).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Removed index `{}` from `{}`",
+        name.cyan(),
+        file.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the package indexes in a `uv.toml` or `pyproject.toml` file, in order of priority.
+pub(crate) fn index_list(user: bool, project_dir: &Path, printer: Printer) -> Result<ExitStatus> {
+    let file = IndexFile::find(user, project_dir)?;
+    let doc = file.read()?;
+
+    let Some(indexes) = file.indexes(&doc).filter(|indexes| !indexes.is_empty()) else {
+        writeln!(
+            printer.stderr(),
+            "No indexes found in `{}`",
+            file.path().user_display()
+        )?;
+        return Ok(ExitStatus::Success);
+    };
+
+    for table in indexes {
+        let Some(url) = table.get("url").and_then(Item::as_str) else {
+            continue;
+        };
+        let mut line = match table.get("name").and_then(Item::as_str) {
+            Some(name) => format!("{} {url}", name.cyan()),
+            None => url.to_string(),
+        };
+        for flag in ["default", "explicit"] {
+            if table.get(flag).and_then(Item::as_bool) == Some(true) {
+                write!(line, " {}", format!("({flag})").dimmed())?;
+            }
+        }
+        writeln!(printer.stdout(), "{line}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Store (or remove) credentials for a package index in the system keyring.
+pub(crate) async fn index_auth(
+    name: &IndexName,
+    username: &str,
+    token: Option<&str>,
+    delete: bool,
+    user: bool,
+    project_dir: &Path,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let file = IndexFile::find(user, project_dir)?;
+    let mut doc = file.read()?;
+
+    let not_found = || {
+        anyhow!(
+            "No index named `{name}` found in `{}`",
+            file.path().user_display()
+        )
+    };
+    if file.indexes(&doc).is_none() {
+        return Err(not_found());
+    }
+    let table = file
+        .indexes_mut(&mut doc)?
+        .iter_mut()
+        .find(|table| table.get("name").and_then(Item::as_str) == Some(name.as_ref()))
+        .ok_or_else(not_found)?;
+
+    // Store the credentials under the index URL, as used when fetching credentials from the
+    // keyring.
+    let url = table.get("url").and_then(Item::as_str).ok_or_else(|| {
+        anyhow!(
+            "Index `{name}` in `{}` is missing a URL",
+            file.path().user_display()
+        )
+    })?;
+    let mut url = IndexUrl::from_str(url)?.url().clone();
+    if url.host_str().is_none() {
+        bail!("Credentials can only be stored for remote indexes, but `{name}` is located at: `{url}`");
+    }
+    url.set_username("").ok();
+    url.set_password(None).ok();
+
+    let keyring = KeyringProvider::subprocess();
+    if delete {
+        keyring.remove(&url, username).await?;
+        if table.get("authenticate").and_then(Item::as_str) == Some("always") {
+            table.remove("authenticate");
+        }
+    } else {
+        let token = token.expect("a token is required unless `--delete` is provided");
+        keyring.store(&url, username, token).await?;
+        table.insert("authenticate", toml_edit::value("always"));
+    }
+
+    file.write(&doc).await?;
+
+    if delete {
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for index `{}` from the keyring",
+            name.cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Stored credentials for index `{}` in the keyring",
+            name.cyan()
+        )?;
+
+        // The credentials are only retrieved if the keyring provider is enabled.
+        let enabled = std::env::var(EnvVars::UV_KEYRING_PROVIDER)
+            .is_ok_and(|provider| provider == "subprocess")
+            || file
+                .setting(&doc, "keyring-provider")
+                .and_then(Item::as_str)
+                == Some("subprocess");
+        if !enabled {
+            writeln!(
+                printer.stderr(),
+                "{}{} uv only reads credentials from the keyring when `{}` is set, or `{}` is provided",
+                "hint".bold().cyan(),
+                ":".bold(),
+                "keyring-provider = \"subprocess\"".green(),
+                "--keyring-provider subprocess".green(),
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A configuration file in which package indexes are declared.
+#[derive(Debug)]
+enum IndexFile {
+    /// A `uv.toml` file, which declares indexes in `[[index]]`.
+    UvToml(PathBuf),
+    /// A `pyproject.toml` file, which declares indexes in `[[tool.uv.index]]`.
+    PyProjectToml(PathBuf),
+}
+
+impl IndexFile {
+    /// Determine the file to read or update.
+    ///
+    /// Prefers the project's `uv.toml`, then the project's `pyproject.toml`, falling back to a
+    /// new `uv.toml` if neither exists.
+    fn find(user: bool, project_dir: &Path) -> Result<Self> {
+        if user {
+            return Ok(Self::UvToml(user_config_file()?));
+        }
+
+        let uv_toml = project_dir.join("uv.toml");
+        if uv_toml.is_file() {
+            return Ok(Self::UvToml(uv_toml));
+        }
+        let pyproject_toml = project_dir.join("pyproject.toml");
+        if pyproject_toml.is_file() {
+            return Ok(Self::PyProjectToml(pyproject_toml));
+        }
+        Ok(Self::UvToml(uv_toml))
+    }
+
+    fn path(&self) -> &Path {
+        match self {
+            Self::UvToml(path) | Self::PyProjectToml(path) => path,
+        }
+    }
+
+    /// Read the file, returning an empty document if it does not exist.
+    fn read(&self) -> Result<DocumentMut> {
+        Ok(read_toml(self.path())?.unwrap_or_default())
+    }
+
+    /// Return the top-level setting with the given key in the document, if present.
+    fn setting<'doc>(&self, doc: &'doc DocumentMut, key: &str) -> Option<&'doc Item> {
+        let table = match self {
+            Self::UvToml(_) => doc.as_item(),
+            Self::PyProjectToml(_) => doc.get("tool")?.get("uv")?,
+        };
+        table.get(key)
+    }
+
+    /// Return the array of `index` tables in the document, if present.
+    fn indexes<'doc>(&self, doc: &'doc DocumentMut) -> Option<&'doc ArrayOfTables> {
+        self.setting(doc, "index")?.as_array_of_tables()
+    }
+
+    /// Return the array of `index` tables in the document, creating it if necessary.
+    fn indexes_mut<'doc>(&self, doc: &'doc mut DocumentMut) -> Result<&'doc mut ArrayOfTables> {
+        let table = match self {
+            Self::UvToml(_) => doc.as_table_mut(),
+            Self::PyProjectToml(_) => doc
+                .entry("tool")
+                .or_insert(implicit())
+                .as_table_mut()
+                .and_then(|tool| tool.entry("uv").or_insert(implicit()).as_table_mut())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "`tool.uv` in `{}` is not a table",
+                        self.path().user_display()
+                    )
+                })?,
+        };
+        table
+            .entry("index")
+            .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .ok_or_else(|| {
+                anyhow!(
+                    "Indexes in `{}` must be declared as an array of tables (e.g., `[[index]]`)",
+                    self.path().user_display()
+                )
+            })
+    }
+
+    /// Validate the updated document, and write it to the file.
+    async fn write(&self, doc: &DocumentMut) -> Result<()> {
+        let content = match self {
+            Self::UvToml(path) => validate_uv_toml(path, doc)?,
+            Self::PyProjectToml(path) => {
+                let content = doc.to_string();
+                PyProjectToml::from_string(content.clone())
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+                content
+            }
+        };
+        write_toml(self.path(), content).await
+    }
+}
+
+fn implicit() -> Item {
+    let mut table = Table::new();
+    table.set_implicit(true);
+    Item::Table(table)
+}
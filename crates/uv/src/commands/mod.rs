@@ -4,6 +4,7 @@ use owo_colors::OwoColorize;
 use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
@@ -11,10 +12,13 @@ pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use config::{config_get, config_list, config_set};
 pub(crate) use help::help;
+pub(crate) use index::{index_add, index_auth, index_list, index_remove};
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
+pub(crate) use pip::hash::pip_hash;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
 pub(crate) use pip::show::pip_show;
@@ -29,12 +33,14 @@ pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
-pub(crate) use publish::publish;
+pub(crate) use publish::{publish, publish_check};
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::find::find_script as python_find_script;
+pub(crate) use python::install::download as python_download;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
+pub(crate) use python::manifest::manifest as python_manifest;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 #[cfg(feature = "self-update")]
@@ -58,6 +64,7 @@ use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
 pub(crate) use version::{project_version, self_version};
 
+use crate::commands::reporters::CompileReporter;
 use crate::printer::Printer;
 
 pub(crate) mod build_backend;
@@ -65,8 +72,10 @@ mod build_frontend;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod config;
 mod diagnostics;
 mod help;
+mod index;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -158,14 +167,17 @@ pub(super) async fn compile_bytecode(
     let mut files = 0;
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
-        files += compile_tree(
+        let reporter = Arc::new(CompileReporter::new(printer, site_packages.user_display()));
+        let result = compile_tree(
             &site_packages,
             venv.python_executable(),
             concurrency,
             cache.root(),
+            Some(reporter.clone()),
         )
-        .await
-        .with_context(|| {
+        .await;
+        reporter.on_compile_complete();
+        files += result.with_context(|| {
             format!(
                 "Failed to bytecode-compile Python file in: {}",
                 site_packages.user_display()
@@ -29,8 +29,8 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::{GroupName, PackageName};
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
 use uv_python::{
-    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
-    PythonVersion, VersionRequest,
+    EnvironmentPreference, FindOptions, PythonEnvironment, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVersion, VersionRequest,
 };
 use uv_requirements::upgrade::{read_pylock_toml_requirements, LockedRequirements};
 use uv_requirements::{
@@ -268,10 +268,12 @@ pub(crate) async fn pip_compile(
     }
 
     // Find an interpreter to use for building distributions
-    let environment_preference = EnvironmentPreference::from_system_flag(system, false);
+    let find_options = FindOptions::default()
+        .environments(EnvironmentPreference::from_system_flag(system, false))
+        .preference(python_preference);
     let interpreter = if let Some(python) = python.as_ref() {
         let request = PythonRequest::parse(python);
-        PythonInstallation::find(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find(&request, find_options, &cache)
     } else {
         // TODO(zanieb): The split here hints at a problem with the request abstraction; we should
         // be able to use `PythonInstallation::find(...)` here.
@@ -281,7 +283,7 @@ pub(crate) async fn pip_compile(
         } else {
             PythonRequest::default()
         };
-        PythonInstallation::find_best(&request, environment_preference, python_preference, &cache)
+        PythonInstallation::find_best(&request, find_options, &cache)
     }?
     .into_interpreter();
 
@@ -504,6 +506,7 @@ pub(crate) async fn pip_compile(
         requirements,
         constraints,
         overrides,
+        &[],
         source_trees,
         project,
         BTreeSet::default(),
@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use url::Url;
+
+use uv_client::BaseClientBuilder;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Compute the hash of one or more local or remote package archives.
+pub(crate) async fn pip_hash(
+    files: &[String],
+    algorithm: HashAlgorithm,
+    network_settings: &NetworkSettings,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let client = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .build();
+
+    for file in files {
+        let mut hashers = [Hasher::from(algorithm)];
+
+        if file.starts_with("http://") || file.starts_with("https://") {
+            // Stream the remote archive through the hasher, rather than writing it to disk.
+            let url = Url::parse(file)?;
+            let response = client
+                .for_host(&url)
+                .get(url.clone())
+                .send()
+                .await?
+                .error_for_status()
+                .with_context(|| format!("Failed to download `{url}`"))?;
+            let mut reader = response.bytes_stream();
+            while let Some(chunk) = reader.next().await {
+                let chunk = chunk?;
+                for hasher in &mut hashers {
+                    hasher.update(&chunk);
+                }
+            }
+        } else {
+            let reader = fs_err::tokio::File::open(file).await?;
+            HashReader::new(reader, &mut hashers)
+                .finish()
+                .await
+                .with_context(|| format!("Failed to read `{file}`"))?;
+        }
+
+        let [hasher] = hashers;
+        let digest = HashDigest::from(hasher);
+        writeln!(printer.stdout(), "{file}:")?;
+        writeln!(printer.stdout(), "--hash={digest}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,11 +1,12 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Context;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use tokio::process::Command;
 use tracing::{debug, enabled, Level};
 
 use uv_cache::Cache;
@@ -27,14 +28,15 @@ use uv_normalize::GroupName;
 use uv_pep508::PackageName;
 use uv_pypi_types::Conflicts;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, FindOptions, Prefix, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
     PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
+use uv_static::EnvVars;
 use uv_torch::{TorchMode, TorchStrategy};
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user;
@@ -43,7 +45,9 @@ use uv_workspace::WorkspaceCache;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::report::InstallReport;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::run::run_to_completion;
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -90,10 +94,14 @@ pub(crate) async fn pip_install(
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    user: bool,
+    isolated: bool,
+    test_command: Option<&str>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    report: Option<&Path>,
     printer: Printer,
     preview: PreviewMode,
 ) -> anyhow::Result<ExitStatus> {
@@ -170,15 +178,48 @@ pub(crate) async fn pip_install(
             )
             .collect();
 
+    // With `--isolated`, install into a temporary virtual environment, removed once dropped.
+    let temp_dir = if isolated {
+        Some(cache.venv_dir()?)
+    } else {
+        None
+    };
+
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if let Some(temp_dir) = temp_dir.as_ref() {
         let installation = PythonInstallation::find(
             &python
                 .as_deref()
                 .map(PythonRequest::parse)
                 .unwrap_or_default(),
-            EnvironmentPreference::from_system_flag(system, false),
-            python_preference,
+            FindOptions::default()
+                .environments(EnvironmentPreference::OnlySystem)
+                .preference(python_preference),
+            &cache,
+        )?;
+        report_interpreter(&installation, true, printer)?;
+        debug!(
+            "Creating temporary virtual environment at: {}",
+            temp_dir.path().user_display()
+        );
+        uv_virtualenv::create_venv(
+            temp_dir.path(),
+            installation.into_interpreter(),
+            uv_virtualenv::Prompt::None,
+            false,
+            false,
+            false,
+            false,
+        )?
+    } else if target.is_some() || prefix.is_some() || user {
+        let installation = PythonInstallation::find(
+            &python
+                .as_deref()
+                .map(PythonRequest::parse)
+                .unwrap_or_default(),
+            FindOptions::default()
+                .environments(EnvironmentPreference::from_system_flag(system, false))
+                .preference(python_preference),
             &cache,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -209,6 +250,21 @@ pub(crate) async fn pip_install(
             prefix.root().user_display()
         );
         environment.with_prefix(prefix)?
+    } else if user {
+        let Some(user) = environment.interpreter().user_scheme() else {
+            return Err(anyhow::anyhow!(
+                "The interpreter at {} does not support user installations",
+                environment.root().user_display().cyan()
+            ));
+        };
+        if environment.interpreter().is_virtualenv() {
+            warn_user!(
+                "Installing into the user `site-packages` directory from the virtual environment at {}; packages installed with `--user` are not visible to virtual environments",
+                environment.root().user_display().cyan()
+            );
+        }
+        debug!("Using user scheme at {}", user.root().user_display());
+        environment.with_user(user)?
     } else {
         environment
     };
@@ -273,6 +329,9 @@ pub(crate) async fn pip_install(
                 if dry_run.enabled() {
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
+                if let Some(report) = report {
+                    InstallReport::empty().write(report).await?;
+                }
 
                 return Ok(ExitStatus::Success);
             }
@@ -465,6 +524,7 @@ pub(crate) async fn pip_install(
             requirements,
             constraints,
             overrides,
+            &[],
             source_trees,
             project,
             BTreeSet::default(),
@@ -502,7 +562,7 @@ pub(crate) async fn pip_install(
     };
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -527,12 +587,19 @@ pub(crate) async fn pip_install(
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => changelog,
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
+    };
+
+    // Write the installation report, if requested.
+    if let Some(report) = report {
+        InstallReport::from_changelog(&resolution, &changelog)
+            .write(report)
+            .await?;
     }
 
     // Notify the user of any resolution diagnostics.
@@ -543,5 +610,47 @@ pub(crate) async fn pip_install(
         operations::diagnose_environment(&resolution, &environment, &marker_env, printer)?;
     }
 
+    // Exercise the installed packages in the temporary environment.
+    if let Some(test_command) = test_command {
+        return run_test_command(test_command, &environment).await;
+    }
+
     Ok(ExitStatus::Success)
 }
+
+/// Run a command with the system shell, in the given environment.
+async fn run_test_command(
+    command: &str,
+    environment: &PythonEnvironment,
+) -> anyhow::Result<ExitStatus> {
+    let mut process = if cfg!(windows) {
+        let mut process = Command::new("cmd");
+        process.arg("/C");
+        process
+    } else {
+        let mut process = Command::new("sh");
+        process.arg("-c");
+        process
+    };
+    process.arg(command);
+
+    // Construct the `PATH` environment variable.
+    let new_path = std::env::join_paths(
+        std::iter::once(environment.scripts().to_path_buf()).chain(
+            std::env::var_os(EnvVars::PATH)
+                .as_ref()
+                .iter()
+                .flat_map(std::env::split_paths),
+        ),
+    )
+    .context("Failed to build new PATH variable")?;
+    process.env(EnvVars::PATH, new_path);
+    process.env(EnvVars::VIRTUAL_ENV, environment.root().as_os_str());
+
+    debug!("Running test command: `{command}`");
+    let handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn test command: `{command}`"))?;
+
+    run_to_completion(handle).await
+}
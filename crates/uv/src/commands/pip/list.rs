@@ -6,7 +6,7 @@ use anyhow::Result;
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Serialize;
 use tokio::sync::Semaphore;
 use unicode_width::UnicodeWidthStr;
@@ -40,6 +40,7 @@ pub(crate) async fn pip_list(
     exclude: &[PackageName],
     format: &ListFormat,
     outdated: bool,
+    not_required: bool,
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -70,11 +71,33 @@ pub(crate) async fn pip_list(
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
 
-    // Filter if `--editable` is specified; always sort by name.
+    // Determine the packages that are required by at least one other installed package.
+    let required = if not_required {
+        let markers = environment.interpreter().resolver_marker_environment();
+        let mut required = FxHashSet::default();
+        for dist in site_packages.iter() {
+            let metadata = dist.metadata()?;
+            for requirement in &metadata.requires_dist {
+                if requirement.name == metadata.name {
+                    continue;
+                }
+                if !requirement.marker.evaluate(&markers, &[]) {
+                    continue;
+                }
+                required.insert(requirement.name.clone());
+            }
+        }
+        required
+    } else {
+        FxHashSet::default()
+    };
+
+    // Filter if `--editable` or `--not-required` is specified; always sort by name.
     let results = site_packages
         .iter()
         .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .filter(|dist| !exclude.contains(dist.name()))
+        .filter(|dist| !required.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
 
@@ -8,11 +8,13 @@ use uv_python::{Interpreter, PythonVersion};
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
+pub(crate) mod hash;
 pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod report;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
@@ -106,6 +106,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
     constraints: Vec<NameRequirementSpecification>,
     overrides: Vec<UnresolvedRequirementSpecification>,
+    excludes: &[PackageName],
     source_trees: Vec<PathBuf>,
     mut project: Option<PackageName>,
     workspace_members: BTreeSet<PackageName>,
@@ -252,7 +253,8 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             .map(|constraint| constraint.requirement)
             .chain(upgrade.constraints().cloned()),
     );
-    let overrides = Overrides::from_requirements(overrides);
+    let overrides =
+        Overrides::from_requirements(overrides).with_excluded(excludes.iter().cloned())?;
     let preferences = Preferences::from_iter(preferences, &resolver_env);
 
     // Determine any lookahead requirements.
@@ -858,6 +860,9 @@ pub(crate) enum Error {
     #[error(transparent)]
     Requirements(#[from] uv_requirements::Error),
 
+    #[error(transparent)]
+    ExcludedOverride(#[from] uv_configuration::ExcludedOverrideError),
+
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 
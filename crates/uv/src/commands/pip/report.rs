@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Context;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use uv_distribution_types::{
+    BuiltDist, Dist, InstalledMetadata, Name, Node, Resolution, ResolvedDist, SourceDist,
+};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{ArchiveInfo, DirectUrl, HashDigest};
+
+use crate::commands::pip::operations::Changelog;
+
+/// A machine-readable report of the packages installed by `uv pip install --report`.
+///
+/// The structure mirrors pip's installation report (version `1`), in which the
+/// `download_info` of each package follows the `direct_url.json` specification.
+#[derive(Debug, Serialize)]
+pub(crate) struct InstallReport {
+    version: &'static str,
+    install: Vec<InstallReportItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct InstallReportItem {
+    name: PackageName,
+    version: Version,
+    download_info: Option<DirectUrl>,
+    is_direct: bool,
+    requested: bool,
+}
+
+impl InstallReport {
+    /// Create an [`InstallReport`] in which nothing was installed.
+    pub(crate) fn empty() -> Self {
+        Self {
+            version: "1",
+            install: Vec::new(),
+        }
+    }
+
+    /// Create an [`InstallReport`] for the distributions that were installed (or reinstalled), as
+    /// recorded in the [`Changelog`].
+    pub(crate) fn from_changelog(resolution: &Resolution, changelog: &Changelog) -> Self {
+        let graph = resolution.graph();
+
+        // Packages that are direct dependencies of the root were requested by the user.
+        let requested: FxHashSet<&PackageName> = graph
+            .node_indices()
+            .filter(|index| matches!(graph[*index], Node::Root))
+            .flat_map(|root| graph.neighbors(root))
+            .filter_map(|index| match &graph[index] {
+                Node::Dist { dist, .. } => Some(dist.name()),
+                Node::Root => None,
+            })
+            .collect();
+
+        let dists: FxHashMap<&PackageName, (&ResolvedDist, &[HashDigest])> = resolution
+            .hashes()
+            .map(|(dist, hashes)| (dist.name(), (dist, hashes)))
+            .collect();
+
+        let mut install = changelog
+            .installed
+            .iter()
+            .chain(&changelog.reinstalled)
+            .map(|local| {
+                let name = local.name();
+                let (download_info, is_direct) = match dists.get(name) {
+                    Some((ResolvedDist::Installable { dist, .. }, hashes)) => {
+                        (download_info(dist, hashes), dist.file().is_none())
+                    }
+                    Some((ResolvedDist::Installed { .. }, _)) | None => (None, false),
+                };
+                InstallReportItem {
+                    name: name.clone(),
+                    version: local.installed_version().version().clone(),
+                    download_info,
+                    is_direct,
+                    requested: requested.contains(name),
+                }
+            })
+            .collect::<Vec<_>>();
+        install.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            version: "1",
+            install,
+        }
+    }
+
+    /// Write the report to the given path, as JSON.
+    pub(crate) async fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        uv_fs::write_atomic(path, json)
+            .await
+            .with_context(|| format!("Failed to write report to: {}", path.user_display()))
+    }
+}
+
+/// Return the `direct_url.json`-style description of where a distribution was obtained.
+fn download_info(dist: &Dist, hashes: &[HashDigest]) -> Option<DirectUrl> {
+    let parsed_url = match dist {
+        Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_)) => {
+            let file = dist.file()?;
+            let hashes = if hashes.is_empty() {
+                file.hashes.as_slice()
+            } else {
+                hashes
+            };
+            return Some(DirectUrl::ArchiveUrl {
+                url: file.url.to_url().ok()?.to_string(),
+                archive_info: archive_info(hashes),
+                subdirectory: None,
+            });
+        }
+        Dist::Built(BuiltDist::DirectUrl(dist)) => dist.parsed_url(),
+        Dist::Built(BuiltDist::Path(dist)) => dist.parsed_url(),
+        Dist::Source(SourceDist::DirectUrl(dist)) => dist.parsed_url(),
+        Dist::Source(SourceDist::Git(dist)) => dist.parsed_url(),
+        Dist::Source(SourceDist::Path(dist)) => dist.parsed_url(),
+        Dist::Source(SourceDist::Directory(dist)) => dist.parsed_url(),
+    };
+    let mut direct_url = DirectUrl::from(&parsed_url);
+    if let DirectUrl::ArchiveUrl {
+        archive_info: info, ..
+    } = &mut direct_url
+    {
+        *info = archive_info(hashes);
+    }
+    Some(direct_url)
+}
+
+/// Convert a set of hashes into an [`ArchiveInfo`], preferring SHA-256 for the legacy `hash` key.
+fn archive_info(hashes: &[HashDigest]) -> ArchiveInfo {
+    if hashes.is_empty() {
+        return ArchiveInfo {
+            hash: None,
+            hashes: None,
+        };
+    }
+    let hashes: BTreeMap<String, String> = hashes
+        .iter()
+        .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+        .collect();
+    let hash = hashes
+        .get("sha256")
+        .map(|digest| format!("sha256={digest}"))
+        .or_else(|| {
+            hashes
+                .iter()
+                .next()
+                .map(|(algorithm, digest)| format!("{algorithm}={digest}"))
+        });
+    ArchiveInfo {
+        hash,
+        hashes: Some(hashes),
+    }
+}
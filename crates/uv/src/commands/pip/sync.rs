@@ -21,8 +21,8 @@ use uv_installer::SitePackages;
 use uv_pep508::PackageName;
 use uv_pypi_types::Conflicts;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
-    PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, FindOptions, Prefix, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
@@ -149,8 +149,9 @@ pub(crate) async fn pip_sync(
                 .as_deref()
                 .map(PythonRequest::parse)
                 .unwrap_or_default(),
-            EnvironmentPreference::from_system_flag(system, false),
-            python_preference,
+            FindOptions::default()
+                .environments(EnvironmentPreference::from_system_flag(system, false))
+                .preference(python_preference),
             &cache,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -398,6 +399,7 @@ pub(crate) async fn pip_sync(
             requirements,
             constraints,
             overrides,
+            &[],
             source_trees,
             project,
             BTreeSet::default(),
@@ -36,6 +36,7 @@ pub(crate) async fn pip_uninstall(
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     dry_run: DryRun,
+    break_on_not_installed: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -135,6 +136,32 @@ pub(crate) async fn pip_uninstall(
         urls
     };
 
+    // With `--break-on-not-installed`, abort before making any changes if a package is missing.
+    if break_on_not_installed {
+        let missing = names
+            .iter()
+            .filter(|package| site_packages.get_packages(package).is_empty())
+            .map(ToString::to_string)
+            .chain(
+                urls.iter()
+                    .filter(|url| site_packages.get_urls(url).is_empty())
+                    .map(ToString::to_string),
+            )
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let s = if missing.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}{} The following package{s} {} not installed: {}",
+                "error".red().bold(),
+                ":".bold(),
+                if missing.len() == 1 { "is" } else { "are" },
+                missing.iter().map(|package| package.bold()).join(", ")
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     // Map to the local distributions.
     let distributions = {
         let mut distributions = Vec::with_capacity(names.len() + urls.len());
@@ -80,6 +80,7 @@ pub(crate) async fn add(
     extras: Vec<ExtraName>,
     package: Option<PackageName>,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -170,6 +171,7 @@ pub(crate) async fn add(
         let interpreter = ScriptInterpreter::discover(
             Pep723ItemRef::Script(&script),
             python.as_deref().map(PythonRequest::parse),
+            python_variable,
             &network_settings,
             python_preference,
             python_downloads,
@@ -228,11 +230,13 @@ pub(crate) async fn add(
                 project.workspace(),
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
                 &install_mirrors,
                 false,
+                true,
                 no_config,
                 active,
                 cache,
@@ -247,11 +251,13 @@ pub(crate) async fn add(
             let environment = ProjectEnvironment::get_or_init(
                 project.workspace(),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &install_mirrors,
                 &network_settings,
                 python_preference,
                 python_downloads,
                 no_sync,
+                true,
                 no_config,
                 active,
                 cache,
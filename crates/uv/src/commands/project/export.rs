@@ -69,6 +69,7 @@ pub(crate) async fn export(
     include_header: bool,
     script: Option<Pep723Script>,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     network_settings: NetworkSettings,
@@ -133,6 +134,7 @@ pub(crate) async fn export(
             ExportTarget::Script(script) => ScriptInterpreter::discover(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
@@ -149,11 +151,13 @@ pub(crate) async fn export(
                 project.workspace(),
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
                 &install_mirrors,
                 false,
+                true,
                 no_config,
                 Some(false),
                 cache,
@@ -10,16 +10,16 @@ use uv_cache::Cache;
 use uv_cli::AuthorFrom;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    PreviewMode, ProjectBuildBackend, VersionControlError, VersionControlSystem,
+    PreviewMode, ProjectBuildBackend, PythonPin, VersionControlError, VersionControlSystem,
 };
 use uv_fs::{Simplified, CWD};
 use uv_git::GIT;
 use uv_pep440::Version;
 use uv_pep508::PackageName;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
-    VersionRequest,
+    EnvironmentPreference, FindOptions, Interpreter, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_resolver::RequiresPython;
 use uv_scripts::{Pep723Script, ScriptTag};
@@ -29,6 +29,7 @@ use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::project::{find_requires_python, init_script_python_requirement};
+use crate::commands::python::pin::exact_request;
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -49,7 +50,7 @@ pub(crate) async fn init(
     build_backend: Option<ProjectBuildBackend>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
-    pin_python: bool,
+    pin: PythonPin,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
@@ -82,7 +83,7 @@ pub(crate) async fn init(
                 no_workspace,
                 no_readme,
                 author_from,
-                pin_python,
+                pin.is_enabled(),
                 package,
                 no_config,
             )
@@ -139,7 +140,7 @@ pub(crate) async fn init(
                 build_backend,
                 no_readme,
                 author_from,
-                pin_python,
+                pin,
                 python,
                 install_mirrors,
                 no_workspace,
@@ -282,7 +283,7 @@ async fn init_project(
     build_backend: Option<ProjectBuildBackend>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
-    pin_python: bool,
+    pin: PythonPin,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
@@ -376,18 +377,39 @@ async fn init_project(
         // This can be arbitrary, i.e., not a version — in which case we may need to resolve the
         // interpreter
         match python_request {
-            PythonRequest::Version(VersionRequest::MajorMinor(major, minor, variant)) => {
+            ref python_request @ PythonRequest::Version(VersionRequest::MajorMinor(
+                major,
+                minor,
+                variant,
+            )) => {
                 let requires_python = RequiresPython::greater_than_equal_version(&Version::new([
                     u64::from(major),
                     u64::from(minor),
                 ]));
 
-                let python_request = if pin_python {
-                    Some(PythonRequest::Version(VersionRequest::MajorMinor(
+                let python_request = match pin {
+                    PythonPin::Minor => Some(PythonRequest::Version(VersionRequest::MajorMinor(
                         major, minor, variant,
-                    )))
-                } else {
-                    None
+                    ))),
+                    PythonPin::Patch => {
+                        let interpreter = PythonInstallation::find_or_download(
+                            Some(python_request),
+                            FindOptions::default()
+                                .environments(EnvironmentPreference::OnlySystem)
+                                .preference(python_preference)
+                                .downloads(python_downloads),
+                            &client_builder,
+                            cache,
+                            Some(&reporter),
+                            &install_mirrors.mirrors(),
+                            install_mirrors.python_downloads_json_url.as_deref(),
+                        )
+                        .await?
+                        .into_interpreter();
+
+                        Some(exact_request(&interpreter.key()))
+                    }
+                    PythonPin::None => None,
                 };
 
                 (requires_python, python_request)
@@ -404,7 +426,7 @@ async fn init_project(
                     u64::from(patch),
                 ]));
 
-                let python_request = if pin_python {
+                let python_request = if pin.is_enabled() {
                     Some(PythonRequest::Version(VersionRequest::MajorMinorPatch(
                         major, minor, patch, variant,
                     )))
@@ -420,27 +442,23 @@ async fn init_project(
             )) => {
                 let requires_python = RequiresPython::from_specifiers(specifiers);
 
-                let python_request = if pin_python {
+                let python_request = if pin.is_enabled() {
                     let interpreter = PythonInstallation::find_or_download(
                         Some(python_request),
-                        EnvironmentPreference::OnlySystem,
-                        python_preference,
-                        python_downloads,
+                        FindOptions::default()
+                            .environments(EnvironmentPreference::OnlySystem)
+                            .preference(python_preference)
+                            .downloads(python_downloads),
                         &client_builder,
                         cache,
                         Some(&reporter),
-                        install_mirrors.python_install_mirror.as_deref(),
-                        install_mirrors.pypy_install_mirror.as_deref(),
+                        &install_mirrors.mirrors(),
                         install_mirrors.python_downloads_json_url.as_deref(),
                     )
                     .await?
                     .into_interpreter();
 
-                    Some(PythonRequest::Version(VersionRequest::MajorMinor(
-                        interpreter.python_major(),
-                        interpreter.python_minor(),
-                        variant,
-                    )))
+                    pin_request(&interpreter, pin, variant)
                 } else {
                     None
                 };
@@ -450,14 +468,14 @@ async fn init_project(
             python_request => {
                 let interpreter = PythonInstallation::find_or_download(
                     Some(&python_request),
-                    EnvironmentPreference::OnlySystem,
-                    python_preference,
-                    python_downloads,
+                    FindOptions::default()
+                        .environments(EnvironmentPreference::OnlySystem)
+                        .preference(python_preference)
+                        .downloads(python_downloads),
                     &client_builder,
                     cache,
                     Some(&reporter),
-                    install_mirrors.python_install_mirror.as_deref(),
-                    install_mirrors.pypy_install_mirror.as_deref(),
+                    &install_mirrors.mirrors(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?
@@ -466,15 +484,7 @@ async fn init_project(
                 let requires_python =
                     RequiresPython::greater_than_equal_version(&interpreter.python_minor_version());
 
-                let python_request = if pin_python {
-                    Some(PythonRequest::Version(VersionRequest::MajorMinor(
-                        interpreter.python_major(),
-                        interpreter.python_minor(),
-                        PythonVariant::Default,
-                    )))
-                } else {
-                    None
-                };
+                let python_request = pin_request(&interpreter, pin, PythonVariant::Default);
 
                 (requires_python, python_request)
             }
@@ -487,16 +497,7 @@ async fn init_project(
         let requires_python =
             RequiresPython::greater_than_equal_version(&interpreter.python_minor_version());
 
-        // Pin to the minor version.
-        let python_request = if pin_python {
-            Some(PythonRequest::Version(VersionRequest::MajorMinor(
-                interpreter.python_major(),
-                interpreter.python_minor(),
-                PythonVariant::Default,
-            )))
-        } else {
-            None
-        };
+        let python_request = pin_request(&interpreter, pin, PythonVariant::Default);
 
         (requires_python, python_request)
     } else if let Some(requires_python) = workspace
@@ -512,28 +513,23 @@ async fn init_project(
             PythonVariant::Default,
         ));
 
-        // Pin to the minor version.
-        let python_request = if pin_python {
+        let python_request = if pin.is_enabled() {
             let interpreter = PythonInstallation::find_or_download(
                 Some(&python_request),
-                EnvironmentPreference::OnlySystem,
-                python_preference,
-                python_downloads,
+                FindOptions::default()
+                    .environments(EnvironmentPreference::OnlySystem)
+                    .preference(python_preference)
+                    .downloads(python_downloads),
                 &client_builder,
                 cache,
                 Some(&reporter),
-                install_mirrors.python_install_mirror.as_deref(),
-                install_mirrors.pypy_install_mirror.as_deref(),
+                &install_mirrors.mirrors(),
                 install_mirrors.python_downloads_json_url.as_deref(),
             )
             .await?
             .into_interpreter();
 
-            Some(PythonRequest::Version(VersionRequest::MajorMinor(
-                interpreter.python_major(),
-                interpreter.python_minor(),
-                PythonVariant::Default,
-            )))
+            pin_request(&interpreter, pin, PythonVariant::Default)
         } else {
             None
         };
@@ -543,14 +539,14 @@ async fn init_project(
         // (4) Default to the system Python
         let interpreter = PythonInstallation::find_or_download(
             None,
-            EnvironmentPreference::OnlySystem,
-            python_preference,
-            python_downloads,
+            FindOptions::default()
+                .environments(EnvironmentPreference::OnlySystem)
+                .preference(python_preference)
+                .downloads(python_downloads),
             &client_builder,
             cache,
             Some(&reporter),
-            install_mirrors.python_install_mirror.as_deref(),
-            install_mirrors.pypy_install_mirror.as_deref(),
+            &install_mirrors.mirrors(),
             install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?
@@ -559,16 +555,7 @@ async fn init_project(
         let requires_python =
             RequiresPython::greater_than_equal_version(&interpreter.python_minor_version());
 
-        // Pin to the minor version.
-        let python_request = if pin_python {
-            Some(PythonRequest::Version(VersionRequest::MajorMinor(
-                interpreter.python_major(),
-                interpreter.python_minor(),
-                PythonVariant::Default,
-            )))
-        } else {
-            None
-        };
+        let python_request = pin_request(&interpreter, pin, PythonVariant::Default);
 
         (requires_python, python_request)
     };
@@ -665,6 +652,23 @@ async fn init_project(
     Ok(())
 }
 
+/// Determine the request to record in the `.python-version` file for the given interpreter.
+fn pin_request(
+    interpreter: &Interpreter,
+    pin: PythonPin,
+    variant: PythonVariant,
+) -> Option<PythonRequest> {
+    match pin {
+        PythonPin::Minor => Some(PythonRequest::Version(VersionRequest::MajorMinor(
+            interpreter.python_major(),
+            interpreter.python_minor(),
+            variant,
+        ))),
+        PythonPin::Patch => Some(exact_request(&interpreter.key())),
+        PythonPin::None => None,
+    }
+}
+
 /// The kind of entity to initialize (either a PEP 723 script or a Python project).
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum InitKind {
@@ -12,7 +12,8 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DryRun, ExtrasSpecification, PreviewMode, Reinstall, Upgrade,
+    Concurrency, Constraints, DryRun, ExtrasSpecification, PreviewMode, Reinstall, TargetTriple,
+    Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -80,7 +81,10 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    exclude_package: Option<Vec<PackageName>>,
+    python_platform: Option<Option<TargetTriple>>,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     network_settings: NetworkSettings,
@@ -142,11 +146,13 @@ pub(crate) async fn lock(
                 workspace,
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
                 &install_mirrors,
                 false,
+                true,
                 no_config,
                 Some(false),
                 cache,
@@ -157,6 +163,7 @@ pub(crate) async fn lock(
             LockTarget::Script(script) => ScriptInterpreter::discover(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
@@ -195,6 +202,8 @@ pub(crate) async fn lock(
         printer,
         preview,
     )
+    .with_excluded_packages(exclude_package)
+    .with_python_platform(python_platform)
     .execute(target)
     .await
     {
@@ -250,6 +259,8 @@ pub(super) enum LockMode<'env> {
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    excluded_packages: Option<Vec<PackageName>>,
+    python_platform: Option<Option<TargetTriple>>,
     settings: &'env ResolverSettings,
     network_settings: &'env NetworkSettings,
     state: &'env UniversalState,
@@ -276,6 +287,8 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            excluded_packages: None,
+            python_platform: None,
             settings,
             network_settings,
             state,
@@ -297,6 +310,31 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the packages to exclude from the [`LockOperation`], as they're provided externally.
+    ///
+    /// If `None`, the exclusions are retained from the existing lockfile.
+    #[must_use]
+    pub(super) fn with_excluded_packages(
+        mut self,
+        excluded_packages: Option<Vec<PackageName>>,
+    ) -> Self {
+        self.excluded_packages = excluded_packages;
+        self
+    }
+
+    /// Set the target platform for the [`LockOperation`], or `Some(None)` to lock for all
+    /// platforms.
+    ///
+    /// If `None`, the target platform is retained from the existing lockfile.
+    #[must_use]
+    pub(super) fn with_python_platform(
+        mut self,
+        python_platform: Option<Option<TargetTriple>>,
+    ) -> Self {
+        self.python_platform = python_platform;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -321,6 +359,8 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     Some(existing),
                     self.constraints,
+                    self.excluded_packages.as_deref(),
+                    self.python_platform,
                     self.settings,
                     self.network_settings,
                     self.state,
@@ -359,6 +399,8 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     existing,
                     self.constraints,
+                    self.excluded_packages.as_deref(),
+                    self.python_platform,
                     self.settings,
                     self.network_settings,
                     self.state,
@@ -389,6 +431,8 @@ async fn do_lock(
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
+    excluded_packages: Option<&[PackageName]>,
+    python_platform: Option<Option<TargetTriple>>,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
     state: &UniversalState,
@@ -429,6 +473,21 @@ async fn do_lock(
     let dependency_groups = target.dependency_groups()?;
     let source_trees = vec![];
 
+    // Unless the excluded packages were provided explicitly (or cleared), retain the exclusions
+    // from the existing lockfile.
+    let excluded_packages = match excluded_packages {
+        Some(excluded_packages) => excluded_packages.to_vec(),
+        None => existing_lock
+            .as_ref()
+            .map(|lock| lock.external().iter().cloned().collect())
+            .unwrap_or_default(),
+    };
+
+    // Similarly, unless provided explicitly (or cleared), retain the target platform from the
+    // existing lockfile.
+    let python_platform =
+        python_platform.unwrap_or_else(|| existing_lock.as_ref().and_then(Lock::python_platform));
+
     // If necessary, lower the overrides and constraints.
     let requirements = target.lower(requirements, index_locations, *sources)?;
     let overrides = target.lower(overrides, index_locations, *sources)?;
@@ -488,6 +547,34 @@ async fn do_lock(
         environments
     };
 
+    // If a target platform was requested, limit the supported environments to that platform.
+    let platform_environments = if let Some(python_platform) = python_platform {
+        let marker = python_platform.marker_tree(interpreter.markers());
+        let markers = if let Some(environments) = environments {
+            environments
+                .iter()
+                .filter_map(|environment| {
+                    let mut environment = *environment;
+                    environment.and(marker);
+                    (!environment.is_false()).then_some(environment)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            vec![marker]
+        };
+        if markers.is_empty() {
+            return if let Some(contents) = marker.contents() {
+                Err(ProjectError::DisjointPlatform(contents))
+            } else {
+                Err(ProjectError::EmptyEnvironment)
+            };
+        }
+        Some(SupportedEnvironments::from_markers(markers))
+    } else {
+        None
+    };
+    let environments = platform_environments.as_ref().or(environments);
+
     // Collect the list of required platforms.
     let required_environments = if let Some(required_environments) = target.required_environments()
     {
@@ -686,6 +773,7 @@ async fn do_lock(
             environments,
             required_environments,
             dependency_metadata,
+            &excluded_packages,
             interpreter,
             &requires_python,
             index_locations,
@@ -803,6 +891,7 @@ async fn do_lock(
                     .cloned()
                     .map(UnresolvedRequirementSpecification::from)
                     .collect(),
+                &excluded_packages,
                 source_trees,
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
@@ -843,6 +932,8 @@ async fn do_lock(
                 build_constraints,
                 dependency_groups,
                 dependency_metadata.values().cloned(),
+                excluded_packages.iter().cloned(),
+                python_platform,
             )
             .relative_to(target.install_path())?;
 
@@ -898,6 +989,7 @@ impl ValidatedLock {
         environments: Option<&SupportedEnvironments>,
         required_environments: Option<&SupportedEnvironments>,
         dependency_metadata: &DependencyMetadata,
+        excluded_packages: &[PackageName],
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
         index_locations: &IndexLocations,
@@ -1074,6 +1166,7 @@ impl ValidatedLock {
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
+                excluded_packages,
                 indexes,
                 interpreter.tags()?,
                 hasher,
@@ -1171,6 +1264,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedExternal(expected, actual) => {
+                debug!(
+                    "Ignoring existing lockfile due to mismatched external packages:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MissingRoot(name) => {
                 debug!("Ignoring existing lockfile due to missing root package: `{name}`");
                 Ok(Self::Preferable(lock))
@@ -28,9 +28,9 @@ use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_pypi_types::{ConflictPackage, ConflictSet, Conflicts};
 use uv_python::{
-    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    EnvironmentPreference, FindOptions, Interpreter, InvalidEnvironmentKind, PythonDownloads,
+    PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest, PythonVariant,
+    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
@@ -62,7 +62,7 @@ pub(crate) mod export;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
-mod lock_target;
+pub(crate) mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
@@ -102,6 +102,9 @@ pub(crate) enum ProjectError {
     #[error("The Python request from `{0}` resolved to Python {1}, which is incompatible with the project's Python requirement: `{2}`. Use `uv python pin` to update the `.python-version` file to a compatible version.")]
     DotPythonVersionProjectIncompatibility(String, Version, RequiresPython),
 
+    #[error("The Python request from `{0}` resolved to Python {1}, which is incompatible with the project's Python requirement: `{2}`. Update or unset `{0}` to use a compatible version.")]
+    EnvironmentVariableProjectIncompatibility(&'static str, Version, RequiresPython),
+
     #[error("The resolved Python interpreter (Python {0}) is incompatible with the project's Python requirement: `{1}`")]
     RequiresPythonProjectIncompatibility(Version, RequiresPython),
 
@@ -111,6 +114,9 @@ pub(crate) enum ProjectError {
     #[error("The Python request from `{0}` resolved to Python {1}, which is incompatible with the script's Python requirement: `{2}`")]
     DotPythonVersionScriptIncompatibility(String, Version, RequiresPython),
 
+    #[error("The Python request from `{0}` resolved to Python {1}, which is incompatible with the script's Python requirement: `{2}`. Update or unset `{0}` to use a compatible version.")]
+    EnvironmentVariableScriptIncompatibility(&'static str, Version, RequiresPython),
+
     #[error("The resolved Python interpreter (Python {0}) is incompatible with the script's Python requirement: `{1}`")]
     RequiresPythonScriptIncompatibility(Version, RequiresPython),
 
@@ -169,6 +175,9 @@ pub(crate) enum ProjectError {
     #[error("Environment markers `{0}` don't overlap with Python requirement `{1}`")]
     DisjointEnvironment(MarkerTreeContents, VersionSpecifiers),
 
+    #[error("Target platform markers `{0}` don't overlap with the supported environments")]
+    DisjointPlatform(MarkerTreeContents),
+
     #[error("The workspace contains conflicting Python requirements:\n{}", _0.iter().map(|(name, specifiers)| format!("- `{name}`: `{specifiers}`")).join("\n"))]
     DisjointRequiresPython(BTreeMap<PackageName, VersionSpecifiers>),
 
@@ -178,6 +187,9 @@ pub(crate) enum ProjectError {
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
+    #[error("The project environment at `{}` uses Python {}, but Python {} was requested. Use `--recreate-venv` to recreate the environment with the requested version.", _0.user_display(), _1, _2)]
+    ProjectEnvironmentPatchMismatch(PathBuf, Version, String),
+
     #[error("Failed to parse `uv.lock`")]
     UvLockParse(#[source] toml::de::Error),
 
@@ -453,7 +465,7 @@ pub(crate) fn validate_project_requires_python(
         };
         if specifiers.contains(interpreter.python_version()) {
             return match source {
-                PythonRequestSource::UserRequest => {
+                PythonRequestSource::UserRequest | PythonRequestSource::EnvironmentVariable(_) => {
                     Err(ProjectError::RequestedMemberIncompatibility(
                         interpreter.python_version().clone(),
                         requires_python.clone(),
@@ -492,6 +504,13 @@ pub(crate) fn validate_project_requires_python(
                 requires_python.clone(),
             ))
         }
+        PythonRequestSource::EnvironmentVariable(name) => {
+            Err(ProjectError::EnvironmentVariableProjectIncompatibility(
+                name,
+                interpreter.python_version().clone(),
+                requires_python.clone(),
+            ))
+        }
         PythonRequestSource::DotPythonVersion(file) => {
             Err(ProjectError::DotPythonVersionProjectIncompatibility(
                 file.path().user_display().to_string(),
@@ -525,6 +544,13 @@ fn validate_script_requires_python(
                 requires_python.clone(),
             ))
         }
+        PythonRequestSource::EnvironmentVariable(name) => {
+            Err(ProjectError::EnvironmentVariableScriptIncompatibility(
+                name,
+                interpreter.python_version().clone(),
+                requires_python.clone(),
+            ))
+        }
         PythonRequestSource::DotPythonVersion(file) => {
             Err(ProjectError::DotPythonVersionScriptIncompatibility(
                 file.file_name().to_string(),
@@ -641,6 +667,7 @@ impl ScriptInterpreter {
     pub(crate) async fn discover(
         script: Pep723ItemRef<'_>,
         python_request: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
         python_downloads: PythonDownloads,
@@ -658,7 +685,14 @@ impl ScriptInterpreter {
             source,
             python_request,
             requires_python,
-        } = ScriptPython::from_request(python_request, workspace, script, no_config).await?;
+        } = ScriptPython::from_request(
+            python_request,
+            python_variable,
+            workspace,
+            script,
+            no_config,
+        )
+        .await?;
 
         let root = Self::root(script, active, cache);
         match PythonEnvironment::from_root(&root, cache) {
@@ -698,14 +732,15 @@ impl ScriptInterpreter {
 
         let interpreter = PythonInstallation::find_or_download(
             python_request.as_ref(),
-            EnvironmentPreference::Any,
-            python_preference,
-            python_downloads,
+            FindOptions::default()
+                .environments(EnvironmentPreference::Any)
+                .preference(python_preference)
+                .downloads(python_downloads)
+                .request_variable(source.variable()),
             &client_builder,
             cache,
             Some(&reporter),
-            install_mirrors.python_install_mirror.as_deref(),
-            install_mirrors.pypy_install_mirror.as_deref(),
+            &install_mirrors.mirrors(),
             install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?
@@ -832,6 +867,15 @@ fn environment_is_usable(
     Ok(())
 }
 
+/// Returns `true` if the [`PythonRequest`] pins an exact patch version, e.g., `3.12.1`.
+fn is_exact_patch_request(request: &PythonRequest) -> bool {
+    matches!(
+        request,
+        PythonRequest::Version(VersionRequest::MajorMinorPatch(..))
+            | PythonRequest::ImplementationVersion(_, VersionRequest::MajorMinorPatch(..))
+    )
+}
+
 /// An interpreter suitable for the project.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -848,11 +892,13 @@ impl ProjectInterpreter {
         workspace: &Workspace,
         project_dir: &Path,
         python_request: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
         python_downloads: PythonDownloads,
         install_mirrors: &PythonInstallMirrors,
         keep_incompatible: bool,
+        recreate_venv: bool,
         no_config: bool,
         active: Option<bool>,
         cache: &Cache,
@@ -863,8 +909,14 @@ impl ProjectInterpreter {
             source,
             python_request,
             requires_python,
-        } = WorkspacePython::from_request(python_request, Some(workspace), project_dir, no_config)
-            .await?;
+        } = WorkspacePython::from_request(
+            python_request,
+            python_variable,
+            Some(workspace),
+            project_dir,
+            no_config,
+        )
+        .await?;
 
         // Read from the virtual environment first.
         let root = workspace.venv(active);
@@ -885,6 +937,25 @@ impl ProjectInterpreter {
                         );
                         return Ok(Self::Environment(venv));
                     }
+                    // If an exact patch version was requested and the environment no longer
+                    // provides it (e.g., after an in-place upgrade of its interpreter), require
+                    // the user to opt in to recreating the environment.
+                    Err(_)
+                        if !recreate_venv
+                            && python_request.as_ref().is_some_and(|request| {
+                                is_exact_patch_request(request)
+                                    && !request.satisfied(venv.interpreter(), cache)
+                            }) =>
+                    {
+                        return Err(ProjectError::ProjectEnvironmentPatchMismatch(
+                            root,
+                            venv.interpreter().python_version().clone(),
+                            python_request
+                                .as_ref()
+                                .map(PythonRequest::to_canonical_string)
+                                .unwrap_or_default(),
+                        ));
+                    }
                     Err(err) => {
                         debug!("{err}");
                     }
@@ -938,14 +1009,15 @@ impl ProjectInterpreter {
         // Locate the Python interpreter to use in the environment.
         let python = PythonInstallation::find_or_download(
             python_request.as_ref(),
-            EnvironmentPreference::OnlySystem,
-            python_preference,
-            python_downloads,
+            FindOptions::default()
+                .environments(EnvironmentPreference::OnlySystem)
+                .preference(python_preference)
+                .downloads(python_downloads)
+                .request_variable(source.variable()),
             &client_builder,
             cache,
             Some(&reporter),
-            install_mirrors.python_install_mirror.as_deref(),
-            install_mirrors.pypy_install_mirror.as_deref(),
+            &install_mirrors.mirrors(),
             install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await?;
@@ -1017,16 +1089,34 @@ pub(crate) enum RequiresPythonSource {
 pub(crate) enum PythonRequestSource {
     /// The request was provided by the user.
     UserRequest,
+    /// The request was read from an environment variable, e.g., `UV_PYTHON`.
+    EnvironmentVariable(&'static str),
     /// The request was inferred from a `.python-version` or `.python-versions` file.
     DotPythonVersion(PythonVersionFile),
     /// The request was inferred from a `pyproject.toml` file.
     RequiresPython,
 }
 
+impl PythonRequestSource {
+    /// Return the environment variable the request was read from, if any.
+    pub(crate) fn variable(&self) -> Option<&'static str> {
+        match self {
+            Self::EnvironmentVariable(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for PythonRequestSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PythonRequestSource::UserRequest => write!(f, "explicit request"),
+            PythonRequestSource::EnvironmentVariable(name) => {
+                write!(f, "`{name}` environment variable")
+            }
+            PythonRequestSource::DotPythonVersion(file) if file.is_global() => {
+                write!(f, "global version file at `{}`", file.path().user_display())
+            }
             PythonRequestSource::DotPythonVersion(file) => {
                 write!(f, "version file at `{}`", file.path().user_display())
             }
@@ -1051,8 +1141,12 @@ pub(crate) struct WorkspacePython {
 
 impl WorkspacePython {
     /// Determine the [`WorkspacePython`] for the current [`Workspace`].
+    ///
+    /// If the `python_request` was read from an environment variable, e.g., `UV_PYTHON`, rather
+    /// than provided on the command line, `python_variable` is the name of the variable.
     pub(crate) async fn from_request(
         python_request: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         workspace: Option<&Workspace>,
         project_dir: &Path,
         no_config: bool,
@@ -1062,8 +1156,11 @@ impl WorkspacePython {
         let workspace_root = workspace.map(Workspace::install_path);
 
         let (source, python_request) = if let Some(request) = python_request {
-            // (1) Explicit request from user
-            let source = PythonRequestSource::UserRequest;
+            // (1) Explicit request from user, possibly via `UV_PYTHON`
+            let source = match python_variable {
+                Some(name) => PythonRequestSource::EnvironmentVariable(name),
+                None => PythonRequestSource::UserRequest,
+            };
             let request = Some(request);
             (source, request)
         } else if let Some(file) = PythonVersionFile::discover(
@@ -1124,8 +1221,11 @@ pub(crate) struct ScriptPython {
 
 impl ScriptPython {
     /// Determine the [`ScriptPython`] for the current [`Workspace`].
+    ///
+    /// See [`WorkspacePython::from_request`].
     pub(crate) async fn from_request(
         python_request: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         workspace: Option<&Workspace>,
         script: Pep723ItemRef<'_>,
         no_config: bool,
@@ -1137,6 +1237,7 @@ impl ScriptPython {
             requires_python,
         } = WorkspacePython::from_request(
             python_request,
+            python_variable,
             workspace,
             script.path().and_then(Path::parent).unwrap_or(&**CWD),
             no_config,
@@ -1205,11 +1306,13 @@ impl ProjectEnvironment {
     pub(crate) async fn get_or_init(
         workspace: &Workspace,
         python: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         install_mirrors: &PythonInstallMirrors,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
         python_downloads: PythonDownloads,
         no_sync: bool,
+        recreate_venv: bool,
         no_config: bool,
         active: Option<bool>,
         cache: &Cache,
@@ -1223,11 +1326,13 @@ impl ProjectEnvironment {
             workspace,
             workspace.install_path().as_ref(),
             python,
+            python_variable,
             network_settings,
             python_preference,
             python_downloads,
             install_mirrors,
             no_sync,
+            recreate_venv,
             no_config,
             active,
             cache,
@@ -1409,6 +1514,7 @@ impl ScriptEnvironment {
     pub(crate) async fn get_or_init(
         script: Pep723ItemRef<'_>,
         python_request: Option<PythonRequest>,
+        python_variable: Option<&'static str>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
         python_downloads: PythonDownloads,
@@ -1426,6 +1532,7 @@ impl ScriptEnvironment {
         match ScriptInterpreter::discover(
             script,
             python_request,
+            python_variable,
             network_settings,
             python_preference,
             python_downloads,
@@ -1882,6 +1989,7 @@ pub(crate) async fn resolve_environment(
         requirements,
         constraints,
         overrides,
+        &[],
         source_trees,
         project,
         BTreeSet::default(),
@@ -2254,6 +2362,7 @@ pub(crate) async fn update_environment(
         requirements,
         constraints,
         overrides,
+        &[],
         source_trees,
         project,
         BTreeSet::default(),
@@ -2352,14 +2461,14 @@ pub(crate) async fn init_script_python_requirement(
 
     let interpreter = PythonInstallation::find_or_download(
         Some(&python_request),
-        EnvironmentPreference::Any,
-        python_preference,
-        python_downloads,
+        FindOptions::default()
+            .environments(EnvironmentPreference::Any)
+            .preference(python_preference)
+            .downloads(python_downloads),
         client_builder,
         cache,
         Some(reporter),
-        install_mirrors.python_install_mirror.as_deref(),
-        install_mirrors.pypy_install_mirror.as_deref(),
+        &install_mirrors.mirrors(),
         install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
@@ -4,6 +4,7 @@ use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use console::Term;
 use owo_colors::OwoColorize;
 use tracing::debug;
 
@@ -46,9 +47,12 @@ pub(crate) async fn remove(
     active: Option<bool>,
     no_sync: bool,
     packages: Vec<PackageName>,
+    all: bool,
+    yes: bool,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -121,6 +125,42 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If `--all` was provided, remove every dependency of the given type.
+    let packages = if all {
+        let packages = toml.dependency_names(&dependency_type);
+        let table = dependency_table(&dependency_type);
+        if packages.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No dependencies found in `{}`",
+                table.cyan()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        if !yes {
+            let term = Term::stderr();
+            if !term.is_term() {
+                anyhow::bail!(
+                    "Removing all dependencies from `{table}` requires confirmation; use `--yes` to proceed"
+                );
+            }
+            let prompt = format!(
+                "Remove {} from `{table}`?",
+                if packages.len() == 1 {
+                    "1 dependency".to_string()
+                } else {
+                    format!("all {} dependencies", packages.len())
+                }
+            );
+            if !uv_console::confirm(&prompt, &term, false).context("Confirm prompt failed")? {
+                return Ok(ExitStatus::Failure);
+            }
+        }
+        packages
+    } else {
+        packages
+    };
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -211,11 +251,13 @@ pub(crate) async fn remove(
                     project.workspace(),
                     project_dir,
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     &network_settings,
                     python_preference,
                     python_downloads,
                     &install_mirrors,
                     false,
+                    true,
                     no_config,
                     active,
                     cache,
@@ -230,11 +272,13 @@ pub(crate) async fn remove(
                 let environment = ProjectEnvironment::get_or_init(
                     project.workspace(),
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     &install_mirrors,
                     &network_settings,
                     python_preference,
                     python_downloads,
                     no_sync,
+                    true,
                     no_config,
                     active,
                     cache,
@@ -251,6 +295,7 @@ pub(crate) async fn remove(
             let interpreter = ScriptInterpreter::discover(
                 Pep723ItemRef::Script(&script),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
@@ -433,6 +478,16 @@ impl RemoveTarget {
 ///
 /// This is useful when a dependency of the user-specified type was not found, but it may be present
 /// elsewhere.
+/// Return the name of the table that contains dependencies of the given type, for display.
+fn dependency_table(dependency_type: &DependencyType) -> String {
+    match dependency_type {
+        DependencyType::Production => "project.dependencies".to_string(),
+        DependencyType::Dev => "dependency-groups.dev".to_string(),
+        DependencyType::Optional(extra) => format!("project.optional-dependencies.{extra}"),
+        DependencyType::Group(group) => format!("dependency-groups.{group}"),
+    }
+}
+
 fn show_other_dependency_type_hint(
     printer: Printer,
     name: &PackageName,
@@ -26,8 +26,8 @@ use uv_fs::{PythonExt, Simplified};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_python::{
-    EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
+    EnvironmentPreference, FindOptions, Interpreter, PyVenvConfiguration, PythonDownloads,
+    PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
     VersionFileDiscoveryOptions,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
@@ -35,6 +35,7 @@ use uv_resolver::{Installable, Lock, Preference};
 use uv_scripts::Pep723Item;
 use uv_settings::PythonInstallMirrors;
 use uv_shell::runnable::WindowsRunnable;
+use uv_shell::shlex_split;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache, WorkspaceError};
@@ -71,6 +72,7 @@ pub(crate) async fn run(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    recreate_venv: bool,
     isolated: bool,
     all_packages: bool,
     package: Option<PackageName>,
@@ -81,6 +83,7 @@ pub(crate) async fn run(
     editable: EditableMode,
     modifications: Modifications,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -94,6 +97,7 @@ pub(crate) async fn run(
     no_env_file: bool,
     preview: PreviewMode,
     max_recursion_depth: u32,
+    python_args: Option<String>,
 ) -> anyhow::Result<ExitStatus> {
     // Check if max recursion depth was exceeded. This most commonly happens
     // for scripts with a shebang line like `#!/usr/bin/env -S uv run`, so try
@@ -110,6 +114,23 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         );
     }
 
+    // Split the interpreter arguments, e.g., `--python-args "-X dev"`.
+    let python_args = if let Some(python_args) = python_args {
+        shlex_split(&python_args).ok_or_else(|| {
+            anyhow!("Failed to parse `--python-args`: unterminated quote or trailing backslash in `{python_args}`")
+        })?
+    } else {
+        Vec::new()
+    };
+    if !python_args.is_empty() {
+        if let Some(RunCommand::External(executable, _)) = command.as_ref() {
+            warn_user!(
+                "`--python-args` has no effect when running `{}`, which is not run with the Python interpreter",
+                executable.to_string_lossy()
+            );
+        }
+    }
+
     // These cases seem quite complex because (in theory) they should change the "current package".
     // Let's ban them entirely for now.
     let mut requirements_from_stdin: bool = false;
@@ -224,6 +245,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             let environment = ScriptEnvironment::get_or_init(
                 (&script).into(),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
@@ -348,6 +370,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 let environment = ScriptEnvironment::get_or_init(
                     (&script).into(),
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     &network_settings,
                     python_preference,
                     python_downloads,
@@ -423,6 +446,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 let interpreter = ScriptInterpreter::discover(
                     (&script).into(),
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     &network_settings,
                     python_preference,
                     python_downloads,
@@ -600,6 +624,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     requires_python,
                 } = WorkspacePython::from_request(
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     Some(project.workspace()),
                     project_dir,
                     no_config,
@@ -608,14 +633,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
                 let interpreter = PythonInstallation::find_or_download(
                     python_request.as_ref(),
-                    EnvironmentPreference::Any,
-                    python_preference,
-                    python_downloads,
+                    FindOptions::default()
+                        .environments(EnvironmentPreference::Any)
+                        .preference(python_preference)
+                        .downloads(python_downloads)
+                        .request_variable(source.variable()),
                     &client_builder,
                     cache,
                     Some(&download_reporter),
-                    install_mirrors.python_install_mirror.as_deref(),
-                    install_mirrors.pypy_install_mirror.as_deref(),
+                    &install_mirrors.mirrors(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?
@@ -647,11 +673,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 ProjectEnvironment::get_or_init(
                     project.workspace(),
                     python.as_deref().map(PythonRequest::parse),
+                    python_variable,
                     &install_mirrors,
                     &network_settings,
                     python_preference,
                     python_downloads,
                     no_sync,
+                    recreate_venv,
                     no_config,
                     active,
                     cache,
@@ -828,29 +856,31 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
                 // (1) Explicit request from user
-                let python_request = if let Some(request) = python.as_deref() {
-                    Some(PythonRequest::parse(request))
+                let (python_request, python_variable) = if let Some(request) = python.as_deref() {
+                    (Some(PythonRequest::parse(request)), python_variable)
                 // (2) Request from `.python-version`
                 } else {
-                    PythonVersionFile::discover(
+                    let request = PythonVersionFile::discover(
                         &project_dir,
                         &VersionFileDiscoveryOptions::default().with_no_config(no_config),
                     )
                     .await?
-                    .and_then(PythonVersionFile::into_version)
+                    .and_then(PythonVersionFile::into_version);
+                    (request, None)
                 };
 
                 let python = PythonInstallation::find_or_download(
                     python_request.as_ref(),
-                    // No opt-in is required for system environments, since we are not mutating it.
-                    EnvironmentPreference::Any,
-                    python_preference,
-                    python_downloads,
+                    FindOptions::default()
+                        // No opt-in is required for system environments, since we are not mutating it.
+                        .environments(EnvironmentPreference::Any)
+                        .preference(python_preference)
+                        .downloads(python_downloads)
+                        .request_variable(python_variable),
                     &client_builder,
                     cache,
                     Some(&download_reporter),
-                    install_mirrors.python_install_mirror.as_deref(),
-                    install_mirrors.pypy_install_mirror.as_deref(),
+                    &install_mirrors.mirrors(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                 )
                 .await?;
@@ -1085,7 +1115,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     };
 
     debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
+    let mut process = command.as_command(interpreter, &python_args);
 
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
@@ -1240,10 +1270,14 @@ impl RunCommand {
     }
 
     /// Convert a [`RunCommand`] into a [`Command`].
-    fn as_command(&self, interpreter: &Interpreter) -> Command {
+    ///
+    /// The `python_args` are passed to the Python interpreter, ahead of the script or module; they
+    /// are ignored for external commands.
+    fn as_command(&self, interpreter: &Interpreter, python_args: &[String]) -> Command {
         match self {
             Self::Python(args) => {
                 let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
                 process.args(args);
                 process
             }
@@ -1251,14 +1285,16 @@ impl RunCommand {
                 let name = PathBuf::from(target).with_extension(std::env::consts::EXE_EXTENSION);
                 let entrypoint = interpreter.scripts().join(name);
 
-                // If the target is an installed, executable script — prefer that
-                if uv_fs::which::is_executable(&entrypoint) {
+                // If the target is an installed, executable script — prefer that, unless we need
+                // to pass arguments to the interpreter
+                if python_args.is_empty() && uv_fs::which::is_executable(&entrypoint) {
                     let mut process = Command::new(entrypoint);
                     process.args(args);
                     process
                 // Otherwise, invoke `python <module>`
                 } else {
                     let mut process = Command::new(interpreter.sys_executable());
+                    process.args(python_args);
                     process.arg(path);
                     process.args(args);
                     process
@@ -1266,18 +1302,21 @@ impl RunCommand {
             }
             Self::PythonScript(target, args) | Self::PythonZipapp(target, args) => {
                 let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
                 process.arg(target);
                 process.args(args);
                 process
             }
             Self::PythonRemote(.., target, args) => {
                 let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
                 process.arg(target.path());
                 process.args(args);
                 process
             }
             Self::PythonModule(module, args) => {
                 let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
                 process.arg("-m");
                 process.arg(module);
                 process.args(args);
@@ -1298,12 +1337,14 @@ impl RunCommand {
                     .unwrap_or_else(|| python_executable.to_path_buf());
 
                 let mut process = Command::new(&pythonw_executable);
+                process.args(python_args);
                 process.arg(target);
                 process.args(args);
                 process
             }
             Self::PythonStdin(script, args) => {
                 let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
                 process.arg("-c");
 
                 #[cfg(unix)]
@@ -1336,6 +1377,7 @@ impl RunCommand {
                     .unwrap_or_else(|| python_executable.to_path_buf());
 
                 let mut process = Command::new(&pythonw_executable);
+                process.args(python_args);
                 process.arg("-c");
 
                 #[cfg(unix)]
@@ -1362,7 +1404,11 @@ impl RunCommand {
                 process.args(args);
                 process
             }
-            Self::Empty => Command::new(interpreter.sys_executable()),
+            Self::Empty => {
+                let mut process = Command::new(interpreter.sys_executable());
+                process.args(python_args);
+                process
+            }
         }
     }
 }
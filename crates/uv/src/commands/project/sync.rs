@@ -6,19 +6,22 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    PreviewMode,
+    BuildOptions, Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun,
+    EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode,
+    InstallOptions, PreviewMode,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, InstalledDist, Name, Requirement, Resolution, ResolvedDist,
+    SourceDist,
 };
 use uv_fs::Simplified;
+use uv_install_wheel::RecordMismatch;
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
@@ -40,9 +43,10 @@ use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     default_dependency_groups, detect_conflicts, script_specification, update_environment,
-    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
+    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, ScriptInterpreter,
+    UniversalState,
 };
-use crate::commands::{diagnostics, ExitStatus};
+use crate::commands::{diagnostics, elapsed, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings};
 
@@ -53,15 +57,19 @@ pub(crate) async fn sync(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    verify_hashes: bool,
     active: Option<bool>,
+    recreate_venv: bool,
     all_packages: bool,
     package: Option<PackageName>,
+    exclude_package: Option<Vec<PackageName>>,
     extras: ExtrasSpecification,
     dev: DependencyGroups,
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -126,17 +134,36 @@ pub(crate) async fn sync(
         SyncTarget::Script(..) => DefaultExtras::default(),
     };
 
+    // Verify the existing environment against the lockfile, without modifying either.
+    if verify_hashes {
+        return verify_environment(
+            &target,
+            active,
+            all_packages,
+            package.as_ref(),
+            &extras.with_defaults(default_extras),
+            &dev.with_defaults(default_groups),
+            &install_options,
+            &settings.resolver.build_options,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
     // Discover or create the virtual environment.
     let environment = match &target {
         SyncTarget::Project(project) => SyncEnvironment::Project(
             ProjectEnvironment::get_or_init(
                 project.workspace(),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &install_mirrors,
                 &network_settings,
                 python_preference,
                 python_downloads,
                 false,
+                recreate_venv,
                 no_config,
                 active,
                 cache,
@@ -149,6 +176,7 @@ pub(crate) async fn sync(
             ScriptEnvironment::get_or_init(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 &network_settings,
                 python_preference,
                 python_downloads,
@@ -367,6 +395,7 @@ pub(crate) async fn sync(
         printer,
         preview,
     )
+    .with_excluded_packages(exclude_package)
     .execute(lock_target)
     .await
     {
@@ -466,6 +495,152 @@ pub(crate) async fn sync(
     }
 }
 
+/// Verify the packages installed in the environment for the [`SyncTarget`] against the lockfile.
+///
+/// Checks that each package the lockfile requires in the environment is installed at its locked
+/// version, and that the files installed by each package match the hashes in its `RECORD` file.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn verify_environment(
+    target: &SyncTarget,
+    active: Option<bool>,
+    all_packages: bool,
+    package: Option<&PackageName>,
+    extras: &ExtrasSpecificationWithDefaults,
+    dev: &DependencyGroupsWithDefaults,
+    install_options: &InstallOptions,
+    build_options: &BuildOptions,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let (lock_target, root) = match target {
+        SyncTarget::Project(project) => (
+            LockTarget::from(project.workspace()),
+            project.workspace().venv(active),
+        ),
+        SyncTarget::Script(script) => (
+            LockTarget::from(script),
+            ScriptInterpreter::root(Pep723ItemRef::Script(script), active, cache),
+        ),
+    };
+
+    let Some(lock) = lock_target.read().await? else {
+        return Err(anyhow::anyhow!(
+            "`uv sync --verify-hashes` requires a lockfile; run `{}` to generate one",
+            "uv lock".green(),
+        ));
+    };
+
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Determine the distributions that the lockfile requires in this environment, as in a sync.
+    let marker_env = environment.interpreter().resolver_marker_environment();
+    let tags = environment.interpreter().tags()?;
+    let resolution = identify_installation_target(target, &lock, all_packages, package)
+        .to_resolution(
+            &marker_env,
+            tags,
+            extras,
+            dev,
+            build_options,
+            install_options,
+        )?;
+    let resolution = apply_no_virtual_project(resolution);
+
+    let mut verified = 0usize;
+    let mut failures = Vec::new();
+    for locked in resolution
+        .distributions()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        let installed = site_packages.get_packages(locked.name());
+        let dist = match installed.as_slice() {
+            [] => {
+                failures.push(format!("{}: is not installed", locked.to_string().bold()));
+                continue;
+            }
+            [dist] => *dist,
+            _ => {
+                failures.push(format!(
+                    "{}: multiple versions are installed ({})",
+                    locked.name().bold(),
+                    installed
+                        .iter()
+                        .map(|dist| format!("`{}`", dist.version()))
+                        .join(", "),
+                ));
+                continue;
+            }
+        };
+
+        // Packages with dynamic versions have no version in the lockfile.
+        if let Some(version) = locked.version() {
+            if dist.version() != version {
+                failures.push(format!(
+                    "{}: installed version does not match the lockfile (`{version}`)",
+                    dist.to_string().bold(),
+                ));
+                continue;
+            }
+        }
+
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            debug!("Skipping verification of non-wheel installation: {dist}");
+            continue;
+        }
+
+        let mismatches = uv_install_wheel::verify_wheel(dist.install_path())?;
+        if mismatches.is_empty() {
+            verified += 1;
+            continue;
+        }
+        for mismatch in mismatches {
+            match mismatch {
+                RecordMismatch::Missing { path } => {
+                    failures.push(format!("{}: `{path}` is missing", dist.to_string().bold()))
+                }
+                RecordMismatch::Hash {
+                    path,
+                    expected,
+                    actual,
+                } => failures.push(format!(
+                    "{}: `{path}` has hash `{actual}`, but expected `{expected}`",
+                    dist.to_string().bold()
+                )),
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        let s = if verified == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Verified {} {}",
+                format!("{verified} package{s}").bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if failures.len() == 1 { "" } else { "es" };
+    writeln!(
+        printer.stderr(),
+        "Found {} in the project environment:",
+        format!("{} mismatch{s}", failures.len()).bold()
+    )?;
+    for failure in failures {
+        writeln!(printer.stderr(), " {} {failure}", "-".red())?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
 /// The outcome of a `lock` operation within a `sync` operation.
 #[derive(Debug)]
 enum Outcome {
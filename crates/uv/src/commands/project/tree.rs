@@ -1,8 +1,10 @@
+use std::fmt::Write;
 use std::path::Path;
 
-use anstream::print;
+use anstream::{print, println};
 use anyhow::{Error, Result};
 use futures::StreamExt;
+use owo_colors::OwoColorize;
 use tokio::sync::Semaphore;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
@@ -41,12 +43,16 @@ pub(crate) async fn tree(
     depth: u8,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
+    why: Vec<PackageName>,
+    json: bool,
+    cycles: bool,
     no_dedupe: bool,
     invert: bool,
     outdated: bool,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python: Option<String>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     network_settings: &NetworkSettings,
@@ -87,6 +93,7 @@ pub(crate) async fn tree(
             LockTarget::Script(script) => ScriptInterpreter::discover(
                 Pep723ItemRef::Script(script),
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 network_settings,
                 python_preference,
                 python_downloads,
@@ -103,11 +110,13 @@ pub(crate) async fn tree(
                 workspace,
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
+                python_variable,
                 network_settings,
                 python_preference,
                 python_downloads,
                 &install_mirrors,
                 false,
+                true,
                 no_config,
                 Some(false),
                 cache,
@@ -271,12 +280,35 @@ pub(crate) async fn tree(
         depth.into(),
         &prune,
         &package,
+        &why,
         &dev.with_defaults(defaults),
         no_dedupe,
         invert,
     );
 
-    print!("{tree}");
+    if cycles {
+        let cycles = tree.cycles();
+        if cycles.is_empty() {
+            writeln!(printer.stderr(), "No dependency cycles found")?;
+            return Ok(ExitStatus::Success);
+        }
+        for cycle in &cycles {
+            println!("{cycle}");
+        }
+        let s = if cycles.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Found {} dependency cycle{s}", cycles.len()).bold()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&tree.to_json())?);
+    } else {
+        print!("{tree}");
+    }
 
     Ok(ExitStatus::Success)
 }
@@ -15,7 +15,8 @@ use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBu
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
 use uv_publish::{
-    check_trusted_publishing, files_for_publishing, upload, CheckUrlClient, TrustedPublishResult,
+    check_file, check_trusted_publishing, files_for_publishing, generate_attestation, upload,
+    AttestationError, CheckUrlClient, PublishError, TrustedPublishResult,
 };
 use uv_warnings::warn_user_once;
 
@@ -33,6 +34,7 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
+    attestation: Option<bool>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -74,7 +76,7 @@ pub(crate) async fn publish(
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
-    let (publish_url, credentials) = gather_credentials(
+    let (publish_url, credentials, uses_trusted_publishing) = gather_credentials(
         publish_url,
         username,
         password,
@@ -111,6 +113,10 @@ pub(crate) async fn publish(
         None
     };
 
+    // PyPI only accepts attestations for uploads with trusted publishing, so we only generate them
+    // by default in that case.
+    let mut attest = attestation.unwrap_or(uses_trusted_publishing);
+
     for (file, raw_filename, filename) in files {
         if let Some(check_url_client) = &check_url_client {
             if uv_publish::check_url(check_url_client, &file, &filename, &download_concurrency)
@@ -121,6 +127,25 @@ pub(crate) async fn publish(
             }
         }
 
+        let attestations = if attest {
+            match generate_attestation(&file).await {
+                Ok(attestation) => vec![attestation],
+                // Only require `pypi-attestations` if attestations were explicitly requested.
+                Err(AttestationError::MissingTool) if attestation.is_none() => {
+                    warn_user_once!(
+                        "Skipping attestations because `pypi-attestations` was not found. \
+                        Install it with `uv tool install pypi-attestations` to attest the \
+                        uploaded files, or use `--no-attestation` to disable attestations."
+                    );
+                    attest = false;
+                    Vec::new()
+                }
+                Err(err) => return Err(PublishError::Attestation(file, err).into()),
+            }
+        } else {
+            Vec::new()
+        };
+
         let size = fs_err::metadata(&file)?.len();
         let (bytes, unit) = human_readable_bytes(size);
         writeln!(
@@ -138,6 +163,7 @@ pub(crate) async fn publish(
             &upload_client,
             &credentials,
             check_url_client.as_ref(),
+            &attestations,
             &download_concurrency,
             // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
             Arc::new(reporter),
@@ -156,6 +182,48 @@ pub(crate) async fn publish(
     Ok(ExitStatus::Success)
 }
 
+/// Check that the files can be published, without uploading them.
+pub(crate) async fn publish_check(paths: Vec<String>, printer: Printer) -> Result<ExitStatus> {
+    let files = files_for_publishing(paths)?;
+    match files.len() {
+        0 => bail!("No files found to check"),
+        1 => writeln!(printer.stderr(), "Checking 1 file")?,
+        n => writeln!(printer.stderr(), "Checking {n} files")?,
+    }
+
+    let mut failed = false;
+    for (file, _raw_filename, filename) in files {
+        match check_file(&file, &filename).await {
+            Ok(()) => {
+                writeln!(printer.stderr(), "{} {filename}", "Passed".bold().green())?;
+            }
+            Err(err) => {
+                failed = true;
+                writeln!(
+                    printer.stderr(),
+                    "{}: Failed to check {}",
+                    "error".red().bold(),
+                    filename.to_string().cyan()
+                )?;
+                for err in anyhow::Error::from(err).chain() {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+            }
+        }
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
@@ -194,7 +262,8 @@ enum Prompt {
 /// If no credentials are found, the auth middleware does a final check for cached credentials and
 /// otherwise errors without sending the request.
 ///
-/// Returns the publish URL, the username and the password.
+/// Returns the publish URL, the credentials, and whether the credentials are a trusted publishing
+/// token.
 async fn gather_credentials(
     mut publish_url: Url,
     mut username: Option<String>,
@@ -205,7 +274,7 @@ async fn gather_credentials(
     check_url: Option<&IndexUrl>,
     prompt: Prompt,
     printer: Printer,
-) -> Result<(Url, Credentials)> {
+) -> Result<(Url, Credentials, bool)> {
     // Support reading username and password from the URL, for symmetry with the index API.
     if let Some(url_password) = publish_url.password() {
         if password.is_some_and(|password| password != url_password) {
@@ -237,6 +306,10 @@ async fn gather_credentials(
         oidc_client,
     )
     .await?;
+    let uses_trusted_publishing = matches!(
+        trusted_publishing_token,
+        TrustedPublishResult::Configured(_)
+    );
 
     let (username, mut password) =
         if let TrustedPublishResult::Configured(password) = &trusted_publishing_token {
@@ -319,7 +392,7 @@ async fn gather_credentials(
 
     let credentials = Credentials::basic(username, password);
 
-    Ok((publish_url, credentials))
+    Ok((publish_url, credentials, uses_trusted_publishing))
 }
 
 fn prompt_username_and_password() -> Result<(Option<String>, Option<String>)> {
@@ -362,6 +435,7 @@ mod tests {
             Printer::Quiet,
         )
         .await
+        .map(|(url, credentials, _uses_trusted_publishing)| (url, credentials))
     }
 
     #[tokio::test]
@@ -5,7 +5,8 @@ use std::path::Path;
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, FindOptions, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -25,6 +26,7 @@ pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
+    show_source: bool,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -62,6 +64,7 @@ pub(crate) async fn find(
         requires_python,
     } = WorkspacePython::from_request(
         request.map(|request| PythonRequest::parse(&request)),
+        None,
         project.as_ref().map(VirtualProject::workspace),
         project_dir,
         no_config,
@@ -69,9 +72,10 @@ pub(crate) async fn find(
     .await?;
 
     let python = PythonInstallation::find(
-        &python_request.unwrap_or_default(),
-        environment_preference,
-        python_preference,
+        python_request.as_ref().unwrap_or(&PythonRequest::Default),
+        FindOptions::default()
+            .environments(environment_preference)
+            .preference(python_preference),
         cache,
     )?;
 
@@ -104,6 +108,21 @@ pub(crate) async fn find(
         )?;
     }
 
+    if show_source {
+        if let Some(python_request) = &python_request {
+            writeln!(
+                printer.stderr(),
+                "Requested `{}` from {source}",
+                python_request.to_canonical_string()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "No Python request found; used the first available interpreter"
+            )?;
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -120,6 +139,7 @@ pub(crate) async fn find_script(
     let interpreter = match ScriptInterpreter::discover(
         script,
         None,
+        None,
         network_settings,
         python_preference,
         python_downloads,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Write;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Error, Result};
 use futures::stream::FuturesUnordered;
@@ -9,25 +10,35 @@ use futures::StreamExt;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use tracing::{debug, trace};
 
-use uv_configuration::PreviewMode;
-use uv_fs::Simplified;
+use uv_cache::Cache;
+use uv_cli::PythonInstallFormat;
+use uv_configuration::{Concurrency, PreviewMode};
+use uv_fs::{LockWait, Simplified};
+use uv_installer::compile_tree;
 use uv_python::downloads::{self, DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::managed::{
-    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+    python_executable_dir, read_bin_script, KeyFilter, ManagedPythonInstallation,
+    ManagedPythonInstallations,
 };
-use uv_python::platform::{Arch, Libc};
+use uv_python::outcome::{
+    FetchStatus, InstallOutcome, PostInstallReport, TransferRecorder, TransferStats,
+};
+use uv_python::platform::{Arch, Libc, TargetPlatform};
 use uv_python::{
-    PythonDownloads, PythonInstallationKey, PythonRequest, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionFilePreference,
+    BuildFlavor, InstallMirrors, Interpreter, PythonDownloads, PythonInstallationKey,
+    PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions, VersionFilePreference,
+    VersionRequest,
 };
 use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::warn_user;
 
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
-use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::reporters::{CompileReporter, PythonDownloadReporter};
+use crate::commands::tool::update_shell::{ensure_on_path, PathUpdate};
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -43,16 +54,77 @@ struct InstallRequest {
 }
 
 impl InstallRequest {
-    fn new(request: PythonRequest, python_downloads_json_url: Option<&str>) -> Result<Self> {
-        // Make sure the request is a valid download request and fill platform information
-        let download_request = PythonDownloadRequest::from_request(&request)
-            .ok_or_else(|| {
+    fn new(
+        request: PythonRequest,
+        arch: Option<Arch>,
+        platform: Option<TargetPlatform>,
+        build_flavor: Option<BuildFlavor>,
+        allow_prereleases: bool,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<Self> {
+        // Make sure the request is a valid download request
+        let mut download_request =
+            PythonDownloadRequest::from_request(&request).ok_or_else(|| {
                 anyhow::anyhow!(
                     "`{}` is not a valid Python download request; see `uv help python` for supported formats and `uv python list --only-downloads` for available versions",
                     request.to_canonical_string()
                 )
-            })?
-            .fill()?;
+            })?;
+
+        // Apply the `--arch` override, unless the request already includes a different architecture
+        if let Some(arch) = arch {
+            if let Some(requested) = download_request
+                .arch()
+                .filter(|requested| **requested != arch)
+            {
+                anyhow::bail!(
+                    "The request `{}` is for {requested}, which conflicts with `--arch {arch}`",
+                    request.to_canonical_string()
+                );
+            }
+            download_request = download_request.with_arch(arch);
+        }
+
+        // Apply the `--platform` override, unless the request already includes a different
+        // platform
+        if let Some(platform) = platform {
+            let conflicts = download_request.os().is_some_and(|os| *os != platform.os)
+                || download_request
+                    .arch()
+                    .is_some_and(|arch| *arch != platform.arch)
+                || download_request
+                    .libc()
+                    .is_some_and(|libc| *libc != platform.libc);
+            if conflicts {
+                anyhow::bail!(
+                    "The request `{}` conflicts with `--platform {platform}`",
+                    request.to_canonical_string()
+                );
+            }
+            download_request = download_request.with_platform(platform);
+        }
+
+        // Apply the `--build-flavor` override, unless the request already includes a different
+        // flavor
+        if let Some(build_flavor) = build_flavor {
+            if let Some(requested) = download_request
+                .build_flavor()
+                .filter(|requested| *requested != build_flavor)
+            {
+                anyhow::bail!(
+                    "The request `{}` is for the {requested} build, which conflicts with `--build-flavor {build_flavor}`",
+                    request.to_canonical_string()
+                );
+            }
+            download_request = download_request.with_build_flavor(build_flavor);
+        }
+
+        // Fill the remaining platform information
+        let mut download_request = download_request.fill()?;
+        // A request for the latest version never selects a pre-release
+        if allow_prereleases && !matches!(request, PythonRequest::Latest(_)) {
+            download_request = download_request.with_prereleases(true);
+        }
 
         // Find a matching download
         let download =
@@ -70,6 +142,18 @@ impl InstallRequest {
                 Err(err) => return Err(err.into()),
             };
 
+        // Pin the flavor of the selected download, such that installations of other flavors don't
+        // satisfy the request
+        let mut download_request =
+            download_request.with_build_flavor(download.key().build_flavor());
+
+        // Pin the version of the selected download for requests for the latest version, such that
+        // installations of older versions don't satisfy the request
+        if matches!(request, PythonRequest::Latest(_)) {
+            download_request =
+                download_request.with_version(VersionRequest::from(&download.key().version()));
+        }
+
         Ok(Self {
             request,
             download_request,
@@ -92,6 +176,7 @@ impl std::fmt::Display for InstallRequest {
 struct Changelog {
     existing: FxHashSet<PythonInstallationKey>,
     installed: FxHashSet<PythonInstallationKey>,
+    already_installed: FxHashSet<PythonInstallationKey>,
     uninstalled: FxHashSet<PythonInstallationKey>,
     installed_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>>,
 }
@@ -121,6 +206,52 @@ impl Changelog {
             }))
             .sorted_unstable_by(|a, b| a.key.cmp(&b.key).then_with(|| a.kind.cmp(&b.kind)))
     }
+
+    /// The result of the request for the given installation.
+    fn result(&self, key: &PythonInstallationKey) -> InstallResult {
+        if !self.installed.contains(key) {
+            InstallResult::AlreadyInstalled
+        } else if self.uninstalled.contains(key) {
+            InstallResult::Reinstalled
+        } else {
+            InstallResult::Installed
+        }
+    }
+}
+
+/// The output of `uv python install --output-format json`.
+#[derive(Debug, Serialize)]
+struct InstallSummary {
+    installations: Vec<InstallationSummary>,
+    failures: Vec<FailureSummary>,
+}
+
+#[derive(Debug, Serialize)]
+struct InstallationSummary {
+    #[serde(flatten)]
+    outcome: InstallOutcome,
+    result: InstallResult,
+}
+
+#[derive(Debug, Serialize)]
+struct FailureSummary {
+    key: PythonInstallationKey,
+    result: InstallResult,
+    error: String,
+}
+
+/// The result of a requested installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum InstallResult {
+    /// The installation was newly installed.
+    Installed,
+    /// An existing installation was replaced.
+    Reinstalled,
+    /// A matching installation was already present.
+    AlreadyInstalled,
+    /// The installation failed.
+    Failed,
 }
 
 /// Download and install Python versions.
@@ -131,14 +262,26 @@ pub(crate) async fn install(
     targets: Vec<String>,
     reinstall: bool,
     force: bool,
-    python_install_mirror: Option<String>,
-    pypy_install_mirror: Option<String>,
+    install_mirrors: InstallMirrors,
     python_downloads_json_url: Option<String>,
     network_settings: NetworkSettings,
     default: bool,
+    allow_prereleases: bool,
+    arch: Option<Arch>,
+    build_flavor: Option<BuildFlavor>,
+    compile_bytecode: bool,
+    keep: Option<bool>,
+    externally_managed: Option<bool>,
+    install_bin: Option<bool>,
+    windowed: bool,
+    modify_path: bool,
+    no_wait: bool,
+    output_format: PythonInstallFormat,
     python_downloads: PythonDownloads,
+    concurrency: Concurrency,
     no_config: bool,
     preview: PreviewMode,
+    cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -152,6 +295,19 @@ pub(crate) async fn install(
         anyhow::bail!("The `--default` flag cannot be used with multiple targets");
     }
 
+    if windowed && !cfg!(windows) {
+        warn_user!("The `--windowed` flag is only supported on Windows; ignoring");
+    }
+
+    if let Some(arch) = arch {
+        if !arch.is_supported_by_host() {
+            anyhow::bail!(
+                "Python for {arch} cannot run on this machine ({})",
+                Arch::from_env()
+            );
+        }
+    }
+
     // Resolve the requests
     let mut is_default_install = false;
     let requests: Vec<_> = if targets.is_empty() {
@@ -174,13 +330,31 @@ pub(crate) async fn install(
             }]
         })
         .into_iter()
-        .map(|a| InstallRequest::new(a, python_downloads_json_url.as_deref()))
+        .map(|a| {
+            InstallRequest::new(
+                a,
+                arch,
+                None,
+                build_flavor,
+                allow_prereleases,
+                python_downloads_json_url.as_deref(),
+            )
+        })
         .collect::<Result<Vec<_>>>()?
     } else {
         targets
             .iter()
             .map(|target| PythonRequest::parse(target.as_str()))
-            .map(|a| InstallRequest::new(a, python_downloads_json_url.as_deref()))
+            .map(|a| {
+                InstallRequest::new(
+                    a,
+                    arch,
+                    None,
+                    build_flavor,
+                    allow_prereleases,
+                    python_downloads_json_url.as_deref(),
+                )
+            })
             .collect::<Result<Vec<_>>>()?
     };
 
@@ -192,12 +366,42 @@ pub(crate) async fn install(
     let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
     let installations_dir = installations.root();
     let scratch_dir = installations.scratch();
-    let _lock = installations.lock().await?;
-    let existing_installations: Vec<_> = installations
-        .find_all()?
+    let _lock = if no_wait {
+        installations.lock_with(LockWait::NoWait).await?
+    } else {
+        installations.lock().await?
+    };
+    let matching = installations.find_matching(&KeyFilter::default())?;
+    for malformed in matching.malformed() {
+        warn_user!("Ignoring malformed managed Python installation: {malformed}");
+    }
+    let existing_installations: Vec<_> = matching
+        .into_iter()
         .inspect(|installation| trace!("Found existing installation {}", installation.key()))
         .collect();
 
+    // Track the installations that are kept, so the mark survives a reinstall
+    let kept: FxHashSet<_> = existing_installations
+        .iter()
+        .filter(|installation| installation.is_kept())
+        .map(|installation| installation.key().clone())
+        .collect();
+
+    // Track the installations that are not externally managed, so the state survives a reinstall
+    // or an upgrade
+    let unmanaged: FxHashSet<_> = existing_installations
+        .iter()
+        .filter(|installation| !installation.is_externally_managed())
+        .map(|installation| installation.key().clone())
+        .collect();
+
+    // Track the installations that opted out of linking executables, for the same reason
+    let unlinked: FxHashSet<_> = existing_installations
+        .iter()
+        .filter(|installation| installation.is_bin_disabled())
+        .map(|installation| installation.key().clone())
+        .collect();
+
     // Find requests that are already satisfied
     let mut changelog = Changelog::default();
     let (satisfied, unsatisfied): (Vec<_>, Vec<_>) = if reinstall {
@@ -224,6 +428,10 @@ pub(crate) async fn install(
                     // Construct an install request matching the existing installation
                     match InstallRequest::new(
                         PythonRequest::Key(installation.into()),
+                        None,
+                        None,
+                        None,
+                        allow_prereleases,
                         python_downloads_json_url.as_deref(),
                     ) {
                         Ok(request) => {
@@ -276,6 +484,12 @@ pub(crate) async fn install(
         })
     };
 
+    for installation in &satisfied {
+        changelog
+            .already_installed
+            .insert(installation.key().clone());
+    }
+
     // Check if Python downloads are banned
     if matches!(python_downloads, PythonDownloads::Never) && !unsatisfied.is_empty() {
         writeln!(
@@ -311,39 +525,48 @@ pub(crate) async fn install(
 
     for download in &downloads {
         tasks.push(async {
-            (
-                *download,
-                download
-                    .fetch_with_retry(
-                        &client,
-                        installations_dir,
-                        &scratch_dir,
-                        reinstall,
-                        python_install_mirror.as_deref(),
-                        pypy_install_mirror.as_deref(),
-                        Some(&reporter),
-                    )
-                    .await,
-            )
+            let recorder = TransferRecorder::new(Some(&reporter));
+            let result = download
+                .fetch_with_retry(
+                    &client,
+                    cache,
+                    installations_dir,
+                    &scratch_dir,
+                    reinstall,
+                    &install_mirrors,
+                    Some(&recorder),
+                )
+                .await;
+            (*download, result, recorder.finish())
         });
     }
 
     let mut errors = vec![];
     let mut downloaded = Vec::with_capacity(downloads.len());
-    while let Some((download, result)) = tasks.next().await {
+    let mut transfers = FxHashMap::default();
+    while let Some((download, result, transfer)) = tasks.next().await {
         match result {
             Ok(download_result) => {
-                let path = match download_result {
+                let status = FetchStatus::from(&download_result);
+                let installation = match download_result {
                     // We should only encounter already-available during concurrent installs
-                    DownloadResult::AlreadyAvailable(path) => path,
-                    DownloadResult::Fetched(path) => path,
+                    DownloadResult::AlreadyAvailable(path) => {
+                        let installation = ManagedPythonInstallation::new(path, download);
+                        changelog
+                            .already_installed
+                            .insert(installation.key().clone());
+                        installation
+                    }
+                    DownloadResult::Fetched(path) => {
+                        let installation = ManagedPythonInstallation::new(path, download);
+                        changelog.installed.insert(installation.key().clone());
+                        if changelog.existing.contains(installation.key()) {
+                            changelog.uninstalled.insert(installation.key().clone());
+                        }
+                        installation
+                    }
                 };
-
-                let installation = ManagedPythonInstallation::new(path, download);
-                changelog.installed.insert(installation.key().clone());
-                if changelog.existing.contains(installation.key()) {
-                    changelog.uninstalled.insert(installation.key().clone());
-                }
+                transfers.insert(installation.key().clone(), (status, transfer));
                 downloaded.push(installation);
             }
             Err(err) => {
@@ -352,7 +575,8 @@ pub(crate) async fn install(
         }
     }
 
-    let bin = if preview.is_enabled() {
+    // Executables are linked in preview mode, unless requested otherwise
+    let bin = if install_bin.unwrap_or(preview.is_enabled()) {
         Some(python_executable_dir()?)
     } else {
         None
@@ -362,38 +586,68 @@ pub(crate) async fn install(
 
     // Ensure that the installations are _complete_ for both downloaded installations and existing
     // installations that match the request
+    let mut summaries = Vec::with_capacity(installations.len());
     for installation in &installations {
-        installation.ensure_externally_managed()?;
-        installation.ensure_sysconfig_patched()?;
-        installation.ensure_canonical_executables()?;
-        if let Err(e) = installation.ensure_dylib_patched() {
-            e.warn_user(installation);
+        // Unless requested otherwise, retain the state of the existing installation, or of the
+        // installation being upgraded
+        let previous = existing_installations
+            .iter()
+            .find(|existing| existing.key() == installation.key())
+            .or_else(|| installation.upgrade_of(&existing_installations));
+        let externally_managed = externally_managed
+            .unwrap_or_else(|| previous.is_none_or(|previous| !unmanaged.contains(previous.key())));
+        let bin_disabled = install_bin.map_or_else(
+            || previous.is_some_and(|previous| unlinked.contains(previous.key())),
+            |install_bin| !install_bin,
+        );
+        if installation.is_bin_disabled() != bin_disabled {
+            installation.set_bin_disabled(bin_disabled)?;
+        }
+        let post_install = PostInstallReport::run(installation, externally_managed)?;
+        let is_kept = keep.unwrap_or_else(|| kept.contains(installation.key()));
+        if installation.is_kept() != is_kept {
+            if is_kept {
+                debug!("Marking `{}` as kept", installation.key());
+            } else {
+                debug!("Removing the kept mark from `{}`", installation.key());
+            }
+            installation.set_kept(is_kept)?;
+        }
+        if matches!(output_format, PythonInstallFormat::Text) {
+            post_install.dylib().warn_user(installation);
         }
+        let (status, transfer) = transfers
+            .remove(installation.key())
+            .unwrap_or((FetchStatus::AlreadyAvailable, TransferStats::default()));
+        summaries.push(InstallationSummary {
+            outcome: InstallOutcome::new(installation, status, transfer, post_install),
+            result: changelog.result(installation.key()),
+        });
 
-        if preview.is_disabled() {
+        if bin_disabled {
+            debug!(
+                "Skipping installation of Python executables for `{}`",
+                installation.key()
+            );
+        } else if let Some(bin) = bin.as_deref() {
+            create_bin_links(
+                installation,
+                bin,
+                reinstall,
+                force,
+                default,
+                is_default_install,
+                windowed,
+                first_request,
+                &existing_installations,
+                &installations,
+                &mut changelog,
+                &mut errors,
+            )?;
+        } else {
             debug!("Skipping installation of Python executables, use `--preview` to enable.");
-            continue;
         }
 
-        let bin = bin
-            .as_ref()
-            .expect("We should have a bin directory with preview enabled")
-            .as_path();
-
-        create_bin_links(
-            installation,
-            bin,
-            reinstall,
-            force,
-            default,
-            is_default_install,
-            first_request,
-            &existing_installations,
-            &installations,
-            &mut changelog,
-            &mut errors,
-        )?;
-
         if preview.is_enabled() {
             #[cfg(windows)]
             {
@@ -402,6 +656,30 @@ pub(crate) async fn install(
         }
     }
 
+    if compile_bytecode && !downloaded.is_empty() {
+        compile_stdlib(&downloaded, &concurrency, cache, printer).await?;
+    }
+
+    if matches!(output_format, PythonInstallFormat::Json) {
+        let failures = errors
+            .iter()
+            .map(|(key, err)| FailureSummary {
+                key: key.clone(),
+                result: InstallResult::Failed,
+                error: format!("{err:#}"),
+            })
+            .collect();
+        let summary = InstallSummary {
+            installations: summaries,
+            failures,
+        };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&summary)?
+        )?;
+    }
+
     if changelog.installed.is_empty() && errors.is_empty() {
         if is_default_install {
             writeln!(
@@ -410,6 +688,7 @@ pub(crate) async fn install(
             )?;
         } else if requests.len() > 1 {
             writeln!(printer.stderr(), "All requested versions already installed")?;
+            write_already_installed(&changelog, printer)?;
         }
         return Ok(ExitStatus::Success);
     }
@@ -471,13 +750,38 @@ pub(crate) async fn install(
                 }
             }
         }
+    }
 
-        if preview.is_enabled() {
-            let bin = bin
-                .as_ref()
-                .expect("We should have a bin directory with preview enabled")
-                .as_path();
-            warn_if_not_on_path(bin);
+    // Report the requested versions that were already present, so they're distinguishable from
+    // failures
+    if !changelog.installed.is_empty() || !errors.is_empty() {
+        write_already_installed(&changelog, printer)?;
+    }
+
+    // Only warn about the bin directory if we linked any executables into it
+    if let Some(bin) = bin.as_deref() {
+        if changelog
+            .installed_executables
+            .values()
+            .any(|executables| !executables.is_empty())
+        {
+            if modify_path {
+                match ensure_on_path(bin, printer).await {
+                    Ok(PathUpdate::Done) => {}
+                    // The configuration files were updated by a previous `--modify-path`.
+                    Ok(PathUpdate::UpToDate(shell)) => {
+                        debug!(
+                            "The {shell} configuration files already include `{}`",
+                            bin.simplified_display()
+                        );
+                    }
+                    Err(err) => {
+                        warn_user!("{err}");
+                    }
+                }
+            } else {
+                warn_if_not_on_path(bin);
+            }
         }
     }
 
@@ -500,6 +804,207 @@ pub(crate) async fn install(
                     err.to_string().trim()
                 )?;
             }
+            if let Some(hint) = err
+                .downcast_ref::<downloads::Error>()
+                .and_then(downloads::Error::hint)
+            {
+                writeln!(
+                    printer.stderr(),
+                    "\n{}{} {hint}",
+                    "hint".bold().cyan(),
+                    ":".bold()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a line for each requested installation that was already present.
+fn write_already_installed(changelog: &Changelog, printer: Printer) -> Result<()> {
+    for key in changelog
+        .already_installed
+        .iter()
+        .filter(|key| !changelog.installed.contains(*key))
+        .sorted()
+    {
+        writeln!(
+            printer.stderr(),
+            " {} {} {}",
+            "=".dimmed(),
+            key.bold(),
+            "(already installed)".dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+/// Download Python distribution archives into a directory, without installing them.
+///
+/// The archives may be for a platform other than the current one, e.g., to provision another
+/// machine.
+pub(crate) async fn download(
+    project_dir: &Path,
+    targets: Vec<String>,
+    out_dir: &Path,
+    install_mirrors: InstallMirrors,
+    python_downloads_json_url: Option<String>,
+    network_settings: NetworkSettings,
+    allow_prereleases: bool,
+    arch: Option<Arch>,
+    platform: Option<TargetPlatform>,
+    build_flavor: Option<BuildFlavor>,
+    python_downloads: PythonDownloads,
+    no_config: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Resolve the requests
+    let requests = if targets.is_empty() {
+        PythonVersionFile::discover(
+            project_dir,
+            &VersionFileDiscoveryOptions::default()
+                .with_no_config(no_config)
+                .with_preference(VersionFilePreference::Versions),
+        )
+        .await?
+        .map(PythonVersionFile::into_versions)
+        .unwrap_or_else(|| vec![PythonRequest::Default])
+    } else {
+        targets
+            .iter()
+            .map(|target| PythonRequest::parse(target.as_str()))
+            .collect()
+    };
+    let requests = requests
+        .into_iter()
+        .map(|request| {
+            InstallRequest::new(
+                request,
+                arch,
+                platform,
+                build_flavor,
+                allow_prereleases,
+                python_downloads_json_url.as_deref(),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if requests.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    // Check if Python downloads are banned
+    if matches!(python_downloads, PythonDownloads::Never) {
+        writeln!(
+            printer.stderr(),
+            "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit downloads.",
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Ensure we only download each version once
+    let downloads = requests
+        .iter()
+        .inspect(|request| {
+            debug!(
+                "Found download `{}` for request `{}`",
+                request.download,
+                request.cyan(),
+            );
+        })
+        .map(|request| request.download)
+        .unique_by(|download| download.key())
+        .collect::<Vec<_>>();
+
+    // Download the archives concurrently
+    let client = uv_client::BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .build();
+    let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
+    let mut tasks = FuturesUnordered::new();
+
+    for download in &downloads {
+        tasks.push(async {
+            (
+                *download,
+                download
+                    .fetch_archive(&client, out_dir, &install_mirrors, Some(&reporter))
+                    .await,
+            )
+        });
+    }
+
+    let mut errors = vec![];
+    let mut archives = Vec::with_capacity(downloads.len());
+    while let Some((download, result)) = tasks.next().await {
+        match result {
+            Ok(path) => archives.push((download.key().clone(), path)),
+            Err(err) => errors.push((download.key().clone(), anyhow::Error::new(err))),
+        }
+    }
+
+    if !archives.is_empty() {
+        let s = if archives.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Downloaded {} {}",
+                format!("{} archive{s}", archives.len()).bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+        for (key, path) in archives
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            writeln!(
+                printer.stderr(),
+                " {} {} ({})",
+                "+".green(),
+                key.bold(),
+                path.user_display().cyan()
+            )?;
+        }
+    }
+
+    if !errors.is_empty() {
+        for (key, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to download {}",
+                "error".red().bold(),
+                key.green()
+            )?;
+            for err in err.chain() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+            if let Some(hint) = err
+                .downcast_ref::<downloads::Error>()
+                .and_then(downloads::Error::hint)
+            {
+                writeln!(
+                    printer.stderr(),
+                    "\n{}{} {hint}",
+                    "hint".bold().cyan(),
+                    ":".bold()
+                )?;
+            }
         }
         return Ok(ExitStatus::Failure);
     }
@@ -507,6 +1012,85 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Compile the standard library of each newly installed Python version to bytecode.
+///
+/// Individual files that fail to compile are ignored, as are installations that already contain
+/// bytecode; if compilation fails entirely, the installation is kept and a warning is shown.
+async fn compile_stdlib(
+    installations: &[ManagedPythonInstallation],
+    concurrency: &Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let start = std::time::Instant::now();
+    let mut files = 0;
+    for installation in installations {
+        let interpreter = match Interpreter::query(installation.executable(false), cache) {
+            Ok(interpreter) => interpreter,
+            Err(err) => {
+                warn_user!(
+                    "Failed to query `{}` for bytecode compilation: {err}",
+                    installation.key().green()
+                );
+                continue;
+            }
+        };
+        let stdlib = interpreter.stdlib();
+        if has_bytecode(stdlib) {
+            debug!(
+                "Skipping bytecode compilation for `{}`; bytecode is already present",
+                installation.key()
+            );
+            continue;
+        }
+        let reporter = Arc::new(CompileReporter::new(printer, installation.key()));
+        let result = compile_tree(
+            stdlib,
+            interpreter.sys_executable(),
+            concurrency,
+            cache.root(),
+            Some(reporter.clone()),
+        )
+        .await;
+        reporter.on_compile_complete();
+        match result {
+            Ok(count) => files += count,
+            Err(err) => {
+                warn_user!(
+                    "Failed to bytecode-compile the standard library of `{}`: {err}",
+                    installation.key().green()
+                );
+            }
+        }
+    }
+    if files > 0 {
+        let s = if files == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Bytecode compiled {} {}",
+                format!("{files} file{s}").bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed()
+            )
+            .dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the given standard library directory already contains bytecode.
+fn has_bytecode(stdlib: &Path) -> bool {
+    fs_err::read_dir(stdlib.join("__pycache__")).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "pyc")
+        })
+    })
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 #[allow(clippy::fn_params_excessive_bools)]
 fn create_bin_links(
@@ -516,29 +1100,40 @@ fn create_bin_links(
     force: bool,
     default: bool,
     is_default_install: bool,
+    windowed: bool,
     first_request: &InstallRequest,
     existing_installations: &[ManagedPythonInstallation],
     installations: &[&ManagedPythonInstallation],
     changelog: &mut Changelog,
     errors: &mut Vec<(PythonInstallationKey, Error)>,
 ) -> Result<(), Error> {
-    let targets =
+    let mut targets =
         if (default || is_default_install) && first_request.matches_installation(installation) {
             vec![
-                installation.key().executable_name_minor(),
-                installation.key().executable_name_major(),
-                installation.key().executable_name(),
+                (installation.key().executable_name_minor(), false),
+                (installation.key().executable_name_major(), false),
+                (installation.key().executable_name(), false),
             ]
         } else {
-            vec![installation.key().executable_name_minor()]
+            vec![(installation.key().executable_name_minor(), false)]
         };
 
-    for target in targets {
+    // If requested, also link the windowed executable, which runs without a console window.
+    if windowed && cfg!(windows) {
+        targets.push((installation.key().windowed_executable_name_minor(), true));
+    }
+
+    for (target, windowed) in targets {
         let target = bin.join(target);
-        match installation.create_bin_link(&target) {
-            Ok(()) => {
+        let result = if windowed {
+            installation.create_windowed_bin_link(&target)
+        } else {
+            installation.create_bin_link(&target)
+        };
+        match result {
+            Ok(kind) => {
                 debug!(
-                    "Installed executable at `{}` for {}",
+                    "Installed executable at `{}` for {} ({kind:?})",
                     target.simplified_display(),
                     installation.key(),
                 );
@@ -557,54 +1152,54 @@ fn create_bin_links(
                     target.simplified_display()
                 );
 
-                //  Figure out what installation it references, if any
-                let existing = find_matching_bin_link(
+                let existing = ExistingExecutable::classify(
+                    &target,
+                    installation,
                     installations
                         .iter()
                         .copied()
                         .chain(existing_installations.iter()),
-                    &target,
                 );
 
-                match existing {
-                    None => {
-                        // Determine if the link is valid, i.e., if it points to an existing
-                        // Python we don't manage. On Windows, we just assume it is valid because
-                        // symlinks are not common for Python interpreters.
-                        let valid_link = cfg!(windows)
-                            || target
-                                .read_link()
-                                .and_then(|target| target.try_exists())
-                                .inspect_err(|err| {
-                                    debug!("Failed to inspect executable with error: {err}");
-                                })
-                                // If we can't verify the link, assume it is valid.
-                                .unwrap_or(true);
-
+                match &existing {
+                    ExistingExecutable::Foreign(link) => {
                         // There's an existing executable we don't manage, require `--force`
-                        if valid_link {
-                            if !force {
-                                errors.push((
-                                    installation.key().clone(),
-                                    anyhow::anyhow!(
+                        if !force {
+                            errors.push((
+                                installation.key().clone(),
+                                match link {
+                                    Some(link) => anyhow::anyhow!(
+                                        "Executable already exists at `{}` (linked to `{}`) but is not managed by uv; use `--force` to replace it",
+                                        to.simplified_display(),
+                                        link.simplified_display(),
+                                    ),
+                                    None => anyhow::anyhow!(
                                         "Executable already exists at `{}` but is not managed by uv; use `--force` to replace it",
                                         to.simplified_display()
                                     ),
-                                ));
-                                continue;
-                            }
-                            debug!(
+                                },
+                            ));
+                            continue;
+                        }
+                        match link {
+                            Some(link) => debug!(
+                                "Replacing existing executable at `{}` (linked to `{}`) due to `--force`",
+                                target.simplified_display(),
+                                link.simplified_display(),
+                            ),
+                            None => debug!(
                                 "Replacing existing executable at `{}` due to `--force`",
                                 target.simplified_display()
-                            );
-                        } else {
-                            debug!(
-                                "Replacing broken symlink at `{}`",
-                                target.simplified_display()
-                            );
+                            ),
                         }
                     }
-                    Some(existing) if existing == installation => {
+                    ExistingExecutable::BrokenLink => {
+                        debug!(
+                            "Replacing broken symlink at `{}`",
+                            target.simplified_display()
+                        );
+                    }
+                    ExistingExecutable::Same => {
                         // The existing link points to the same installation, so we're done unless
                         // they requested we reinstall
                         if !(reinstall || force) {
@@ -621,47 +1216,43 @@ fn create_bin_links(
                             target.simplified_display(),
                         );
                     }
-                    Some(existing) => {
+                    ExistingExecutable::Upgradable(other)
+                    | ExistingExecutable::OtherManaged(other) => {
                         // The existing link points to a different installation, check if it
                         // is reasonable to replace
                         if force {
                             debug!(
                                 "Replacing existing executable for `{}` at `{}` with executable for `{}` due to `--force` flag",
-                                existing.key(),
+                                other.key(),
+                                target.simplified_display(),
+                                installation.key(),
+                            );
+                        } else if matches!(existing, ExistingExecutable::Upgradable(_)) {
+                            debug!(
+                                "Replacing existing executable for `{}` at `{}` with executable for `{}` since it is an upgrade",
+                                other.key(),
+                                target.simplified_display(),
+                                installation.key(),
+                            );
+                        } else if default {
+                            debug!(
+                                "Replacing existing executable for `{}` at `{}` with executable for `{}` since `--default` was requested`",
+                                other.key(),
                                 target.simplified_display(),
                                 installation.key(),
                             );
                         } else {
-                            if installation.is_upgrade_of(existing) {
-                                debug!(
-                                    "Replacing existing executable for `{}` at `{}` with executable for `{}` since it is an upgrade",
-                                    existing.key(),
-                                    target.simplified_display(),
-                                    installation.key(),
-                                );
-                            } else if default {
-                                debug!(
-                                    "Replacing existing executable for `{}` at `{}` with executable for `{}` since `--default` was requested`",
-                                    existing.key(),
-                                    target.simplified_display(),
-                                    installation.key(),
-                                );
-                            } else {
-                                debug!(
-                                    "Executable already exists for `{}` at `{}`. Use `--force` to replace it",
-                                    existing.key(),
-                                    to.simplified_display()
-                                );
-                                continue;
-                            }
+                            debug!(
+                                "Executable already exists for `{}` at `{}`. Use `--force` to replace it",
+                                other.key(),
+                                to.simplified_display()
+                            );
+                            continue;
                         }
                     }
                 }
 
-                // Replace the existing link
-                fs_err::remove_file(&to)?;
-
-                if let Some(existing) = existing {
+                if let Some(existing) = existing.installation() {
                     // Ensure we do not report installation of this executable for an existing
                     // key if we undo it
                     changelog
@@ -671,9 +1262,14 @@ fn create_bin_links(
                         .remove(&target);
                 }
 
-                installation.create_bin_link(&target)?;
+                // Replace the existing link
+                let kind = if windowed {
+                    installation.replace_windowed_bin_link(&target)?
+                } else {
+                    installation.replace_bin_link(&target)?
+                };
                 debug!(
-                    "Updated executable at `{}` to {}",
+                    "Updated executable at `{}` to {} ({kind:?})",
                     target.simplified_display(),
                     installation.key(),
                 );
@@ -739,6 +1335,68 @@ fn warn_if_not_on_path(bin: &Path) {
     }
 }
 
+/// An existing executable in the bin directory, classified by its ownership.
+#[derive(Debug)]
+enum ExistingExecutable<'a> {
+    /// A link to the installation being linked.
+    Same,
+    /// A link to an older patch version of the same minor version, which the installation being
+    /// linked is an upgrade of.
+    Upgradable(&'a ManagedPythonInstallation),
+    /// A link to another uv-managed installation, e.g., a different minor version or a newer patch
+    /// version.
+    OtherManaged(&'a ManagedPythonInstallation),
+    /// A symbolic link to a path that no longer exists.
+    BrokenLink,
+    /// An executable that is not managed by uv, with the path it links to, if it is a link.
+    Foreign(Option<PathBuf>),
+}
+
+impl<'a> ExistingExecutable<'a> {
+    /// Classify the existing executable at `path` with respect to the `installation` being linked.
+    fn classify(
+        path: &Path,
+        installation: &ManagedPythonInstallation,
+        installations: impl Iterator<Item = &'a ManagedPythonInstallation>,
+    ) -> Self {
+        //  Figure out what installation it references, if any
+        match find_matching_bin_link(installations, path) {
+            Some(existing) if existing == installation => Self::Same,
+            Some(existing) if installation.is_upgrade_of(existing) => Self::Upgradable(existing),
+            Some(existing) => Self::OtherManaged(existing),
+            // On Windows, we just assume the executable is valid because symlinks are not common
+            // for Python interpreters.
+            None if cfg!(windows) => Self::Foreign(None),
+            None => {
+                let Ok(link) = path.read_link() else {
+                    return Self::Foreign(None);
+                };
+                // Determine if the link is valid, i.e., if it points to an existing Python we
+                // don't manage. If we can't verify the link, assume it is valid.
+                let valid = path
+                    .try_exists()
+                    .inspect_err(|err| {
+                        debug!("Failed to inspect executable with error: {err}");
+                    })
+                    .unwrap_or(true);
+                if valid {
+                    Self::Foreign(Some(link))
+                } else {
+                    Self::BrokenLink
+                }
+            }
+        }
+    }
+
+    /// The uv-managed installation the executable links to, if any.
+    fn installation(&self) -> Option<&'a ManagedPythonInstallation> {
+        match *self {
+            Self::Upgradable(installation) | Self::OtherManaged(installation) => Some(installation),
+            Self::Same | Self::BrokenLink | Self::Foreign(_) => None,
+        }
+    }
+}
+
 /// Find the [`ManagedPythonInstallation`] corresponding to an executable link installed at the
 /// given path, if any.
 ///
@@ -749,10 +1407,12 @@ fn find_matching_bin_link<'a>(
     path: &Path,
 ) -> Option<&'a ManagedPythonInstallation> {
     let target = if cfg!(unix) {
-        if !path.is_symlink() {
-            return None;
+        if path.is_symlink() {
+            path.read_link().ok()?
+        } else {
+            // The link may have been written as a launcher script, if symlinks are not supported
+            read_bin_script(path)?
         }
-        path.read_link().ok()?
     } else if cfg!(windows) {
         let launcher = Launcher::try_from_path(path).ok()??;
         if !matches!(launcher.kind, LauncherKind::Python) {
@@ -763,5 +1423,7 @@ fn find_matching_bin_link<'a>(
         unreachable!("Only Windows and Unix are supported")
     };
 
-    installations.find(|installation| installation.executable(false) == target)
+    installations.find(|installation| {
+        installation.executable(false) == target || installation.executable(true) == target
+    })
 }
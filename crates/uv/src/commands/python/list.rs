@@ -1,6 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::time::Duration;
 use uv_cli::PythonListFormat;
 use uv_pep440::Version;
 
@@ -8,9 +9,13 @@ use anyhow::Result;
 use itertools::Either;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
-use uv_cache::Cache;
+use url::Url;
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_client::BaseClientBuilder;
 use uv_fs::Simplified;
-use uv_python::downloads::PythonDownloadRequest;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
+use uv_python::managed::{KeyFilter, ManagedPythonInstallations};
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
     PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
@@ -18,7 +23,7 @@ use uv_python::{
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
-use crate::settings::PythonListKinds;
+use crate::settings::{NetworkSettings, PythonListKinds};
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
 enum Kind {
@@ -38,6 +43,8 @@ struct NamedVersionParts {
 struct PrintData {
     key: String,
     version: Version,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_version: Option<Version>,
     version_parts: NamedVersionParts,
     path: Option<String>,
     symlink: Option<String>,
@@ -57,11 +64,13 @@ pub(crate) async fn list(
     all_versions: bool,
     all_platforms: bool,
     all_arches: bool,
+    outdated: bool,
     show_urls: bool,
     output_format: PythonListFormat,
     python_downloads_json_url: Option<String>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    network_settings: &NetworkSettings,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -155,8 +164,46 @@ pub(crate) async fn list(
         }
     }
 
+    // Find the managed installations that are marked as kept, and those that are not marked as
+    // externally managed
+    let (kept, unmanaged) = ManagedPythonInstallations::from_settings(None)
+        .and_then(|installations| {
+            let installations = installations
+                .find_matching(&KeyFilter::default())?
+                .into_iter()
+                .collect::<Vec<_>>();
+            let kept = installations
+                .iter()
+                .filter(|installation| installation.is_kept())
+                .map(|installation| installation.key().clone())
+                .collect::<FxHashSet<_>>();
+            let unmanaged = installations
+                .iter()
+                .filter(|installation| !installation.is_externally_managed())
+                .map(|installation| installation.key().clone())
+                .collect::<FxHashSet<_>>();
+            Ok((kept, unmanaged))
+        })
+        .unwrap_or_default();
+
+    // In verbose mode, or if requested, find the upgrades available for the latest installed
+    // patch version of each managed minor version.
+    let show_upgrades = outdated || matches!(printer, Printer::Verbose);
+    let upgrade_downloads = if show_upgrades {
+        upgrade_metadata(
+            python_downloads_json_url.as_deref(),
+            network_settings,
+            cache,
+            printer,
+        )
+        .await?
+    } else {
+        Vec::new()
+    };
+
     let mut seen_minor = FxHashSet::default();
     let mut seen_patch = FxHashSet::default();
+    let mut seen_installed_minor = FxHashSet::default();
     let mut seen_paths = FxHashSet::default();
     let mut include = Vec::new();
     for (key, kind, uri) in output.iter().rev() {
@@ -169,46 +216,62 @@ pub(crate) async fn list(
 
         // Only show the latest patch version for each download unless all were requested
         if !matches!(kind, Kind::System) {
-            if let [major, minor, ..] = *key.version().release() {
-                if !seen_minor.insert((
-                    *key.os(),
-                    major,
-                    minor,
-                    key.variant(),
-                    key.implementation(),
-                    *key.arch(),
-                    *key.libc(),
-                )) {
-                    if matches!(kind, Kind::Download) && !all_versions {
-                        continue;
-                    }
+            if !seen_minor.insert((
+                *key.os(),
+                key.major(),
+                key.minor(),
+                key.variant(),
+                key.implementation(),
+                *key.arch(),
+                *key.libc(),
+            )) {
+                if matches!(kind, Kind::Download) && !all_versions {
+                    continue;
                 }
             }
-            if let [major, minor, patch] = *key.version().release() {
-                if !seen_patch.insert((
-                    *key.os(),
-                    major,
-                    minor,
-                    patch,
-                    key.variant(),
-                    key.implementation(),
-                    *key.arch(),
-                    key.libc(),
-                )) {
-                    if matches!(kind, Kind::Download) {
-                        continue;
-                    }
+            if !seen_patch.insert((
+                *key.os(),
+                key.major(),
+                key.minor(),
+                key.patch(),
+                key.variant(),
+                key.implementation(),
+                *key.arch(),
+                key.libc(),
+            )) {
+                if matches!(kind, Kind::Download) {
+                    continue;
                 }
             }
         }
-        include.push((key, uri));
+        let is_kept = matches!(kind, Kind::Managed) && kept.contains(key);
+        let externally_managed = matches!(kind, Kind::Managed).then(|| !unmanaged.contains(key));
+        let upgrade = if show_upgrades
+            && matches!(kind, Kind::Managed)
+            && seen_installed_minor.insert((
+                *key.os(),
+                key.major(),
+                key.minor(),
+                key.variant(),
+                key.implementation(),
+                *key.arch(),
+                *key.libc(),
+            )) {
+            ManagedPythonDownload::latest_available_patch(key, &upgrade_downloads)
+        } else {
+            None
+        };
+        if outdated && upgrade.is_none() {
+            continue;
+        }
+        include.push((key, uri, is_kept, externally_managed, upgrade));
     }
 
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, uri, .., upgrade)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -232,6 +295,8 @@ pub(crate) async fn list(
                     Ok(PrintData {
                         key: key.to_string(),
                         version: version.version().clone(),
+                        latest_version: upgrade
+                            .map(|download| download.key().version().version().clone()),
                         #[allow(clippy::get_first)]
                         version_parts: NamedVersionParts {
                             major: release.get(0).copied().unwrap_or(0),
@@ -255,35 +320,64 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, uri, is_kept, externally_managed, upgrade) in include {
+                // Statically-linked builds can't load compiled extension modules.
+                let note = if key.libc().is_static() {
+                    format!(" {}", "(static; cannot load extension modules)".dimmed())
+                } else {
+                    String::new()
+                };
+                let upgrade = upgrade
+                    .map(|download| format!("({} -> {})", key.version(), download.key().version()));
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
+                        let mut kept = if is_kept {
+                            format!(" {}", "(kept)".dimmed())
+                        } else {
+                            String::new()
+                        };
+                        // In verbose mode, show whether `pip` may modify managed installations.
+                        if matches!(printer, Printer::Verbose) {
+                            match externally_managed {
+                                Some(true) => {
+                                    let _ = write!(kept, " {}", "(externally managed)".dimmed());
+                                }
+                                Some(false) => {
+                                    let _ =
+                                        write!(kept, " {}", "(not externally managed)".dimmed());
+                                }
+                                None => {}
+                            }
+                        }
+                        if let Some(upgrade) = upgrade {
+                            let _ = write!(kept, " {}", upgrade.yellow());
+                        }
                         let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
                         if is_symlink {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {} -> {}",
+                                "{key:width$}    {} -> {}{kept}{note}",
                                 path.user_display().cyan(),
                                 path.read_link()?.user_display().cyan()
                             )?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
+                                "{key:width$}    {}{kept}{note}",
                                 path.user_display().cyan()
                             )?;
                         }
                     }
                     Either::Right(url) => {
                         if show_urls {
-                            writeln!(printer.stdout(), "{key:width$}    {}", url.dimmed())?;
+                            writeln!(printer.stdout(), "{key:width$}    {}{note}", url.dimmed())?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
+                                "{key:width$}    {}{note}",
                                 "<download available>".dimmed()
                             )?;
                         }
@@ -295,3 +389,89 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the Python download metadata used to find the available upgrades.
+///
+/// Unlike the metadata used to list downloads, the metadata may be fetched from a remote
+/// `python-downloads-json-url`. The fetched metadata is cached, such that it can be used with
+/// `--offline`, in which case the age of the cached copy is shown.
+async fn upgrade_metadata(
+    python_downloads_json_url: Option<&str>,
+    network_settings: &NetworkSettings,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Vec<ManagedPythonDownload>> {
+    let Some(url) = python_downloads_json_url
+        .and_then(|url| Url::parse(url).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+    else {
+        return Ok(ManagedPythonDownload::iter_all(python_downloads_json_url)?
+            .cloned()
+            .collect());
+    };
+
+    let bucket = cache.bucket(CacheBucket::Python);
+    let path = bucket.join(format!("downloads-{}.json", cache_digest(&url.as_str())));
+
+    if network_settings.connectivity.is_offline() {
+        let contents = match fs_err::read(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                anyhow::bail!(
+                    "The Python download metadata at `{url}` has not been cached; run the command without `--offline` to fetch it"
+                );
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let age = fs_err::metadata(&path)?
+            .modified()?
+            .elapsed()
+            .unwrap_or_default();
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Using cached Python download metadata, last fetched {}",
+                format_age(age)
+            )
+            .dimmed()
+        )?;
+        return Ok(ManagedPythonDownload::from_json(&contents, url.as_str())?);
+    }
+
+    let client = BaseClientBuilder::new()
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .build();
+    let contents = client
+        .for_host(&url)
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    let downloads = ManagedPythonDownload::from_json(&contents, url.as_str())?;
+
+    fs_err::create_dir_all(&bucket)?;
+    uv_fs::write_atomic(&path, &contents).await?;
+
+    Ok(downloads)
+}
+
+/// Format the age of cached metadata, e.g., `3 days ago`.
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    let (count, unit) = if secs < 60 {
+        return String::from("less than a minute ago");
+    } else if secs < 60 * 60 {
+        (secs / 60, "minute")
+    } else if secs < 24 * 60 * 60 {
+        (secs / (60 * 60), "hour")
+    } else {
+        (secs / (24 * 60 * 60), "day")
+    };
+    let s = if count == 1 { "" } else { "s" };
+    format!("{count} {unit}{s} ago")
+}
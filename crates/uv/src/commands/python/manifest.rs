@@ -0,0 +1,66 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anstream::println;
+use anyhow::{anyhow, Context, Result};
+use owo_colors::OwoColorize;
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_python::MirrorManifest;
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Generate Python download metadata for a directory of python-build-standalone archives.
+pub(crate) fn manifest(
+    directory: &Path,
+    base_url: Option<&Url>,
+    upstream: Option<&Path>,
+    output: Option<&Path>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let base_url = if let Some(base_url) = base_url {
+        base_url.clone()
+    } else {
+        Url::from_directory_path(std::path::absolute(directory)?)
+            .map_err(|()| anyhow!("Failed to convert `{}` to a URL", directory.user_display()))?
+    };
+
+    let mut manifest = MirrorManifest::from_directory(directory, &base_url).with_context(|| {
+        format!(
+            "Failed to read Python archives from `{}`",
+            directory.user_display()
+        )
+    })?;
+
+    if let Some(upstream) = upstream {
+        manifest.merge(upstream, &base_url)?;
+    }
+
+    for rejected in manifest.rejected() {
+        warn_user!("Skipping `{}`: {}", rejected.filename(), rejected.reason());
+    }
+
+    let json = manifest.to_json()?;
+    if let Some(output) = output {
+        fs_err::write(output, json)?;
+        let s = if manifest.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Wrote {} to {}",
+            format!("{} Python download{s}", manifest.len()).bold(),
+            output.user_display().cyan()
+        )?;
+    } else {
+        println!("{json}");
+    }
+
+    // Fail if any archives were rejected, so that an incomplete mirror doesn't go unnoticed.
+    if manifest.rejected().is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
@@ -2,6 +2,7 @@ pub(crate) mod dir;
 pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod manifest;
 pub(crate) mod pin;
 pub(crate) mod uninstall;
 
@@ -9,9 +9,11 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_dirs::user_uv_config_dir;
 use uv_fs::Simplified;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
 use uv_python::{
-    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
-    VersionFileDiscoveryOptions, PYTHON_VERSION_FILENAME,
+    EnvironmentPreference, FindOptions, ImplementationName, PythonInstallation,
+    PythonInstallationKey, PythonPreference, PythonRequest, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionRequest, PYTHON_VERSION_FILENAME,
 };
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
@@ -20,10 +22,14 @@ use crate::commands::{project::find_requires_python, ExitStatus};
 use crate::printer::Printer;
 
 /// Pin to a specific Python version.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pin(
     project_dir: &Path,
     request: Option<String>,
     resolved: bool,
+    exact: bool,
+    from_downloads: bool,
+    rm: bool,
     python_preference: PythonPreference,
     no_project: bool,
     global: bool,
@@ -56,9 +62,34 @@ pub(crate) async fn pin(
         PythonVersionFile::discover(project_dir, &VersionFileDiscoveryOptions::default()).await
     };
 
-    let Some(request) = request else {
+    if rm {
+        return remove(version_file?, global, printer).await;
+    }
+
+    let request = if let Some(request) = request {
+        PythonRequest::parse(&request)
+    } else if exact {
+        // Resolve the current pinned Python version
+        let Some(pin) = version_file
+            .as_ref()
+            .ok()
+            .and_then(Option::as_ref)
+            .and_then(PythonVersionFile::version)
+        else {
+            bail!("No pinned Python version found")
+        };
+        pin.clone()
+    } else {
         // Display the current pinned Python version
         if let Some(file) = version_file? {
+            // Discovery falls back to the global pin; make it clear when it's in use.
+            if file.is_global() && !global {
+                writeln!(
+                    printer.stderr(),
+                    "Using global pin from `{}`",
+                    file.path().user_display().cyan()
+                )?;
+            }
             for pin in file.versions() {
                 writeln!(printer.stdout(), "{}", pin.to_canonical_string())?;
                 if let Some(virtual_project) = &virtual_project {
@@ -74,7 +105,6 @@ pub(crate) async fn pin(
         }
         bail!("No pinned Python version found")
     };
-    let request = PythonRequest::parse(&request);
 
     if let PythonRequest::ExecutableName(name) = request {
         bail!("Requests for arbitrary names (e.g., `{name}`) are not supported in version files");
@@ -82,19 +112,55 @@ pub(crate) async fn pin(
 
     let python = match PythonInstallation::find(
         &request,
-        EnvironmentPreference::OnlySystem,
-        python_preference,
+        FindOptions::default()
+            .environments(EnvironmentPreference::OnlySystem)
+            .preference(python_preference),
         cache,
     ) {
         Ok(python) => Some(python),
-        // If no matching Python version is found, don't fail unless `resolved` was requested
-        Err(uv_python::Error::MissingPython(err)) if !resolved => {
-            warn_user_once!("{err}");
+        // If no matching Python version is found, don't fail unless `resolved` or `exact` was
+        // requested
+        Err(uv_python::Error::MissingPython(err)) if !resolved && (!exact || from_downloads) => {
+            // The latest version, and exact pins, are resolved against the available downloads
+            // instead
+            if !exact && !matches!(request, PythonRequest::Latest(_)) {
+                warn_user_once!("{err}");
+            }
             None
         }
         Err(err) => return Err(err.into()),
     };
 
+    // Pin the resolved version rather than the `latest` alias, which changes over time
+    let request = if exact || matches!(request, PythonRequest::Latest(_)) {
+        let key = if let Some(python) = &python {
+            python.key()
+        } else {
+            let Some(download_request) = PythonDownloadRequest::from_request(&request) else {
+                bail!(
+                    "The Python request `{}` cannot be resolved against the available downloads",
+                    request.to_canonical_string()
+                );
+            };
+            ManagedPythonDownload::from_request(&download_request.fill()?, None)?
+                .key()
+                .clone()
+        };
+        debug!("Resolved `{}` to `{key}`", request.to_canonical_string());
+        let resolved = exact_request(&key);
+        if exact {
+            writeln!(
+                printer.stdout(),
+                "Resolved `{}` to `{}`",
+                request.to_canonical_string().cyan(),
+                resolved.to_canonical_string().green()
+            )?;
+        }
+        resolved
+    } else {
+        request
+    };
+
     if let Some(virtual_project) = &virtual_project {
         if let Some(request_version) = pep440_version_from_request(&request) {
             assert_pin_compatible_with_project(
@@ -184,6 +250,39 @@ pub(crate) async fn pin(
     Ok(ExitStatus::Success)
 }
 
+/// Remove the Python version file that currently applies.
+async fn remove(
+    version_file: Option<PythonVersionFile>,
+    global: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Discovery falls back to the global pin, which should only be removed with `--global`.
+    let Some(file) = version_file.filter(|file| global || !file.is_global()) else {
+        writeln!(printer.stderr(), "No pinned Python version found")?;
+        return Ok(ExitStatus::Success);
+    };
+
+    fs_err::tokio::remove_file(file.path()).await?;
+    writeln!(
+        printer.stdout(),
+        "Removed `{}`",
+        file.path().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Construct a request for the exact version of the given installation, e.g., `3.12.4`.
+///
+/// Implementations other than CPython are included in the request, e.g., `pypy@3.10.14`.
+pub(crate) fn exact_request(key: &PythonInstallationKey) -> PythonRequest {
+    let version = VersionRequest::from(&key.version());
+    match ImplementationName::from_str(key.implementation().into()) {
+        Ok(ImplementationName::CPython) | Err(_) => PythonRequest::Version(version),
+        Ok(implementation) => PythonRequest::ImplementationVersion(implementation, version),
+    }
+}
+
 fn pep440_version_from_request(request: &PythonRequest) -> Option<uv_pep440::Version> {
     let version_request = match request {
         PythonRequest::Version(ref version)
@@ -233,8 +332,9 @@ fn warn_if_existing_pin_incompatible_with_project(
     // interpreter to check for compatibility on the current system.
     match PythonInstallation::find(
         pin,
-        EnvironmentPreference::OnlySystem,
-        python_preference,
+        FindOptions::default()
+            .environments(EnvironmentPreference::OnlySystem)
+            .preference(python_preference),
         cache,
     ) {
         Ok(python) => {
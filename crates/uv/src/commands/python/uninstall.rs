@@ -1,8 +1,9 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use console::Term;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use itertools::Itertools;
@@ -11,30 +12,43 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, warn};
 
 use uv_configuration::PreviewMode;
-use uv_fs::Simplified;
+use uv_fs::{LockWait, Simplified, CWD};
 use uv_python::downloads::PythonDownloadRequest;
-use uv_python::managed::{python_executable_dir, ManagedPythonInstallations};
-use uv_python::{PythonInstallationKey, PythonRequest};
+use uv_python::managed::{
+    python_executable_dir, KeyFilter, ManagedPythonInstallation, ManagedPythonInstallations,
+};
+use uv_python::{PyVenvConfiguration, PythonInstallationKey, PythonRequest};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
 
 use crate::commands::python::install::format_executables;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{elapsed, human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Uninstall managed Python versions.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    yes: bool,
+    force: bool,
+    no_wait: bool,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
     let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
 
-    let _lock = installations.lock().await?;
+    let _lock = if no_wait {
+        installations.lock_with(LockWait::NoWait).await?
+    } else {
+        installations.lock().await?
+    };
 
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer, preview).await?;
+    let status = do_uninstall(&installations, targets, all, yes, force, printer, preview).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -54,7 +68,7 @@ pub(crate) async fn uninstall(
         }
     }
 
-    Ok(ExitStatus::Success)
+    Ok(status)
 }
 
 /// Perform the uninstallation of managed Python installations.
@@ -62,6 +76,8 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    yes: bool,
+    force: bool,
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
@@ -88,7 +104,11 @@ async fn do_uninstall(
         .map(|result| result.map(|request| request.with_prereleases(true)))
         .collect::<Result<Vec<_>>>()?;
 
-    let installed_installations: Vec<_> = installations.find_all()?.collect();
+    let matching = installations.find_matching(&KeyFilter::default())?;
+    for malformed in matching.malformed() {
+        warn_user!("Ignoring malformed managed Python installation: {malformed}");
+    }
+    let installed_installations: Vec<_> = matching.into_iter().collect();
     let mut matching_installations = BTreeSet::default();
     for (request, download_request) in requests.iter().zip(download_requests) {
         if matches!(requests.as_slice(), [PythonRequest::Default]) {
@@ -140,50 +160,69 @@ async fn do_uninstall(
         return Ok(ExitStatus::Failure);
     }
 
-    // Find and remove all relevant Python executables
-    let mut uninstalled_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>> =
-        FxHashMap::default();
-    for executable in python_executable_dir()?
-        .read_dir()
-        .into_iter()
-        .flatten()
-        .filter_map(|entry| match entry {
-            Ok(entry) => Some(entry),
-            Err(err) => {
-                warn!("Failed to read executable: {}", err);
-                None
-            }
-        })
-        .filter(|entry| entry.file_type().is_ok_and(|file_type| !file_type.is_dir()))
-        .map(|entry| entry.path())
-        // Only include files that match the expected Python executable names
-        // TODO(zanieb): This is a minor optimization to avoid opening more files, but we could
-        // leave broken links behind, i.e., if the user created them.
-        .filter(|path| {
-            matching_installations.iter().any(|installation| {
-                let name = path.file_name().and_then(|name| name.to_str());
-                name == Some(&installation.key().executable_name_minor())
-                    || name == Some(&installation.key().executable_name_major())
-                    || name == Some(&installation.key().executable_name())
-            })
-        })
-        .sorted()
-    {
-        let Some(installation) = matching_installations
+    // Skip any kept installations, unless forced
+    if !force {
+        let kept = matching_installations
             .iter()
-            .find(|installation| installation.is_bin_link(executable.as_path()))
-        else {
-            continue;
+            .filter(|installation| installation.is_kept())
+            .cloned()
+            .collect::<Vec<_>>();
+        for installation in &kept {
+            writeln!(
+                printer.stderr(),
+                "Skipping {}, which is marked as kept (use `--force` to uninstall it)",
+                installation.key().green()
+            )?;
+            matching_installations.remove(installation);
+        }
+        if matching_installations.is_empty() {
+            return Ok(ExitStatus::Success);
+        }
+    }
+
+    // Find all relevant Python executables
+    let executables = find_bin_links(&matching_installations)?;
+
+    // When uninstalling everything, show what will be affected and confirm before proceeding.
+    if all && !yes {
+        write_impact_report(&matching_installations, &executables, printer)?;
+
+        let term = Term::stderr();
+        if !term.is_term() {
+            anyhow::bail!(
+                "Uninstalling all Python installations requires confirmation; use `--yes` to proceed"
+            );
+        }
+        let s = if matching_installations.len() == 1 {
+            ""
+        } else {
+            "s"
         };
+        let prompt = format!(
+            "Uninstall {} Python installation{s}?",
+            matching_installations.len()
+        );
+        if !uv_console::confirm(&prompt, &term, false).context("Confirm prompt failed")? {
+            return Ok(ExitStatus::Failure);
+        }
+    }
 
-        fs_err::remove_file(&executable)?;
+    // Remove the executables, continuing past any failures.
+    let mut errors = vec![];
+    let mut uninstalled_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>> =
+        FxHashMap::default();
+    for (executable, key) in executables {
+        if let Err(err) = fs_err::remove_file(&executable) {
+            errors.push((key, anyhow::Error::new(err)));
+            continue;
+        }
         debug!(
             "Removed `{}` for `{}`",
             executable.simplified_display(),
-            installation.key()
+            key
         );
         uninstalled_executables
-            .entry(installation.key().clone())
+            .entry(key)
             .or_default()
             .insert(executable);
     }
@@ -199,7 +238,6 @@ async fn do_uninstall(
     }
 
     let mut uninstalled = vec![];
-    let mut errors = vec![];
     while let Some((key, result)) = tasks.next().await {
         if let Err(err) = result {
             errors.push((key.clone(), anyhow::Error::new(err)));
@@ -285,3 +323,173 @@ async fn do_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Find the executables in the bin directory that link to the given installations.
+///
+/// Executables that do not link to one of the installations, e.g., those created by the user, are
+/// left alone.
+fn find_bin_links(
+    installations: &BTreeSet<ManagedPythonInstallation>,
+) -> Result<Vec<(PathBuf, PythonInstallationKey)>> {
+    Ok(python_executable_dir()?
+        .read_dir()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| match entry {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                warn!("Failed to read executable: {}", err);
+                None
+            }
+        })
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| !file_type.is_dir()))
+        .map(|entry| entry.path())
+        // Only include files that match the expected Python executable names
+        // TODO(zanieb): This is a minor optimization to avoid opening more files, but we could
+        // leave broken links behind, i.e., if the user created them.
+        .filter(|path| {
+            installations.iter().any(|installation| {
+                let name = path.file_name().and_then(|name| name.to_str());
+                name == Some(&installation.key().executable_name_minor())
+                    || name == Some(&installation.key().executable_name_major())
+                    || name == Some(&installation.key().executable_name())
+                    || name == Some(&installation.key().windowed_executable_name_minor())
+            })
+        })
+        .sorted()
+        .filter_map(|executable| {
+            let installation = installations
+                .iter()
+                .find(|installation| installation.is_bin_link(executable.as_path()))?;
+            Some((executable, installation.key().clone()))
+        })
+        .collect())
+}
+
+/// Write a report of the installations, executables, and environments affected by an
+/// uninstallation.
+fn write_impact_report(
+    installations: &BTreeSet<ManagedPythonInstallation>,
+    executables: &[(PathBuf, PythonInstallationKey)],
+    printer: Printer,
+) -> Result<()> {
+    writeln!(
+        printer.stderr(),
+        "The following Python installations will be uninstalled:"
+    )?;
+    for installation in installations {
+        writeln!(
+            printer.stderr(),
+            " {} {} ({})",
+            "-".red(),
+            installation.key().bold(),
+            format_size(directory_size(installation.path())).dimmed()
+        )?;
+    }
+
+    if !executables.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "The following executables will be removed:"
+        )?;
+        for (executable, _) in executables {
+            writeln!(
+                printer.stderr(),
+                " {} {}",
+                "-".red(),
+                executable.user_display().cyan()
+            )?;
+        }
+    }
+
+    let environments = find_dependent_environments(installations);
+    if !environments.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "The following environments use these installations and will no longer work:"
+        )?;
+        for (environment, key) in environments {
+            writeln!(
+                printer.stderr(),
+                " {} {} ({})",
+                "-".yellow(),
+                environment.user_display().cyan(),
+                key
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find virtual environments that were created from one of the given installations.
+///
+/// There's no registry of every environment on the machine, so we check the active environment,
+/// the `.venv` in the working directory, and the environments of installed tools.
+fn find_dependent_environments(
+    installations: &BTreeSet<ManagedPythonInstallation>,
+) -> Vec<(PathBuf, PythonInstallationKey)> {
+    let mut candidates = vec![];
+    if let Some(active) = std::env::var_os(EnvVars::VIRTUAL_ENV) {
+        candidates.push(PathBuf::from(active));
+    }
+    candidates.push(CWD.join(".venv"));
+    if let Ok(tools) = InstalledTools::from_settings() {
+        candidates.extend(uv_fs::directories(tools.root()).into_iter().flatten());
+    }
+
+    let installations = installations
+        .iter()
+        .map(|installation| {
+            let path = fs_err::canonicalize(installation.path())
+                .unwrap_or_else(|_| installation.path().to_path_buf());
+            (path, installation.key())
+        })
+        .collect::<Vec<_>>();
+
+    let mut environments = vec![];
+    let mut seen = FxHashSet::default();
+    for candidate in candidates {
+        let Ok(root) = fs_err::canonicalize(&candidate) else {
+            continue;
+        };
+        if !seen.insert(root) {
+            continue;
+        }
+        let Ok(cfg) = PyVenvConfiguration::parse(candidate.join("pyvenv.cfg")) else {
+            continue;
+        };
+        let Some(home) = cfg.home() else {
+            continue;
+        };
+        let home = fs_err::canonicalize(home).unwrap_or_else(|_| home.to_path_buf());
+        if let Some((_, key)) = installations
+            .iter()
+            .find(|(path, _)| home.starts_with(path))
+        {
+            environments.push((candidate, (*key).clone()));
+        }
+    }
+    environments
+}
+
+/// Return the total size of the files in a directory, in bytes.
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Format a size in bytes for display, e.g., `48.2MiB`.
+fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -1,5 +1,6 @@
 use std::env;
 use std::fmt::Write;
+use std::path::Path;
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -573,6 +574,36 @@ impl uv_installer::InstallReporter for InstallReporter {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct CompileReporter {
+    progress: ProgressBar,
+}
+
+impl CompileReporter {
+    /// Initialize a [`CompileReporter`] for compiling the files under the given label.
+    pub(crate) fn new(printer: Printer, label: impl std::fmt::Display) -> Self {
+        let progress = ProgressBar::with_draw_target(None, printer.target());
+        progress.enable_steady_tick(Duration::from_millis(200));
+        progress.set_style(
+            ProgressStyle::with_template("{spinner:.white} {msg:.dim} ({pos} files)")
+                .unwrap()
+                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+        );
+        progress.set_message(format!("Compiling {label}..."));
+        Self { progress }
+    }
+
+    pub(crate) fn on_compile_complete(&self) {
+        self.progress.finish_and_clear();
+    }
+}
+
+impl uv_installer::CompileReporter for CompileReporter {
+    fn on_compile_progress(&self, _source_file: &Path) {
+        self.progress.inc(1);
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PythonDownloadReporter {
     reporter: ProgressReporter,
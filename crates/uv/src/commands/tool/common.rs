@@ -16,8 +16,8 @@ use uv_installer::SitePackages;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::PackageName;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVariant, VersionRequest,
+    EnvironmentPreference, FindOptions, Interpreter, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVariant, VersionRequest,
 };
 use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
@@ -142,14 +142,14 @@ pub(crate) async fn refine_interpreter(
 
     let interpreter = PythonInstallation::find_or_download(
         Some(&python_request),
-        EnvironmentPreference::OnlySystem,
-        python_preference,
-        python_downloads,
+        FindOptions::default()
+            .environments(EnvironmentPreference::OnlySystem)
+            .preference(python_preference)
+            .downloads(python_downloads),
         client_builder,
         cache,
         Some(reporter),
-        install_mirrors.python_install_mirror.as_deref(),
-        install_mirrors.pypy_install_mirror.as_deref(),
+        &install_mirrors.mirrors(),
         install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
@@ -14,11 +14,12 @@ use uv_distribution_types::{
     NameRequirementSpecification, Requirement, RequirementSource,
     UnresolvedRequirementSpecification,
 };
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, FindOptions, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -45,6 +46,7 @@ pub(crate) async fn install(
     package: String,
     editable: bool,
     from: Option<String>,
+    extras: &[ExtraName],
     with: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
@@ -76,14 +78,14 @@ pub(crate) async fn install(
     // requirements, even if we end up using a different interpreter for the tool install itself.
     let interpreter = PythonInstallation::find_or_download(
         python_request.as_ref(),
-        EnvironmentPreference::OnlySystem,
-        python_preference,
-        python_downloads,
+        FindOptions::default()
+            .environments(EnvironmentPreference::OnlySystem)
+            .preference(python_preference)
+            .downloads(python_downloads),
         &client_builder,
         &cache,
         Some(&reporter),
-        install_mirrors.python_install_mirror.as_deref(),
-        install_mirrors.pypy_install_mirror.as_deref(),
+        &install_mirrors.mirrors(),
         install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
@@ -202,6 +204,22 @@ pub(crate) async fn install(
         }
     };
 
+    // Include any extras requested via `--extras`.
+    let from = if extras.is_empty() {
+        from
+    } else {
+        let mut from_extras = from.extras.to_vec();
+        for extra in extras {
+            if !from_extras.contains(extra) {
+                from_extras.push(extra.clone());
+            }
+        }
+        Requirement {
+            extras: from_extras.into_boxed_slice(),
+            ..from
+        }
+    };
+
     if from.name.as_str().eq_ignore_ascii_case("python") {
         return Err(anyhow::anyhow!(
             "Cannot install Python with `{}`. Did you mean to use `{}`?",
@@ -3,20 +3,36 @@ use std::fmt::Write;
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::ToolListFormat;
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_tool::InstalledTools;
 use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+/// An installed tool, as printed with `--output-format json` (or `--json`).
+#[derive(Debug, Serialize)]
+struct PrintData {
+    name: PackageName,
+    version: Version,
+    entrypoints: Vec<String>,
+    python: Option<String>,
+}
+
 /// List installed tools.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn list(
     show_paths: bool,
     show_version_specifiers: bool,
     show_with: bool,
+    output_format: ToolListFormat,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -24,8 +40,7 @@ pub(crate) async fn list(
     let _lock = match installed_tools.lock().await {
         Ok(lock) => lock,
         Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-            writeln!(printer.stderr(), "No tools installed")?;
-            return Ok(ExitStatus::Success);
+            return no_tools_installed(output_format, printer);
         }
         Err(err) => return Err(err.into()),
     };
@@ -34,10 +49,11 @@ pub(crate) async fn list(
     tools.sort_by_key(|(name, _)| name.clone());
 
     if tools.is_empty() {
-        writeln!(printer.stderr(), "No tools installed")?;
-        return Ok(ExitStatus::Success);
+        return no_tools_installed(output_format, printer);
     }
 
+    let mut data = Vec::new();
+
     for (name, tool) in tools {
         // Skip invalid tools
         let Ok(tool) = tool else {
@@ -64,6 +80,29 @@ pub(crate) async fn list(
             }
         };
 
+        if matches!(output_format, ToolListFormat::Json) {
+            // If the environment can't be queried, report the tool without a Python version.
+            let python = match installed_tools.get_environment(&name, cache) {
+                Ok(environment) => environment
+                    .map(|environment| environment.interpreter().python_version().to_string()),
+                Err(err) => {
+                    debug!("Failed to query the environment for tool `{name}`: {err}");
+                    None
+                }
+            };
+            data.push(PrintData {
+                entrypoints: tool
+                    .entrypoints()
+                    .iter()
+                    .map(|entrypoint| entrypoint.name.clone())
+                    .collect(),
+                name,
+                version,
+                python,
+            });
+            continue;
+        }
+
         let version_specifier = show_version_specifiers
             .then(|| {
                 tool.requirements()
@@ -121,5 +160,18 @@ pub(crate) async fn list(
         }
     }
 
+    if matches!(output_format, ToolListFormat::Json) {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Report that no tools are installed, printing an empty list in JSON mode.
+fn no_tools_installed(output_format: ToolListFormat, printer: Printer) -> Result<ExitStatus> {
+    match output_format {
+        ToolListFormat::Text => writeln!(printer.stderr(), "No tools installed")?,
+        ToolListFormat::Json => writeln!(printer.stdout(), "[]")?,
+    }
     Ok(ExitStatus::Success)
 }
@@ -24,14 +24,14 @@ use uv_distribution_types::{
     IndexUrl, Name, NameRequirementSpecification, Requirement, RequirementSource,
     UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
-use uv_fs::Simplified;
+use uv_fs::{Simplified, CWD};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_python::VersionRequest;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    EnvironmentPreference, FindOptions, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
@@ -41,12 +41,13 @@ use uv_static::EnvVars;
 use uv_tool::{entrypoint_paths, InstalledTools};
 use uv_warnings::warn_user;
 use uv_warnings::warn_user_once;
-use uv_workspace::WorkspaceCache;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations;
+use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     resolve_names, EnvironmentSpecification, PlatformState, ProjectError,
 };
@@ -83,6 +84,7 @@ impl Display for ToolRunCommand {
 pub(crate) async fn run(
     command: Option<ExternalCommand>,
     from: Option<String>,
+    from_lockfile: bool,
     with: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
@@ -239,6 +241,13 @@ pub(crate) async fn run(
         }
     }
 
+    // If requested, pin the tool to the version in the project's lockfile.
+    let from = if from_lockfile {
+        Some(read_locked_requirement(target).await?)
+    } else {
+        from
+    };
+
     let request = ToolRequest::parse(target, from.as_deref());
 
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
@@ -408,6 +417,49 @@ fn get_entrypoints(
     )?)
 }
 
+/// Read the version of a tool's package from the lockfile of the current project, returning a
+/// requirement that pins it (e.g., `ruff==0.6.0`).
+async fn read_locked_requirement(target: &str) -> anyhow::Result<String> {
+    let name = PackageName::from_str(target).with_context(|| {
+        format!("With `--from-lockfile`, the command must be a package name, but found `{target}`")
+    })?;
+
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(&CWD, &DiscoveryOptions::default(), &workspace_cache).await?;
+    let lock_target = LockTarget::from(&workspace);
+    let Some(lock) = lock_target.read().await? else {
+        bail!(
+            "No lockfile found at `{}`; run `{}` to create one",
+            lock_target.lock_path().user_display(),
+            "uv lock".green(),
+        );
+    };
+
+    let versions = lock
+        .packages()
+        .iter()
+        .filter(|package| package.name() == &name)
+        .filter_map(|package| package.version())
+        .unique()
+        .collect::<Vec<_>>();
+    match versions.as_slice() {
+        [] => bail!("`{}` is not pinned in the lockfile", name.cyan()),
+        [version] => {
+            debug!("Using locked version of `{name}`: {version}");
+            Ok(format!("{name}=={version}"))
+        }
+        versions => bail!(
+            "`{}` is locked at multiple versions ({}); use `--from` to select one",
+            name.cyan(),
+            versions
+                .iter()
+                .map(|version| format!("v{version}"))
+                .join(", "),
+        ),
+    }
+}
+
 /// Display a list of tools that provide the executable.
 ///
 /// If there is no package providing the executable, we will display a message to how to install a package.
@@ -717,14 +769,14 @@ async fn get_or_create_environment(
     // Discover an interpreter.
     let interpreter = PythonInstallation::find_or_download(
         python_request.as_ref(),
-        EnvironmentPreference::OnlySystem,
-        python_preference,
-        python_downloads,
+        FindOptions::default()
+            .environments(EnvironmentPreference::OnlySystem)
+            .preference(python_preference)
+            .downloads(python_downloads),
         &client_builder,
         cache,
         Some(&reporter),
-        install_mirrors.python_install_mirror.as_deref(),
-        install_mirrors.pypy_install_mirror.as_deref(),
+        &install_mirrors.mirrors(),
         install_mirrors.python_downloads_json_url.as_deref(),
     )
     .await?
@@ -1,6 +1,7 @@
 #![cfg_attr(windows, allow(unreachable_code))]
 
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
@@ -17,6 +18,30 @@ use crate::printer::Printer;
 /// Ensure that the executable directory is in PATH.
 pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     let executable_directory = tool_executable_dir()?;
+    match ensure_on_path(&executable_directory, printer).await? {
+        PathUpdate::Done => Ok(ExitStatus::Success),
+        PathUpdate::UpToDate(shell) => Err(anyhow::anyhow!("The executable directory {} is not in PATH, but the {shell} configuration files are already up-to-date", executable_directory.simplified_display().cyan())),
+    }
+}
+
+/// The outcome of [`ensure_on_path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PathUpdate {
+    /// The executable directory was already in PATH, or has been added to it.
+    Done,
+    /// The executable directory is not in PATH, but the configuration files for the shell already
+    /// include it, e.g., because the shell hasn't been restarted since they were updated.
+    UpToDate(Shell),
+}
+
+/// Ensure that the given executable directory is in PATH, updating the shell configuration files
+/// (or, on Windows, the user `PATH` in the registry) as necessary.
+///
+/// Updates are idempotent: a configuration file that already contains the command is skipped.
+pub(crate) async fn ensure_on_path(
+    executable_directory: &Path,
+    printer: Printer,
+) -> Result<PathUpdate> {
     debug!(
         "Ensuring that the executable directory is in PATH: {}",
         executable_directory.simplified_display()
@@ -24,7 +49,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
 
     #[cfg(windows)]
     {
-        if uv_shell::windows::prepend_path(&executable_directory)? {
+        if uv_shell::windows::prepend_path(executable_directory)? {
             writeln!(
                 printer.stderr(),
                 "Updated PATH to include executable directory {}",
@@ -39,16 +64,16 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
             )?;
         }
 
-        return Ok(ExitStatus::Success);
+        return Ok(PathUpdate::Done);
     }
 
-    if Shell::contains_path(&executable_directory) {
+    if Shell::contains_path(executable_directory) {
         writeln!(
             printer.stderr(),
             "Executable directory {} is already in PATH",
             executable_directory.simplified_display().cyan()
         )?;
-        return Ok(ExitStatus::Success);
+        return Ok(PathUpdate::Done);
     }
 
     // Determine the current shell.
@@ -63,7 +88,7 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
     }
 
     // Prepare the command (e.g., `export PATH="$HOME/.cargo/bin:$PATH"`).
-    let Some(command) = shell.prepend_path(&executable_directory) else {
+    let Some(command) = shell.prepend_path(executable_directory) else {
         return Err(anyhow::anyhow!("The executable directory {} is not in PATH, but the necessary command to update {shell} could not be determined", executable_directory.simplified_display().cyan()));
     };
 
@@ -134,8 +159,8 @@ pub(crate) async fn update_shell(printer: Printer) -> Result<ExitStatus> {
 
     if updated {
         writeln!(printer.stderr(), "Restart your shell to apply changes")?;
-        Ok(ExitStatus::Success)
+        Ok(PathUpdate::Done)
     } else {
-        Err(anyhow::anyhow!("The executable directory {} is not in PATH, but the {shell} configuration files are already up-to-date", executable_directory.simplified_display().cyan()))
+        Ok(PathUpdate::UpToDate(shell))
     }
 }
@@ -8,12 +8,13 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, PreviewMode};
-use uv_distribution_types::Requirement;
+use uv_distribution_types::{Name, Requirement, Resolution};
 use uv_fs::CWD;
+use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    EnvironmentPreference, FindOptions, Interpreter, PythonDownloads, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -40,6 +41,7 @@ pub(crate) async fn upgrade(
     install_mirrors: PythonInstallMirrors,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
+    dry_run: DryRun,
     network_settings: NetworkSettings,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -90,14 +92,14 @@ pub(crate) async fn upgrade(
         Some(
             PythonInstallation::find_or_download(
                 python_request.as_ref(),
-                EnvironmentPreference::OnlySystem,
-                python_preference,
-                python_downloads,
+                FindOptions::default()
+                    .environments(EnvironmentPreference::OnlySystem)
+                    .preference(python_preference)
+                    .downloads(python_downloads),
                 &client_builder,
                 cache,
                 Some(&reporter),
-                install_mirrors.python_install_mirror.as_deref(),
-                install_mirrors.pypy_install_mirror.as_deref(),
+                &install_mirrors.mirrors(),
                 install_mirrors.python_downloads_json_url.as_deref(),
             )
             .await?
@@ -128,6 +130,7 @@ pub(crate) async fn upgrade(
             &filesystem,
             installer_metadata,
             concurrency,
+            dry_run,
             preview,
         )
         .await;
@@ -184,7 +187,12 @@ pub(crate) async fn upgrade(
             let s = if tools.len() > 1 { "s" } else { "" };
             writeln!(
                 printer.stderr(),
-                "Upgraded tool environment{s} for {} to {}",
+                "{} tool environment{s} for {} to {}",
+                if dry_run.enabled() {
+                    "Would upgrade"
+                } else {
+                    "Upgraded"
+                },
                 conjunction(tools),
                 python_request.cyan(),
             )?;
@@ -219,6 +227,7 @@ async fn upgrade_tool(
     filesystem: &ResolverInstallerOptions,
     installer_metadata: bool,
     concurrency: Concurrency,
+    dry_run: DryRun,
     preview: PreviewMode,
 ) -> Result<UpgradeOutcome> {
     // Ensure the tool is installed.
@@ -288,6 +297,50 @@ async fn upgrade_tool(
     let state = PlatformState::default();
     let workspace_cache = WorkspaceCache::default();
 
+    // In dry-run mode, resolve the tool's requirements and compare against the installed version,
+    // without modifying the environment.
+    if dry_run.enabled() {
+        let new_interpreter = interpreter.filter(|interpreter| !environment.uses(interpreter));
+
+        let resolution = resolve_environment(
+            spec.into(),
+            new_interpreter.unwrap_or_else(|| environment.interpreter()),
+            &settings.resolver,
+            network_settings,
+            &state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+        let resolution = Resolution::from(resolution);
+
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let installed = site_packages
+            .get_packages(name)
+            .first()
+            .map(|dist| dist.version().clone());
+        let resolved = resolution
+            .distributions()
+            .find(|dist| dist.name() == name)
+            .and_then(|dist| dist.version().cloned());
+
+        return match (installed, resolved) {
+            (Some(installed), Some(resolved)) if installed != resolved => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {name} v{installed} -> v{resolved}",
+                    "Would update".green().bold(),
+                )?;
+                Ok(UpgradeOutcome::UpgradeTool)
+            }
+            _ if new_interpreter.is_some() => Ok(UpgradeOutcome::UpgradeEnvironment),
+            _ => Ok(UpgradeOutcome::NoOp),
+        };
+    }
+
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
     let (environment, outcome) = if let Some(interpreter) =
@@ -6,6 +6,7 @@ use std::vec;
 
 use anstream::eprint;
 use anyhow::Result;
+use itertools::Itertools;
 use miette::{Diagnostic, IntoDiagnostic};
 use owo_colors::OwoColorize;
 use thiserror::Error;
@@ -18,16 +19,20 @@ use uv_configuration::{
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations};
+use uv_distribution_types::{
+    DependencyMetadata, DistributionMetadata, Index, IndexLocations, Name,
+};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, FindOptions, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
-use uv_shell::{shlex_posix, shlex_windows, Shell};
+use uv_shell::{shlex_posix, Shell};
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
+use uv_virtualenv::Relinked;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
 
@@ -45,6 +50,7 @@ pub(crate) async fn venv(
     project_dir: &Path,
     path: Option<PathBuf>,
     python_request: Option<&str>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -55,9 +61,12 @@ pub(crate) async fn venv(
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
+    shell: Option<Shell>,
     system_site_packages: bool,
-    seed: bool,
+    seed: Option<&[Requirement]>,
     allow_existing: bool,
+    relink: bool,
+    check: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
     no_config: bool,
@@ -71,6 +80,7 @@ pub(crate) async fn venv(
         project_dir,
         path,
         python_request,
+        python_variable,
         install_mirrors,
         link_mode,
         index_locations,
@@ -79,11 +89,14 @@ pub(crate) async fn venv(
         keyring_provider,
         network_settings,
         prompt,
+        shell,
         system_site_packages,
         seed,
         python_preference,
         python_downloads,
         allow_existing,
+        relink,
+        check,
         exclude_newer,
         concurrency,
         no_config,
@@ -109,6 +122,14 @@ enum VenvError {
     #[diagnostic(code(uv::venv::creation))]
     Creation(#[source] uv_virtualenv::Error),
 
+    #[error("Failed to relink virtualenv")]
+    #[diagnostic(code(uv::venv::relink))]
+    Relink(#[source] uv_virtualenv::Error),
+
+    #[error("No virtual environment found at `{}`", _0.user_display())]
+    #[diagnostic(code(uv::venv::missing))]
+    Missing(PathBuf),
+
     #[error("Failed to install seed packages")]
     #[diagnostic(code(uv::venv::seed))]
     Seed(#[source] AnyErrorBuild),
@@ -128,6 +149,7 @@ async fn venv_impl(
     project_dir: &Path,
     path: Option<PathBuf>,
     python_request: Option<&str>,
+    python_variable: Option<&'static str>,
     install_mirrors: PythonInstallMirrors,
     link_mode: LinkMode,
     index_locations: &IndexLocations,
@@ -136,11 +158,14 @@ async fn venv_impl(
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
+    shell: Option<Shell>,
     system_site_packages: bool,
-    seed: bool,
+    seed: Option<&[Requirement]>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     allow_existing: bool,
+    relink: bool,
+    check: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
     no_config: bool,
@@ -190,6 +215,11 @@ async fn venv_impl(
             .unwrap_or(PathBuf::from(".venv")),
     );
 
+    // Compare an existing virtual environment against its current base interpreter.
+    if check {
+        return check_venv(&path, cache, printer);
+    }
+
     let client_builder = BaseClientBuilder::default()
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
@@ -203,6 +233,7 @@ async fn venv_impl(
         requires_python,
     } = WorkspacePython::from_request(
         python_request.map(PythonRequest::parse),
+        python_variable,
         project.as_ref().map(VirtualProject::workspace),
         project_dir,
         no_config,
@@ -214,14 +245,15 @@ async fn venv_impl(
     let interpreter = {
         let python = PythonInstallation::find_or_download(
             python_request.as_ref(),
-            EnvironmentPreference::OnlySystem,
-            python_preference,
-            python_downloads,
+            FindOptions::default()
+                .environments(EnvironmentPreference::OnlySystem)
+                .preference(python_preference)
+                .downloads(python_downloads)
+                .request_variable(source.variable()),
             &client_builder,
             cache,
             Some(&reporter),
-            install_mirrors.python_install_mirror.as_deref(),
-            install_mirrors.pypy_install_mirror.as_deref(),
+            &install_mirrors.mirrors(),
             install_mirrors.python_downloads_json_url.as_deref(),
         )
         .await
@@ -256,10 +288,37 @@ async fn venv_impl(
         }
     }
 
+    // Re-link an existing virtual environment in-place, rather than recreating it.
+    if relink && path.join("pyvenv.cfg").is_file() {
+        writeln!(
+            printer.stderr(),
+            "Relinking virtual environment at: {}",
+            path.user_display().cyan()
+        )
+        .into_diagnostic()?;
+
+        let (_, relinked) = uv_virtualenv::relink_venv(
+            &path,
+            interpreter,
+            prompt,
+            system_site_packages,
+            relocatable,
+        )
+        .map_err(VenvError::Relink)?;
+
+        report_relinked(&relinked, printer).into_diagnostic()?;
+
+        return Ok(ExitStatus::Success);
+    }
+
     writeln!(
         printer.stderr(),
         "Creating virtual environment {}at: {}",
-        if seed { "with seed packages " } else { "" },
+        if seed.is_some() {
+            "with seed packages "
+        } else {
+            ""
+        },
         path.user_display().cyan()
     )
     .into_diagnostic()?;
@@ -272,12 +331,12 @@ async fn venv_impl(
         system_site_packages,
         allow_existing,
         relocatable,
-        seed,
+        seed.is_some(),
     )
     .map_err(VenvError::Creation)?;
 
     // Install seed packages.
-    if seed {
+    if let Some(seed) = seed {
         // Extract the interpreter.
         let interpreter = venv.interpreter();
 
@@ -357,7 +416,9 @@ async fn venv_impl(
         );
 
         // Resolve the seed packages.
-        let requirements = if interpreter.python_tuple() >= (3, 12) {
+        let requirements = if !seed.is_empty() {
+            seed.to_vec()
+        } else if interpreter.python_tuple() >= (3, 12) {
             vec![Requirement::from(
                 uv_pep508::Requirement::from_str("pip").unwrap(),
             )]
@@ -385,40 +446,126 @@ async fn venv_impl(
             .await
             .map_err(|err| VenvError::Seed(err.into()))?;
 
+        // Record the seeded packages, e.g., for later inspection of the environment.
+        let seeded = resolution
+            .distributions()
+            .map(|dist| format!("{}{}", dist.name(), dist.version_or_url()))
+            .sorted()
+            .join(",");
+        venv.set_pyvenv_cfg("seed-packages", &seeded)
+            .into_diagnostic()?;
+
         let changelog = Changelog::from_installed(installed);
         DefaultInstallLogger
             .on_complete(&changelog, printer)
             .into_diagnostic()?;
     }
 
-    // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
-        None => None,
-        Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => Some(format!(
-            "source {}",
-            shlex_posix(venv.scripts().join("activate"))
-        )),
-        Some(Shell::Fish) => Some(format!(
-            "source {}",
-            shlex_posix(venv.scripts().join("activate.fish"))
-        )),
-        Some(Shell::Nushell) => Some(format!(
-            "overlay use {}",
-            shlex_posix(venv.scripts().join("activate.nu"))
-        )),
-        Some(Shell::Csh) => Some(format!(
-            "source {}",
-            shlex_posix(venv.scripts().join("activate.csh"))
-        )),
-        Some(Shell::Powershell) => Some(shlex_windows(
-            venv.scripts().join("activate"),
-            Shell::Powershell,
-        )),
-        Some(Shell::Cmd) => Some(shlex_windows(venv.scripts().join("activate"), Shell::Cmd)),
+    // Determine the appropriate activation command, falling back to the POSIX script if the shell
+    // is unknown.
+    let activation = match shell.or_else(Shell::from_parent_or_env) {
+        Some(shell) => shell.activate_command(venv.scripts()),
+        None => format!("source {}", shlex_posix(venv.scripts().join("activate"))),
     };
-    if let Some(act) = activation {
-        writeln!(printer.stderr(), "Activate with: {}", act.green()).into_diagnostic()?;
-    }
+    writeln!(printer.stderr(), "Activate with: {}", activation.green()).into_diagnostic()?;
 
     Ok(ExitStatus::Success)
 }
+
+/// Check that the virtual environment at `path` still matches the base interpreter it was created
+/// with, as recorded in its `pyvenv.cfg`.
+fn check_venv(path: &Path, cache: &Cache, printer: Printer) -> miette::Result<ExitStatus> {
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(VenvError::Missing(path.to_path_buf()).into());
+    }
+
+    let cfg = PyVenvConfiguration::parse(path.join("pyvenv.cfg")).into_diagnostic()?;
+    let environment = PythonEnvironment::from_root(path, cache).into_diagnostic()?;
+    let interpreter = environment.interpreter();
+
+    // Prefer the full installation key, falling back to the Python version for environments that
+    // were created by older versions of uv (or by other tools).
+    let (recorded, current) = if let Some(key) = cfg.python_key() {
+        (Some(key.to_string()), interpreter.key().to_string())
+    } else {
+        (
+            cfg.version().map(ToString::to_string),
+            interpreter.python_full_version().to_string(),
+        )
+    };
+
+    let Some(recorded) = recorded else {
+        writeln!(
+            printer.stderr(),
+            "The virtual environment at {} does not record the Python interpreter it was created with",
+            path.user_display().cyan()
+        )
+        .into_diagnostic()?;
+        return Ok(ExitStatus::Failure);
+    };
+
+    if recorded == current {
+        writeln!(
+            printer.stderr(),
+            "The virtual environment at {} matches its base interpreter: {}",
+            path.user_display().cyan(),
+            current.cyan()
+        )
+        .into_diagnostic()?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "The base interpreter of the virtual environment at {} has changed since it was created:",
+        path.user_display().cyan()
+    )
+    .into_diagnostic()?;
+    writeln!(printer.stderr(), "{}", format!("- {recorded}").red()).into_diagnostic()?;
+    writeln!(printer.stderr(), "{}", format!("+ {current}").green()).into_diagnostic()?;
+    writeln!(
+        printer.stderr(),
+        "Use `{}` to update the environment",
+        "uv venv --relink".green()
+    )
+    .into_diagnostic()?;
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Summarize the changes made when re-linking a virtual environment.
+fn report_relinked(relinked: &Relinked, printer: Printer) -> std::fmt::Result {
+    if relinked.previous_home.as_ref() != Some(&relinked.home) {
+        writeln!(
+            printer.stderr(),
+            "Updated base interpreter: {} -> {}",
+            relinked
+                .previous_home
+                .as_ref()
+                .map_or_else(
+                    || "unknown".to_string(),
+                    |home| home.user_display().to_string()
+                )
+                .red(),
+            relinked.home.user_display().green()
+        )?;
+    }
+
+    if relinked.previous_version.as_ref() != Some(&relinked.version) {
+        writeln!(
+            printer.stderr(),
+            "Updated Python version: {} -> {}",
+            relinked
+                .previous_version
+                .as_ref()
+                .map_or_else(|| "unknown".to_string(), ToString::to_string)
+                .red(),
+            relinked.version.to_string().green()
+        )?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Regenerated executables, activation scripts, and `pyvenv.cfg`"
+    )
+}
@@ -13,7 +13,8 @@ use std::sync::atomic::Ordering;
 use anstream::eprintln;
 use anyhow::{bail, Context, Result};
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -26,8 +27,9 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand,
-    SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs, VersionArgs,
+    ConfigCommand, ConfigNamespace, IndexCommand, IndexNamespace, PipCommand, PipNamespace,
+    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs, VersionArgs,
 };
 use uv_configuration::min_stack_size;
 use uv_fs::{Simplified, CWD};
@@ -56,8 +58,12 @@ pub(crate) mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
 
+/// Run the given command.
+///
+/// If the command's `--python` request was read from an environment variable, e.g., `UV_PYTHON`,
+/// rather than provided on the command line, `python_variable` is the name of the variable.
 #[instrument(skip_all)]
-async fn run(mut cli: Cli) -> Result<ExitStatus> {
+async fn run(mut cli: Cli, python_variable: Option<&'static str>) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
     if cli.top_level.global_args.quiet == 0 {
         uv_warnings::enable();
@@ -91,6 +97,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Supports `--isolated` as its own argument, so we can't warn either way.
             Commands::Project(command) if matches!(**command, ProjectCommand::Run(_)) => false,
 
+            // Supports `--isolated` as its own argument, so we can't warn either way.
+            Commands::Pip(PipNamespace {
+                command: PipCommand::Install(_),
+            }) => false,
+
             // `--isolated` moved to `--no-workspace`.
             Commands::Project(command) if matches!(**command, ProjectCommand::Init(_)) => {
                 warn_user!("The `--isolated` flag is deprecated and has no effect. Instead, use `--no-config` to prevent uv from discovering configuration files or `--no-workspace` to prevent uv from adding the initialized project to the containing workspace.");
@@ -130,6 +141,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Some(FilesystemOptions::from_file(config_file)?)
     } else if deprecated_isolated || cli.top_level.no_config {
         None
+    } else if matches!(&*cli.command, Commands::Config(_) | Commands::Index(_)) {
+        // `uv config` and `uv index` edit configuration files directly, so an invalid file
+        // shouldn't prevent them from running.
+        None
     } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
         // For commands that operate at the user-level, ignore local configuration.
         FilesystemOptions::user()?.combine(FilesystemOptions::system()?)
@@ -337,6 +352,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         }
     }
 
+    // `uv pip install --log` writes debug-level logs to a file, in addition to stderr.
+    let log_file = if let Commands::Pip(PipNamespace {
+        command: PipCommand::Install(args),
+    }) = &*cli.command
+    {
+        args.log.as_deref()
+    } else {
+        None
+    };
+
     // Configure the `tracing` crate, which controls internal logging.
     #[cfg(feature = "tracing-durations-export")]
     let (duration_layer, _duration_guard) = logging::setup_duration()?;
@@ -350,6 +375,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             3.. => logging::Level::TraceAll,
         },
         duration_layer,
+        log_file,
         globals.color,
     )?;
 
@@ -744,10 +770,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.user,
+                args.isolated,
+                args.test_command.as_deref(),
                 globals.python_preference,
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
                 printer,
                 globals.preview,
             )
@@ -784,6 +814,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.dry_run,
+                args.break_on_not_installed,
                 printer,
             )
             .await
@@ -825,6 +856,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.exclude,
                 &args.format,
                 args.outdated,
+                args.not_required,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -909,6 +941,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Hash(args),
+        }) => {
+            show_settings!(args);
+
+            commands::pip_hash(
+                &args.files,
+                args.algorithm.into(),
+                &globals.network_settings,
+                printer,
+            )
+            .await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
         })
@@ -928,6 +973,69 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Get(args),
+        }) => {
+            show_settings!(args);
+            commands::config_get(&args.key, args.user, &project_dir, printer)
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Set(args),
+        }) => {
+            show_settings!(args);
+            commands::config_set(&args.key, &args.value, args.user, &project_dir, printer).await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::List,
+        }) => {
+            commands::config_list(
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                cli.top_level.no_config,
+                printer,
+            )
+            .await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Add(args),
+        }) => {
+            show_settings!(args);
+            commands::index_add(
+                &args.name,
+                &args.url,
+                args.default,
+                args.user,
+                &project_dir,
+                printer,
+            )
+            .await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Remove(args),
+        }) => {
+            show_settings!(args);
+            commands::index_remove(&args.name, args.user, &project_dir, printer).await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::List(args),
+        }) => {
+            show_settings!(args);
+            commands::index_list(args.user, &project_dir, printer)
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Auth(args),
+        }) => {
+            commands::index_auth(
+                &args.name,
+                &args.username,
+                args.token.as_deref(),
+                args.delete,
+                args.user,
+                &project_dir,
+                printer,
+            )
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -1008,6 +1116,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.path,
                 args.settings.python.as_deref(),
+                python_variable,
                 args.settings.install_mirrors,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1018,9 +1127,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 uv_virtualenv::Prompt::from_args(prompt),
+                args.shell,
                 args.system_site_packages,
-                args.seed,
+                args.seed.as_deref(),
                 args.allow_existing,
+                args.relink,
+                args.check,
                 args.settings.exclude_newer,
                 globals.concurrency,
                 cli.top_level.no_config,
@@ -1040,6 +1152,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 script,
                 globals,
                 cli.top_level.no_config,
+                python_variable,
                 filesystem,
                 cache,
                 printer,
@@ -1186,6 +1299,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             Box::pin(commands::tool_run(
                 args.command,
                 args.from,
+                args.from_lockfile,
                 &requirements,
                 &constraints,
                 &overrides,
@@ -1260,6 +1374,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.package,
                 args.editable,
                 args.from,
+                &args.extras,
                 &requirements,
                 &constraints,
                 &overrides,
@@ -1294,6 +1409,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.show_paths,
                 args.show_version_specifiers,
                 args.show_with,
+                args.output_format,
                 &cache,
                 printer,
             )
@@ -1315,6 +1431,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_mirrors,
                 args.args,
                 args.filesystem,
+                args.dry_run,
                 globals.network_settings,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1367,11 +1484,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.all_versions,
                 args.all_platforms,
                 args.all_arches,
+                args.outdated,
                 args.show_urls,
                 args.output_format,
                 args.python_downloads_json_url,
                 globals.python_preference,
                 globals.python_downloads,
+                &globals.network_settings,
                 &cache,
                 printer,
             )
@@ -1384,20 +1503,54 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonInstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            if let Some(out_dir) = args.download_dir {
+                return commands::python_download(
+                    &project_dir,
+                    args.targets,
+                    &out_dir,
+                    args.install_mirrors,
+                    args.python_downloads_json_url,
+                    globals.network_settings,
+                    args.allow_prereleases,
+                    args.arch,
+                    args.platform,
+                    args.build_flavor,
+                    globals.python_downloads,
+                    cli.top_level.no_config,
+                    printer,
+                )
+                .await;
+            }
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             commands::python_install(
                 &project_dir,
                 args.install_dir,
                 args.targets,
                 args.reinstall,
                 args.force,
-                args.python_install_mirror,
-                args.pypy_install_mirror,
+                args.install_mirrors,
                 args.python_downloads_json_url,
                 globals.network_settings,
                 args.default,
+                args.allow_prereleases,
+                args.arch,
+                args.build_flavor,
+                args.compile_bytecode,
+                args.keep,
+                args.externally_managed,
+                args.install_bin,
+                args.windowed,
+                args.modify_path,
+                args.no_wait,
+                args.output_format,
                 globals.python_downloads,
+                globals.concurrency,
                 cli.top_level.no_config,
                 globals.preview,
+                &cache,
                 printer,
             )
             .await
@@ -1413,6 +1566,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_dir,
                 args.targets,
                 args.all,
+                args.yes,
+                args.force,
+                args.no_wait,
                 printer,
                 globals.preview,
             )
@@ -1444,6 +1600,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.request,
                     args.show_version,
+                    args.show_source,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -1467,6 +1624,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.request,
                 args.resolved,
+                args.exact,
+                args.from_downloads,
+                args.rm,
                 globals.python_preference,
                 args.no_project,
                 args.global,
@@ -1485,6 +1645,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::python_dir(args.bin)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Manifest(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonManifestSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_manifest(
+                &args.directory,
+                args.base_url.as_ref(),
+                args.upstream.as_deref(),
+                args.output.as_deref(),
+                printer,
+            )
+        }
         Commands::Publish(args) => {
             show_settings!(args);
 
@@ -1508,9 +1683,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 keyring_provider,
                 check_url,
                 index,
+                check,
+                attestation,
                 index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
+            if check {
+                return commands::publish_check(files, printer).await;
+            }
+
             let (publish_url, check_url) = if let Some(index_name) = index {
                 debug!("Publishing with index {index_name}");
                 let index = index_locations
@@ -1556,6 +1737,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 username,
                 password,
                 check_url,
+                attestation,
                 &cache,
                 printer,
             )
@@ -1610,6 +1792,7 @@ async fn run_project(
     globals: GlobalSettings,
     // TODO(zanieb): Determine a better story for passing `no_config` in here
     no_config: bool,
+    python_variable: Option<&'static str>,
     filesystem: Option<FilesystemOptions>,
     cache: Cache,
     printer: Printer,
@@ -1646,7 +1829,7 @@ async fn run_project(
                 args.build_backend,
                 args.no_readme,
                 args.author_from,
-                args.pin_python,
+                args.pin,
                 args.python,
                 args.install_mirrors,
                 args.no_workspace,
@@ -1698,6 +1881,7 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.recreate_venv,
                 args.isolated,
                 args.all_packages,
                 args.package,
@@ -1708,6 +1892,7 @@ async fn run_project(
                 args.editable,
                 args.modifications,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -1721,6 +1906,7 @@ async fn run_project(
                 args.no_env_file,
                 globals.preview,
                 args.max_recursion_depth,
+                args.python_args,
             ))
             .await
         }
@@ -1748,15 +1934,19 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.verify_hashes,
                 args.active,
+                args.recreate_venv,
                 args.all_packages,
                 args.package,
+                args.exclude_package,
                 args.extras,
                 args.dev,
                 args.editable,
                 args.install_options,
                 args.modifications,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1801,7 +1991,10 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.exclude_package,
+                args.python_platform,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -1877,6 +2070,7 @@ async fn run_project(
                 args.extras,
                 args.package,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -1918,9 +2112,12 @@ async fn run_project(
                 args.active,
                 args.no_sync,
                 args.packages,
+                args.all,
+                args.yes,
                 args.dependency_type,
                 args.package,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -1960,12 +2157,16 @@ async fn run_project(
                 args.depth,
                 args.prune,
                 args.package,
+                args.why,
+                args.json,
+                args.cycles,
                 args.no_dedupe,
                 args.invert,
                 args.outdated,
                 args.python_version,
                 args.python_platform,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.resolver,
                 &globals.network_settings,
@@ -2013,6 +2214,7 @@ async fn run_project(
                 args.include_header,
                 script,
                 args.python,
+                python_variable,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -2031,6 +2233,18 @@ async fn run_project(
     }
 }
 
+/// Returns `UV_PYTHON` if the `--python` option of the invoked command was read from it, rather
+/// than provided on the command line.
+fn python_request_variable(matches: &ArgMatches) -> Option<&'static str> {
+    let mut matches = matches;
+    while let Some((_, subcommand)) = matches.subcommand() {
+        matches = subcommand;
+    }
+    (matches.ids().any(|id| id == "python")
+        && matches.value_source("python") == Some(ValueSource::EnvVariable))
+    .then_some(EnvVars::UV_PYTHON)
+}
+
 /// The main entry point for a uv invocation.
 ///
 /// # Usage
@@ -2064,7 +2278,13 @@ where
 
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
+    let mut python_variable = None;
+    let cli = match Cli::command()
+        .try_get_matches_from(args)
+        .and_then(|mut matches| {
+            python_variable = python_request_variable(&matches);
+            Cli::from_arg_matches_mut(&mut matches)
+        }) {
         Ok(cli) => cli,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
@@ -2122,7 +2342,7 @@ where
             .build()
             .expect("Failed building the Runtime");
         // Box the large main future to avoid stack overflows.
-        let result = runtime.block_on(Box::pin(run(cli)));
+        let result = runtime.block_on(Box::pin(run(cli, python_variable)));
         // Avoid waiting for pending tasks to complete.
         //
         // The resolver may have kicked off HTTP requests during resolution that
@@ -2151,6 +2371,17 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
             }
+            // Interpreter errors are often boxed when wrapped by discovery errors.
+            if let Some(hint) = err.chain().find_map(|err| {
+                err.downcast_ref::<uv_python::InterpreterError>()
+                    .or_else(|| {
+                        err.downcast_ref::<Box<uv_python::InterpreterError>>()
+                            .map(AsRef::as_ref)
+                    })
+                    .and_then(uv_python::InterpreterError::hint)
+            }) {
+                eprintln!("\n{}{} {hint}", "hint".bold().cyan(), ":".bold());
+            }
             ExitStatus::Error.into()
         }
     }
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::Context;
@@ -115,6 +116,7 @@ where
 pub(crate) fn setup_logging(
     level: Level,
     durations: impl Layer<Registry> + Send + Sync,
+    log_file: Option<&Path>,
     color: ColorChoice,
 ) -> anyhow::Result<()> {
     // We use directives here to ensure `RUST_LOG` can override them
@@ -166,11 +168,39 @@ pub(crate) fn setup_logging(
         };
     let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
 
+    // If requested, write all `DEBUG` messages from uv to a file, independent of the `--verbose`
+    // level, with timestamps and without any colors.
+    let file_layer = if let Some(log_file) = log_file {
+        let file = fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_file)
+            .context("Failed to open log file")?;
+        let format = UvFormat {
+            display_timestamp: true,
+            display_level: true,
+            show_spans: false,
+        };
+        Some(
+            tracing_subscriber::fmt::layer()
+                .event_format(format)
+                .with_writer(std::sync::Mutex::new(anstream::StripStream::new(file)))
+                .with_ansi(false)
+                .with_filter(
+                    tracing_subscriber::filter::Targets::new()
+                        .with_target("uv", tracing::level_filters::LevelFilter::DEBUG),
+                ),
+        )
+    } else {
+        None
+    };
+
     let detailed_logging = std::env::var(EnvVars::UV_LOG_CONTEXT).is_ok();
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(file_layer)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -190,6 +220,7 @@ pub(crate) fn setup_logging(
 
         tracing_subscriber::registry()
             .with(durations_layer)
+            .with(file_layer)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(format)
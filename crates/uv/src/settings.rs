@@ -10,21 +10,22 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, PythonManifestArgs,
+    ResolverInstallerArgs, ToolUpgradeArgs,
 };
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs,
-    ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs,
+    PythonInstallFormat, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    RemoveArgs, RunArgs, SeedPackage, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs,
+    ToolListFormat, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
     ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall,
+    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, PythonPin, Reinstall,
     RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
     VersionControlSystem,
 };
@@ -33,7 +34,11 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::{PackageName, PipGroupName};
 use uv_pep508::{ExtraName, MarkerTree, RequirementOrigin};
 use uv_pypi_types::SupportedEnvironments;
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::platform::{Arch, TargetPlatform};
+use uv_python::{
+    BuildFlavor, ImplementationName, InstallMirrors, Prefix, PythonDownloads, PythonPreference,
+    PythonVersion, Target,
+};
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ForkStrategy, PrereleaseMode, ResolutionMode,
 };
@@ -41,6 +46,7 @@ use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, PublishOptions, PythonInstallMirrors,
     ResolverInstallerOptions, ResolverOptions,
 };
+use uv_shell::Shell;
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
 use uv_warnings::warn_user_once;
@@ -235,7 +241,7 @@ pub(crate) struct InitSettings {
     pub(crate) build_backend: Option<ProjectBuildBackend>,
     pub(crate) no_readme: bool,
     pub(crate) author_from: Option<AuthorFrom>,
-    pub(crate) pin_python: bool,
+    pub(crate) pin: PythonPin,
     pub(crate) no_workspace: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -263,6 +269,7 @@ impl InitSettings {
             author_from,
             no_pin_python,
             pin_python,
+            pin,
             no_workspace,
             python,
             ..
@@ -279,6 +286,15 @@ impl InitSettings {
         let package = flag(package || build_backend.is_some(), no_package || r#virtual)
             .unwrap_or(kind.packaged_by_default());
 
+        // `--bare` avoids creating a `.python-version` file, unless a pin is explicitly requested.
+        let pin = match flag(pin_python, no_pin_python) {
+            Some(false) => PythonPin::None,
+            None if bare && pin.is_none() => PythonPin::None,
+            _ => pin
+                .or(filesystem.as_ref().and_then(|fs| fs.python_pin))
+                .unwrap_or_default(),
+        };
+
         let install_mirrors = filesystem
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
@@ -297,7 +313,7 @@ impl InitSettings {
             build_backend,
             no_readme: no_readme || bare,
             author_from,
-            pin_python: flag(pin_python, no_pin_python).unwrap_or(!bare),
+            pin,
             no_workspace,
             python: python.and_then(Maybe::into_option),
             install_mirrors,
@@ -325,6 +341,7 @@ pub(crate) struct RunSettings {
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) recreate_venv: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -332,6 +349,7 @@ pub(crate) struct RunSettings {
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
     pub(crate) max_recursion_depth: u32,
+    pub(crate) python_args: Option<String>,
 }
 
 impl RunSettings {
@@ -363,6 +381,7 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            python_args,
             command: _,
             with,
             with_editable,
@@ -371,6 +390,7 @@ impl RunSettings {
             active,
             no_active,
             no_sync,
+            recreate_venv,
             locked,
             frozen,
             installer,
@@ -437,6 +457,7 @@ impl RunSettings {
             package,
             no_project,
             no_sync,
+            recreate_venv,
             active: flag(active, no_active),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -448,6 +469,7 @@ impl RunSettings {
             no_env_file,
             install_mirrors,
             max_recursion_depth: max_recursion_depth.unwrap_or(Self::DEFAULT_MAX_RECURSION_DEPTH),
+            python_args,
         }
     }
 }
@@ -458,6 +480,7 @@ impl RunSettings {
 pub(crate) struct ToolRunSettings {
     pub(crate) command: Option<ExternalCommand>,
     pub(crate) from: Option<String>,
+    pub(crate) from_lockfile: bool,
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_editable: Vec<String>,
@@ -486,6 +509,7 @@ impl ToolRunSettings {
         let ToolRunArgs {
             command,
             from,
+            from_lockfile,
             with,
             with_editable,
             with_requirements,
@@ -539,6 +563,7 @@ impl ToolRunSettings {
         Self {
             command,
             from,
+            from_lockfile,
             with: with
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
@@ -582,6 +607,7 @@ impl ToolRunSettings {
 pub(crate) struct ToolInstallSettings {
     pub(crate) package: String,
     pub(crate) from: Option<String>,
+    pub(crate) extras: Vec<ExtraName>,
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_editable: Vec<String>,
@@ -605,6 +631,7 @@ impl ToolInstallSettings {
             package,
             editable,
             from,
+            extras,
             with,
             with_editable,
             with_requirements,
@@ -636,6 +663,7 @@ impl ToolInstallSettings {
         Self {
             package,
             from,
+            extras,
             with: with
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
@@ -680,6 +708,7 @@ pub(crate) struct ToolUpgradeSettings {
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) args: ResolverInstallerOptions,
     pub(crate) filesystem: ResolverInstallerOptions,
+    pub(crate) dry_run: DryRun,
 }
 impl ToolUpgradeSettings {
     /// Resolve the [`ToolUpgradeSettings`] from the CLI and filesystem configuration.
@@ -692,6 +721,7 @@ impl ToolUpgradeSettings {
             upgrade_package,
             index_args,
             all,
+            dry_run,
             reinstall,
             no_reinstall,
             reinstall_package,
@@ -762,6 +792,7 @@ impl ToolUpgradeSettings {
             args,
             filesystem: top_level,
             install_mirrors,
+            dry_run: DryRun::from_args(dry_run),
         }
     }
 }
@@ -773,6 +804,7 @@ pub(crate) struct ToolListSettings {
     pub(crate) show_paths: bool,
     pub(crate) show_version_specifiers: bool,
     pub(crate) show_with: bool,
+    pub(crate) output_format: ToolListFormat,
 }
 
 impl ToolListSettings {
@@ -783,6 +815,8 @@ impl ToolListSettings {
             show_paths,
             show_version_specifiers,
             show_with,
+            output_format,
+            json,
             python_preference: _,
             no_python_downloads: _,
         } = args;
@@ -791,6 +825,11 @@ impl ToolListSettings {
             show_paths,
             show_version_specifiers,
             show_with,
+            output_format: if json {
+                ToolListFormat::Json
+            } else {
+                output_format
+            },
         }
     }
 }
@@ -850,6 +889,7 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_platforms: bool,
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
+    pub(crate) outdated: bool,
     pub(crate) show_urls: bool,
     pub(crate) output_format: PythonListFormat,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -866,6 +906,7 @@ impl PythonListSettings {
             all_arches,
             only_installed,
             only_downloads,
+            outdated,
             show_urls,
             output_format,
             python_downloads_json_url: python_downloads_json_url_arg,
@@ -880,7 +921,7 @@ impl PythonListSettings {
         let python_downloads_json_url =
             python_downloads_json_url_arg.or(python_downloads_json_url_option);
 
-        let kinds = if only_installed {
+        let kinds = if only_installed || outdated {
             PythonListKinds::Installed
         } else if only_downloads {
             PythonListKinds::Downloads
@@ -894,6 +935,7 @@ impl PythonListSettings {
             all_platforms,
             all_arches,
             all_versions,
+            outdated,
             show_urls,
             output_format,
             python_downloads_json_url,
@@ -918,6 +960,38 @@ impl PythonDirSettings {
     }
 }
 
+/// The resolved settings to use for a `python manifest` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonManifestSettings {
+    pub(crate) directory: PathBuf,
+    pub(crate) base_url: Option<Url>,
+    pub(crate) upstream: Option<PathBuf>,
+    pub(crate) output: Option<PathBuf>,
+}
+
+impl PythonManifestSettings {
+    /// Resolve the [`PythonManifestSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: PythonManifestArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let PythonManifestArgs {
+            directory,
+            base_url,
+            upstream,
+            output,
+        } = args;
+
+        Self {
+            directory,
+            base_url,
+            upstream,
+            output,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python install` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -926,10 +1000,23 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) targets: Vec<String>,
     pub(crate) reinstall: bool,
     pub(crate) force: bool,
-    pub(crate) python_install_mirror: Option<String>,
-    pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) install_mirrors: InstallMirrors,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
+    pub(crate) allow_prereleases: bool,
+    pub(crate) arch: Option<Arch>,
+    pub(crate) platform: Option<TargetPlatform>,
+    pub(crate) build_flavor: Option<BuildFlavor>,
+    /// The directory to download archives to, if the distributions should only be downloaded.
+    pub(crate) download_dir: Option<PathBuf>,
+    pub(crate) compile_bytecode: bool,
+    pub(crate) keep: Option<bool>,
+    pub(crate) externally_managed: Option<bool>,
+    pub(crate) install_bin: Option<bool>,
+    pub(crate) windowed: bool,
+    pub(crate) modify_path: bool,
+    pub(crate) no_wait: bool,
+    pub(crate) output_format: PythonInstallFormat,
 }
 
 impl PythonInstallSettings {
@@ -937,16 +1024,17 @@ impl PythonInstallSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonInstallArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
+        let (install_mirrors, python_downloads_json_url, externally_managed) = match options {
             Some(options) => (
-                options.install_mirrors.python_install_mirror,
-                options.install_mirrors.pypy_install_mirror,
+                options.install_mirrors.mirrors(),
                 options.install_mirrors.python_downloads_json_url,
+                options.install_mirrors.python_externally_managed,
             ),
-            None => (None, None, None),
+            None => (PythonInstallMirrors::default().mirrors(), None, None),
         };
-        let python_mirror = args.mirror.or(python_mirror);
-        let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
+        let install_mirrors = install_mirrors
+            .with_implementation(ImplementationName::CPython, args.mirror)
+            .with_implementation(ImplementationName::PyPy, args.pypy_mirror);
         let python_downloads_json_url =
             args.python_downloads_json_url.or(python_downloads_json_url);
 
@@ -959,17 +1047,55 @@ impl PythonInstallSettings {
             pypy_mirror: _,
             python_downloads_json_url: _,
             default,
+            allow_prereleases,
+            arch,
+            platform,
+            build_flavor,
+            download_only,
+            out_dir,
+            compile_bytecode,
+            keep,
+            no_keep,
+            externally_managed: externally_managed_arg,
+            no_externally_managed,
+            bin,
+            no_bin,
+            windowed,
+            modify_path,
+            no_modify_path,
+            no_wait,
+            output_format,
         } = args;
 
+        let externally_managed = if no_externally_managed {
+            Some(false)
+        } else {
+            externally_managed_arg
+        }
+        .or(externally_managed);
+        let install_bin = if no_bin { Some(false) } else { bin };
+
         Self {
             install_dir,
             targets,
             reinstall,
             force,
-            python_install_mirror: python_mirror,
-            pypy_install_mirror: pypy_mirror,
+            install_mirrors,
             python_downloads_json_url,
             default,
+            allow_prereleases,
+            arch,
+            platform,
+            build_flavor,
+            download_dir: out_dir.filter(|_| download_only),
+            compile_bytecode,
+            keep: flag(keep, no_keep),
+            externally_managed,
+            install_bin,
+            windowed,
+            modify_path: flag(modify_path, no_modify_path).unwrap_or(false),
+            no_wait,
+            output_format,
         }
     }
 }
@@ -981,6 +1107,9 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) yes: bool,
+    pub(crate) force: bool,
+    pub(crate) no_wait: bool,
 }
 
 impl PythonUninstallSettings {
@@ -994,12 +1123,18 @@ impl PythonUninstallSettings {
             install_dir,
             targets,
             all,
+            yes,
+            force,
+            no_wait,
         } = args;
 
         Self {
             install_dir,
             targets,
             all,
+            yes,
+            force,
+            no_wait,
         }
     }
 }
@@ -1010,6 +1145,7 @@ impl PythonUninstallSettings {
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
+    pub(crate) show_source: bool,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
 }
@@ -1021,6 +1157,7 @@ impl PythonFindSettings {
         let PythonFindArgs {
             request,
             show_version,
+            show_source,
             no_project,
             system,
             no_system,
@@ -1030,6 +1167,7 @@ impl PythonFindSettings {
         Self {
             request,
             show_version,
+            show_source,
             no_project,
             system: flag(system, no_system).unwrap_or_default(),
         }
@@ -1042,6 +1180,9 @@ impl PythonFindSettings {
 pub(crate) struct PythonPinSettings {
     pub(crate) request: Option<String>,
     pub(crate) resolved: bool,
+    pub(crate) exact: bool,
+    pub(crate) from_downloads: bool,
+    pub(crate) rm: bool,
     pub(crate) no_project: bool,
     pub(crate) global: bool,
 }
@@ -1054,6 +1195,9 @@ impl PythonPinSettings {
             request,
             no_resolved,
             resolved,
+            exact,
+            from_downloads,
+            rm,
             no_project,
             global,
         } = args;
@@ -1061,6 +1205,9 @@ impl PythonPinSettings {
         Self {
             request,
             resolved: flag(resolved, no_resolved).unwrap_or(false),
+            exact,
+            from_downloads,
+            rm,
             no_project,
             global,
         }
@@ -1074,8 +1221,10 @@ pub(crate) struct SyncSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) verify_hashes: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) recreate_venv: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DependencyGroups,
     pub(crate) editable: EditableMode,
@@ -1083,6 +1232,7 @@ pub(crate) struct SyncSettings {
     pub(crate) modifications: Modifications,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) exclude_package: Option<Vec<PackageName>>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1112,10 +1262,13 @@ impl SyncSettings {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            exclude_package,
+            no_exclude_package,
             locked,
             frozen,
             active,
             no_active,
+            recreate_venv,
             dry_run,
             installer,
             build,
@@ -1126,6 +1279,7 @@ impl SyncSettings {
             python,
             check,
             no_check,
+            verify_hashes,
         } = args;
         let install_mirrors = filesystem
             .clone()
@@ -1148,8 +1302,10 @@ impl SyncSettings {
             locked,
             frozen,
             dry_run,
+            verify_hashes,
             script,
             active: flag(active, no_active),
+            recreate_venv,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1182,6 +1338,7 @@ impl SyncSettings {
             },
             all_packages,
             package,
+            exclude_package: excluded_packages(exclude_package, no_exclude_package),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings,
@@ -1198,6 +1355,9 @@ pub(crate) struct LockSettings {
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) exclude_package: Option<Vec<PackageName>>,
+    /// The target platform, if provided explicitly; `Some(None)` locks for all platforms.
+    pub(crate) python_platform: Option<Option<TargetTriple>>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1213,6 +1373,10 @@ impl LockSettings {
             check_exists,
             dry_run,
             script,
+            exclude_package,
+            no_exclude_package,
+            python_platform,
+            no_python_platform,
             resolver,
             build,
             refresh,
@@ -1229,6 +1393,12 @@ impl LockSettings {
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
             script,
+            exclude_package: excluded_packages(exclude_package, no_exclude_package),
+            python_platform: if no_python_platform {
+                Some(None)
+            } else {
+                python_platform.map(Some)
+            },
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1400,6 +1570,8 @@ pub(crate) struct RemoveSettings {
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    pub(crate) all: bool,
+    pub(crate) yes: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
@@ -1417,6 +1589,8 @@ impl RemoveSettings {
             dev,
             optional,
             packages,
+            all,
+            yes,
             group,
             no_sync,
             locked,
@@ -1457,6 +1631,8 @@ impl RemoveSettings {
             active: flag(active, no_active),
             no_sync,
             packages,
+            all,
+            yes,
             dependency_type,
             package,
             script,
@@ -1482,6 +1658,9 @@ pub(crate) struct TreeSettings {
     pub(crate) depth: u8,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
+    pub(crate) why: Vec<PackageName>,
+    pub(crate) json: bool,
+    pub(crate) cycles: bool,
     pub(crate) no_dedupe: bool,
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
@@ -1499,6 +1678,9 @@ impl TreeSettings {
     pub(crate) fn resolve(args: TreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let TreeArgs {
             tree,
+            why,
+            json,
+            cycles,
             universal,
             dev,
             only_dev,
@@ -1539,6 +1721,9 @@ impl TreeSettings {
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
+            why,
+            json,
+            cycles,
             no_dedupe: tree.no_dedupe,
             invert: tree.invert,
             outdated: tree.outdated,
@@ -1711,7 +1896,7 @@ impl PipCompileSettings {
             header,
             annotation_style,
             custom_compile_command,
-            resolver,
+            mut resolver,
             python,
             system,
             no_system,
@@ -1721,6 +1906,7 @@ impl PipCompileSettings {
             build,
             no_binary,
             only_binary,
+            build_option,
             python_version,
             python_platform,
             universal,
@@ -1788,6 +1974,14 @@ impl PipCompileSettings {
             SupportedEnvironments::default()
         };
 
+        // Build options are passed to the build backend alongside any `--config-setting` values.
+        if !build_option.is_empty() {
+            resolver
+                .config_setting
+                .get_or_insert_with(Vec::new)
+                .extend(build_option);
+        }
+
         Self {
             format,
             src_file,
@@ -1943,10 +2137,14 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) user: bool,
+    pub(crate) isolated: bool,
+    pub(crate) test_command: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1980,6 +2178,9 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            user,
+            isolated,
+            test_command,
             no_build,
             build,
             no_binary,
@@ -1991,6 +2192,8 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
+            log: _,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2054,6 +2257,7 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -2062,6 +2266,9 @@ impl PipInstallSettings {
             } else {
                 Modifications::Sufficient
             },
+            user,
+            isolated,
+            test_command,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2098,6 +2305,7 @@ pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) break_on_not_installed: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2116,6 +2324,7 @@ impl PipUninstallSettings {
             target,
             prefix,
             dry_run,
+            break_on_not_installed,
             compat_args: _,
         } = args;
 
@@ -2123,6 +2332,7 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            break_on_not_installed,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2186,6 +2396,7 @@ pub(crate) struct PipListSettings {
     pub(crate) exclude: Vec<PackageName>,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
+    pub(crate) not_required: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2197,8 +2408,10 @@ impl PipListSettings {
             exclude_editable,
             exclude,
             format,
+            json,
             outdated,
             no_outdated,
+            not_required,
             strict,
             no_strict,
             fetch,
@@ -2211,8 +2424,9 @@ impl PipListSettings {
         Self {
             editable: flag(editable, exclude_editable),
             exclude,
-            format,
+            format: if json { ListFormat::Json } else { format },
             outdated: flag(outdated, no_outdated).unwrap_or(false),
+            not_required,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2425,10 +2639,13 @@ impl BuildSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct VenvSettings {
-    pub(crate) seed: bool,
+    pub(crate) seed: Option<Vec<Requirement>>,
     pub(crate) allow_existing: bool,
+    pub(crate) relink: bool,
+    pub(crate) check: bool,
     pub(crate) path: Option<PathBuf>,
     pub(crate) prompt: Option<String>,
+    pub(crate) shell: Option<Shell>,
     pub(crate) system_site_packages: bool,
     pub(crate) relocatable: bool,
     pub(crate) no_project: bool,
@@ -2445,8 +2662,11 @@ impl VenvSettings {
             no_system,
             seed,
             allow_existing,
+            relink,
+            check,
             path,
             prompt,
+            shell,
             system_site_packages,
             relocatable,
             index_args,
@@ -2459,11 +2679,29 @@ impl VenvSettings {
             compat_args: _,
         } = args;
 
+        // An empty list of seed packages indicates the defaults.
+        let seed = seed.and_then(|packages| {
+            let mut enabled = true;
+            let mut requirements = Vec::new();
+            for package in packages {
+                match package {
+                    SeedPackage::Flag(flag) => enabled = flag,
+                    SeedPackage::Requirement(requirement) => {
+                        requirements.push(Requirement::from(requirement));
+                    }
+                }
+            }
+            enabled.then_some(requirements)
+        });
+
         Self {
             seed,
             allow_existing,
+            relink,
+            check,
             path,
             prompt,
+            shell,
             system_site_packages,
             no_project,
             relocatable,
@@ -3074,6 +3312,8 @@ pub(crate) struct PublishSettings {
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) index: Option<String>,
+    pub(crate) check: bool,
+    pub(crate) attestation: Option<bool>,
 
     // Both CLI and configuration.
     pub(crate) publish_url: Url,
@@ -3131,6 +3371,8 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
+            check: args.check,
+            attestation: flag(args.attestation, args.no_attestation),
             index_locations: IndexLocations::new(
                 index
                     .into_iter()
@@ -3164,6 +3406,22 @@ mod env {
     );
 }
 
+/// Resolve the packages to exclude from the resolution, if provided explicitly.
+///
+/// Returns `None` if the exclusions in the existing lockfile should be retained.
+fn excluded_packages(
+    exclude_package: Vec<PackageName>,
+    no_exclude_package: bool,
+) -> Option<Vec<PackageName>> {
+    if no_exclude_package {
+        Some(vec![])
+    } else if exclude_package.is_empty() {
+        None
+    } else {
+        Some(exclude_package)
+    }
+}
+
 /// Attempt to load and parse an environment variable with the given name.
 ///
 /// Exits the program and prints an error message containing the expected type if
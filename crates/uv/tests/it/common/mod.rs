@@ -25,7 +25,8 @@ use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_python::managed::ManagedPythonInstallations;
 use uv_python::{
-    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, PythonVersion,
+    EnvironmentPreference, FindOptions, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVersion,
 };
 use uv_static::EnvVars;
 
@@ -585,7 +586,7 @@ impl TestContext {
 
         // Make virtual environment activation cross-platform and shell-agnostic
         filters.push((
-            r"Activate with: (.*)\\Scripts\\activate".to_string(),
+            r"Activate with: (.*)\\Scripts\\activate(?:\.\w+)?".to_string(),
             "Activate with: source $1/[BIN]/activate".to_string(),
         ));
         filters.push((
@@ -775,6 +776,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip hash` command with options shared across scenarios.
+    pub fn pip_hash(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("hash");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     pub fn pip_list(&self) -> Command {
         let mut command = self.new_command();
         command.arg("pip").arg("list");
@@ -948,6 +957,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python manifest` command with options shared across scenarios.
+    pub fn python_manifest(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("python").arg("manifest");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `uv run` command with options shared across scenarios.
     pub fn run(&self) -> Command {
         let mut command = self.new_command();
@@ -1396,8 +1413,9 @@ pub fn python_installations_for_versions(
         .map(|python_version| {
             if let Ok(python) = PythonInstallation::find(
                 &PythonRequest::parse(python_version),
-                EnvironmentPreference::OnlySystem,
-                PythonPreference::Managed,
+                FindOptions::default()
+                    .environments(EnvironmentPreference::OnlySystem)
+                    .preference(PythonPreference::Managed),
                 &cache,
             ) {
                 python.into_interpreter().sys_executable().to_owned()
@@ -1435,6 +1435,102 @@ fn add_remove_inline_optional() -> Result<()> {
     Ok(())
 }
 
+/// Add dependencies to multiple optional extras.
+#[test]
+fn add_optional_multiple_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio==3.7.0").arg("--optional=io"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    // Add to a second extra, creating it.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--optional=test"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Add to the first extra again, retaining the version specifier.
+    uv_snapshot!(context.filters(), context.add().arg("sniffio>=1.3").arg("--optional=io"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Audited 3 packages in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        io = [
+            "anyio==3.7.0",
+            "sniffio>=1.3",
+        ]
+        test = [
+            "iniconfig>=2.0.0",
+        ]
+        "###
+        );
+    });
+
+    // Each extra is recorded in the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"provides-extras = ["io", "test"]"#));
+
+    // An invalid extra name is rejected.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--optional=-test"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '-test' for '--optional <OPTIONAL>': Not a valid package or extra name: "-test". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters.
+
+    For more information, try '--help'.
+    "#);
+
+    Ok(())
+}
+
 /// Add and remove a workspace dependency.
 #[test]
 fn add_remove_workspace() -> Result<()> {
@@ -4539,6 +4635,47 @@ fn add_requirements_file() -> Result<()> {
     Ok(())
 }
 
+/// Add a platform-specific package with a marker flag.
+#[test]
+fn add_marker_flag() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // The marker doesn't apply to the current platform, so nothing is installed.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--marker").arg("sys_platform == 'fantasy_os'"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+    assert_snapshot!(
+        pyproject_toml, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "iniconfig>=2.0.0 ; sys_platform == 'fantasy_os'",
+    ]
+    "#
+    );
+
+    Ok(())
+}
+
 /// Add requirements from a file with a marker flag.
 ///
 /// We test that:
@@ -5367,6 +5504,109 @@ fn add_group_to_unsorted() -> Result<()> {
     Ok(())
 }
 
+/// Remove all dependencies with `--all`.
+#[test]
+fn remove_all() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+
+        [dependency-groups]
+        test = [
+            "typing-extensions",
+        ]
+    "#})?;
+
+    // Without a terminal, removing all dependencies requires `--yes`.
+    uv_snapshot!(context.filters(), context.remove().arg("--all"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Removing all dependencies from `project.dependencies` requires confirmation; use `--yes` to proceed
+    ");
+
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--yes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = [
+            "typing-extensions",
+        ]
+        "#
+        );
+    });
+
+    // Remove all dependencies from a group.
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--group").arg("test").arg("--yes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = []
+        "#
+        );
+    });
+
+    // There's nothing left to remove.
+    uv_snapshot!(context.filters(), context.remove().arg("--all").arg("--yes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No dependencies found in `project.dependencies`
+    ");
+
+    Ok(())
+}
+
 /// Remomve a requirement from a dependency group.
 #[test]
 fn remove_group() -> Result<()> {
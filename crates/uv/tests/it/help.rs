@@ -31,6 +31,8 @@ fn help() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Read or update settings in a `uv.toml` file
+      index                      Manage the package indexes in a configuration file
       self                       Manage the uv executable
       version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
@@ -111,6 +113,8 @@ fn help_flag() {
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Read or update settings in a `uv.toml` file
+      index    Manage the package indexes in a configuration file
       self     Manage the uv executable
       version  Read or update the project's version
       help     Display documentation for a command
@@ -189,6 +193,8 @@ fn help_short_flag() {
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Read or update settings in a `uv.toml` file
+      index    Manage the package indexes in a configuration file
       self     Manage the uv executable
       version  Read or update the project's version
       help     Display documentation for a command
@@ -554,6 +560,10 @@ fn help_subsubcommand() {
               
               If multiple Python versions are requested, uv will exit with an error.
 
+          --no-wait
+              Fail immediately if another uv process holds the lock on the Python installation
+              directory, instead of waiting for it to be released
+
     Cache options:
       -n, --no-cache
               Avoid reading from or writing to the cache, instead using a temporary directory for the
@@ -799,6 +809,9 @@ fn help_flag_subsubcommand() {
               Replace existing Python executables during installation
           --default
               Use as the default Python version
+          --no-wait
+              Fail immediately if another uv process holds the lock on the Python installation
+              directory, instead of waiting for it to be released
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -949,6 +962,8 @@ fn help_with_global_option() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Read or update settings in a `uv.toml` file
+      index                      Manage the package indexes in a configuration file
       self                       Manage the uv executable
       version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
@@ -1070,6 +1085,8 @@ fn help_with_no_pager() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Read or update settings in a `uv.toml` file
+      index                      Manage the package indexes in a configuration file
       self                       Manage the uv executable
       version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
@@ -0,0 +1,253 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Add, list, and remove indexes in a `uv.toml` file, creating it if necessary.
+#[test]
+fn index_uv_toml() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "list"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No indexes found in `uv.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "add", "pytorch", "https://download.pytorch.org/whl/cpu"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `pytorch` to `uv.toml`
+    "###);
+
+    // A subsequent index takes precedence over the existing index.
+    uv_snapshot!(context.filters(), context.command().args(["index", "add", "internal", "https://example.com/simple", "--default"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `internal` to `uv.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "list"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    internal https://example.com/simple (default)
+    pytorch https://download.pytorch.org/whl/cpu
+
+    ----- stderr -----
+    "###);
+
+    assert_snapshot!(context.read("uv.toml"), @r###"
+    [[index]]
+    name = "internal"
+    url = "https://example.com/simple"
+    default = true
+
+    [[index]]
+    name = "pytorch"
+    url = "https://download.pytorch.org/whl/cpu"
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "remove", "pytorch"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed index `pytorch` from `uv.toml`
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "remove", "pytorch"]), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No index named `pytorch` found in `uv.toml`
+    "###);
+
+    assert_snapshot!(context.read("uv.toml"), @r###"
+    [[index]]
+    name = "internal"
+    url = "https://example.com/simple"
+    default = true
+    "###);
+
+    Ok(())
+}
+
+/// Add an index to `tool.uv.index` in a `pyproject.toml` file, preserving existing comments.
+#[test]
+fn index_pyproject_toml() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = []
+
+        [tool.uv]
+        # Always compile bytecode.
+        compile-bytecode = true
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "add", "pytorch", "https://download.pytorch.org/whl/cpu"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `pytorch` to `pyproject.toml`
+    "###);
+
+    // Adding an index with the same name replaces it.
+    uv_snapshot!(context.filters(), context.command().args(["index", "add", "pytorch", "https://download.pytorch.org/whl/cu121"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Added index `pytorch` to `pyproject.toml`
+    "###);
+
+    assert_snapshot!(context.read("pyproject.toml"), @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    dependencies = []
+
+    [tool.uv]
+    # Always compile bytecode.
+    compile-bytecode = true
+
+    [[tool.uv.index]]
+    name = "pytorch"
+    url = "https://download.pytorch.org/whl/cu121"
+    "###);
+
+    uv_snapshot!(context.filters(), context.command().args(["index", "list"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytorch https://download.pytorch.org/whl/cu121
+
+    ----- stderr -----
+    "###);
+
+    // Invalid index names are rejected.
+    uv_snapshot!(context.filters(), context.command().args(["index", "add", "py torch", "https://download.pytorch.org/whl/cpu"]), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'py torch' for '<NAME>': Index names may only contain letters, digits, hyphens, underscores, and periods, but found unsupported character (` `) in: `py torch`
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Store and remove credentials for an index with the `keyring` command.
+#[test]
+#[cfg(unix)]
+fn index_auth() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new_with_versions(&[]);
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [[index]]
+        name = "internal"
+        url = "https://example.com/simple"
+    "#})?;
+
+    // Create a `keyring` executable that records its arguments and input.
+    let log = context.temp_dir.child("keyring.log");
+    let bin = context.temp_dir.child("bin");
+    bin.create_dir_all()?;
+    let keyring = bin.child("keyring");
+    keyring.write_str(&formatdoc! {r#"
+        #!/bin/sh
+        echo "keyring $*" >> "{log}"
+        if [ "$1" = "set" ]; then
+            read -r password
+            echo "password: $password" >> "{log}"
+        fi
+        "#,
+        log = log.display(),
+    })?;
+    fs_err::set_permissions(&keyring, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.command()
+        .args(["index", "auth", "internal", "--token", "secret"])
+        .env(EnvVars::PATH, bin.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for index `internal` in the keyring
+    hint: uv only reads credentials from the keyring when `keyring-provider = "subprocess"` is set, or `--keyring-provider subprocess` is provided
+    "###);
+
+    assert_snapshot!(context.read("uv.toml"), @r###"
+    [[index]]
+    name = "internal"
+    url = "https://example.com/simple"
+    authenticate = "always"
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .args(["index", "auth", "--delete", "internal"])
+        .env(EnvVars::PATH, bin.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for index `internal` from the keyring
+    "###);
+
+    assert_snapshot!(context.read("uv.toml"), @r###"
+    [[index]]
+    name = "internal"
+    url = "https://example.com/simple"
+    "###);
+
+    assert_snapshot!(context.read("keyring.log"), @r###"
+    keyring set https://example.com/simple __token__
+    password: secret
+    keyring del https://example.com/simple __token__
+    "###);
+
+    uv_snapshot!(context.filters(), context.command()
+        .args(["index", "auth", "pytorch", "--token", "secret"])
+        .env(EnvVars::PATH, bin.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No index named `pytorch` found in `uv.toml`
+    "###);
+
+    Ok(())
+}
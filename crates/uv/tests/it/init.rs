@@ -1071,6 +1071,196 @@ fn init_no_pin_python() {
     });
 }
 
+/// Run `uv init` with each `--pin` policy.
+#[test]
+fn init_pin() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `minor` records the minor version of the discovered interpreter.
+    uv_snapshot!(context.filters(), context.init().arg("minor").arg("--pin").arg("minor"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `minor` at `[TEMP_DIR]/minor`
+    "###);
+
+    let python_version = context.read("minor/.python-version");
+    assert_snapshot!(python_version, @r###"
+    3.12
+    "###);
+
+    // `patch` records the exact version of the discovered interpreter.
+    uv_snapshot!(context.filters(), context.init().arg("patch").arg("--pin").arg("patch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `patch` at `[TEMP_DIR]/patch`
+    "###);
+
+    let python_version = context.read("patch/.python-version");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @r###"
+        3.12.[X]
+        "###);
+    });
+
+    // `none` skips the `.python-version` file, but still records `requires-python`.
+    uv_snapshot!(context.filters(), context.init().arg("none").arg("--pin").arg("none"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `none` at `[TEMP_DIR]/none`
+    "###);
+
+    assert!(!context.temp_dir.child("none/.python-version").exists());
+
+    let pyproject = context.read("none/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "none"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Run `uv init --pin` in a directory where a parent directory already has a pin.
+#[test]
+fn init_pin_parent_pin() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.11")?;
+
+    // `minor` records the version requested by the parent pin.
+    uv_snapshot!(context.filters(), context.init().arg("minor").arg("--pin").arg("minor"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `minor` at `[TEMP_DIR]/minor`
+    "###);
+
+    let python_version = context.read("minor/.python-version");
+    assert_snapshot!(python_version, @r###"
+    3.11
+    "###);
+
+    // `patch` resolves the parent pin to the exact version of the matching interpreter.
+    uv_snapshot!(context.filters(), context.init().arg("patch").arg("--pin").arg("patch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `patch` at `[TEMP_DIR]/patch`
+    "###);
+
+    let python_version = context.read("patch/.python-version");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @r###"
+        3.11.[X]
+        "###);
+    });
+
+    // `none` relies on the parent pin, but still records `requires-python`.
+    uv_snapshot!(context.filters(), context.init().arg("none").arg("--pin").arg("none"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `none` at `[TEMP_DIR]/none`
+    "###);
+
+    assert!(!context.temp_dir.child("none/.python-version").exists());
+
+    let pyproject = context.read("none/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "none"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.11"
+        dependencies = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Run `uv init` with the pin policy provided in a `uv.toml`.
+#[test]
+fn init_pin_configuration() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(r#"python-pin = "patch""#)?;
+
+    uv_snapshot!(context.filters(), context.init().arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo` at `[TEMP_DIR]/foo`
+    "###);
+
+    let python_version = context.read("foo/.python-version");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @r###"
+        3.12.[X]
+        "###);
+    });
+
+    // The command line takes precedence.
+    uv_snapshot!(context.filters(), context.init().arg("bar").arg("--no-pin-python"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `bar` at `[TEMP_DIR]/bar`
+    "###);
+
+    assert!(!context.temp_dir.child("bar/.python-version").exists());
+
+    Ok(())
+}
+
 #[test]
 fn init_library_current_dir() -> Result<()> {
     let context = TestContext::new("3.12");
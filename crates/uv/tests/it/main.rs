@@ -17,6 +17,8 @@ mod cache_clean;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+mod config;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -28,6 +30,8 @@ mod export;
 
 mod help;
 
+mod index;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
@@ -51,6 +55,8 @@ mod pip_compile_scenarios;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_freeze;
 
+mod pip_hash;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_install;
 
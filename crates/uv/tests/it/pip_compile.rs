@@ -17492,3 +17492,61 @@ fn pubgrub_panic_double_self_dependency_extra() -> Result<()> {
 
     Ok(())
 }
+
+/// Build options should be passed to the build backend when extracting metadata.
+#[test]
+fn compile_build_option() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A minimal in-tree build backend that reads its dependencies from the config settings.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dynamic = ["dependencies"]
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project.child("backend.py").write_str(indoc! {r#"
+        import os
+
+
+        def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+            dependency = (config_settings or {}).get("dependency")
+            metadata = "Metadata-Version: 2.1\nName: project\nVersion: 0.1.0\n"
+            if dependency:
+                metadata += f"Requires-Dist: {dependency}\n"
+            dist_info = "project-0.1.0.dist-info"
+            os.makedirs(os.path.join(metadata_directory, dist_info))
+            with open(os.path.join(metadata_directory, dist_info, "METADATA"), "w") as f:
+                f.write(metadata)
+            return dist_info
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./project")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--build-option")
+        .arg("dependency=iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --build-option dependency=iniconfig
+    iniconfig==2.0.0
+        # via project
+    ./project
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
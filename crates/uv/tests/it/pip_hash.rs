@@ -0,0 +1,70 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+#[test]
+fn hash_local_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let archive = context.temp_dir.child("example-1.0.0.tar.gz");
+    archive.write_str("hello world\n")?;
+
+    uv_snapshot!(context.pip_hash().arg("example-1.0.0.tar.gz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    example-1.0.0.tar.gz:
+    --hash=sha256:a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.pip_hash().arg("example-1.0.0.tar.gz").arg("--algorithm").arg("sha384"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    example-1.0.0.tar.gz:
+    --hash=sha384:6b3b69ff0a404f28d75e98a066d3fc64fffd9940870cc68bece28545b9a75086b343d7a1366838083e4b8f3ca6fd3c80
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn hash_missing_file() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_hash().arg("missing.whl"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: failed to open file `missing.whl`: No such file or directory (os error 2)
+    "
+    );
+}
+
+#[test]
+#[cfg(feature = "pypi")]
+fn hash_remote_wheel() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_hash().arg("https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl:
+    --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+
+    ----- stderr -----
+    "
+    );
+}
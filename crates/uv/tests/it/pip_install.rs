@@ -8,6 +8,7 @@ use flate2::write::GzEncoder;
 use fs_err as fs;
 use fs_err::File;
 use indoc::indoc;
+use insta::assert_snapshot;
 use predicates::prelude::predicate;
 use url::Url;
 use wiremock::{
@@ -18,8 +19,8 @@ use wiremock::{
 #[cfg(feature = "git")]
 use crate::common::{self, decode_token};
 use crate::common::{
-    build_vendor_links_url, download_to_disk, get_bin, uv_snapshot, venv_bin_path,
-    venv_to_interpreter, TestContext,
+    build_vendor_links_url, download_to_disk, get_bin, site_packages_path, uv_snapshot,
+    venv_bin_path, venv_to_interpreter, TestContext,
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
@@ -288,6 +289,78 @@ fn invalid_uv_toml_option_disallowed() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn invalid_uv_toml_option_unknown_key() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r"
+        cache_dir = '/tmp/uv'
+    "})?;
+
+    let mut filters = context.filters();
+    filters.push((
+        "expected one of `required-version`, `native-tls`, .*",
+        "expected one of `required-version`, `native-tls`, [...]",
+    ));
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`. Unknown configuration key: `cache_dir` (did you mean `cache-dir`?)
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | cache_dir = '/tmp/uv'
+      | ^^^^^^^^^
+    unknown field `cache_dir`, expected one of `required-version`, `native-tls`, [...]
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_uv_toml_option_unknown_keys() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r"
+        cache_dir = '/tmp/uv'
+        offlien = true
+        unrelated = true
+
+        [pip]
+        index_url = 'https://test.pypi.org/simple'
+    "})?;
+
+    let mut filters = context.filters();
+    filters.push((
+        "expected one of `required-version`, `native-tls`, .*",
+        "expected one of `required-version`, `native-tls`, [...]",
+    ));
+
+    // Every unknown key is reported, including those in nested tables
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`. Unknown configuration keys: `cache_dir` (did you mean `cache-dir`?), `offlien` (did you mean `offline`?), `unrelated`, `pip.index_url` (did you mean `pip.index-url`?)
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | cache_dir = '/tmp/uv'
+      | ^^^^^^^^^
+    unknown field `cache_dir`, expected one of `required-version`, `native-tls`, [...]
+    "###
+    );
+
+    Ok(())
+}
+
 /// For indirect, non-user controlled pyproject.toml, we don't enforce correctness.
 ///
 /// If we fail to extract the PEP 621 metadata, we fall back to treating it as a source
@@ -472,6 +545,105 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Write a report of the installed packages with `--report`.
+#[test]
+fn install_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--report")
+        .arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    "###
+    );
+
+    let report = fs_err::read_to_string(context.temp_dir.child("report.json"))?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(report, @r###"
+        {
+          "version": "1",
+          "install": [
+            {
+              "name": "iniconfig",
+              "version": "2.0.0",
+              "download_info": {
+                "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+                "archive_info": {
+                  "hash": "sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+                  "hashes": {
+                    "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                  }
+                }
+              },
+              "is_direct": false,
+              "requested": false
+            },
+            {
+              "name": "project",
+              "version": "0.1.0",
+              "download_info": {
+                "url": "file://[TEMP_DIR]/project",
+                "dir_info": {}
+              },
+              "is_direct": true,
+              "requested": true
+            }
+          ]
+        }
+        "###);
+    });
+
+    // If the requirements are already satisfied, the report is empty.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--report")
+        .arg("report.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    let report = fs_err::read_to_string(context.temp_dir.child("report.json"))?;
+    assert_snapshot!(report, @r###"
+    {
+      "version": "1",
+      "install": []
+    }
+    "###);
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -1424,6 +1596,86 @@ fn install_editable_no_binary() {
     );
 }
 
+/// Install an editable package and verify that `direct_url.json` is written to the `.dist-info`
+/// directory, as required by PEP 610.
+#[test]
+fn install_editable_direct_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/black_editable")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + black==0.1.0 (from file://[WORKSPACE]/scripts/packages/black_editable)
+    "###
+    );
+
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("black-0.1.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            direct_url, @r###"{"url":"file://[WORKSPACE]/scripts/packages/black_editable","dir_info":{"editable":true}}"###
+        );
+    });
+
+    // The `direct_url.json` should round-trip as an editable local directory.
+    let direct_url: serde_json::Value = serde_json::from_str(&direct_url)?;
+    assert_eq!(
+        direct_url["dir_info"]["editable"],
+        serde_json::Value::Bool(true)
+    );
+    assert!(direct_url["url"]
+        .as_str()
+        .is_some_and(|url| url.starts_with("file://")));
+
+    // Reinstalling without `--editable` should drop the `editable` flag.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(context.workspace_root.join("scripts/packages/black_editable")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ black==0.1.0 (from file://[WORKSPACE]/scripts/packages/black_editable)
+    "###
+    );
+
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("black-0.1.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            direct_url, @r###"{"url":"file://[WORKSPACE]/scripts/packages/black_editable","dir_info":{}}"###
+        );
+    });
+
+    Ok(())
+}
+
 #[test]
 fn install_editable_compatible_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1832,6 +2084,32 @@ fn install_no_index_version() {
     context.assert_command("import flask").failure();
 }
 
+/// Install a package that isn't available in the `--find-links` sources with `--no-index`; the
+/// error should name the missing package.
+#[test]
+fn install_no_index_find_links_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("Flask")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links")), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because flask was not found in the provided package locations and you require flask, we can conclude that your requirements are unsatisfiable.
+
+          hint: Package `flask` not found in local sources and `--no-index` is set
+    "###
+    );
+
+    context.assert_command("import flask").failure();
+}
+
 /// Install a package via --extra-index-url.
 ///
 /// This is a regression test where previously uv would consult test.pypi.org
@@ -3088,6 +3366,68 @@ fn no_deps_editable() {
     context.assert_command("import aiohttp").failure();
 }
 
+/// Install an editable package with `--no-deps`, and verify that it's installed as an editable
+/// without touching its dependencies.
+#[test]
+fn no_deps_editable_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps")
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/setuptools_editable")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // The `direct_url.json` should mark the package as editable.
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("setuptools_editable-0.1.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            direct_url, @r###"{"url":"file://[WORKSPACE]/scripts/packages/setuptools_editable","dir_info":{"editable":true}}"###
+        );
+    });
+
+    // The editable `.pth` file should be present.
+    assert!(context
+        .site_packages()
+        .join("__editable__.setuptools_editable-0.1.0.pth")
+        .is_file());
+
+    // The dependency (`iniconfig`) should not be installed.
+    context
+        .assert_command("import setuptools_editable")
+        .success();
+    context.assert_command("import iniconfig").failure();
+
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    -e file://[WORKSPACE]/scripts/packages/setuptools_editable
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
 /// Avoid downgrading already-installed packages when `--upgrade` is provided.
 #[test]
 fn install_no_downgrade() -> Result<()> {
@@ -3841,6 +4181,106 @@ fn config_settings_registry() {
     );
 }
 
+/// Config settings should be passed through to the build backend's `build_wheel` hook.
+#[test]
+fn config_settings_backend() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A minimal in-tree build backend that records the config settings it receives.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project.child("backend.py").write_str(indoc! {r#"
+        import json
+        import os
+        import zipfile
+
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "project-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("project/__init__.py", "")
+                wheel.writestr(
+                    "project/config_settings.json",
+                    json.dumps(config_settings, sort_keys=True),
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.1\nName: project\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nGenerator: backend\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("project-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+
+    // Repeated keys should be collected into a list, in order.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--config-settings")
+        .arg("mode=release")
+        .arg("-C")
+        .arg("flag=a")
+        .arg("-C")
+        .arg("flag=b"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    "###
+    );
+
+    let config_settings = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project")
+            .join("config_settings.json"),
+    )?;
+    assert_snapshot!(config_settings, @r###"{"flag": ["a", "b"], "mode": "release"}"###);
+
+    // Without any config settings, the backend should receive an empty dictionary.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--reinstall"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/project)
+    "###
+    );
+
+    let config_settings = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project")
+            .join("config_settings.json"),
+    )?;
+    assert_snapshot!(config_settings, @"{}");
+
+    Ok(())
+}
+
 #[test]
 fn config_settings_path() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5912,6 +6352,8 @@ fn already_installed_dependent_editable() {
       × No solution found when resolving dependencies:
       ╰─▶ Because first-local was not found in the provided package locations and second-local==0.1.0 depends on first-local, we can conclude that second-local==0.1.0 cannot be used.
           And because only second-local==0.1.0 is available and you require second-local, we can conclude that your requirements are unsatisfiable.
+
+          hint: Package `first-local` not found in local sources and `--no-index` is set
     "###
     );
 
@@ -6031,6 +6473,8 @@ fn already_installed_local_path_dependent() {
       × No solution found when resolving dependencies:
       ╰─▶ Because first-local was not found in the provided package locations and second-local==0.1.0 depends on first-local, we can conclude that second-local==0.1.0 cannot be used.
           And because only second-local==0.1.0 is available and you require second-local, we can conclude that your requirements are unsatisfiable.
+
+          hint: Package `first-local` not found in local sources and `--no-index` is set
     "###
     );
 
@@ -8188,7 +8632,8 @@ fn install_incompatible_python_version_interpreter_broken_in_path() -> Result<()
 
     // Request Python 3.12, which should fail since the virtual environment does not have a matching
     // version.
-    // Since the broken interpreter is at the front of the PATH, this query error should be raised
+    // Since the virtual environment was found, but rejected, the broken interpreter at the front
+    // of the PATH is reported as a hint rather than raised
     uv_snapshot!(context.filters(), context.pip_install()
         .arg("-p").arg("3.12")
         .arg("anyio")
@@ -8199,11 +8644,9 @@ fn install_incompatible_python_version_interpreter_broken_in_path() -> Result<()
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to inspect Python interpreter from first executable in the search path at `[BIN]/python3`
-      Caused by: Querying Python at `[BIN]/python3` failed with exit status exit status: 1
+    error: No virtual environment found for Python 3.12; run `uv venv` to create an environment, or pass `--system` to install into a non-virtual environment
 
-    [stderr]
-    error: intentionally broken python executable
+    hint: 1 candidate interpreter failed to run; use `--verbose` to show where uv searched and why each candidate was rejected
     "###
     );
 
@@ -11349,3 +11792,302 @@ fn pep_751_dependency() -> Result<()> {
 
     Ok(())
 }
+
+/// Install into the user `site-packages` directory with `--user`.
+#[test]
+#[cfg(unix)]
+fn install_user() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let user_base = context.temp_dir.child("user");
+
+    // Installing from within a virtual environment should warn, since the user `site-packages`
+    // directory isn't visible to the virtual environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--user")
+        .arg("iniconfig==2.0.0")
+        .env(EnvVars::PYTHONUSERBASE, user_base.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    warning: Installing into the user `site-packages` directory from the virtual environment at [VENV]/; packages installed with `--user` are not visible to virtual environments
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // The package should be installed into the user base, rather than the virtual environment.
+    user_base
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages")
+        .child("iniconfig")
+        .assert(predicate::path::is_dir());
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Install into a `--target` directory, then upgrade the package in place.
+#[test]
+fn install_target() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==1.1.1")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "
+    );
+
+    // The package should be installed at the top-level of the target directory, rather than in a
+    // nested `site-packages` directory.
+    let target = context.temp_dir.child("target");
+    target.child("iniconfig").assert(predicate::path::is_dir());
+    target
+        .child("iniconfig-1.1.1.dist-info")
+        .assert(predicate::path::is_dir());
+
+    // Ensure that we can't import the package from the virtual environment...
+    context.assert_command("import iniconfig").failure();
+
+    // ...but that we can import the package by augmenting the `PYTHONPATH`.
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-B")
+        .arg("-c")
+        .arg("import iniconfig")
+        .env(EnvVars::PYTHONPATH, target.path())
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // Installing again should be a no-op, since the package is already present in the target.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Audited 1 package in [TIME]
+    "
+    );
+
+    // Upgrading should replace the existing version in the target directory.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--upgrade")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "
+    );
+
+    target
+        .child("iniconfig-1.1.1.dist-info")
+        .assert(predicate::path::missing());
+    target
+        .child("iniconfig-2.0.0.dist-info")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+/// Install into a `--prefix` directory, which should mirror the layout of the interpreter's scheme.
+#[test]
+fn install_prefix() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--prefix")
+        .arg("prefix"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // The package should be installed into the `site-packages` directory within the prefix.
+    let site_packages = site_packages_path(&context.temp_dir.join("prefix"), "python3.12");
+    assert!(site_packages.join("iniconfig").is_dir());
+
+    // Ensure that we can't import the package from the virtual environment...
+    context.assert_command("import iniconfig").failure();
+
+    // ...but that we can import the package by augmenting the `PYTHONPATH`.
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-B")
+        .arg("-c")
+        .arg("import iniconfig")
+        .env(EnvVars::PYTHONPATH, &site_packages)
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // Installing again should be a no-op, since the package is already present in the prefix.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--prefix")
+        .arg("prefix"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/python
+    Audited 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Write detailed logs to a file with `--log`, without changing the output on stderr.
+#[test]
+fn install_log() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--log")
+        .arg("install.log"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    let log = fs_err::read_to_string(context.temp_dir.child("install.log"))?;
+    assert!(log.contains(" DEBUG uv "), "{log}");
+    assert!(log.contains("iniconfig"), "{log}");
+    assert!(!log.contains('\x1b'), "{log}");
+
+    // Subsequent runs append to the log.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--log")
+        .arg("install.log")
+        .assert()
+        .success();
+
+    let appended = fs_err::read_to_string(context.temp_dir.child("install.log"))?;
+    assert!(appended.starts_with(&log));
+    assert!(appended.len() > log.len());
+
+    Ok(())
+}
+
+/// Install into a temporary virtual environment with `--isolated`, and exercise the installed
+/// packages with `--test-command`.
+#[test]
+fn install_isolated() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--isolated")
+        .arg("--test-command")
+        .arg("python -c \"import iniconfig\""), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // The package was not installed into the virtual environment.
+    context.assert_command("import iniconfig").failure();
+
+    // The exit status of the test command is propagated.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--isolated")
+        .arg("--test-command")
+        .arg("python -c \"raise SystemExit(3)\""), @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // `--test-command` requires `--isolated`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--test-command")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --isolated
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --test-command <COMMAND> --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirements <REQUIREMENTS>|--editable <EDITABLE>|--group <GROUP>>
+
+    For more information, try '--help'.
+    "
+    );
+}
@@ -170,6 +170,32 @@ fn list_outdated_json() -> Result<()> {
     "###
     );
 
+    // `--json` is shorthand for `--format json`.
+    uv_snapshot!(context.pip_list().arg("--outdated").arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"anyio","version":"3.0.0","latest_version":"4.3.0","latest_filetype":"wheel"}]
+
+    ----- stderr -----
+    "###
+    );
+
+    // `--json` conflicts with an explicit `--format`.
+    uv_snapshot!(context.pip_list().arg("--json").arg("--format").arg("columns"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--json' cannot be used with '--format <FORMAT>'
+
+    Usage: uv pip list --cache-dir [CACHE_DIR] --json --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    "
+    );
+
     Ok(())
 }
 
@@ -474,6 +500,63 @@ fn list_exclude() {
     );
 }
 
+#[test]
+fn list_not_required() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.0.0\nMarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.0.0
+     + idna==3.6
+     + markupsafe==2.1.3
+     + sniffio==1.3.1
+    "###
+    );
+
+    // `idna` and `sniffio` are required by `anyio`.
+    uv_snapshot!(context.pip_list().arg("--not-required"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package    Version
+    ---------- -------
+    anyio      3.0.0
+    markupsafe 2.1.3
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.pip_list()
+        .arg("--not-required")
+        .arg("--format")
+        .arg("freeze"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.0.0
+    markupsafe==2.1.3
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(windows))]
 fn list_format_json() {
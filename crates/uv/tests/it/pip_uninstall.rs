@@ -141,6 +141,72 @@ fn uninstall() -> Result<()> {
     Ok(())
 }
 
+/// With `--break-on-not-installed`, uninstalling a package that isn't installed should fail
+/// without uninstalling any of the other requested packages.
+#[test]
+fn uninstall_break_on_not_installed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Without the flag, the missing package is skipped.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping iniconfig as it is not installed
+    warning: No packages to uninstall
+    "###
+    );
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("MarkupSafe")
+        .arg("iniconfig")
+        .arg("anyio")
+        .arg("--break-on-not-installed"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following packages are not installed: anyio, iniconfig
+    "###
+    );
+
+    // MarkupSafe should still be installed.
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import markupsafe")
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("MarkupSafe")
+        .arg("--break-on-not-installed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn missing_record() -> Result<()> {
     let context = TestContext::new("3.12");
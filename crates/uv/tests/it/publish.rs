@@ -1,6 +1,7 @@
 use crate::common::{uv_snapshot, venv_bin_path, TestContext};
+use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
-use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
+use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild, PathCreateDir};
 use indoc::indoc;
 use std::env;
 use std::env::current_dir;
@@ -386,3 +387,94 @@ fn invalid_index() {
     "###
     );
 }
+
+/// Check that `--check` validates the files without uploading them.
+#[test]
+fn check_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    let links = context.workspace_root.join("scripts/links");
+    for filename in [
+        "tqdm-999.0.0.tar.gz",
+        "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl",
+    ] {
+        fs_err::copy(links.join(filename), dist.join(filename))?;
+    }
+    // The metadata inside the archive doesn't match the filename.
+    fs_err::copy(
+        links.join("tqdm-999.0.0.tar.gz"),
+        dist.join("tqdm-1.0.0.tar.gz"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.publish().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checking 3 files
+    error: Failed to check tqdm-1.0.0.tar.gz
+      Caused by: Version in metadata does not match filename: `999.0.0` != `1.0.0`
+    Passed tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl
+    Passed tqdm-999.0.0.tar.gz
+    "###
+    );
+
+    fs_err::remove_file(dist.join("tqdm-1.0.0.tar.gz"))?;
+
+    uv_snapshot!(context.filters(), context.publish().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checking 2 files
+    Passed tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl
+    Passed tqdm-999.0.0.tar.gz
+    "###
+    );
+
+    Ok(())
+}
+
+/// Check that `--attestation` fails before uploading if `pypi-attestations` is missing.
+#[test]
+fn attestation_missing_tool() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-999.0.0.tar.gz"),
+        dist.join("tqdm-999.0.0.tar.gz"),
+    )?;
+    // Ensure that `pypi-attestations` can't be found.
+    let bin = context.temp_dir.child("bin");
+    bin.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--attestation")
+        .env(EnvVars::PATH, bin.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to generate attestation for `dist/tqdm-999.0.0.tar.gz`
+      Caused by: `pypi-attestations` was not found, install it with `uv tool install pypi-attestations` to generate attestations
+    "###
+    );
+
+    Ok(())
+}
@@ -675,6 +675,185 @@ fn python_find_unsupported_version() {
     "###);
 }
 
+/// When no interpreter is found, `--verbose` lists where uv searched and why each candidate was
+/// rejected.
+#[cfg(unix)]
+#[test]
+fn python_find_not_found_trace() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.11"]);
+
+    // Create a "broken" Python executable in the test context `bin`
+    let python = context.bin_dir.join("python3.12");
+    fs_err::write(&python, "#!/bin/sh\nexit 1")?;
+    let mut perms = fs_err::metadata(&python)?.permissions();
+    perms.set_mode(0o755);
+    fs_err::set_permissions(&python, perms)?;
+
+    // Put the broken interpreter _before_ the other interpreters in the PATH
+    let path = std::env::join_paths(
+        std::iter::once(context.bin_dir.to_path_buf())
+            .chain(std::env::split_paths(&context.python_path())),
+    )?;
+
+    let mut filters = context.filters();
+    filters.push(("(?m)^DEBUG .*\n", ""));
+
+    // By default, only the broken interpreter is mentioned
+    uv_snapshot!(filters, context.python_find()
+        .arg("3.12")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &path)
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.child("managed").as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in virtual environments, managed installations, or search path
+
+    hint: 1 candidate interpreter failed to run; use `--verbose` to show where uv searched and why each candidate was rejected
+    "###);
+
+    // With `--verbose`, every searched location and rejected candidate is listed
+    uv_snapshot!(filters, context.python_find()
+        .arg("3.12")
+        .arg("--verbose")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &path)
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.child("managed").as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    WARN Skipping broken interpreter from first executable in the search path: Querying Python at `[BIN]/python3.12` failed with exit status exit status: 1
+    error: No interpreter found for Python 3.12 in virtual environments, managed installations, or search path
+
+    Searched:
+    - managed installations in `managed` (no candidates)
+    - `[BIN]/` in the search path (1 candidate)
+    - `[PYTHON-3.11]` in the search path (1 candidate)
+
+    Rejected:
+    - `[BIN]/python3.12` from first executable in the search path: failed to run: Querying Python at `[BIN]/python3.12` failed with exit status exit status: 1
+    - `[PYTHON-3.11]` from search path: CPython 3.11.[X] does not satisfy the request
+    "###);
+
+    Ok(())
+}
+
+/// A broken interpreter earlier in the search path is skipped, rather than aborting discovery.
+#[cfg(unix)]
+#[test]
+fn python_find_skip_broken() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.11"]);
+
+    // Create a Python shim in the test context `bin` that can't be executed, since its
+    // interpreter doesn't exist
+    let python = context.bin_dir.join("python3");
+    fs_err::write(&python, "#!/does/not/exist/python\n")?;
+    let mut perms = fs_err::metadata(&python)?.permissions();
+    perms.set_mode(0o755);
+    fs_err::set_permissions(&python, perms)?;
+
+    // Put the broken shim _before_ the working interpreter in the PATH
+    let path = std::env::join_paths(
+        std::iter::once(context.bin_dir.to_path_buf())
+            .chain(std::env::split_paths(&context.python_path())),
+    )?;
+
+    let mut filters = context.filters();
+    filters.push(("(?m)^DEBUG .*\n", ""));
+
+    uv_snapshot!(filters, context.python_find()
+        .arg("--verbose")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    WARN Skipping broken interpreter from first executable in the search path: Failed to query Python interpreter at `[BIN]/python3`: No such file or directory (os error 2)
+    "###);
+
+    Ok(())
+}
+
+/// An interpreter that hangs is killed after `UV_PYTHON_QUERY_TIMEOUT`, and skipped.
+#[cfg(unix)]
+#[test]
+fn python_find_query_timeout() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.11"]);
+
+    // Create a Python executable in the test context `bin` that never responds
+    let python = context.bin_dir.join("python3");
+    fs_err::write(&python, "#!/bin/sh\nexec sleep 30\n")?;
+    let mut perms = fs_err::metadata(&python)?.permissions();
+    perms.set_mode(0o755);
+    fs_err::set_permissions(&python, perms)?;
+
+    // Put the hanging interpreter _before_ the working interpreter in the PATH
+    let path = std::env::join_paths(
+        std::iter::once(context.bin_dir.to_path_buf())
+            .chain(std::env::split_paths(&context.python_path())),
+    )?;
+
+    let mut filters = context.filters();
+    filters.push(("(?m)^DEBUG .*\n", ""));
+
+    uv_snapshot!(filters, context.python_find()
+        .arg("--verbose")
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &path)
+        .env(EnvVars::UV_PYTHON_QUERY_TIMEOUT, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    WARN Skipping broken interpreter from first executable in the search path: Querying Python at `[BIN]/python3` did not complete after 1s
+    "###);
+
+    // If the interpreter is requested explicitly, the timeout is an error
+    uv_snapshot!(filters, context.python_find()
+        .arg(&python)
+        .env(EnvVars::UV_TEST_PYTHON_PATH, &path)
+        .env(EnvVars::UV_PYTHON_QUERY_TIMEOUT, "1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to inspect Python interpreter from provided path at `[BIN]/python3`
+      Caused by: Querying Python at `[BIN]/python3` did not complete after 1s
+
+    hint: A `sitecustomize` module or `PYTHONSTARTUP` script that blocks (e.g., on a network mount or a prompt) is a common cause; the timeout can be increased with `UV_PYTHON_QUERY_TIMEOUT`, or disabled by setting it to `0`
+    "###);
+
+    Ok(())
+}
+
+/// Setting `UV_PYTHON_QUERY_TIMEOUT` to `0` disables the timeout.
+#[test]
+fn python_find_query_timeout_disabled() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11"]);
+
+    uv_snapshot!(context.filters(), context.python_find()
+        .env(EnvVars::UV_PYTHON_QUERY_TIMEOUT, "0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    "###);
+}
+
 #[test]
 fn python_find_venv_invalid() {
     let context: TestContext = TestContext::new("3.12")
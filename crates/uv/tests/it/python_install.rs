@@ -1,5 +1,7 @@
 use std::{env, path::Path, process::Command};
 
+#[cfg(windows)]
+use crate::common::apply_filters;
 use crate::common::{uv_snapshot, TestContext};
 use assert_fs::{
     assert::PathAssert,
@@ -92,6 +94,630 @@ fn python_install() {
     ");
 }
 
+#[test]
+fn python_install_already_installed() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM]
+    ");
+
+    // Requested versions that are already installed are reported alongside the new installations
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+     = cpython-3.13.3-[PLATFORM] (already installed)
+    ");
+
+    // When every requested version is present, each is listed
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested versions already installed
+     = cpython-3.12.10-[PLATFORM] (already installed)
+     = cpython-3.13.3-[PLATFORM] (already installed)
+    ");
+}
+
+#[test]
+fn python_install_compile_bytecode() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let mut filters = context.filters();
+    filters.push((
+        r"Bytecode compiled \d+ files",
+        "Bytecode compiled [COUNT] files",
+    ));
+
+    // Returns the `__pycache__` directory of the installation's standard library.
+    let stdlib_pycache = || -> anyhow::Result<std::path::PathBuf> {
+        let installation = fs_err::read_dir(context.temp_dir.child("managed"))?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .find(|path| path.is_dir() && !path.ends_with(".temp"))
+            .expect("an installation directory");
+        let stdlib = if cfg!(windows) {
+            installation.join("Lib")
+        } else {
+            installation.join("lib").join("python3.12")
+        };
+        Ok(stdlib.join("__pycache__"))
+    };
+
+    // By default, no bytecode is compiled.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+    assert!(!stdlib_pycache()?.join("os.cpython-312.pyc").exists());
+
+    // With `--compile-bytecode`, the standard library is compiled after installation.
+    uv_snapshot!(filters, context.python_install().arg("3.12").arg("--reinstall").arg("--compile-bytecode"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Bytecode compiled [COUNT] files in [TIME]
+    Installed Python 3.12.10 in [TIME]
+     ~ cpython-3.12.10-[PLATFORM]
+    ");
+    assert!(stdlib_pycache()?.join("os.cpython-312.pyc").exists());
+
+    // Existing installations are left as-is.
+    uv_snapshot!(filters, context.python_install().arg("3.12").arg("--compile-bytecode"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn python_install_keep() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install a version and mark it as kept
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--keep"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM]
+    ");
+
+    // The mark survives a reinstall
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--reinstall"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     ~ cpython-3.12.10-[PLATFORM]
+    ");
+
+    // The kept version is skipped when uninstalling, while its sibling is removed
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("--yes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Skipping cpython-3.12.10-[PLATFORM], which is marked as kept (use `--force` to uninstall it)
+    Uninstalled Python 3.13.3 in [TIME]
+     - cpython-3.13.3-[PLATFORM]
+    ");
+
+    // Requesting only kept versions leaves them in place
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12
+    Skipping cpython-3.12.10-[PLATFORM], which is marked as kept (use `--force` to uninstall it)
+    ");
+
+    // Unless forced
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12").arg("--force"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12
+    Uninstalled Python 3.12.10 in [TIME]
+     - cpython-3.12.10-[PLATFORM]
+    ");
+}
+
+#[test]
+fn python_install_no_keep() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--keep"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+
+    // The mark can be removed from an existing installation
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--no-keep"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // And it is not restored by a reinstall
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--reinstall"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     ~ cpython-3.12.10-[PLATFORM]
+    ");
+
+    // So the version is uninstalled without `--force`
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12
+    Uninstalled Python 3.12.10 in [TIME]
+     - cpython-3.12.10-[PLATFORM]
+    ");
+}
+
+/// Return the version of each managed installation, and whether it is marked as externally managed.
+fn externally_managed_installations(context: &TestContext) -> Vec<(String, bool)> {
+    let mut installations = fs_err::read_dir(context.temp_dir.join("managed"))
+        .unwrap()
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let version = name
+                .strip_prefix("cpython-")?
+                .split('-')
+                .next()?
+                .to_string();
+            let externally_managed = walkdir::WalkDir::new(entry.path())
+                .into_iter()
+                .filter_map(Result::ok)
+                .any(|entry| entry.file_name() == "EXTERNALLY-MANAGED");
+            Some((version, externally_managed))
+        })
+        .collect::<Vec<_>>();
+    installations.sort();
+    installations
+}
+
+#[test]
+fn python_install_externally_managed() -> anyhow::Result<()> {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install a version without the `EXTERNALLY-MANAGED` marker
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.9").arg("--no-externally-managed"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    ");
+    assert_eq!(
+        externally_managed_installations(&context),
+        vec![("3.12.9".to_string(), false)]
+    );
+
+    // Re-running the installation retains the state
+    context.python_install().arg("3.12.9").assert().success();
+    assert_eq!(
+        externally_managed_installations(&context),
+        vec![("3.12.9".to_string(), false)]
+    );
+
+    // An upgrade to a newer patch version inherits the state, while a new minor version is
+    // marked by default
+    context.python_install().arg("3.12.10").assert().success();
+    context.python_install().arg("3.13").assert().success();
+    assert_eq!(
+        externally_managed_installations(&context),
+        vec![
+            ("3.12.10".to_string(), false),
+            ("3.12.9".to_string(), false),
+            ("3.13.3".to_string(), true),
+        ]
+    );
+
+    // The state is visible in verbose output
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("--managed-python")
+        .arg("-v")
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout
+        .lines()
+        .any(|line| line.contains("cpython-3.12.10") && line.contains("(not externally managed)")));
+    assert!(stdout
+        .lines()
+        .any(|line| line.contains("cpython-3.13.3") && line.contains("(externally managed)")));
+
+    // The marker can be toggled on an existing installation
+    context
+        .python_install()
+        .arg("3.12.10")
+        .arg("--externally-managed")
+        .assert()
+        .success();
+    context
+        .python_install()
+        .arg("3.13")
+        .arg("--externally-managed=false")
+        .assert()
+        .success();
+    assert_eq!(
+        externally_managed_installations(&context),
+        vec![
+            ("3.12.10".to_string(), true),
+            ("3.12.9".to_string(), false),
+            ("3.13.3".to_string(), false),
+        ]
+    );
+
+    // Removing the marker is idempotent
+    context
+        .python_install()
+        .arg("3.13")
+        .arg("--externally-managed=false")
+        .assert()
+        .success();
+    assert_eq!(
+        externally_managed_installations(&context),
+        vec![
+            ("3.12.10".to_string(), true),
+            ("3.12.9".to_string(), false),
+            ("3.13.3".to_string(), false),
+        ]
+    );
+
+    // A reinstall retains the state
+    context
+        .python_install()
+        .arg("3.13")
+        .arg("--reinstall")
+        .assert()
+        .success();
+    assert_eq!(
+        externally_managed_installations(&context)[2],
+        ("3.13.3".to_string(), false)
+    );
+
+    Ok(())
+}
+
+/// Return the names of the Python executables in the test bin directory.
+fn bin_executables(context: &TestContext) -> Vec<String> {
+    let mut executables = fs_err::read_dir(&context.bin_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("python"))
+        .collect::<Vec<_>>();
+    executables.sort();
+    executables
+}
+
+#[test]
+fn python_install_no_bin() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install without linking the executables, even in preview mode
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("--no-bin").arg("3.12.9"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.9 in [TIME]
+     + cpython-3.12.9-[PLATFORM]
+    ");
+    assert!(bin_executables(&context).is_empty());
+
+    // An upgrade to a newer patch version respects the recorded choice
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.12.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+    assert!(bin_executables(&context).is_empty());
+
+    // As does a reinstall
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("--reinstall")
+        .arg("3.12.10")
+        .assert()
+        .success();
+    assert!(bin_executables(&context).is_empty());
+
+    // The choice can be made with an environment variable
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_INSTALL_BIN, "false")
+        .assert()
+        .success();
+    assert!(bin_executables(&context).is_empty());
+
+    // `--bin` links the executables, even outside of preview mode
+    context
+        .python_install()
+        .arg("--bin")
+        .arg("3.12.10")
+        .assert()
+        .success();
+    assert!(
+        bin_executables(&context).contains(&format!("python3.12{}", std::env::consts::EXE_SUFFIX))
+    );
+}
+
+#[test]
+fn python_install_windowed() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // The windowed executable is only linked on request
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("3.12")
+        .assert()
+        .success();
+    assert!(!bin_executables(&context)
+        .iter()
+        .any(|name| name.starts_with("pythonw")));
+
+    // On Unix, there's no windowed executable, so the flag is ignored
+    #[cfg(unix)]
+    {
+        uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("--windowed").arg("3.13"), @r"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        warning: The `--windowed` flag is only supported on Windows; ignoring
+        Installed Python 3.13.3 in [TIME]
+         + cpython-3.13.3-[PLATFORM] (python3.13)
+        ");
+        assert_eq!(bin_executables(&context), vec!["python3.12", "python3.13"]);
+    }
+
+    // On Windows, the windowed executable is linked alongside the minor versioned executable
+    #[cfg(windows)]
+    {
+        context
+            .python_install()
+            .arg("--preview")
+            .arg("--windowed")
+            .arg("3.13")
+            .assert()
+            .success();
+        assert_eq!(
+            bin_executables(&context),
+            vec!["python3.12.exe", "python3.13.exe", "pythonw3.13.exe"]
+        );
+    }
+}
+
+#[test]
+fn python_install_output_format_json() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let output = context
+        .python_install()
+        .arg("3.12")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let [installation] = summary["installations"].as_array().unwrap().as_slice() else {
+        panic!("expected a single installation: {summary}");
+    };
+    assert!(installation["key"]
+        .as_str()
+        .unwrap()
+        .starts_with("cpython-3.12.10-"));
+    assert_eq!(installation["result"], "installed");
+    assert_eq!(installation["status"], "fetched");
+    assert!(installation["downloaded_bytes"].as_u64().unwrap() > 0);
+    assert!(installation["download_seconds"].is_f64());
+    assert!(installation["post_install_seconds"].is_f64());
+
+    // Each post-install step is reported, and none warned
+    assert_eq!(
+        installation["steps"],
+        serde_json::json!([
+            {"name": "externally-managed", "warned": false},
+            {"name": "sysconfig-patched", "warned": false},
+            {"name": "canonical-executables", "warned": false},
+            {"name": "dylib-patched", "warned": false},
+        ])
+    );
+
+    // The dylib report is included, and a healthy installation has no failures
+    let dylib = &installation["dylib"];
+    assert!(dylib["examined"].is_array());
+    assert!(dylib["patched"].is_array());
+    assert_eq!(dylib["failed"], serde_json::json!([]));
+    assert_eq!(summary["failures"], serde_json::json!([]));
+
+    // An existing installation is reported as already installed
+    let output = context
+        .python_install()
+        .arg("3.12")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let installation = &summary["installations"][0];
+    assert_eq!(installation["result"], "already-installed");
+    assert_eq!(installation["status"], "already-available");
+    assert_eq!(installation["downloaded_bytes"], 0);
+    assert!(installation["download_seconds"].is_null());
+    assert!(installation["extract_seconds"].is_null());
+
+    Ok(())
+}
+
+#[test]
+fn python_install_download_only() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+
+    // Download an archive for another platform, without installing it
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12.9")
+        .arg("--platform")
+        .arg("linux-aarch64-gnu")
+        .arg("--download-only")
+        .arg("--out-dir")
+        .arg("vendor"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloaded 1 archive in [TIME]
+     + cpython-3.12.9-linux-aarch64-gnu (vendor/cpython-3.12.9-20250317-aarch64-unknown-linux-gnu-install_only_stripped.tar.gz)
+    ");
+
+    let metadata: serde_json::Value = serde_json::from_str(&context.read(
+        "vendor/cpython-3.12.9-20250317-aarch64-unknown-linux-gnu-install_only_stripped.tar.gz.json",
+    ))?;
+    assert_eq!(
+        metadata,
+        serde_json::json!({
+            "key": "cpython-3.12.9-linux-aarch64-gnu",
+            "url": "https://github.com/astral-sh/python-build-standalone/releases/download/20250317/cpython-3.12.9%2B20250317-aarch64-unknown-linux-gnu-install_only_stripped.tar.gz",
+            "sha256": "0354f70e7d3e2d0c36308edc1815c563d9bae1a3221830f7e222f6bb0a7e1a3a",
+        })
+    );
+
+    // Nothing is installed
+    context
+        .temp_dir
+        .child("managed")
+        .assert(predicate::path::missing());
+
+    // The platform must include an operating system and architecture
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12.9")
+        .arg("--platform")
+        .arg("linux")
+        .arg("--download-only")
+        .arg("--out-dir")
+        .arg("vendor"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'linux' for '--platform <PLATFORM>': Invalid platform `linux`; expected `<os>-<arch>[-<libc>]`, e.g., `linux-aarch64-gnu`
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_reinstall() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -269,21 +895,214 @@ fn python_install_automatic() {
         perms.set_mode(0o755);
         fs_err::set_permissions(&python, perms).unwrap();
 
-        // We should ignore the broken executable and download a version still
-        uv_snapshot!(context.filters(), context.run()
-            .env_remove("VIRTUAL_ENV")
-            // In tests, we ignore `PATH` during Python discovery so we need to add the context `bin`
-            .env("UV_TEST_PYTHON_PATH", context.bin_dir.as_os_str())
-            .arg("-p").arg("3.11")
-            .arg("python").arg("-c").arg("import sys; print(sys.version_info[:2])"), @r###"
-        success: true
-        exit_code: 0
-        ----- stdout -----
-        (3, 11)
+        // We should ignore the broken executable and download a version still
+        uv_snapshot!(context.filters(), context.run()
+            .env_remove("VIRTUAL_ENV")
+            // In tests, we ignore `PATH` during Python discovery so we need to add the context `bin`
+            .env("UV_TEST_PYTHON_PATH", context.bin_dir.as_os_str())
+            .arg("-p").arg("3.11")
+            .arg("python").arg("-c").arg("import sys; print(sys.version_info[:2])"), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        (3, 11)
+
+        ----- stderr -----
+        "###);
+    }
+}
+
+/// Test the `PATH` guidance and `--modify-path` when the bin directory is not on the `PATH`.
+#[test]
+#[cfg(unix)]
+fn python_install_preview_modify_path() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let zshenv = context.home_dir.child(".zshenv");
+
+    // Without `--modify-path`, print the command for the current shell.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--preview")
+        .env(EnvVars::SHELL, "/bin/zsh")
+        .env_remove(EnvVars::ZDOTDIR)
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::PATH), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM] (python, python3, python3.13)
+    warning: `[BIN]/` is not on your PATH. To use the installed Python executable, run `export PATH="[BIN]/:$PATH"`.
+    "#);
+
+    zshenv.assert(predicate::path::missing());
+
+    // With `--modify-path`, the shell configuration file should be updated.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--preview")
+        .arg("--reinstall")
+        .arg("--modify-path")
+        .env(EnvVars::SHELL, "/bin/zsh")
+        .env_remove(EnvVars::ZDOTDIR)
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::PATH), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     ~ cpython-3.13.3-[PLATFORM] (python, python3, python3.13)
+    Created configuration file: [HOME]/.zshenv
+    Restart your shell to apply changes
+    ");
+
+    // Repeating the install should not duplicate the entry.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--preview")
+        .arg("--reinstall")
+        .arg("--modify-path")
+        .env(EnvVars::SHELL, "/bin/zsh")
+        .env_remove(EnvVars::ZDOTDIR)
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::PATH), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     ~ cpython-3.13.3-[PLATFORM] (python, python3, python3.13)
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(fs_err::read_to_string(&zshenv).unwrap(), @r#"
+        # uv
+        export PATH="[BIN]/:$PATH"
+        "#);
+    });
+}
+
+/// Test the `PATH` guidance for Bash and Fish when the bin directory is not on the `PATH`.
+#[test]
+#[cfg(unix)]
+fn python_install_preview_path_guidance() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--preview")
+        .env(EnvVars::SHELL, "/bin/bash")
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::PATH), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM] (python, python3, python3.13)
+    warning: `[BIN]/` is not on your PATH. To use the installed Python executable, run `export PATH="[BIN]/:$PATH"`.
+    "#);
+
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--preview")
+        .arg("--reinstall")
+        .env(EnvVars::SHELL, "/usr/bin/fish")
+        .env_remove(EnvVars::ZSH_VERSION)
+        .env_remove(EnvVars::BASH_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::KSH_VERSION)
+        .env_remove(EnvVars::PATH), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     ~ cpython-3.13.3-[PLATFORM] (python, python3, python3.13)
+    warning: `[BIN]/` is not on your PATH. To use the installed Python executable, run `fish_add_path "[BIN]/"`.
+    "#);
+}
+
+/// Test the `PATH` guidance for PowerShell and Command Prompt when the bin directory is not on the
+/// `PATH`.
+#[test]
+#[cfg(windows)]
+fn python_install_preview_path_guidance() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
 
-        ----- stderr -----
-        "###);
-    }
+    // A PowerShell session extends the system-wide module path with the user's module directory.
+    let output = context
+        .python_install()
+        .arg("--preview")
+        .env(
+            EnvVars::PSMODULEPATH,
+            r"C:\Users\user\Documents\WindowsPowerShell\Modules;C:\Program Files\WindowsPowerShell\Modules;C:\Windows\system32\WindowsPowerShell\v1.0\Modules",
+        )
+        .env_remove(EnvVars::PROMPT)
+        .env_remove(EnvVars::SHELL)
+        .env_remove(EnvVars::PATH)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = apply_filters(
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+        context.filters(),
+    );
+    assert!(
+        stderr.contains(r#"run `$env:PATH = "[BIN]/;$env:PATH"`"#),
+        "{stderr}"
+    );
+
+    // Command Prompt sets `PROMPT`.
+    let output = context
+        .python_install()
+        .arg("--preview")
+        .arg("--reinstall")
+        .env(EnvVars::PROMPT, "$P$G")
+        .env_remove(EnvVars::SHELL)
+        .env_remove(EnvVars::PATH)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = apply_filters(
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+        context.filters(),
+    );
+    assert!(
+        stderr.contains(r#"run `set PATH="[BIN]/;%PATH%"`"#),
+        "{stderr}"
+    );
 }
 
 #[test]
@@ -459,6 +1278,63 @@ fn python_install_preview() {
     }
 }
 
+/// A link to an interpreter that uv does not manage is reported with its target and is only replaced
+/// with `--force`.
+#[test]
+#[cfg(unix)]
+fn python_install_preview_foreign_link() {
+    let context = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let bin_python = context.bin_dir.child("python3.13");
+    let foreign = context.temp_dir.child("foreign").child("python3.13");
+    foreign.touch().unwrap();
+    fs_err::create_dir_all(&context.bin_dir).unwrap();
+    fs_err::os::unix::fs::symlink(foreign.path(), bin_python.path()).unwrap();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.13"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install cpython-3.13.3-[PLATFORM]
+      Caused by: Executable already exists at `[BIN]/python3.13` (linked to `[TEMP_DIR]/foreign/python3.13`) but is not managed by uv; use `--force` to replace it
+    ");
+
+    // The foreign link is left as-is
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(
+            read_link_path(&bin_python), @"[TEMP_DIR]/foreign/python3.13"
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.13").arg("--force"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM] (python3.13)
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(
+            read_link_path(&bin_python), @"[TEMP_DIR]/managed/cpython-3.13.3-[PLATFORM]/bin/python3.13"
+        );
+    });
+
+    // The replaced interpreter itself is untouched
+    foreign.assert(predicate::path::exists());
+}
+
 #[test]
 fn python_install_preview_upgrade() {
     let context = TestContext::new_with_versions(&[])
@@ -659,7 +1535,7 @@ fn python_install_freethreaded() {
     error: No download found for request: cpython-3.12t-[PLATFORM]
     "###);
 
-    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r"
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("--yes"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -772,7 +1648,7 @@ fn python_install_default() {
     bin_python_default.assert(predicate::path::exists());
 
     // Uninstall
-    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r"
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("--yes"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1032,6 +1908,48 @@ fn python_install_unknown() {
     "###);
 }
 
+#[test]
+fn python_install_no_wait() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+
+    // Hold the lock on the managed Python directory, as a concurrent uv process would.
+    let managed = context.temp_dir.child("managed");
+    managed.create_dir_all().unwrap();
+    let lock = uv_fs::LockedFile::acquire_blocking(managed.join(".lock"), "test").unwrap();
+
+    // With `--no-wait`, we should fail rather than waiting for the lock.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.13").arg("--no-wait"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Lock for `managed` at `managed/.lock` is held by another process
+    ");
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.13").arg("--no-wait"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Lock for `managed` at `managed/.lock` is held by another process
+    ");
+
+    // Once the lock is released, we proceed (and find nothing to uninstall).
+    drop(lock);
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.13").arg("--no-wait"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.13
+    No existing installations found for: Python 3.13
+    No Python installations found matching the requests
+    ");
+}
+
 #[cfg(unix)]
 #[test]
 fn python_install_preview_broken_link() {
@@ -1115,7 +2033,7 @@ fn python_install_default_from_env() {
     "###);
 
     // We should ignore `UV_PYTHON` here and respect `--all`
-    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").env(EnvVars::UV_PYTHON, "3.11"), @r"
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("--yes").env(EnvVars::UV_PYTHON, "3.11"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1157,6 +2075,77 @@ fn python_install_default_from_env() {
     "###);
 }
 
+#[test]
+fn python_uninstall_all() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let mut filters = context.filters();
+    filters.push((r"\(\d+(\.\d)?[KMGT]?i?B\)", "([SIZE])"));
+
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("3.13")
+        .assert()
+        .success();
+
+    // Create a virtual environment that depends on one of the installations.
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Without `--yes`, the impact report is shown and confirmation is required.
+    uv_snapshot!(filters, context.python_uninstall().arg("--all"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    The following Python installations will be uninstalled:
+     - cpython-3.12.10-[PLATFORM] ([SIZE])
+     - cpython-3.13.3-[PLATFORM] ([SIZE])
+    The following environments use these installations and will no longer work:
+     - .venv (cpython-3.12.10-[PLATFORM])
+    error: Uninstalling all Python installations requires confirmation; use `--yes` to proceed
+    ");
+
+    // Nothing was removed.
+    context.python_find().arg("3.12").assert().success();
+    context.python_find().arg("3.13").assert().success();
+
+    uv_snapshot!(filters, context.python_uninstall().arg("--all").arg("--yes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled 2 versions in [TIME]
+     - cpython-3.12.10-[PLATFORM]
+     - cpython-3.13.3-[PLATFORM]
+    ");
+
+    // The installations are gone, but the environment is left in place.
+    context
+        .temp_dir
+        .child("managed")
+        .assert(predicate::path::missing());
+    context
+        .temp_dir
+        .child(".venv")
+        .child("pyvenv.cfg")
+        .assert(predicate::path::exists());
+}
+
 #[cfg(target_os = "macos")]
 #[test]
 fn python_install_patch_dylib() {
@@ -1208,13 +2197,24 @@ fn python_install_314() {
     let context: TestContext = TestContext::new_with_versions(&[])
         .with_filtered_python_keys()
         .with_filtered_exe_suffix()
+        .with_filtered_python_sources()
         .with_managed_python_dirs()
         .with_filtered_python_names()
         .with_filtered_python_install_bin();
 
-    // Install 3.14
+    // Only pre-releases of 3.14 are available, so they must be requested explicitly
     // For now, this provides test coverage of pre-release handling
     uv_snapshot!(context.filters(), context.python_install().arg("3.14"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No stable download found for request: cpython-3.14-[PLATFORM]; the pre-release cpython-3.14.0a6-[PLATFORM] is available, but pre-releases must be requested explicitly, e.g., `3.14.0a6`
+    ");
+
+    // Install 3.14, allowing pre-releases
+    uv_snapshot!(context.filters(), context.python_install().arg("3.14").arg("--allow-prereleases"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1235,6 +2235,16 @@ fn python_install_314() {
      + cpython-3.14.0a4-[PLATFORM]
     ");
 
+    // Without a version request, an installed pre-release should not be selected
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No interpreter found in [PYTHON SOURCES]; the pre-release Python 3.14.0a6 was found, but pre-releases must be requested explicitly, e.g., `3.14.0a6`
+    ");
+
     // We should be able to find this version without opt-in, because there is no stable release
     // installed
     uv_snapshot!(context.filters(), context.python_find().arg("3.14"), @r"
@@ -1380,3 +2390,151 @@ fn python_install_cached() {
       Caused by: An offline Python installation was requested, but cpython-3.12.10[DATE]-[PLATFORM].tar.gz) is missing in python-cache
     ");
 }
+
+#[test]
+fn python_install_mirror_manifest() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Download an archive by installing through the Python cache.
+    let python_cache = context.temp_dir.child("python-cache");
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("3.12.10")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("3.12.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions matching: Python 3.12.10
+    Uninstalled Python 3.12.10 in [TIME]
+     - cpython-3.12.10-[PLATFORM]
+    ");
+
+    // Populate a mirror with the archive, restoring its python-build-standalone filename, and an
+    // archive with an unparsable filename.
+    let mirror = context.temp_dir.child("mirror");
+    mirror.create_dir_all()?;
+    let cached = fs_err::read_dir(&python_cache)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .find(|filename| filename.contains("cpython-3.12.10-"))
+        .expect("the archive is cached");
+    let filename = cached[cached.find("cpython-").unwrap()..].replacen(
+        "cpython-3.12.10-",
+        "cpython-3.12.10+",
+        1,
+    );
+    fs_err::copy(python_cache.child(&cached), mirror.child(&filename))?;
+    mirror.child("cpython-3.12.10.tar.gz").touch()?;
+
+    uv_snapshot!(context.filters(), context
+        .python_manifest()
+        .arg("mirror")
+        .arg("--output")
+        .arg("manifest.json"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping `cpython-3.12.10.tar.gz`: the filename is not a `python-build-standalone` CPython archive
+    Wrote 1 Python download to manifest.json
+    ");
+
+    let manifest: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("manifest.json"),
+    )?)?;
+    let entries = manifest.as_object().unwrap();
+    assert_eq!(entries.len(), 1);
+    let url = entries.values().next().unwrap()["url"].as_str().unwrap();
+    assert!(url.starts_with("file://"), "{url}");
+    assert!(url.ends_with(&filename.replace('+', "%2B")), "{url}");
+
+    // Install from the mirror.
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("3.12.10")
+        .arg("--python-downloads-json-url")
+        .arg("manifest.json"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.10 in [TIME]
+     + cpython-3.12.10-[PLATFORM]
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn python_install_latest() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an older version and a pre-release of the next version
+    context
+        .python_install()
+        .arg("3.12")
+        .arg("3.14.0a6")
+        .assert()
+        .success();
+
+    // Neither satisfies a request for the latest version, so the newest stable release is
+    // installed
+    uv_snapshot!(context.filters(), context.python_install().arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.3 in [TIME]
+     + cpython-3.13.3-[PLATFORM]
+    ");
+
+    // Once installed, the request is satisfied
+    uv_snapshot!(context.filters(), context.python_install().arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // The pre-release should not be selected, even with `--allow-prereleases`
+    uv_snapshot!(context.filters(), context.python_install().arg("latest").arg("--allow-prereleases"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // The newest installed stable version is found
+    uv_snapshot!(context.filters(), context.python_find().arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/cpython-3.13.3-[PLATFORM]/[INSTALL-BIN]/python
+
+    ----- stderr -----
+    ");
+}
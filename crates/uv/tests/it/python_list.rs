@@ -477,3 +477,134 @@ fn python_list_downloads_installed() {
     ----- stderr -----
     ");
 }
+
+#[test]
+#[cfg(feature = "python-managed")]
+fn python_list_outdated() -> anyhow::Result<()> {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.11"])
+        .with_filtered_python_keys()
+        .with_filtered_python_names()
+        .with_filtered_python_install_bin()
+        .with_managed_python_dirs();
+
+    // Install an outdated patch version, and the latest patch version of another minor version
+    context.python_install().arg("3.12.9").assert().success();
+    context.python_install().arg("3.13").assert().success();
+
+    // Only the outdated installation is shown, skipping the current and system installations
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.9-[PLATFORM]    managed/cpython-3.12.9-[PLATFORM]/[INSTALL-BIN]/python (3.12.9 -> 3.12.10)
+
+    ----- stderr -----
+    ");
+
+    // In verbose mode, the upgrade is shown alongside the other installations
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("-v")
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout
+        .lines()
+        .any(|line| line.contains("cpython-3.12.9") && line.contains("(3.12.9 -> 3.12.10)")));
+    assert!(stdout
+        .lines()
+        .any(|line| line.contains("cpython-3.13.3") && !line.contains("(3.13.3 -> ")));
+    assert!(stdout
+        .lines()
+        .any(|line| line.contains("cpython-3.11") && !line.contains("(3.11")));
+
+    // Once the latest patch version is installed, nothing is outdated
+    context.python_install().arg("3.12.10").assert().success();
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// With `--offline`, `--outdated` falls back to the cached copy of remote download metadata.
+#[tokio::test]
+#[cfg(feature = "python-managed")]
+async fn python_list_outdated_offline() -> anyhow::Result<()> {
+    use assert_cmd::assert::OutputAssertExt;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_python_names()
+        .with_filtered_python_install_bin()
+        .with_managed_python_dirs();
+
+    context.python_install().arg("3.12.9").assert().success();
+
+    // Serve download metadata that only includes the latest 3.12 patch version
+    let metadata: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&fs_err::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../uv-python/download-metadata.json"),
+        )?)?;
+    let metadata = metadata
+        .into_iter()
+        .filter(|(key, _)| key.starts_with("cpython-3.12.10-"))
+        .collect::<serde_json::Map<_, _>>();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/downloads.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata))
+        .mount(&server)
+        .await;
+    let url = format!("{}/downloads.json", server.uri());
+
+    let server_pattern = regex::escape(&server.uri());
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(server_pattern.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    // The metadata has not been fetched yet, so it can't be used offline
+    uv_snapshot!(filters, context.python_list().arg("--outdated").arg("--offline").arg("--python-downloads-json-url").arg(&url), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The Python download metadata at `[SERVER]/downloads.json` has not been cached; run the command without `--offline` to fetch it
+    ");
+
+    // Fetch the metadata, which is cached
+    uv_snapshot!(filters, context.python_list().arg("--outdated").arg("--python-downloads-json-url").arg(&url), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.9-[PLATFORM]    managed/cpython-3.12.9-[PLATFORM]/[INSTALL-BIN]/python (3.12.9 -> 3.12.10)
+
+    ----- stderr -----
+    ");
+
+    // Offline, the cached metadata is used, and its age is shown
+    uv_snapshot!(filters, context.python_list().arg("--outdated").arg("--offline").arg("--python-downloads-json-url").arg(&url), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.9-[PLATFORM]    managed/cpython-3.12.9-[PLATFORM]/[INSTALL-BIN]/python (3.12.9 -> 3.12.10)
+
+    ----- stderr -----
+    Using cached Python download metadata, last fetched less than a minute ago
+    ");
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use crate::common::{uv_snapshot, TestContext};
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
 use insta::assert_snapshot;
 use uv_python::{
@@ -235,6 +236,7 @@ fn python_pin_global_if_no_local() -> Result<()> {
     3.11
 
     ----- stderr -----
+    Using global pin from `[UV_USER_CONFIG_DIR]/.python-version`
     "###);
 
     Ok(())
@@ -266,6 +268,7 @@ fn python_pin_global_use_local_if_available() -> Result<()> {
     3.12
 
     ----- stderr -----
+    Using global pin from `[UV_USER_CONFIG_DIR]/.python-version`
     "###);
 
     let mut global_version_path = PathBuf::from(uv.path());
@@ -328,6 +331,73 @@ fn python_pin_global_use_local_if_available() -> Result<()> {
     Ok(())
 }
 
+/// The global pin is used by discovery when there is no project pin, and a project pin takes
+/// precedence over it.
+#[test]
+fn python_pin_global_discovery() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12").arg("--global"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `[UV_USER_CONFIG_DIR]/.python-version` to `3.12`
+
+    ----- stderr -----
+    ");
+
+    // Without a project pin, the global pin is used instead of the first interpreter on the PATH.
+    uv_snapshot!(context.filters(), context.venv(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--system").arg("--show-source"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    Requested `3.12` from global version file at `[UV_USER_CONFIG_DIR]/.python-version`
+    ");
+
+    // A project pin overrides the global pin.
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+
+    uv_snapshot!(context.filters(), context.venv(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().arg("--system").arg("--show-source"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    Requested `3.11` from version file at `.python-version`
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_pin_global_creates_parent_dirs() {
     let context: TestContext = TestContext::new_with_versions(&["3.12"]);
@@ -776,6 +846,224 @@ fn python_pin_resolve() {
     });
 }
 
+/// Pinning `latest` writes the newest stable version, rather than the alias.
+#[test]
+fn python_pin_latest() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12", "3.13"]);
+
+    // The newest installed version is pinned, even though it's not first on the path
+    uv_snapshot!(context.filters(), context.python_pin().arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.13.[X]`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @"3.13.[X]");
+    });
+
+    // With `--resolved`, the interpreter path is pinned instead
+    uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.python-version` from `3.13.[X]` -> `[PYTHON-3.13]`
+
+    ----- stderr -----
+    ");
+}
+
+/// Pinning `latest` without an installed Python resolves the version from the available
+/// downloads.
+#[test]
+fn python_pin_latest_no_python() {
+    let context: TestContext = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("latest"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.13.3`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @"3.13.3");
+}
+
+/// With `--exact`, the fully-resolved patch version of the installed interpreter is pinned.
+#[test]
+fn python_pin_exact() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12", "3.13"]);
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--exact").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Resolved `3.12` to `3.12.[X]`
+    Pinned `.python-version` to `3.12.[X]`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @"3.12.[X]");
+    });
+
+    // Without a request, the existing pin is resolved
+    context.python_pin().arg("3.13").assert().success();
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--exact"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Resolved `3.13` to `3.13.[X]`
+    Updated `.python-version` from `3.13` -> `3.13.[X]`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(python_version, @"3.13.[X]");
+    });
+
+    // `--exact` and `--resolved` are mutually exclusive
+    uv_snapshot!(context.filters(), context.python_pin().arg("--exact").arg("--resolved").arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exact' cannot be used with '--resolved'
+
+    Usage: uv python pin --cache-dir [CACHE_DIR] --exact [REQUEST]
+
+    For more information, try '--help'.
+    ");
+}
+
+/// With `--exact`, a missing interpreter is an error unless the available downloads are used.
+#[test]
+fn python_pin_exact_no_python() {
+    let context: TestContext = TestContext::new_with_versions(&[]);
+
+    if cfg!(windows) {
+        uv_snapshot!(context.filters(), context.python_pin().arg("--exact").arg("3.12"), @r"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for Python 3.12 in managed installations, search path, or registry
+        ");
+    } else {
+        uv_snapshot!(context.filters(), context.python_pin().arg("--exact").arg("3.12"), @r"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for Python 3.12 in managed installations or search path
+        ");
+    }
+
+    // Nothing is written
+    assert!(!context.temp_dir.join(PYTHON_VERSION_FILENAME).exists());
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--exact").arg("--from-downloads").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Resolved `3.12` to `3.12.10`
+    Pinned `.python-version` to `3.12.10`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @"3.12.10");
+}
+
+/// Remove the Python version pin that currently applies with `--rm`.
+#[test]
+fn python_pin_rm() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Without a pin, there's nothing to remove.
+    uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No pinned Python version found
+    ");
+
+    context.python_pin().arg("3.12").assert().success();
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--rm"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Removed `.python-version`
+
+    ----- stderr -----
+    ");
+
+    assert!(!context.temp_dir.child(PYTHON_VERSION_FILENAME).exists());
+
+    // The nearest pin in an ancestor directory is removed, leaving pins further up intact.
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+    let parent = context.temp_dir.child("parent");
+    parent.child(PYTHON_VERSION_FILENAME).write_str("3.12")?;
+    let child = parent.child("child");
+    child.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("--rm").current_dir(&child), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Removed `[TEMP_DIR]/parent/.python-version`
+
+    ----- stderr -----
+    ");
+
+    assert!(!parent.child(PYTHON_VERSION_FILENAME).exists());
+    assert!(context.temp_dir.child(PYTHON_VERSION_FILENAME).exists());
+
+    // A version request is ambiguous alongside `--rm`.
+    uv_snapshot!(context.filters(), context.python_pin().arg("--rm").arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--rm' cannot be used with '[REQUEST]'
+
+    Usage: uv python pin --cache-dir [CACHE_DIR] --rm
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_pin_with_comments() -> Result<()> {
     let context = TestContext::new_with_versions(&[]);
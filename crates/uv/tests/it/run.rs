@@ -139,6 +139,111 @@ fn run_with_python_version() -> Result<()> {
     Ok(())
 }
 
+/// Requesting an exact patch version that the project environment doesn't provide should error,
+/// unless `--recreate-venv` is provided.
+#[test]
+fn run_exact_patch_mismatch() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.11, <4"
+        dependencies = []
+        "#
+    })?;
+
+    // Determine the exact patch version of the Python 3.11 interpreter.
+    let output = std::process::Command::new(&context.python_versions[1].1)
+        .arg("-c")
+        .arg("import platform; print(platform.python_version())")
+        .output()?;
+    let python_311 = String::from_utf8(output.stdout)?.trim().to_string();
+
+    // Create the project environment with Python 3.12.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Requesting an exact patch version that the environment doesn't provide is an error.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("-p")
+        .arg(&python_311)
+        .arg("python")
+        .arg("--version")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The project environment at `.venv` uses Python 3.12.[X], but Python 3.11.[X] was requested. Use `--recreate-venv` to recreate the environment with the requested version.
+    "###);
+
+    // The same error is raised by `uv sync`.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("-p")
+        .arg(&python_311)
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The project environment at `.venv` uses Python 3.12.[X], but Python 3.11.[X] was requested. Use `--recreate-venv` to recreate the environment with the requested version.
+    "###);
+
+    // With `--recreate-venv`, the environment is rebuilt with the requested version.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--recreate-venv")
+        .arg("-p")
+        .arg(&python_311)
+        .arg("python")
+        .arg("--version")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.11.[X]
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Once the environment provides the requested version, it's used as-is.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("-p")
+        .arg(&python_311)
+        .arg("python")
+        .arg("--version")
+        .env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.11.[X]
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_args() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3008,6 +3113,54 @@ fn run_module_stdin() {
     "###);
 }
 
+/// Pass arguments to the interpreter with `--python-args`.
+#[test]
+fn run_python_args() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r"
+        import sys
+        print(sys._xoptions)
+        print(sys.argv[1:])
+       "
+    })?;
+
+    // The arguments are passed to the interpreter, ahead of the script.
+    uv_snapshot!(context.filters(), context.run().arg("--python-args").arg("-X dev -X 'utf8'").arg("main.py").arg("-X").arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {'dev': True, 'utf8': True}
+    ['-X', 'foo']
+
+    ----- stderr -----
+    "###);
+
+    // And ahead of `-m`.
+    uv_snapshot!(context.filters(), context.run().arg("--python-args").arg("-X dev").arg("-m").arg("main"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {'dev': True}
+    []
+
+    ----- stderr -----
+    "###);
+
+    // Unterminated quotes are rejected.
+    uv_snapshot!(context.filters(), context.run().arg("--python-args").arg("-X 'dev").arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse `--python-args`: unterminated quote or trailing backslash in `-X 'dev`
+    "###);
+
+    Ok(())
+}
+
 /// When the `pyproject.toml` file is invalid.
 #[test]
 fn run_project_toml_error() -> Result<()> {
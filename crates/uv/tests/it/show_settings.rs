@@ -148,7 +148,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -329,7 +331,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -511,7 +515,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -725,7 +731,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -875,7 +883,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1068,7 +1078,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1308,7 +1320,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1557,7 +1571,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1763,7 +1779,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1935,7 +1953,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2165,7 +2185,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2378,7 +2400,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2549,7 +2573,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2704,7 +2730,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2859,7 +2887,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3016,7 +3046,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3163,6 +3195,7 @@ fn resolve_tool() -> anyhow::Result<()> {
     ToolInstallSettings {
         package: "requirements.in",
         from: None,
+        extras: [],
         with: [],
         with_requirements: [],
         with_editable: [],
@@ -3247,7 +3280,9 @@ fn resolve_tool() -> anyhow::Result<()> {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_install_mirrors: None,
             python_downloads_json_url: None,
+            python_externally_managed: None,
         },
     }
 
@@ -3357,7 +3392,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3573,7 +3610,9 @@ fn resolve_both() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3879,7 +3918,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4128,7 +4169,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4286,7 +4329,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4463,7 +4508,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4699,7 +4746,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4914,7 +4963,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5135,7 +5186,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5351,7 +5404,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5574,7 +5629,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5790,7 +5847,9 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5930,10 +5989,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5952,7 +6015,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6078,10 +6143,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6100,7 +6169,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6224,10 +6295,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6246,7 +6321,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6372,10 +6449,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6394,7 +6475,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6518,10 +6601,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6540,7 +6627,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6665,10 +6754,14 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        user: false,
+        isolated: false,
+        test_command: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6687,7 +6780,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_install_mirrors: None,
                 python_downloads_json_url: None,
+                python_externally_managed: None,
             },
             system: false,
             extras: ExtrasSpecification(
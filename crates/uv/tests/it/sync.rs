@@ -43,6 +43,66 @@ fn sync() -> Result<()> {
     Ok(())
 }
 
+/// Respect `tool.uv.required-version` before running any other operations.
+#[test]
+fn sync_required_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        required-version = "<0.1.0"
+        "#,
+    )?;
+
+    let mut filters = context.filters();
+    filters.push((r"running version `[^`]+`", "running version `[VERSION]`"));
+
+    uv_snapshot!(filters, context.sync(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Required uv version `<0.1.0` does not match the running version `[VERSION]`
+    "###);
+
+    // Nothing should have been written.
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        required-version = ">=0.1.0"
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn locked() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -414,6 +474,166 @@ fn check() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn verify_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // The freshly-synced environment should match the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--verify-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    "###);
+
+    // Modify and remove installed files.
+    let iniconfig = context.site_packages().join("iniconfig");
+    fs_err::write(iniconfig.join("py.typed"), "tampered\n")?;
+    fs_err::remove_file(iniconfig.join("exceptions.py"))?;
+
+    // The mismatches should be reported, without modifying the environment.
+    uv_snapshot!(context.filters(), context.sync().arg("--verify-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 2 mismatches in the project environment:
+     - iniconfig==2.0.0: `iniconfig/exceptions.py` is missing
+     - iniconfig==2.0.0: `iniconfig/py.typed` has hash `sha256=kueNCwMpYvR3kqn6lf2YHvY-Hj7wdNU21jBMde3b4p8`, but expected `sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU`
+    "###);
+
+    assert!(!iniconfig.join("exceptions.py").exists());
+
+    Ok(())
+}
+
+/// Verify an environment in which a locked package was removed, or installed at another version.
+#[test]
+fn verify_hashes_missing_and_drift() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // Remove a locked package from the environment.
+    context.pip_uninstall().arg("sniffio").assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--verify-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 1 mismatch in the project environment:
+     - sniffio==1.3.1: is not installed
+    "###);
+
+    // Install a locked package at a different version.
+    context
+        .pip_install()
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--verify-hashes"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 2 mismatches in the project environment:
+     - iniconfig==1.1.1: installed version does not match the lockfile (`2.0.0`)
+     - sniffio==1.3.1: is not installed
+    "###);
+
+    // Neither change should be reverted by the verification.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==1.1.1
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Reinstall a single package with `--reinstall-package`, leaving the others untouched.
+#[test]
+fn sync_reinstall_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["markupsafe==2.1.3", "tomli==2.0.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###);
+
+    // Only `tomli` should be reinstalled.
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("tomli"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tomli==2.0.1
+    "###);
+
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
 /// Sync development dependencies in a (legacy) non-project workspace root.
 #[test]
 fn sync_legacy_non_project_dev_dependencies() -> Result<()> {
@@ -9331,7 +9551,7 @@ fn sync_when_virtual_environment_incompatible_with_interpreter() -> Result<()> {
     }, {
         let contents = fs_err::read_to_string(&pyvenv_cfg).unwrap();
         let lines: Vec<&str> = contents.split('\n').collect();
-        assert_snapshot!(lines[3], @r###"
+        assert_snapshot!(lines[4], @r###"
         version_info = 3.12.[X]
         "###);
     });
@@ -9372,7 +9592,7 @@ fn sync_when_virtual_environment_incompatible_with_interpreter() -> Result<()> {
     }, {
         let contents = fs_err::read_to_string(&pyvenv_cfg).unwrap();
         let lines: Vec<&str> = contents.split('\n').collect();
-        assert_snapshot!(lines[3], @r###"
+        assert_snapshot!(lines[4], @r###"
         version_info = 3.12.[X]
         "###);
     });
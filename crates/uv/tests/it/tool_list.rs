@@ -35,6 +35,57 @@ fn tool_list() {
     "###);
 }
 
+#[test]
+fn tool_list_json() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // With no tools installed, an empty list is printed.
+    uv_snapshot!(context.filters(), context.tool_list().arg("--output-format").arg("json")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    ");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list().arg("--output-format").arg("json")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"black","version":"24.2.0","entrypoints":["black","blackd"],"python":"3.12.[X]"}]
+
+    ----- stderr -----
+    "#);
+
+    // `--json` is equivalent to `--output-format json`.
+    uv_snapshot!(context.filters(), context.tool_list().arg("--json")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"black","version":"24.2.0","entrypoints":["black","blackd"],"python":"3.12.[X]"}]
+
+    ----- stderr -----
+    "#);
+}
+
 #[test]
 fn tool_list_paths() {
     let context = TestContext::new("3.12").with_filtered_exe_suffix();
@@ -2737,3 +2737,75 @@ fn tool_run_windows_runnable_types() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn tool_run_from_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest==8.0.0"]
+        "#
+    })?;
+
+    // Without a lockfile, `--from-lockfile` should fail.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-lockfile")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No lockfile found at `uv.lock`; run `uv lock` to create one
+    "###);
+
+    context.lock().assert().success();
+
+    // The locked version should be used.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-lockfile")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    // Tools that aren't in the lockfile should be rejected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-lockfile")
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `black` is not pinned in the lockfile
+    "###);
+
+    Ok(())
+}
@@ -48,6 +48,68 @@ fn nested_dependencies() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn depth() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "scikit-learn==1.4.1.post1"
+        ]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--universal").arg("--depth").arg("0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── [1 more dependency...]
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--universal").arg("--depth").arg("1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── scikit-learn v1.4.1.post1
+        └── [4 more dependencies...]
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.tree().arg("--universal").arg("--depth").arg("2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── scikit-learn v1.4.1.post1
+        ├── joblib v1.3.2
+        ├── numpy v1.26.4
+        ├── scipy v1.12.0
+        │   └── [1 more dependency...]
+        └── threadpoolctl v3.4.0
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn nested_platform_dependencies() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -771,8 +833,10 @@ fn package() -> Result<()> {
     success: true
     exit_code: 0
     ----- stdout -----
-    scipy v1.12.0
-    └── numpy v1.26.4
+    project v0.1.0
+    └── scikit-learn v1.4.1.post1
+        └── scipy v1.12.0
+            └── numpy v1.26.4
 
     ----- stderr -----
     Resolved 11 packages in [TIME]
@@ -804,6 +868,106 @@ fn package() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn why() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["scikit-learn==1.4.1.post1", "pandas"]
+
+        [dependency-groups]
+        dev = ["python-dateutil"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("numpy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 → pandas v2.2.1 → numpy v1.26.4
+    project v0.1.0 → scikit-learn v1.4.1.post1 → numpy v1.26.4
+    project v0.1.0 → scikit-learn v1.4.1.post1 → scipy v1.12.0 → numpy v1.26.4
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    "###
+    );
+
+    // Paths through dependency groups are annotated.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("six"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 → pandas v2.2.1 → python-dateutil v2.9.0.post0 → six v1.16.0
+    project v0.1.0 → python-dateutil v2.9.0.post0 (group: dev) → six v1.16.0
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    "###
+    );
+
+    // Pruned packages are not considered.
+    uv_snapshot!(context.filters(), context.tree().arg("--why").arg("numpy").arg("--prune").arg("scikit-learn"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0 → pandas v2.2.1 → numpy v1.26.4
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"name":"project","version":"0.1.0","dependencies":[{"name":"anyio","version":"4.3.0","extras":[],"dependencies":[{"name":"idna","version":"3.6","extras":[],"dependencies":[]},{"name":"sniffio","version":"1.3.1","extras":[],"dependencies":[]}]}]}
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    // Selecting a package nests it under the project.
+    uv_snapshot!(context.filters(), context.tree().arg("--json").arg("--package").arg("sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"name":"project","version":"0.1.0","dependencies":[{"name":"sniffio","version":"1.3.1","extras":[],"dependencies":[]}]}
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn group() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -961,9 +1125,19 @@ fn cycle() -> Result<()> {
     success: true
     exit_code: 0
     ----- stdout -----
-    six v1.16.0
-    traceback2 v1.4.0
-    └── linecache2 v1.0.0
+    project v0.1.0
+    ├── fixtures v3.0.0
+    │   ├── six v1.16.0
+    │   └── testtools v2.3.0
+    │       ├── fixtures v3.0.0 (*)
+    │       ├── six v1.16.0
+    │       ├── traceback2 v1.4.0
+    │       │   └── linecache2 v1.0.0
+    │       └── unittest2 v1.1.0
+    │           ├── six v1.16.0
+    │           └── traceback2 v1.4.0 (*)
+    └── testtools v2.3.0 (*)
+    (*) Package tree already displayed
 
     ----- stderr -----
     Resolved 11 packages in [TIME]
@@ -1000,6 +1174,48 @@ fn cycle() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn cycles() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["testtools==2.3.0", "fixtures==3.0.0"]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--cycles"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    fixtures v3.0.0 → testtools v2.3.0 → fixtures v3.0.0
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    Found 1 dependency cycle
+    "###
+    );
+
+    // Pruning a package in the cycle removes it.
+    uv_snapshot!(context.filters(), context.tree().arg("--cycles").arg("--prune").arg("testtools"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 11 packages in [TIME]
+    No dependency cycles found
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn workspace_dev() -> Result<()> {
     let context = TestContext::new("3.12");
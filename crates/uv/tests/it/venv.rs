@@ -1,12 +1,17 @@
+use std::path::Path;
+
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use predicates::prelude::*;
-use uv_python::{PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME};
+use uv_cache::Cache;
+use uv_python::{
+    Interpreter, PyVenvConfiguration, PYTHON_VERSIONS_FILENAME, PYTHON_VERSION_FILENAME,
+};
 use uv_static::EnvVars;
 
-use crate::common::{uv_snapshot, TestContext};
+use crate::common::{uv_snapshot, venv_to_interpreter, TestContext};
 
 #[test]
 fn create_venv() {
@@ -626,6 +631,153 @@ fn seed_older_python_version() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+/// The activation hint should match the shell provided via `--shell` or `UV_VENV_SHELL`.
+#[test]
+#[cfg(unix)]
+fn activate_hint_shell() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Don't normalize the activation command, which is what's under test.
+    let filters = context
+        .filters()
+        .into_iter()
+        .filter(|(pattern, _)| !pattern.starts_with("Activate with"))
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.venv().arg("--shell").arg("bash"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/bin/activate
+    ");
+
+    uv_snapshot!(filters, context.venv().arg("--shell").arg("fish"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/bin/activate.fish
+    ");
+
+    uv_snapshot!(filters, context.venv().arg("--shell").arg("nushell"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: overlay use .venv/bin/activate.nu
+    ");
+
+    uv_snapshot!(filters, context.venv().arg("--shell").arg("csh"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/bin/activate.csh
+    ");
+
+    uv_snapshot!(filters, context.venv().arg("--shell").arg("powershell"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: .venv/bin/activate.ps1
+    ");
+
+    uv_snapshot!(filters, context.venv().env(EnvVars::UV_VENV_SHELL, "cmd"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: .venv/bin/activate.bat
+    ");
+}
+
+#[test]
+fn seed_packages() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Without a value, the default seed packages are installed.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment with seed packages at: .venv
+     + pip==24.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.child("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("seed-packages = pip==24.0"));
+
+    // An explicit set of seed packages replaces the defaults, even on Python 3.12+.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed=pip==23.3.1,setuptools")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment with seed packages at: .venv
+     + pip==23.3.1
+     + setuptools==69.2.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.child("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("seed-packages = pip==23.3.1,setuptools==69.2.0"));
+
+    // Seed packages must be registry requirements.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed=pip @ https://example.com/pip-24.0-py3-none-any.whl")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'pip @ https://example.com/pip-24.0-py3-none-any.whl' for '--seed[=<PACKAGES>]': seed packages must be a package name with an optional version specifier, e.g., `pip==24.0`
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn create_venv_unknown_python_minor() {
     let context = TestContext::new_with_versions(&["3.12"]);
@@ -872,6 +1024,109 @@ fn non_empty_dir_exists_allow_existing() -> Result<()> {
     Ok(())
 }
 
+/// Re-creating a virtual environment with `--allow-existing` should leave unchanged files alone.
+#[test]
+fn allow_existing_unchanged() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    let scripts = if cfg!(windows) { "Scripts" } else { "bin" };
+    let activate = context.venv.child(scripts).child("activate");
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let activate_mtime = fs_err::metadata(&activate)?.modified()?;
+    let pyvenv_cfg_mtime = fs_err::metadata(&pyvenv_cfg)?.modified()?;
+
+    // Running again with the same options should not rewrite any files.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    assert_eq!(fs_err::metadata(&activate)?.modified()?, activate_mtime);
+    assert_eq!(fs_err::metadata(&pyvenv_cfg)?.modified()?, pyvenv_cfg_mtime);
+
+    // Changing the options should rewrite the affected files.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--prompt")
+        .arg("foo")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    assert!(fs_err::read_to_string(&pyvenv_cfg)?.contains("prompt = foo"));
+    assert!(fs_err::read_to_string(&activate)?.contains("foo"));
+
+    // The seed packages are recorded after seeding, but re-running with the same seed packages
+    // should not rewrite `pyvenv.cfg` either.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--seed")
+        .arg("--prompt")
+        .arg("foo")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    assert!(fs_err::read_to_string(&pyvenv_cfg)?.contains("seed-packages = pip==24.0"));
+    let pyvenv_cfg_mtime = fs_err::metadata(&pyvenv_cfg)?.modified()?;
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--allow-existing")
+        .arg("--seed")
+        .arg("--prompt")
+        .arg("foo")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    assert_eq!(fs_err::metadata(&pyvenv_cfg)?.modified()?, pyvenv_cfg_mtime);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(windows)]
 fn windows_shims() -> Result<()> {
@@ -1145,3 +1400,258 @@ fn create_venv_apostrophe() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert_eq!(stdout.trim(), venv_dir.to_string_lossy());
 }
+
+/// The scheme computed by the base interpreter for a given prefix should match the layout of the
+/// virtual environment created at that prefix.
+#[test]
+fn venv_interpreter_scheme() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let cache = Cache::temp()?.init()?;
+    let (_, base_python) = &context.python_versions[0];
+    let base = Interpreter::query(base_python, &cache)?;
+    let venv = Interpreter::query(venv_to_interpreter(&context.venv), &cache)?;
+
+    // The computed scheme should match the paths reported by the virtual environment itself.
+    let expected = venv.scheme_for(&context.venv);
+    let actual = base.scheme_for(&context.venv);
+    for (expected, actual, venv) in [
+        (&expected.purelib, &actual.purelib, venv.purelib()),
+        (&expected.platlib, &actual.platlib, venv.platlib()),
+        (&expected.scripts, &actual.scripts, venv.scripts()),
+    ] {
+        assert_eq!(expected, actual);
+        assert_eq!(fs_err::canonicalize(actual)?, fs_err::canonicalize(venv)?);
+    }
+
+    // The interpreter should report its `sysconfig` variables.
+    let vars = base.sysconfig_vars();
+    assert!(vars.platstdlib().is_some_and(Path::is_dir));
+    assert!(vars
+        .include()
+        .is_some_and(|include| include.ends_with("python3.12") || include.ends_with("Include")));
+    assert!(vars
+        .ext_suffix()
+        .is_some_and(|suffix| suffix.ends_with(".so") || suffix.ends_with(".pyd")));
+    if cfg!(unix) {
+        assert!(vars
+            .soabi()
+            .is_some_and(|soabi| soabi.starts_with("cpython-312")));
+    }
+
+    Ok(())
+}
+
+/// Re-link a virtual environment after its base interpreter was moved.
+#[test]
+#[cfg(unix)]
+fn relink_venv() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Without an existing environment, `--relink` creates a new one.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--prompt")
+        .arg("foo")
+        .arg("--relink"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    // Installed packages should be retained when re-linking.
+    context.pip_install().arg("iniconfig").assert().success();
+
+    // Simulate the base interpreter being moved.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?;
+    let home = contents
+        .lines()
+        .find_map(|line| line.strip_prefix("home = "))
+        .unwrap()
+        .to_string();
+    let missing = context.temp_dir.child("missing").child("bin");
+    fs_err::write(
+        &pyvenv_cfg,
+        PyVenvConfiguration::set(&contents, "home", missing.to_str().unwrap()),
+    )?;
+    let python = context.venv.child("bin").child("python");
+    fs_err::remove_file(&python)?;
+    fs_err::os::unix::fs::symlink(missing.child("python3"), &python)?;
+
+    // The environment is now broken.
+    std::process::Command::new(venv_to_interpreter(&context.venv))
+        .arg("--version")
+        .assert()
+        .failure();
+
+    let home_pattern = regex::escape(&home);
+    let mut filters = context.filters();
+    filters.insert(0, (&home_pattern, "[HOME]"));
+
+    uv_snapshot!(filters, context.venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--relink"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Relinking virtual environment at: .venv
+    Updated base interpreter: [TEMP_DIR]/missing/bin -> [HOME]
+    Regenerated executables, activation scripts, and `pyvenv.cfg`
+    "
+    );
+
+    // The environment should work again, with its packages and prompt intact.
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("--version"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.[X]
+
+    ----- stderr -----
+    "
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?;
+    assert!(contents.contains(&format!("home = {home}")), "{contents}");
+    assert!(contents.contains("prompt = foo"), "{contents}");
+
+    Ok(())
+}
+
+/// Re-linking a virtual environment to a different Python minor version is not supported.
+#[test]
+fn relink_venv_incompatible_version() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Pretend that the environment was created with Python 3.11.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?;
+    fs_err::write(
+        &pyvenv_cfg,
+        PyVenvConfiguration::set(&contents, "version_info", "3.11.9"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--relink"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Relinking virtual environment at: .venv
+    uv::venv::relink
+
+      × Failed to relink virtualenv
+      ╰─▶ The virtual environment was created with Python 3.11.9, which is incompatible with Python 3.12.[X]; recreate the environment to change the minor version
+    "
+    );
+
+    Ok(())
+}
+
+/// Check a virtual environment against the base interpreter recorded in its `pyvenv.cfg`.
+#[test]
+fn check_venv() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv().arg("--check"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    uv::venv::missing
+
+      × No virtual environment found at `.venv`
+    "
+    );
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let contents = fs_err::read_to_string(&pyvenv_cfg)?;
+    let current = PyVenvConfiguration::parse(&pyvenv_cfg)?
+        .python_key()
+        .expect("`uv-python-key` should be recorded")
+        .to_string();
+
+    let current_pattern = regex::escape(&current);
+    let mut filters = context.filters();
+    filters.insert(0, (&current_pattern, "[CURRENT]"));
+
+    uv_snapshot!(filters, context.venv().arg("--check"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    The virtual environment at .venv matches its base interpreter: [CURRENT]
+    "
+    );
+
+    // Simulate the managed Python installation being upgraded to a new patch version after the
+    // environment was created.
+    let (implementation, rest) = current.split_once('-').unwrap();
+    let (_, platform) = rest.split_once('-').unwrap();
+    let recorded = format!("{implementation}-3.12.0-{platform}");
+    assert_ne!(recorded, current);
+    fs_err::write(
+        &pyvenv_cfg,
+        PyVenvConfiguration::set(&contents, "uv-python-key", &recorded),
+    )?;
+
+    let recorded_pattern = regex::escape(&recorded);
+    filters.insert(0, (&recorded_pattern, "[RECORDED]"));
+
+    uv_snapshot!(filters, context.venv().arg("--check"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    The base interpreter of the virtual environment at .venv has changed since it was created:
+    - [RECORDED]
+    + [CURRENT]
+    Use `uv venv --relink` to update the environment
+    "
+    );
+
+    Ok(())
+}